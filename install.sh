@@ -162,16 +162,73 @@ setup_aliases() {
     cat >> "$config_file" << 'EOF'
 
 # Git Navigator aliases
-alias gs="git-navigator status"
-alias ga="git-navigator add"
-alias gd="git-navigator diff"
-alias grs="git-navigator reset"
-alias gco="git-navigator checkout"
-alias gb="git-navigator branches"
-alias gcb="git-navigator checkout-branch"
-alias gl="git log --graph --pretty=format:'%Cred%h%Creset -%C(yellow)%d%Creset %s %Cgreen(%cr) %C(bold blue)<%an>%Creset' --abbrev-commit"
+alias gs="GIT_NAVIGATOR_ALIAS=gs git-navigator status"
+alias ga="GIT_NAVIGATOR_ALIAS=ga git-navigator add"
+alias gd="GIT_NAVIGATOR_ALIAS=gd git-navigator diff"
+alias grs="GIT_NAVIGATOR_ALIAS=grs git-navigator reset"
+alias gco="GIT_NAVIGATOR_ALIAS=gco git-navigator checkout"
+alias gb="GIT_NAVIGATOR_ALIAS=gb git-navigator branches"
+alias gcb="GIT_NAVIGATOR_ALIAS=gcb git-navigator checkout-branch"
+alias gl="GIT_NAVIGATOR_ALIAS=gl git-navigator log"
+alias gc="GIT_NAVIGATOR_ALIAS=gc git-navigator commit"
+alias ge="GIT_NAVIGATOR_ALIAS=ge git-navigator edit"
 EOF
 
+    # gcd lists changed directories, grepo known repositories and gwt worktrees;
+    # each cds into one by index
+    if [[ "$config_file" == *.fish ]]; then
+        cat >> "$config_file" << 'EOF'
+function gcd
+    if test (count $argv) -eq 0
+        git-navigator dirs
+    else
+        set -l dir (git-navigator dirs $argv[1]); and cd $dir; or printf '%s\n' $dir
+    end
+end
+function grepo
+    if test (count $argv) -eq 0
+        git-navigator repos
+    else
+        set -l dir (git-navigator repos $argv[1]); and cd $dir; or printf '%s\n' $dir
+    end
+end
+function gwt
+    if test (count $argv) -eq 0
+        git-navigator worktrees
+    else
+        set -l dir (git-navigator worktrees $argv[1]); and cd $dir; or printf '%s\n' $dir
+    end
+end
+EOF
+    else
+        cat >> "$config_file" << 'EOF'
+gcd() {
+    if [ $# -eq 0 ]; then
+        git-navigator dirs
+    else
+        local dir
+        dir="$(git-navigator dirs "$1")" && cd "$dir" || printf '%s\n' "$dir"
+    fi
+}
+grepo() {
+    if [ $# -eq 0 ]; then
+        git-navigator repos
+    else
+        local dir
+        dir="$(git-navigator repos "$1")" && cd "$dir" || printf '%s\n' "$dir"
+    fi
+}
+gwt() {
+    if [ $# -eq 0 ]; then
+        git-navigator worktrees
+    else
+        local dir
+        dir="$(git-navigator worktrees "$1")" && cd "$dir" || printf '%s\n' "$dir"
+    fi
+}
+EOF
+    fi
+
     echo "✓ Aliases added to $config_file"
 }
 
@@ -224,7 +281,11 @@ main() {
         echo "  gco   - Checkout files by index"
         echo "  gb    - Show numbered branches"
         echo "  gcb   - Checkout branch by index"
-        echo "  gl    - Visual git log"
+        echo "  gcd   - cd into a changed directory by index"
+        echo "  grepo - cd into a repository git-navigator has seen, by index"
+        echo "  gwt   - cd into a worktree by index"
+        echo "  gl    - Numbered git log"
+        echo "  gc    - Commit staged changes, or files by index"
         echo -e "\nExample usage:"
         echo "  gs              # Show numbered file status"
         echo "  ga 1 3-5        # Add files 1, 3, 4, 5"
@@ -0,0 +1,212 @@
+//! In-process command API.
+//!
+//! [`run_command`] runs the numbered-file commands (`gs`, `ga`, `gd`, `grs`
+//! and `gco <index>`) without spawning the binary. It goes through the same
+//! index validation and file cache as the CLI, but returns a
+//! [`CommandReport`] instead of printing, so a TUI, a daemon or another
+//! program can show the result its own way.
+//!
+//! Like `ga`, `grs` and `gco`, the commands that change files save the
+//! renumbered list they report in [`CommandReport::status`], so later indices
+//! refer to it. A list that went stale in the meantime is refreshed first, and
+//! indices that now name other files fail with
+//! [`GitNavigatorError::StaleFileList`].
+//!
+//! # Example
+//! ```no_run
+//! use git_navigator::api::{run_command, AppContext, Command};
+//!
+//! let context = AppContext::new("/path/to/repo");
+//! let status = run_command(Command::Status, &context)?;
+//! println!("{} changed file(s)", status.status.len());
+//!
+//! let report = run_command(Command::Add { indices: vec!["1-3".into()] }, &context)?;
+//! for warning in &report.warnings {
+//!     eprintln!("{warning}");
+//! }
+//! # Ok::<(), git_navigator::GitNavigatorError>(())
+//! ```
+
+use crate::commands::diff::show_file_diff;
+use crate::commands::sparse::outside_sparse_warnings;
+use crate::core::{
+    command_init::{IndexCommandContext, IndexCommandInit},
+    error::{GitNavigatorError, Result},
+    git::GitRepo,
+    state::FileEntry,
+};
+use std::env;
+use std::path::{Path, PathBuf};
+
+/// Where commands run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppContext {
+    repo_dir: PathBuf,
+}
+
+impl AppContext {
+    /// Run commands in the repository containing `repo_dir`
+    pub fn new(repo_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            repo_dir: repo_dir.into(),
+        }
+    }
+
+    /// Run commands in the repository containing the current directory
+    pub fn current_dir() -> Result<Self> {
+        Ok(Self::new(env::current_dir()?))
+    }
+
+    pub fn repo_dir(&self) -> &Path {
+        &self.repo_dir
+    }
+
+    fn open_repo(&self) -> Result<GitRepo> {
+        GitRepo::open(&self.repo_dir).map_err(|_| GitNavigatorError::NotInGitRepo)
+    }
+
+    fn with_indices(
+        &self,
+        indices: Vec<String>,
+        empty_files_msg: &str,
+    ) -> Result<IndexCommandContext> {
+        IndexCommandInit::initialize_in(
+            &self.repo_dir,
+            indices,
+            "Cannot load file cache",
+            empty_files_msg,
+        )
+    }
+}
+
+/// A command to run, taking indices as the CLI does ("1", "3-5,8", ...)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// List the changed files and save their numbering, like `gs`
+    Status,
+    /// Stage files, like `ga`
+    Add { indices: Vec<String> },
+    /// Render the diffs of files, like `gd`
+    Diff { indices: Vec<String> },
+    /// Unstage files, like `grs`
+    Reset { indices: Vec<String> },
+    /// Discard the changes to files, like `gco <index>`
+    Checkout { indices: Vec<String> },
+}
+
+/// What a command did
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CommandReport {
+    /// Files the command acted on (empty for [`Command::Status`])
+    pub files: Vec<FileEntry>,
+    /// The changed files after the command ran, as now numbered
+    pub status: Vec<FileEntry>,
+    /// Diff text for [`Command::Diff`], colored when `colored` would color output
+    pub diff: Option<String>,
+    /// Warnings the CLI would have printed
+    pub warnings: Vec<String>,
+}
+
+/// Run `command` in the repository of `context`
+pub fn run_command(command: Command, context: &AppContext) -> Result<CommandReport> {
+    match command {
+        Command::Status => status(context),
+        Command::Add { indices } => {
+            let index_context = context.with_indices(indices, "No files available to add")?;
+            if index_context.git_repo.get_status()?.is_empty() {
+                return Err(GitNavigatorError::NoChangesToAdd);
+            }
+            apply(
+                &index_context,
+                true,
+                |file| vec![file.path.clone()],
+                |git_repo, paths| git_repo.add_files(paths),
+            )
+        }
+        Command::Diff { indices } => {
+            let index_context = context.with_indices(indices, "No files found in cache")?;
+            let files = index_context.get_selected_files();
+            let mut diff = String::new();
+            for file in &files {
+                diff.push_str(&show_file_diff(&index_context.git_repo, file)?);
+            }
+            Ok(CommandReport {
+                files: files.into_iter().cloned().collect(),
+                status: index_context.git_repo.get_status()?,
+                diff: Some(diff),
+                warnings: Vec::new(),
+            })
+        }
+        Command::Reset { indices } => {
+            let index_context = context.with_indices(indices, "No files available to reset")?;
+            // A rename is unstaged at both ends, as `grs` does
+            apply(
+                &index_context,
+                false,
+                FileEntry::affected_paths,
+                |git_repo, paths| git_repo.reset_files(paths),
+            )
+        }
+        Command::Checkout { indices } => {
+            let index_context = context.with_indices(indices, "No files available to checkout")?;
+            apply(
+                &index_context,
+                true,
+                |file| vec![file.path.clone()],
+                |git_repo, paths| git_repo.checkout_files(paths),
+            )
+        }
+    }
+}
+
+fn status(context: &AppContext) -> Result<CommandReport> {
+    let git_repo = context.open_repo()?;
+    let status = git_repo.get_status()?;
+    let mut warnings = Vec::new();
+    if let Err(e) = git_repo.save_file_list(&status) {
+        warnings.push(format!("File cache save failed: {e}"));
+    }
+    Ok(CommandReport {
+        status,
+        warnings,
+        ..Default::default()
+    })
+}
+
+/// Run a git operation on the paths `paths_of` gives for the selected files
+/// and report the new status. Staging and discarding skip files outside a
+/// sparse checkout, so those warn
+fn apply(
+    context: &IndexCommandContext,
+    check_sparse: bool,
+    paths_of: impl Fn(&FileEntry) -> Vec<PathBuf>,
+    operation: impl FnOnce(&GitRepo, &[PathBuf]) -> Result<()>,
+) -> Result<CommandReport> {
+    let files = context.get_selected_files();
+    let mut warnings = if check_sparse {
+        outside_sparse_warnings(&context.git_repo, &files)
+    } else {
+        Vec::new()
+    };
+    let mut paths: Vec<PathBuf> = Vec::new();
+    for path in files.iter().flat_map(|file| paths_of(file)) {
+        if !paths.contains(&path) {
+            paths.push(path);
+        }
+    }
+    if paths.is_empty() {
+        return Err(GitNavigatorError::NoValidFilesSelected);
+    }
+
+    operation(&context.git_repo, &paths)?;
+    let status = context.git_repo.get_status()?;
+    if let Err(e) = context.git_repo.save_file_list(&status) {
+        warnings.push(format!("File cache save failed: {e}"));
+    }
+    Ok(CommandReport {
+        files: files.into_iter().cloned().collect(),
+        status,
+        diff: None,
+        warnings,
+    })
+}
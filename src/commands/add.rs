@@ -1,11 +1,18 @@
+use crate::commands::sparse::warn_outside_sparse;
 use crate::commands::status::{execute_status, print_files_only};
 use crate::core::{
-    command_init::IndexCommandInit,
+    command_init::{IndexCommandContext, IndexCommandInit},
     error::{GitNavigatorError, Result},
-    print_error, print_error_with_structured_usage, print_info, print_success,
+    git::PatchHunk,
+    is_interactive, print_error, print_error_with_structured_usage, print_info, print_success,
+    state::FileEntry,
+    templates::colored_index,
 };
+use colored::*;
+use std::io::{self, Write};
 
-pub fn execute_add(indices_args: Vec<String>) -> Result<()> {
+/// Add files by index to the index, or with `patch` pick their hunks one by one
+pub fn execute_add(indices_args: Vec<String>, patch: bool) -> Result<()> {
     // Initialize everything needed for this index-based command
     let context = match IndexCommandInit::initialize_with_messages(
         indices_args,
@@ -16,8 +23,12 @@ pub fn execute_add(indices_args: Vec<String>) -> Result<()> {
         Err(GitNavigatorError::NoIndicesProvided) => {
             print_error_with_structured_usage(
                 "No file indices provided",
-                &["ga <index>..."],
-                &[("-h, --help", "Show this help message")],
+                "add",
+                &["<index>...", "--patch <index>..."],
+                &[
+                    ("-p, --patch", "Pick the hunks to stage one by one"),
+                    ("-h, --help", "Show this help message"),
+                ],
             );
             return Err(GitNavigatorError::NoIndicesProvided);
         }
@@ -35,6 +46,10 @@ pub fn execute_add(indices_args: Vec<String>) -> Result<()> {
 
     // Get the selected files and prepare them for adding
     let selected_files = context.get_selected_files();
+    warn_outside_sparse(&context.git_repo, &selected_files);
+    if patch {
+        return add_hunks(&context, &selected_files);
+    }
 
     // Extract paths efficiently - unfortunately git2 API requires owned PathBuf
     // so we can't avoid the clone, but we can at least do it efficiently
@@ -61,14 +76,145 @@ pub fn execute_add(indices_args: Vec<String>) -> Result<()> {
         }
     }
 
-    // Show updated status
+    show_updated_status(&context)
+}
+
+/// Show updated status, numbered the way the next command resolves it
+fn show_updated_status(context: &IndexCommandContext) -> Result<()> {
     print_info("Updated status:");
     let updated_files = context.git_repo.get_status()?;
+    if let Err(e) = context.git_repo.save_file_list(&updated_files) {
+        log::warn!("Cache save failed: {e}");
+    }
     print_files_only(&updated_files);
 
     Ok(())
 }
 
+/// What to do with the hunk on screen, as `git add -p` asks it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HunkAnswer {
+    /// y: stage this hunk
+    Stage,
+    /// n: leave it unstaged
+    Skip,
+    /// a: stage it and the rest of the file
+    StageRest,
+    /// d: leave it and the rest of the file
+    SkipRest,
+    /// q: stop, keeping what was picked so far
+    Quit,
+    /// ?: explain the answers
+    Help,
+}
+
+const HUNK_HELP: &str = "y - stage this hunk
+n - do not stage this hunk
+a - stage this hunk and the rest of the file
+d - do not stage this hunk or the rest of the file
+q - quit; hunks already picked are staged";
+
+fn parse_answer(answer: &str) -> Option<HunkAnswer> {
+    match answer.trim().to_lowercase().as_str() {
+        "y" | "yes" => Some(HunkAnswer::Stage),
+        "n" | "no" => Some(HunkAnswer::Skip),
+        "a" => Some(HunkAnswer::StageRest),
+        "d" => Some(HunkAnswer::SkipRest),
+        "q" => Some(HunkAnswer::Quit),
+        "?" => Some(HunkAnswer::Help),
+        _ => None,
+    }
+}
+
+/// Offer each hunk of `files` and stage the ones picked
+fn add_hunks(context: &IndexCommandContext, files: &[&FileEntry]) -> Result<()> {
+    if !is_interactive() {
+        return Err(GitNavigatorError::NonInteractive(
+            "pass the indices without --patch to stage whole files".to_string(),
+        ));
+    }
+
+    let (mut offered, mut staged) = (0, 0);
+    'files: for file in files {
+        let hunks = context.git_repo.unstaged_hunks(&file.path)?;
+        if hunks.is_empty() {
+            print_info(&format!("No unstaged changes in {}", file.path.display()));
+            continue;
+        }
+
+        println!(
+            "\n{} {}",
+            colored_index(file.index),
+            file.path.display().to_string().bold()
+        );
+        let mut accepted = vec![false; hunks.len()];
+        let mut quit = false;
+        let mut position = 0;
+        while position < hunks.len() {
+            print_hunk(&hunks[position]);
+            let prompt = format!(
+                "({}/{}) Stage this hunk [y,n,a,d,q,?]?",
+                position + 1,
+                hunks.len()
+            );
+            let Some(answer) = parse_answer(&read_answer(&prompt)?) else {
+                println!("{}", HUNK_HELP.bright_black());
+                continue;
+            };
+            match answer {
+                HunkAnswer::Stage => accepted[position] = true,
+                HunkAnswer::Skip => {}
+                HunkAnswer::StageRest => accepted[position..].fill(true),
+                HunkAnswer::SkipRest => {}
+                HunkAnswer::Quit => quit = true,
+                HunkAnswer::Help => {
+                    println!("{}", HUNK_HELP.bright_black());
+                    continue;
+                }
+            }
+            position += 1;
+            if quit || matches!(answer, HunkAnswer::StageRest | HunkAnswer::SkipRest) {
+                break;
+            }
+        }
+
+        offered += position;
+        staged += accepted.iter().filter(|&&picked| picked).count();
+        context.git_repo.stage_hunks(&file.path, &accepted)?;
+        if quit {
+            break 'files;
+        }
+    }
+
+    if staged == 0 {
+        print_info("No hunks staged.");
+        return Ok(());
+    }
+    print_success(&format!("Staged {staged} of {offered} hunk(s) shown."));
+    show_updated_status(context)
+}
+
+fn print_hunk(hunk: &PatchHunk) {
+    println!("{}", hunk.header.cyan());
+    for (origin, line) in &hunk.lines {
+        let line = format!("{origin}{line}");
+        match origin {
+            '+' => println!("{}", line.green()),
+            '-' => println!("{}", line.red()),
+            _ => println!("{line}"),
+        }
+    }
+}
+
+fn read_answer(prompt: &str) -> Result<String> {
+    print!("{} ", prompt.blue());
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(input.trim().to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -76,9 +222,21 @@ mod tests {
     use crate::core::state::FileEntry;
     use std::path::PathBuf;
 
+    #[test]
+    fn test_parse_answer() {
+        assert_eq!(parse_answer("y"), Some(HunkAnswer::Stage));
+        assert_eq!(parse_answer(" N "), Some(HunkAnswer::Skip));
+        assert_eq!(parse_answer("a"), Some(HunkAnswer::StageRest));
+        assert_eq!(parse_answer("d"), Some(HunkAnswer::SkipRest));
+        assert_eq!(parse_answer("q"), Some(HunkAnswer::Quit));
+        assert_eq!(parse_answer("?"), Some(HunkAnswer::Help));
+        assert_eq!(parse_answer("maybe"), None);
+        assert_eq!(parse_answer(""), None);
+    }
+
     #[test]
     fn test_execute_add_no_indices() {
-        let result = execute_add(vec![]);
+        let result = execute_add(vec![], false);
         assert!(result.is_err());
         let error_msg = result.unwrap_err().to_string();
         // Could be either no indices provided OR cache load error (depending on cache state)
@@ -90,7 +248,7 @@ mod tests {
 
     #[test]
     fn test_execute_add_empty_indices() {
-        let result = execute_add(vec!["".to_string()]);
+        let result = execute_add(vec!["".to_string()], false);
         assert!(result.is_err());
         // This will fail during parsing, not during empty check
         assert!(result.is_err());
@@ -98,7 +256,7 @@ mod tests {
 
     #[test]
     fn test_execute_add_invalid_indices() {
-        let result = execute_add(vec!["abc".to_string()]);
+        let result = execute_add(vec!["abc".to_string()], false);
         assert!(result.is_err());
         let error_msg = result.unwrap_err().to_string();
         // Could be either invalid index format OR cache load error (depending on cache state)
@@ -111,24 +269,27 @@ mod tests {
     #[test]
     fn test_memory_efficient_path_collection() {
         // Test that our path collection is memory efficient
-        let files = vec![
+        let files = [
             FileEntry {
                 index: 1,
                 status: GitStatus::Modified,
                 path: PathBuf::from("file1.txt"),
                 staged: false,
+                old_path: None,
             },
             FileEntry {
                 index: 2,
                 status: GitStatus::Added,
                 path: PathBuf::from("file2.txt"),
                 staged: true,
+                old_path: None,
             },
             FileEntry {
                 index: 3,
                 status: GitStatus::Untracked,
                 path: PathBuf::from("very/long/path/to/file3.txt"),
                 staged: false,
+                old_path: None,
             },
         ];
 
@@ -157,18 +318,20 @@ mod tests {
     #[test]
     fn test_vector_preallocation_efficiency() {
         // Test that pre-allocation with known capacity is more efficient
-        let files = vec![
+        let files = [
             FileEntry {
                 index: 1,
                 status: GitStatus::Modified,
                 path: PathBuf::from("file1.txt"),
                 staged: false,
+                old_path: None,
             },
             FileEntry {
                 index: 2,
                 status: GitStatus::Added,
                 path: PathBuf::from("file2.txt"),
                 staged: true,
+                old_path: None,
             },
         ];
 
@@ -183,24 +346,27 @@ mod tests {
     #[test]
     fn test_path_extraction_handles_deleted_files() {
         // Test that path extraction works correctly for deleted files
-        let files = vec![
+        let files = [
             FileEntry {
                 index: 1,
                 status: GitStatus::Modified,
                 path: PathBuf::from("modified.txt"),
                 staged: false,
+                old_path: None,
             },
             FileEntry {
                 index: 2,
                 status: GitStatus::Deleted,
                 path: PathBuf::from("deleted.txt"),
                 staged: false,
+                old_path: None,
             },
             FileEntry {
                 index: 3,
                 status: GitStatus::Added,
                 path: PathBuf::from("added.txt"),
                 staged: true,
+                old_path: None,
             },
         ];
 
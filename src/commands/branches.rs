@@ -1,32 +1,110 @@
+use crate::commands::checkout::switch_branch;
 use crate::core::{
+    cache::CacheStore,
+    config::Settings,
     error::{GitNavigatorError, Result},
+    format_ahead_behind, format_ahead_behind_unknown, format_upstream_gone,
     git::GitRepo,
-    print_info, print_section_header,
-    state::{BranchEntry, StateCache},
+    is_plain_mode,
+    packages::wildcard_match,
+    print_info, print_section_header, print_success, print_warning,
+    state::BranchEntry,
+    templates::{colored_index, colored_index_label, templates},
 };
+use clap::{Parser, ValueEnum};
 use colored::*;
 use std::env;
-use std::fs;
-use std::path::PathBuf;
 
-pub fn execute_branches(branch_index: Option<usize>) -> Result<()> {
+#[derive(Parser)]
+pub struct BranchesArgs {
+    /// Branch index to checkout, or a pattern that narrows the list to the
+    /// matching branches ("feature/", "*-fix"); with -a always a pattern
+    #[arg(value_name = "INDEX|PATTERN")]
+    pub target: Option<String>,
+
+    /// List remote-tracking branches, numbered apart from the local ones; with
+    /// an index, check that branch out as a local branch tracking it
+    #[arg(short, long)]
+    pub remotes: bool,
+
+    /// List local and remote-tracking branches
+    #[arg(short, long, conflicts_with = "remotes")]
+    pub all: bool,
+
+    /// Delete local branches whose upstream was deleted on the remote
+    #[arg(long, conflicts_with_all = ["target", "remotes", "all"])]
+    pub prune: bool,
+
+    /// Order of the numbered list (default: branch.sort, else name)
+    #[arg(long, value_enum)]
+    pub sort: Option<BranchSort>,
+}
+
+/// How `gb` orders the branches it numbers
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum BranchSort {
+    /// Alphabetically
+    #[default]
+    Name,
+    /// Most recent tip commit first, so active branches get the low numbers
+    #[value(name = "committerdate")]
+    CommitterDate,
+}
+
+impl BranchSort {
+    /// Read `branch.sort` (`name` or `committerdate`)
+    pub fn from_settings(settings: &Settings) -> Self {
+        match settings.get_str("branch.sort") {
+            Some(value) => Self::from_str(value, true).unwrap_or_else(|_| {
+                log::warn!("Ignoring unknown branch.sort value '{value}'");
+                Self::default()
+            }),
+            None => Self::default(),
+        }
+    }
+}
+
+type LoadBranches = fn(&CacheStore) -> Result<Vec<BranchEntry>>;
+type SaveBranches = fn(&CacheStore, &[BranchEntry]) -> Result<()>;
+
+pub fn execute_branches(args: BranchesArgs) -> Result<()> {
     // Check if we're in a git repository
     let current_dir = env::current_dir()?;
     let git_repo = GitRepo::open(&current_dir).map_err(|_| GitNavigatorError::NotInGitRepo)?;
-
-    if let Some(index) = branch_index {
+    let sort = args
+        .sort
+        .unwrap_or_else(|| BranchSort::from_settings(&git_repo.load_settings()));
+
+    let pattern = args.target.as_deref();
+    let index = pattern.and_then(|target| target.parse::<usize>().ok());
+
+    if args.prune {
+        prune_gone_branches(&git_repo)
+    } else if args.all {
+        list_branches(&git_repo, sort, pattern)?;
+        list_remote_branches(&git_repo, sort, pattern)
+    } else if args.remotes {
+        match index {
+            Some(index) => checkout_remote_branch_by_index(&git_repo, index),
+            None => list_remote_branches(&git_repo, sort, pattern),
+        }
+    } else if let Some(index) = index {
         // Switch to branch by index
         checkout_branch_by_index(&git_repo, index)
     } else {
         // List branches with indices
-        list_branches(&git_repo)
+        list_branches(&git_repo, sort, pattern)
     }
 }
 
-fn list_branches(git_repo: &GitRepo) -> Result<()> {
+fn list_branches(git_repo: &GitRepo, sort: BranchSort, pattern: Option<&str>) -> Result<()> {
     // Get all local branches
-    let branches = get_local_branches(git_repo)?;
+    let branches = local_branches_by(git_repo, sort, pattern)?;
 
+    if let (true, Some(pattern)) = (branches.is_empty(), pattern) {
+        print_info(&format!("No local branches match '{pattern}'."));
+        return Ok(());
+    }
     if branches.is_empty() {
         print_info("No branches found. Make your first commit to create one.");
         return Ok(());
@@ -36,57 +114,61 @@ fn list_branches(git_repo: &GitRepo) -> Result<()> {
     print_section_header("Local Branches");
 
     // Display branches with proper formatting and colors
+    let mut gone_count = 0;
     for branch in &branches {
+        let gone = git_repo.upstream_gone(&branch.name);
+        if gone {
+            gone_count += 1;
+        }
+        let gone_text = if gone {
+            format_upstream_gone()
+        } else {
+            String::new()
+        };
+
         if branch.is_current {
             // Current branch format: [*] branch-name (+ahead/-behind)
             let ahead_behind_text = match git_repo.get_ahead_behind() {
-                Ok(Some((ahead, behind))) => {
-                    if ahead > 0 && behind > 0 {
-                        format!(
-                            " {}+{}/−{}{}",
-                            "(".bright_black(),
-                            ahead.to_string().white(),
-                            behind.to_string().white(),
-                            ")".bright_black()
-                        )
-                    } else if ahead > 0 {
-                        format!(
-                            " {}+{}{}",
-                            "(".bright_black(),
-                            ahead.to_string().white(),
-                            ")".bright_black()
-                        )
-                    } else if behind > 0 {
-                        format!(
-                            " {}-{}{}",
-                            "(".bright_black(),
-                            behind.to_string().white(),
-                            ")".bright_black()
-                        )
-                    } else {
-                        String::new()
-                    }
-                }
-                Ok(None) => String::new(),
+                Ok(Some((ahead, behind))) => format_ahead_behind(ahead, behind),
+                Ok(None) => gone_text,
+                Err(GitNavigatorError::ShallowHistory) => format_ahead_behind_unknown(),
                 Err(_) => String::new(),
             };
 
+            // With ui.number_current_branch it keeps its number: [3*]
+            let marker = if branch.index > 0 {
+                format!("{}*", templates().index_number(branch.index))
+            } else {
+                "*".to_string()
+            };
+
+            if is_plain_mode() && branch.index > 0 {
+                println!(
+                    "Index {}: {} (current branch){}",
+                    branch.index, branch.name, ahead_behind_text
+                );
+                continue;
+            }
+            if is_plain_mode() {
+                println!("Current branch: {}{}", branch.name, ahead_behind_text);
+                continue;
+            }
+
             println!(
-                "{}{}{} {}{}",
-                "[".bright_black(),
-                "*".white(),
-                "]".bright_black(),
+                "{} {}{}",
+                colored_index_label(&marker),
                 branch.name.blue(),
                 ahead_behind_text
             );
+        } else if is_plain_mode() {
+            println!("Index {}: {}{}", branch.index, branch.name, gone_text);
         } else {
             // Other branches format: [index] branch-name
             println!(
-                "{}{}{} {}",
-                "[".bright_black(),
-                branch.index.to_string().white(),
-                "]".bright_black(),
-                branch.name.blue()
+                "{} {}{}",
+                colored_index(branch.index),
+                branch.name.blue(),
+                gone_text
             );
         }
     }
@@ -94,26 +176,239 @@ fn list_branches(git_repo: &GitRepo) -> Result<()> {
     // Add spacing after branch list
     println!();
 
+    if gone_count > 0 {
+        print_info(&format!(
+            "{gone_count} branch(es) track an upstream that is gone; 'gb --prune' deletes them."
+        ));
+    }
+
+    if let Some(pattern) = pattern {
+        print_info(&format!(
+            "Only branches matching '{pattern}' are numbered; 'gb <index>' picks from this list."
+        ));
+    }
+
     // Save to cache for branch checkout command
+    save_branches(git_repo, &branches);
+
+    Ok(())
+}
+
+/// List remote-tracking branches, numbered on their own for `gb -r <index>`
+fn list_remote_branches(git_repo: &GitRepo, sort: BranchSort, pattern: Option<&str>) -> Result<()> {
+    let branches = remote_branches_by(git_repo, sort, pattern)?;
+    if let (true, Some(pattern)) = (branches.is_empty(), pattern) {
+        print_info(&format!("No remote-tracking branches match '{pattern}'."));
+        return Ok(());
+    }
+    if branches.is_empty() {
+        print_info("No remote-tracking branches. 'git fetch' brings them in from a remote.");
+        return Ok(());
+    }
+
+    print_section_header("Remote Branches");
+    for branch in &branches {
+        if is_plain_mode() {
+            println!("Index {}: {}", branch.index, branch.name);
+        } else {
+            println!("{} {}", colored_index(branch.index), branch.name.blue());
+        }
+    }
+    println!();
+
+    save_remote_branches(git_repo, &branches);
+    Ok(())
+}
+
+fn save_branches(git_repo: &GitRepo, branches: &[BranchEntry]) {
+    save_branch_list(git_repo, branches, CacheStore::save_branches);
+}
+
+fn save_remote_branches(git_repo: &GitRepo, branches: &[BranchEntry]) {
+    save_branch_list(git_repo, branches, CacheStore::save_remote_branches);
+}
+
+#[cfg_attr(test, allow(unused_variables))]
+fn save_branch_list(git_repo: &GitRepo, branches: &[BranchEntry], save: SaveBranches) {
     #[cfg(not(test))]
     {
-        if let Err(e) = save_branches_cache(&branches, git_repo.get_repo_path()) {
+        if let Err(e) = git_repo
+            .cache_store()
+            .and_then(|store| save(&store, branches))
+        {
             // Log cache errors but don't fail the command
             log::warn!("Branch cache save failed: {e}");
             #[cfg(debug_assertions)]
             eprintln!("Warning: Branch cache save failed: {e}");
         }
     }
+}
+
+/// Renumber the cached branches after a switch or a new branch
+///
+/// The current branch has no index, so switching shifts the numbers; saving
+/// them keeps `gb <index>` pointing at what a fresh `gb` would show.
+pub(crate) fn refresh_branch_cache(git_repo: &GitRepo) {
+    match local_branches(git_repo) {
+        Ok(branches) => save_branches(git_repo, &branches),
+        Err(e) => log::warn!("Could not list branches to refresh the cache: {e}"),
+    }
+}
+
+/// Delete the branches whose upstream is gone, keeping the current one
+///
+/// Deletion goes through `git branch -d`, so branches with commits that were
+/// never merged are kept and reported instead.
+fn prune_gone_branches(git_repo: &GitRepo) -> Result<()> {
+    let gone: Vec<BranchEntry> = local_branches(git_repo)?
+        .into_iter()
+        .filter(|branch| git_repo.upstream_gone(&branch.name))
+        .collect();
+    if gone.is_empty() {
+        print_info("No branches with a gone upstream.");
+        return Ok(());
+    }
+
+    let mut deleted = 0;
+    for branch in &gone {
+        if branch.is_current {
+            print_warning(&format!(
+                "Kept current branch '{}'; switch to another branch to delete it",
+                branch.name
+            ));
+            continue;
+        }
+        match git_repo.delete_branch(&branch.name) {
+            Ok(()) => {
+                print_success(&format!("Deleted branch '{}'", branch.name));
+                deleted += 1;
+            }
+            Err(e) => {
+                log::debug!("Could not delete '{}': {e}", branch.name);
+                print_warning(&format!(
+                    "Kept '{}': it has unmerged commits. Use 'git branch -D {}' to delete it anyway",
+                    branch.name, branch.name
+                ));
+            }
+        }
+    }
 
+    if deleted > 0 {
+        refresh_branch_cache(git_repo);
+    }
     Ok(())
 }
 
 fn checkout_branch_by_index(git_repo: &GitRepo, index: usize) -> Result<()> {
-    // Load cached branches from previous gb command
-    let branches = load_branches_cache(&git_repo.get_repo_path()).map_err(|e| {
+    let target_branch = find_cached_branch(git_repo, index)?;
+
+    // Check if trying to switch to current branch
+    if target_branch.is_current {
+        return Err(GitNavigatorError::custom_empty_files_error(
+            "Cannot switch to current branch",
+        ));
+    }
+
+    // Switch with the same dirty-worktree safety checks as `gco <branch>`
+    switch_branch(git_repo, &target_branch.name, false)?;
+    refresh_branch_cache(git_repo);
+    println!("Switched to branch '{}'", target_branch.name);
+    Ok(())
+}
+
+/// Switch to the local branch for a remote-tracking branch from `gb -r`
+///
+/// Without a local branch of that name yet, one is created tracking the
+/// remote branch, as `git checkout --track` would. It is removed again when
+/// the switch itself fails, so a blocked checkout leaves nothing behind.
+fn checkout_remote_branch_by_index(git_repo: &GitRepo, index: usize) -> Result<()> {
+    let remote_branch = find_cached_remote_branch(git_repo, index)?;
+    let local_name = local_name_for(&remote_branch.name);
+
+    if git_repo.get_current_branch().ok().as_deref() == Some(local_name) {
+        return Err(GitNavigatorError::custom_empty_files_error(format!(
+            "Already on '{local_name}'"
+        )));
+    }
+
+    if git_repo.branch_exists(local_name) {
+        switch_branch(git_repo, local_name, false)?;
+        refresh_branch_cache(git_repo);
+        println!("Switched to existing branch '{local_name}'");
+        return Ok(());
+    }
+
+    git_repo.track_branch(local_name, &remote_branch.name)?;
+    if let Err(e) = switch_branch(git_repo, local_name, false) {
+        if let Err(delete_error) = git_repo.delete_branch(local_name) {
+            log::warn!("Could not remove '{local_name}' again: {delete_error}");
+        }
+        return Err(e);
+    }
+    refresh_branch_cache(git_repo);
+    println!(
+        "Switched to a new branch '{local_name}' tracking '{}'",
+        remote_branch.name
+    );
+    Ok(())
+}
+
+/// The local branch name for a remote-tracking branch: "origin/feature/x"
+/// becomes "feature/x"
+fn local_name_for(remote_branch: &str) -> &str {
+    remote_branch
+        .split_once('/')
+        .map_or(remote_branch, |(_, name)| name)
+}
+
+/// Look up a branch by its index in the cache written by the last `gb`
+///
+/// Without a cache yet, the branches are numbered as `gb` would number them.
+pub(crate) fn find_cached_branch(git_repo: &GitRepo, index: usize) -> Result<BranchEntry> {
+    find_numbered_branch(
+        git_repo,
+        index,
+        (CacheStore::load_branches, CacheStore::save_branches),
+        local_branches,
+        "gb",
+    )
+}
+
+/// Look up a remote-tracking branch by its index from the last `gb -r`
+fn find_cached_remote_branch(git_repo: &GitRepo, index: usize) -> Result<BranchEntry> {
+    find_numbered_branch(
+        git_repo,
+        index,
+        (
+            CacheStore::load_remote_branches,
+            CacheStore::save_remote_branches,
+        ),
+        remote_branches,
+        "gb -r",
+    )
+}
+
+fn find_numbered_branch(
+    git_repo: &GitRepo,
+    index: usize,
+    (load, save): (LoadBranches, SaveBranches),
+    list: fn(&GitRepo) -> Result<Vec<BranchEntry>>,
+    listing: &str,
+) -> Result<BranchEntry> {
+    let store = git_repo.cache_store()?;
+    let loaded = store.load_or_rebuild(load, save, || list(git_repo));
+    let branches = match loaded {
+        Err(GitNavigatorError::CacheFileNotFound { .. }) => {
+            let branches = list(git_repo)?;
+            save_branch_list(git_repo, &branches, save);
+            Ok(branches)
+        }
+        result => result,
+    }
+    .map_err(|e| {
         log::warn!("Failed to load branch cache: {e}");
         GitNavigatorError::custom_cache_error(
-            "Cannot load branch cache. Run 'gb' first to list branches.",
+            format!("Cannot load branch cache. Run '{listing}' first to list branches."),
             e,
         )
     })?;
@@ -124,52 +419,30 @@ fn checkout_branch_by_index(git_repo: &GitRepo, index: usize) -> Result<()> {
         ));
     }
 
-    // Find branch by index
-    let target_branch = branches
-        .iter()
+    branches
+        .into_iter()
         .find(|branch| branch.index == index)
         .ok_or_else(|| {
-            GitNavigatorError::custom_empty_files_error(format!(
-                "Branch index {index} not found"
-            ))
-        })?;
-
-    // Check if trying to switch to current branch
-    if target_branch.is_current {
-        return Err(GitNavigatorError::custom_empty_files_error(
-            "Cannot switch to current branch",
-        ));
-    }
-
-    // Execute git checkout command
-    let workdir = git_repo
-        .get_repository()
-        .workdir()
-        .ok_or_else(|| GitNavigatorError::custom_empty_files_error("No workdir found"))?;
-
-    let output = std::process::Command::new("git")
-        .arg("checkout")
-        .arg(&target_branch.name)
-        .current_dir(workdir)
-        .output()
-        .map_err(GitNavigatorError::Io)?;
+            GitNavigatorError::custom_empty_files_error(format!("Branch index {index} not found"))
+        })
+}
 
-    if output.status.success() {
-        println!("Switched to branch '{}'", target_branch.name);
-        Ok(())
-    } else {
-        let error_msg = String::from_utf8_lossy(&output.stderr);
-        Err(GitNavigatorError::custom_empty_files_error(format!(
-            "Failed to checkout branch '{}': {}",
-            target_branch.name,
-            error_msg.trim()
-        )))
-    }
+/// Local branches numbered in the configured `branch.sort` order
+pub(crate) fn local_branches(git_repo: &GitRepo) -> Result<Vec<BranchEntry>> {
+    local_branches_by(
+        git_repo,
+        BranchSort::from_settings(&git_repo.load_settings()),
+        None,
+    )
 }
 
-fn get_local_branches(git_repo: &GitRepo) -> Result<Vec<BranchEntry>> {
+/// Local branches matching `pattern` (all without one), numbered in `sort` order
+fn local_branches_by(
+    git_repo: &GitRepo,
+    sort: BranchSort,
+    pattern: Option<&str>,
+) -> Result<Vec<BranchEntry>> {
     let repo = git_repo.get_repository();
-    let mut branches = Vec::new();
 
     // Get current branch
     let current_branch = git_repo
@@ -181,13 +454,12 @@ fn get_local_branches(git_repo: &GitRepo) -> Result<Vec<BranchEntry>> {
         GitNavigatorError::custom_empty_files_error(format!("Failed to list branches: {e}"))
     })?;
 
-    let mut branch_names = Vec::new();
+    let mut branches = Vec::new();
     for branch in branch_iter {
-        let branch = branch.map_err(|e| {
+        let (branch, _) = branch.map_err(|e| {
             GitNavigatorError::custom_empty_files_error(format!("Failed to read branch: {e}"))
         })?;
         let name = branch
-            .0
             .name()
             .map_err(|e| {
                 GitNavigatorError::custom_empty_files_error(format!(
@@ -198,17 +470,135 @@ fn get_local_branches(git_repo: &GitRepo) -> Result<Vec<BranchEntry>> {
                 GitNavigatorError::custom_empty_files_error("Branch name is not valid UTF-8")
             })?
             .to_string();
-        branch_names.push(name);
+        if pattern.is_none_or(|pattern| branch_matches(pattern, &name)) {
+            branches.push((name, tip_time(&branch)));
+        }
+    }
+
+    let number_current = git_repo
+        .load_settings()
+        .get_bool("ui.number_current_branch")
+        .unwrap_or(false);
+    Ok(number_branches(
+        sorted_names(branches, sort),
+        &current_branch,
+        number_current,
+    ))
+}
+
+fn remote_branches(git_repo: &GitRepo) -> Result<Vec<BranchEntry>> {
+    remote_branches_by(
+        git_repo,
+        BranchSort::from_settings(&git_repo.load_settings()),
+        None,
+    )
+}
+
+/// Remote-tracking branches matching `pattern` in `sort` order, numbered from 1
+///
+/// The symbolic `origin/HEAD` is left out: it only points at another entry.
+/// A pattern matches with or without the remote, so `feature/*` finds
+/// `origin/feature/login`.
+fn remote_branches_by(
+    git_repo: &GitRepo,
+    sort: BranchSort,
+    pattern: Option<&str>,
+) -> Result<Vec<BranchEntry>> {
+    let repo = git_repo.get_repository();
+    let branch_iter = repo.branches(Some(git2::BranchType::Remote)).map_err(|e| {
+        GitNavigatorError::custom_empty_files_error(format!("Failed to list branches: {e}"))
+    })?;
+
+    let mut branches = Vec::new();
+    for branch in branch_iter {
+        let (branch, _) = branch.map_err(|e| {
+            GitNavigatorError::custom_empty_files_error(format!("Failed to read branch: {e}"))
+        })?;
+        if branch.get().kind() == Some(git2::ReferenceType::Symbolic) {
+            continue;
+        }
+        match branch.name() {
+            Ok(Some(name))
+                if pattern.is_none_or(|pattern| {
+                    branch_matches(pattern, name) || branch_matches(pattern, local_name_for(name))
+                }) =>
+            {
+                branches.push((name.to_string(), tip_time(&branch)))
+            }
+            Ok(_) => {}
+            Err(e) => log::debug!("Skipping remote branch with an unreadable name: {e}"),
+        }
+    }
+
+    Ok(sorted_names(branches, sort)
+        .into_iter()
+        .enumerate()
+        .map(|(i, name)| BranchEntry {
+            index: i + 1,
+            name,
+            is_current: false,
+        })
+        .collect())
+}
+
+/// Whether `name` matches a `gb <pattern>` filter: a glob when the pattern
+/// has `*` or `?`, a substring otherwise
+fn branch_matches(pattern: &str, name: &str) -> bool {
+    if pattern.contains(['*', '?']) {
+        wildcard_match(pattern, name)
+    } else {
+        name.contains(pattern)
     }
+}
 
-    // Sort branch names for consistent ordering
-    branch_names.sort();
+/// Committer time of the branch's tip commit, 0 when it cannot be read
+fn tip_time(branch: &git2::Branch) -> i64 {
+    branch
+        .get()
+        .peel_to_commit()
+        .map(|commit| commit.committer().when().seconds())
+        .unwrap_or(0)
+}
+
+/// Branch names in `sort` order; branches committed to at the same second
+/// stay in name order
+fn sorted_names(mut branches: Vec<(String, i64)>, sort: BranchSort) -> Vec<String> {
+    branches.sort_by(|(a_name, a_time), (b_name, b_time)| match sort {
+        BranchSort::Name => a_name.cmp(b_name),
+        BranchSort::CommitterDate => b_time.cmp(a_time).then_with(|| a_name.cmp(b_name)),
+    });
+    branches.into_iter().map(|(name, _)| name).collect()
+}
+
+/// Number branches in the order given
+///
+/// By default the current branch comes first with no number (index 0), so
+/// the others shift when you switch. With `number_current` every branch keeps
+/// its place in the sorted list, the current one included.
+fn number_branches(
+    branch_names: Vec<String>,
+    current_branch: &str,
+    number_current: bool,
+) -> Vec<BranchEntry> {
+    if number_current {
+        return branch_names
+            .into_iter()
+            .enumerate()
+            .map(|(i, name)| BranchEntry {
+                index: i + 1,
+                is_current: name == current_branch,
+                name,
+            })
+            .collect();
+    }
+
+    let mut branches = Vec::new();
 
     // Add current branch first (not numbered)
-    if branch_names.contains(&current_branch) {
+    if branch_names.iter().any(|name| name == current_branch) {
         branches.push(BranchEntry {
             index: 0, // Not used for current branch
-            name: current_branch.clone(),
+            name: current_branch.to_string(),
             is_current: true,
         });
     }
@@ -226,146 +616,17 @@ fn get_local_branches(git_repo: &GitRepo) -> Result<Vec<BranchEntry>> {
         }
     }
 
-    Ok(branches)
-}
-
-#[cfg(not(test))]
-fn save_branches_cache(branches: &[BranchEntry], repo_path: PathBuf) -> Result<()> {
-    use crate::core::error::GitNavigatorError;
-
-    log::debug!("Attempting to save {} branches to cache", branches.len());
-
-    // Get cache directory
-    let cache_dir = get_cache_dir(&repo_path).map_err(|e| {
-        log::warn!("Failed to determine cache directory: {e}");
-        e
-    })?;
-
-    log::debug!("Using cache directory: {}", cache_dir.display());
-
-    // Create cache directory
-    if let Err(e) = fs::create_dir_all(&cache_dir) {
-        log::error!(
-            "Failed to create cache directory '{}': {}",
-            cache_dir.display(),
-            e
-        );
-        return Err(GitNavigatorError::cache_directory_creation_failed(
-            &cache_dir, e,
-        ));
-    }
-
-    let cache_file = cache_dir.join("branches.json");
-    log::debug!("Cache file path: {}", cache_file.display());
-
-    let cache = StateCache {
-        files: Vec::new(), // Not used for branches command
-        branches: branches.to_vec(),
-        last_updated: std::time::SystemTime::now(),
-        repo_path,
-    };
-
-    // Serialize cache data
-    let json = serde_json::to_string_pretty(&cache).map_err(|e| {
-        log::error!("Failed to serialize branch cache data: {e}");
-        GitNavigatorError::cache_serialization_failed(e)
-    })?;
-
-    // Write cache file
-    if let Err(e) = fs::write(&cache_file, json) {
-        log::error!(
-            "Failed to write branch cache file '{}': {}",
-            cache_file.display(),
-            e
-        );
-        return Err(GitNavigatorError::cache_write_failed(&cache_file, e));
-    }
-
-    log::debug!("Successfully cached {} branches", branches.len());
-    Ok(())
-}
-
-fn load_branches_cache(repo_path: &PathBuf) -> Result<Vec<BranchEntry>> {
-    use crate::core::error::GitNavigatorError;
-
-    log::debug!(
-        "Attempting to load branch cache for repo: {}",
-        repo_path.display()
-    );
-
-    let cache_dir = get_cache_dir(repo_path).map_err(|e| {
-        log::warn!("Failed to determine cache directory: {e}");
-        e
-    })?;
-    log::debug!("load_branches_cache: cache_dir = {cache_dir:?}");
-
-    let cache_file = cache_dir.join("branches.json");
-    log::debug!("Looking for branch cache file: {}", cache_file.display());
-    log::debug!(
-        "load_branches_cache: cache_file = {:?}, exists = {}",
-        cache_file,
-        cache_file.exists()
-    );
-
-    if !cache_file.exists() {
-        log::debug!("Branch cache file does not exist: {}", cache_file.display());
-        return Err(GitNavigatorError::cache_file_not_found(&cache_file));
-    }
-
-    let content = fs::read_to_string(&cache_file).map_err(|e| {
-        log::error!(
-            "Failed to read branch cache file '{}': {}",
-            cache_file.display(),
-            e
-        );
-        GitNavigatorError::cache_read_failed(&cache_file, e)
-    })?;
-
-    let cache: StateCache = serde_json::from_str(&content).map_err(|e| {
-        log::error!(
-            "Failed to parse branch cache file '{}': {}",
-            cache_file.display(),
-            e
-        );
-        GitNavigatorError::cache_parse_failed(&cache_file, e)
-    })?;
-
-    log::debug!(
-        "Successfully loaded {} branches from cache",
-        cache.branches.len()
-    );
-
-    if cache.branches.is_empty() {
-        log::debug!("Branch cache file exists but contains no branches");
-        return Err(GitNavigatorError::NoCachedFiles);
-    }
-
-    Ok(cache.branches)
-}
-
-fn get_cache_dir(repo_path: &PathBuf) -> Result<PathBuf> {
-    // Respect XDG_CACHE_HOME environment variable first, fallback to dirs::cache_dir()
-    let cache_home = std::env::var("XDG_CACHE_HOME")
-        .map(std::path::PathBuf::from)
-        .unwrap_or_else(|_| dirs::cache_dir().unwrap_or_else(|| std::path::PathBuf::from("/tmp")));
-
-    // Create a hash of the repo path for unique cache directory
-    let repo_hash = format!("{:x}", md5::compute(repo_path.to_string_lossy().as_bytes()));
-
-    log::debug!("get_cache_dir: repo_path = {repo_path:?}");
-    log::debug!("get_cache_dir: cache_home = {cache_home:?}");
-    log::debug!("get_cache_dir: repo_hash = {repo_hash:?}");
-
-    Ok(cache_home.join("git-navigator").join(repo_hash))
+    branches
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::path::PathBuf;
     use tempfile::TempDir;
 
     fn setup_test_repo() -> Result<(TempDir, PathBuf)> {
-        let temp_dir = TempDir::new().map_err(|e| GitNavigatorError::Io(e))?;
+        let temp_dir = TempDir::new().map_err(GitNavigatorError::Io)?;
         let repo_path = temp_dir.path().to_path_buf();
 
         // Initialize git repo
@@ -373,20 +634,20 @@ mod tests {
             .args(["init"])
             .current_dir(&repo_path)
             .output()
-            .map_err(|e| GitNavigatorError::Io(e))?;
+            .map_err(GitNavigatorError::Io)?;
 
         // Set git config
         std::process::Command::new("git")
             .args(["config", "user.name", "Test User"])
             .current_dir(&repo_path)
             .output()
-            .map_err(|e| GitNavigatorError::Io(e))?;
+            .map_err(GitNavigatorError::Io)?;
 
         std::process::Command::new("git")
             .args(["config", "user.email", "test@example.com"])
             .current_dir(&repo_path)
             .output()
-            .map_err(|e| GitNavigatorError::Io(e))?;
+            .map_err(GitNavigatorError::Io)?;
 
         Ok((temp_dir, repo_path))
     }
@@ -397,49 +658,98 @@ mod tests {
 
         // Test that we can open the repo without changing directories
         let git_repo = GitRepo::open(&repo_path)?;
-        let branches = get_local_branches(&git_repo)?;
+        let branches = local_branches(&git_repo)?;
 
         // Verify no branches exist
         assert!(branches.is_empty());
         Ok(())
     }
 
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
     #[test]
-    fn test_execute_branches_not_in_git_repo() -> Result<()> {
-        let temp_dir = TempDir::new().map_err(|e| GitNavigatorError::Io(e))?;
-        let non_repo_path = temp_dir.path();
+    fn test_sorted_names() {
+        let branches = vec![
+            ("main".to_string(), 300),
+            ("stale".to_string(), 100),
+            ("feature".to_string(), 300),
+            ("fix".to_string(), 200),
+        ];
+        assert_eq!(
+            sorted_names(branches.clone(), BranchSort::Name),
+            names(&["feature", "fix", "main", "stale"])
+        );
+        assert_eq!(
+            sorted_names(branches, BranchSort::CommitterDate),
+            names(&["feature", "main", "fix", "stale"])
+        );
+    }
 
-        // Test that we get an error when trying to open a non-git directory
-        let result = GitRepo::open(non_repo_path);
+    #[test]
+    fn test_branch_sort_from_settings() {
+        let settings = Settings::parse("[branch]\nsort = \"committerdate\"\n").unwrap();
+        assert_eq!(
+            BranchSort::from_settings(&settings),
+            BranchSort::CommitterDate
+        );
+        let settings = Settings::parse("[branch]\nsort = \"size\"\n").unwrap();
+        assert_eq!(BranchSort::from_settings(&settings), BranchSort::Name);
+    }
 
-        assert!(result.is_err());
-        Ok(())
+    #[test]
+    fn test_number_branches_skips_current() {
+        let branches = number_branches(names(&["feature", "fix", "main"]), "fix", false);
+        let numbered: Vec<(usize, &str, bool)> = branches
+            .iter()
+            .map(|b| (b.index, b.name.as_str(), b.is_current))
+            .collect();
+        assert_eq!(
+            numbered,
+            vec![(0, "fix", true), (1, "feature", false), (2, "main", false)]
+        );
     }
 
     #[test]
-    fn test_get_cache_dir() -> Result<()> {
-        let repo_path = PathBuf::from("/test/repo/path");
-        let cache_dir = get_cache_dir(&repo_path)?;
+    fn test_number_branches_numbers_current() {
+        let branches = number_branches(names(&["feature", "fix", "main"]), "fix", true);
+        let numbered: Vec<(usize, &str, bool)> = branches
+            .iter()
+            .map(|b| (b.index, b.name.as_str(), b.is_current))
+            .collect();
+        assert_eq!(
+            numbered,
+            vec![(1, "feature", false), (2, "fix", true), (3, "main", false)]
+        );
+    }
 
-        assert!(cache_dir.to_string_lossy().contains("git-navigator"));
-        assert!(cache_dir.is_absolute());
-        Ok(())
+    #[test]
+    fn test_branch_matches() {
+        assert!(branch_matches("feature/", "feature/login"));
+        assert!(branch_matches("login", "feature/login"));
+        assert!(!branch_matches("feature/", "fix/login"));
+        assert!(branch_matches("*-fix", "release-fix"));
+        assert!(!branch_matches("*-fix", "release-fix-2"));
+        assert!(branch_matches("fix/?", "fix/1"));
     }
 
     #[test]
-    fn test_load_branches_cache_nonexistent_file() {
-        // Use a non-existent path without creating actual temp directories
-        let fake_repo_path = PathBuf::from("/non/existent/repo/path");
+    fn test_local_name_for() {
+        assert_eq!(local_name_for("origin/main"), "main");
+        assert_eq!(local_name_for("upstream/feature/login"), "feature/login");
+        assert_eq!(local_name_for("main"), "main");
+    }
 
-        let result = load_branches_cache(&fake_repo_path);
-        assert!(result.is_err());
+    #[test]
+    fn test_execute_branches_not_in_git_repo() -> Result<()> {
+        let temp_dir = TempDir::new().map_err(GitNavigatorError::Io)?;
+        let non_repo_path = temp_dir.path();
 
-        let error = result.unwrap_err();
-        match error {
-            GitNavigatorError::CacheFileNotFound { path } => {
-                assert!(path.to_string_lossy().contains("branches.json"));
-            }
-            _ => panic!("Expected CacheFileNotFound error, got: {}", error),
-        }
+        // Test that we get an error when trying to open a non-git directory
+        let result = GitRepo::open(non_repo_path);
+
+        assert!(result.is_err());
+        Ok(())
     }
 }
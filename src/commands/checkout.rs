@@ -1,29 +1,114 @@
+use crate::commands::branches::{find_cached_branch, refresh_branch_cache};
+use crate::commands::log::cached_commit;
+use crate::commands::sparse::warn_outside_sparse;
 use crate::commands::status::{execute_status, print_files_only};
 use crate::core::{
+    args_parser::ArgsParser,
+    branch_name::BranchNaming,
     command_init::IndexCommandInit,
     error::{GitNavigatorError, Result},
     git::GitRepo,
-    print_error, print_error_with_structured_usage, print_info, print_success,
+    git_status::GitStatus,
+    is_interactive, print_error, print_error_with_structured_usage, print_info, print_success,
+    print_warning,
 };
+use clap::Parser;
+use colored::*;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 
-pub fn execute_checkout_with_flags(create_branch: bool, indices_args: Vec<String>) -> Result<()> {
+#[derive(Parser)]
+pub struct CheckoutArgs {
+    /// Create and switch to a new branch
+    #[arg(short = 'b', long = "create")]
+    pub create_branch: bool,
+
+    /// Treat all arguments as file indices
+    #[arg(long, conflicts_with_all = ["branch", "create_branch"])]
+    pub files: bool,
+
+    /// Treat the argument as a branch name
+    #[arg(long, conflicts_with = "create_branch")]
+    pub branch: bool,
+
+    /// Stash local changes, switch branch and re-apply them (implies --branch)
+    #[arg(long, conflicts_with_all = ["files", "create_branch"])]
+    pub autostash: bool,
+
+    /// Start the new branch from a branch index (from `gb`) or any commit-ish
+    #[arg(long, value_name = "START", requires = "create_branch")]
+    pub from: Option<String>,
+
+    /// Start the new branch at commit INDEX from the last `gl` listing
+    #[arg(
+        long,
+        value_name = "INDEX",
+        requires = "create_branch",
+        conflicts_with = "from"
+    )]
+    pub from_commit: Option<usize>,
+
+    /// Detach HEAD at commit INDEX from the last `gl` listing
+    #[arg(
+        long,
+        value_name = "INDEX",
+        conflicts_with_all = ["create_branch", "files", "branch", "autostash", "indices"]
+    )]
+    pub commit: Option<usize>,
+
+    /// File indices (e.g., "1 3-5,8"), optionally followed by a commit to take them from
+    /// (e.g., "3 main") OR branch name (e.g., "main") OR branch name to create
+    #[arg(allow_negative_numbers = true)]
+    pub indices: Vec<String>,
+}
+
+/// How the positional arguments of `gco` should be interpreted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CheckoutMode {
+    /// Guess from the shape of the arguments
+    Auto,
+    /// `--files`: every argument is a file index
+    Files,
+    /// `--branch`: the argument is a branch name
+    Branch,
+}
+
+/// Resolved meaning of the positional arguments
+#[derive(Debug, PartialEq, Eq)]
+enum CheckoutTarget {
+    Files,
+    /// File indices followed by the commit to take their content from
+    FilesFrom(String),
+    Branch(String),
+    /// A single numeric argument that is also the name of a local branch
+    Ambiguous(String),
+}
+
+pub fn execute_checkout_with_args(args: CheckoutArgs) -> Result<()> {
     // Handle branch creation flag
-    if create_branch {
-        if indices_args.is_empty() {
+    if args.create_branch {
+        if args.indices.is_empty() {
             print_error_with_structured_usage(
                 "Branch name required with -b flag",
-                &["gco -b <branch-name>"],
+                "checkout",
+                &[
+                    "-b <branch-name> [--from <start>]",
+                    "-b <branch-name> --from-commit <index>",
+                ],
                 &[
                     ("-b, --create", "Create and switch to a new branch"),
+                    ("--from", "Branch index or commit to start from"),
+                    ("--from-commit", "Commit index from gl to start from"),
                     ("-h, --help", "Show this help message"),
                 ],
             );
             return Ok(());
         }
-        if indices_args.len() > 1 {
+        if args.indices.len() > 1 {
             print_error_with_structured_usage(
                 "Only one branch name allowed with -b flag",
-                &["gco -b <branch-name>"],
+                "checkout",
+                &["-b <branch-name>"],
                 &[
                     ("-b, --create", "Create and switch to a new branch"),
                     ("-h, --help", "Show this help message"),
@@ -31,67 +116,215 @@ pub fn execute_checkout_with_flags(create_branch: bool, indices_args: Vec<String
             );
             return Ok(());
         }
-        return create_and_checkout_branch(&indices_args[0]);
+        let from = match args.from_commit {
+            Some(index) => Some(StartPoint::CommitIndex(index)),
+            None => args.from.as_deref().map(StartPoint::Named),
+        };
+        return create_and_checkout_branch(&args.indices[0], from);
+    }
+
+    if let Some(index) = args.commit {
+        return checkout_commit_by_index(index);
+    }
+
+    if args.files {
+        return execute_checkout_with_mode(args.indices, CheckoutMode::Files, false);
+    }
+
+    if args.branch || args.autostash {
+        return execute_checkout_with_mode(args.indices, CheckoutMode::Branch, args.autostash);
     }
 
     // Delegate to original function for backward compatibility
-    execute_checkout(indices_args)
+    execute_checkout(args.indices)
 }
 
 pub fn execute_checkout(indices_args: Vec<String>) -> Result<()> {
+    // Check for branch creation syntax (-b flag)
+    if indices_args.len() == 1 && indices_args[0] == "-b" {
+        print_error_with_structured_usage(
+            "Branch name required after -b flag",
+            "checkout",
+            &["-b <branch-name>"],
+            &[
+                ("-b, --create", "Create and switch to a new branch"),
+                ("-h, --help", "Show this help message"),
+            ],
+        );
+        return Ok(());
+    }
+
+    // Check for branch creation syntax (-b branch_name)
+    if indices_args.len() == 2 && indices_args[0] == "-b" {
+        return create_and_checkout_branch(&indices_args[1], None);
+    }
+
+    execute_checkout_with_mode(indices_args, CheckoutMode::Auto, false)
+}
+
+fn execute_checkout_with_mode(
+    indices_args: Vec<String>,
+    mode: CheckoutMode,
+    autostash: bool,
+) -> Result<()> {
     // If no arguments provided, show usage
     if indices_args.is_empty() {
         print_error_with_structured_usage(
             "No file indices or branch name provided",
-            &["gco <index>...", "gco <branch>", "gco -b <branch-name>"],
+            "checkout",
+            &[
+                "<index>...",
+                "<index>... <ref>",
+                "<branch>",
+                "-b <branch-name>",
+            ],
             &[
                 ("-b, --create", "Create and switch to a new branch"),
+                ("--files", "Treat all arguments as file indices"),
+                ("--branch", "Treat the argument as a branch name"),
                 ("-h, --help", "Show this help message"),
             ],
         );
         return Ok(());
     }
 
-    // Check if this might be a branch name (single argument, not numeric)
-    if indices_args.len() == 1 {
-        let arg = &indices_args[0];
+    if mode == CheckoutMode::Branch && indices_args.len() > 1 {
+        print_error_with_structured_usage(
+            "Only one branch name allowed with --branch",
+            "checkout",
+            &["--branch <branch>"],
+            &[
+                ("--branch", "Treat the argument as a branch name"),
+                ("-h, --help", "Show this help message"),
+            ],
+        );
+        return Ok(());
+    }
 
-        // Check for branch creation syntax (-b flag)
-        if arg == "-b" {
-            print_error_with_structured_usage(
-                "Branch name required after -b flag",
-                &["gco -b <branch-name>"],
-                &[
-                    ("-b, --create", "Create and switch to a new branch"),
-                    ("-h, --help", "Show this help message"),
-                ],
-            );
-            return Ok(());
+    // Only look up branches when an index list could also be a branch name,
+    // and commits and paths when an argument is not an index list
+    let single_selection = indices_args.len() == 1 && ArgsParser::is_selection(&indices_args[0]);
+    let names_other = indices_args
+        .iter()
+        .any(|arg| !ArgsParser::is_selection(arg));
+    let target = if mode == CheckoutMode::Auto && (single_selection || names_other) {
+        let git_repo = GitRepo::open(".").map_err(|_| GitNavigatorError::NotInGitRepo)?;
+        resolve_checkout_target(
+            &indices_args,
+            mode,
+            |name| git_repo.branch_exists(name),
+            |revision| git_repo.commit_exists(revision),
+            |path| Path::new(path).exists(),
+        )?
+    } else {
+        resolve_checkout_target(&indices_args, mode, |_| false, |_| false, |_| false)?
+    };
+
+    match target {
+        CheckoutTarget::Files => checkout_files_by_indices(indices_args, None),
+        CheckoutTarget::FilesFrom(revision) => {
+            let mut indices_args = indices_args;
+            indices_args.pop();
+            checkout_files_by_indices(indices_args, Some(&revision))
         }
+        CheckoutTarget::Branch(name) => checkout_branch_by_name(&name, autostash),
+        CheckoutTarget::Ambiguous(arg) => match prompt_ambiguous_target(&arg)? {
+            CheckoutMode::Files => checkout_files_by_indices(indices_args, None),
+            CheckoutMode::Branch => checkout_branch_by_name(&arg, autostash),
+            CheckoutMode::Auto => {
+                print_info("Checkout canceled");
+                Ok(())
+            }
+        },
+    }
+}
+
+/// Decide whether the arguments refer to files or to a branch
+///
+/// In auto mode a single non-numeric argument is a branch name and numeric
+/// arguments are file indices. A path that exists names files too, but only
+/// once it names no branch or commit. Indices followed by a commit
+/// (`gco 3 main`, `gco 2 HEAD~2`) take the files from that commit. A single
+/// numeric argument that is also a local branch name is reported as
+/// ambiguous, and other mixes of names and indices (e.g. `gco dev 2`) are
+/// rejected instead of guessing.
+fn resolve_checkout_target(
+    args: &[String],
+    mode: CheckoutMode,
+    branch_exists: impl Fn(&str) -> bool,
+    commit_exists: impl Fn(&str) -> bool,
+    path_exists: impl Fn(&str) -> bool,
+) -> Result<CheckoutTarget> {
+    match mode {
+        CheckoutMode::Files => Ok(CheckoutTarget::Files),
+        CheckoutMode::Branch => Ok(CheckoutTarget::Branch(args[0].clone())),
+        CheckoutMode::Auto => {
+            // "1", "1,2", "1-3", "1 2", "1-3,5", indices as listed: "[02]",
+            // "(3)", sections: "untracked", and paths like "src/main.rs"
+            let names_files = |arg: &str| {
+                ArgsParser::is_selection(arg)
+                    || (path_exists(arg) && !branch_exists(arg) && !commit_exists(arg))
+            };
+            let numeric_count = args.iter().filter(|arg| names_files(arg)).count();
+
+            if numeric_count == args.len() {
+                if args.len() == 1 && branch_exists(&args[0]) {
+                    return Ok(CheckoutTarget::Ambiguous(args[0].clone()));
+                }
+                return Ok(CheckoutTarget::Files);
+            }
+
+            if args.len() == 1 {
+                return Ok(CheckoutTarget::Branch(args[0].clone()));
+            }
 
-        // If it's not a pure number or range, treat as potential branch name
-        if !is_numeric_index(arg) {
-            return checkout_branch_by_name(arg);
+            let last = &args[args.len() - 1];
+            if numeric_count == args.len() - 1 && !names_files(last) && commit_exists(last) {
+                return Ok(CheckoutTarget::FilesFrom(last.clone()));
+            }
+
+            if numeric_count > 0 {
+                return Err(GitNavigatorError::mixed_checkout_arguments(args));
+            }
+
+            // Several non-numeric arguments: let the index parser report them
+            Ok(CheckoutTarget::Files)
         }
     }
+}
 
-    // Check for branch creation syntax (-b branch_name)
-    if indices_args.len() == 2 && indices_args[0] == "-b" {
-        return create_and_checkout_branch(&indices_args[1]);
+/// Ask the user how to interpret an ambiguous argument
+///
+/// Returns `CheckoutMode::Auto` when the user cancels. Without an interactive
+/// terminal there is nobody to ask, so an error pointing at the flags is returned.
+fn prompt_ambiguous_target(arg: &str) -> Result<CheckoutMode> {
+    if !is_interactive() {
+        return Err(GitNavigatorError::ambiguous_checkout_target(arg));
     }
 
-    // Otherwise, treat as file indices
-    checkout_files_by_indices(indices_args)
-}
+    println!(
+        "\n'{}' matches both a file index and a branch name.",
+        arg.white()
+    );
+    print!(
+        "{} ",
+        "Checkout [f]ile index, [b]ranch or [c]ancel? [f/b/C]:".blue()
+    );
+    io::stdout().flush()?;
 
-fn is_numeric_index(arg: &str) -> bool {
-    // Check if the argument contains only digits, commas, dashes, and spaces
-    // This covers: "1", "1,2", "1-3", "1 2", "1-3,5"
-    arg.chars()
-        .all(|c| c.is_ascii_digit() || c == ',' || c == '-' || c == ' ')
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+
+    Ok(match input.trim().to_lowercase().as_str() {
+        "f" | "file" | "files" => CheckoutMode::Files,
+        "b" | "branch" => CheckoutMode::Branch,
+        _ => CheckoutMode::Auto,
+    })
 }
 
-fn checkout_files_by_indices(indices_args: Vec<String>) -> Result<()> {
+/// Restore the numbered files to their index version, or with `revision` to
+/// their content at that commit, which is staged as well
+fn checkout_files_by_indices(indices_args: Vec<String>, revision: Option<&str>) -> Result<()> {
     // Initialize everything needed for this index-based command
     let context = match IndexCommandInit::initialize_with_messages(
         indices_args,
@@ -102,7 +335,8 @@ fn checkout_files_by_indices(indices_args: Vec<String>) -> Result<()> {
         Err(GitNavigatorError::NoIndicesProvided) => {
             print_error_with_structured_usage(
                 "No file indices provided",
-                &["gco <index>..."],
+                "checkout",
+                &["<index>..."],
                 &[("-h, --help", "Show this help message")],
             );
             return Ok(());
@@ -121,6 +355,7 @@ fn checkout_files_by_indices(indices_args: Vec<String>) -> Result<()> {
 
     // Get the selected files and prepare them for checkout
     let selected_files = context.get_selected_files();
+    warn_outside_sparse(&context.git_repo, &selected_files);
 
     // Extract paths for checkout
     let paths_to_checkout: Vec<_> = selected_files
@@ -134,39 +369,46 @@ fn checkout_files_by_indices(indices_args: Vec<String>) -> Result<()> {
     }
 
     // Checkout files using git
-    match context.git_repo.checkout_files(&paths_to_checkout) {
-        Ok(()) => {
+    match revision {
+        Some(revision) => {
+            context
+                .git_repo
+                .checkout_files_from(revision, &paths_to_checkout)?;
             print_success(&format!(
-                "Successfully checked out {} file(s).",
+                "Checked out {} file(s) as of {revision}; the content is staged.",
                 selected_files.len()
             ));
         }
-        Err(e) => {
-            return Err(e);
+        None => {
+            context.git_repo.checkout_files(&paths_to_checkout)?;
+            print_success(&format!(
+                "Successfully checked out {} file(s).",
+                selected_files.len()
+            ));
         }
     }
 
-    // Show updated status
+    // Show updated status, numbered the way the next command resolves it
     print_info("Updated status:");
     let updated_files = context.git_repo.get_status()?;
+    if let Err(e) = context.git_repo.save_file_list(&updated_files) {
+        log::warn!("Cache save failed: {e}");
+    }
     print_files_only(&updated_files);
 
     Ok(())
 }
 
-fn checkout_branch_by_name(branch_name: &str) -> Result<()> {
+fn checkout_branch_by_name(branch_name: &str, autostash: bool) -> Result<()> {
     let git_repo = GitRepo::open(".")?;
 
-    match git_repo.checkout_branch(branch_name) {
+    match switch_branch(&git_repo, branch_name, autostash) {
         Ok(()) => {
-            print_success(&format!(
-                "Successfully switched to branch '{branch_name}'"
-            ));
+            refresh_branch_cache(&git_repo);
+            print_success(&format!("Successfully switched to branch '{branch_name}'"));
         }
         Err(e) => {
-            print_error(&format!(
-                "Failed to checkout branch '{branch_name}': {e}"
-            ));
+            print_error(&format!("Failed to checkout branch '{branch_name}': {e}"));
             return Err(e);
         }
     }
@@ -174,13 +416,226 @@ fn checkout_branch_by_name(branch_name: &str) -> Result<()> {
     Ok(())
 }
 
-fn create_and_checkout_branch(branch_name: &str) -> Result<()> {
+/// Detach HEAD at a commit numbered by the last `gl`
+///
+/// Goes through [`switch_branch`], so local changes get the same checks.
+fn checkout_commit_by_index(index: usize) -> Result<()> {
+    let git_repo = GitRepo::open(".").map_err(|_| GitNavigatorError::NotInGitRepo)?;
+    let commit = cached_commit(&git_repo, index)?;
+
+    switch_branch(&git_repo, &commit.oid, false)?;
+    refresh_branch_cache(&git_repo);
+    print_success(&format!(
+        "HEAD is now at {} {} (detached)",
+        commit.short_oid, commit.summary
+    ));
+    print_info("Create a branch with 'gco -b <name>' to keep work made here.");
+    Ok(())
+}
+
+/// Switch branches after checking that local changes survive the switch
+///
+/// With `autostash` any local changes are stashed around the switch, like
+/// `git pull --rebase --autostash`. Otherwise only files whose local changes
+/// differ between HEAD and the target matter, since git would refuse the
+/// checkout: they are stashed if `checkout.autostash` is enabled or the user
+/// accepts the prompt, or listed with their status numbers and the switch is
+/// aborted.
+pub fn switch_branch(git_repo: &GitRepo, branch_name: &str, autostash: bool) -> Result<()> {
+    if !confirm_leaving_orphaned_commits(git_repo)? {
+        return Err(GitNavigatorError::CheckoutCanceled);
+    }
+
+    if autostash {
+        return checkout_with_stash(git_repo, branch_name, &[]);
+    }
+
+    let blocking = git_repo.find_checkout_conflicts(branch_name)?;
+    if blocking.is_empty() {
+        return git_repo.checkout_branch(branch_name);
+    }
+
+    let autostash = git_repo
+        .load_settings()
+        .get_bool("checkout.autostash")
+        .unwrap_or(false);
+    if autostash || confirm_autostash(branch_name, blocking.len())? {
+        return checkout_with_stash(git_repo, branch_name, &blocking);
+    }
+
+    print_blocking_files(git_repo, &blocking)?;
+    Err(GitNavigatorError::checkout_blocked(
+        branch_name,
+        blocking.len(),
+    ))
+}
+
+/// Warn before leaving commits that only a detached HEAD points to
+///
+/// Returns `false` when the user decides to stay. Without a terminal the
+/// warning is printed and the switch goes ahead, as git itself does.
+fn confirm_leaving_orphaned_commits(git_repo: &GitRepo) -> Result<bool> {
+    let orphaned = git_repo.get_orphaned_commits()?;
+    if orphaned.is_empty() {
+        return Ok(true);
+    }
+
+    print_warning(&format!(
+        "Switching away will leave {} commit(s) behind, not connected to any branch:",
+        orphaned.len()
+    ));
+    for (hash, subject) in &orphaned {
+        println!("   {} {}", hash.blue(), subject.bright_black());
+    }
+    println!(
+        "{}",
+        "Keep them with 'gco -b <name>' before switching.".bright_black()
+    );
+
+    if !is_interactive() {
+        return Ok(true);
+    }
+
+    print!("\n{} ", "Switch anyway? [y/N]:".blue());
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+
+    Ok(matches!(input.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+fn confirm_autostash(branch_name: &str, count: usize) -> Result<bool> {
+    if !is_interactive() {
+        return Ok(false);
+    }
+
+    println!(
+        "\nLocal changes to {} file(s) would be overwritten by switching to '{}'.",
+        count.to_string().white(),
+        branch_name.blue()
+    );
+    print!("{} ", "Stash them, switch and re-apply? [y/N]:".blue());
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+
+    Ok(matches!(input.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+fn checkout_with_stash(git_repo: &GitRepo, branch_name: &str, blocking: &[PathBuf]) -> Result<()> {
+    // Untracked files only need stashing when they are in the way
+    let status = git_repo.get_status()?;
+    let include_untracked = status
+        .iter()
+        .any(|file| file.status == GitStatus::Untracked && blocking.contains(&file.path));
+
+    let stash = match git_repo.stash_push("git-navigator autostash", include_untracked)? {
+        Some(stash) => stash,
+        None => return git_repo.checkout_branch(branch_name),
+    };
+    print_info(&format!("Created autostash: {}", git_repo.short_oid(stash)));
+
+    if let Err(e) = git_repo.checkout_branch(branch_name) {
+        // Put the changes back where they were before reporting the failure
+        git_repo.stash_pop()?;
+        return Err(e);
+    }
+
+    match git_repo.stash_pop() {
+        Ok(()) => print_info("Applied autostash."),
+        Err(e) => {
+            log::debug!("stash pop failed: {e}");
+            report_autostash_conflicts(git_repo)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Show the files left in conflict by a failed stash pop
+///
+/// The switch itself succeeded and git keeps the stash entry when the pop
+/// conflicts, so nothing is lost: the user resolves the unmerged files and
+/// drops the stash.
+fn report_autostash_conflicts(git_repo: &GitRepo) -> Result<()> {
+    print_error("Applying autostash resulted in conflicts");
+
+    let unmerged: Vec<_> = git_repo
+        .get_status()?
+        .into_iter()
+        .filter(|file| file.status == GitStatus::Unmerged)
+        .collect();
+    print_files_only(&unmerged);
+
+    println!(
+        "{}",
+        "Your changes are safe in the stash. Resolve the files above, then run 'git stash drop'."
+            .bright_black()
+    );
+    if let Some(tool) = git_repo.git_defaults().merge_tool {
+        println!(
+            "{}",
+            format!("Run 'git mergetool' to resolve them with {tool}.").bright_black()
+        );
+    }
+
+    Ok(())
+}
+
+/// List the files that block a branch switch, numbered like `gs`
+fn print_blocking_files(git_repo: &GitRepo, blocking: &[PathBuf]) -> Result<()> {
+    let blocking_files: Vec<_> = git_repo
+        .get_status()?
+        .into_iter()
+        .filter(|file| blocking.contains(&file.path))
+        .collect();
+
+    print_info("These local changes would be overwritten:");
+    println!();
+    print_files_only(&blocking_files);
+
+    Ok(())
+}
+
+/// Where `gco -b` starts the new branch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StartPoint<'a> {
+    /// `--from`: a branch index from `gb` or a revision
+    Named(&'a str),
+    /// `--from-commit`: a commit index from `gl`
+    CommitIndex(usize),
+}
+
+fn create_and_checkout_branch(name: &str, from: Option<StartPoint>) -> Result<()> {
     let git_repo = GitRepo::open(".")?;
 
-    match git_repo.create_branch(branch_name) {
+    let settings = git_repo.load_settings();
+    let naming = BranchNaming::from_settings(&settings)?;
+    let branch_name = naming.resolve(name, git_repo.config_string("user.name").as_deref())?;
+    let branch_name = branch_name.as_str();
+
+    // Without --from, start from the configured base branch if there is one
+    let from = from.or_else(|| settings.get_str("branch.base").map(StartPoint::Named));
+    let start_point = match from {
+        Some(start) => Some(resolve_start_point(&git_repo, start)?),
+        None => None,
+    };
+
+    let result = match &start_point {
+        Some((revision, _)) => git_repo.create_branch_from(branch_name, revision),
+        None => git_repo.create_branch(branch_name),
+    };
+
+    match result {
         Ok(()) => {
+            refresh_branch_cache(&git_repo);
+            let suffix = start_point
+                .map(|(_, label)| format!(" from '{label}'"))
+                .unwrap_or_default();
             print_success(&format!(
-                "Successfully created and switched to branch '{branch_name}'"
+                "Successfully created and switched to branch '{branch_name}'{suffix}"
             ));
         }
         Err(e) => {
@@ -192,24 +647,238 @@ fn create_and_checkout_branch(branch_name: &str) -> Result<()> {
     Ok(())
 }
 
+/// Resolve the start point of `gco -b` to a revision and how to show it
+///
+/// `--from` takes a branch index from the last `gb` when it is a short
+/// number within that list and not itself a branch name; anything else,
+/// an all-digit commit hash included, is taken as a revision (branch, tag
+/// or commit). `--from-commit` takes a commit from the last `gl`. The start
+/// point must resolve to a commit before the branch is created.
+fn resolve_start_point(git_repo: &GitRepo, start: StartPoint) -> Result<(String, String)> {
+    let start = match start {
+        StartPoint::CommitIndex(index) => {
+            let commit = cached_commit(git_repo, index)?;
+            return Ok((commit.oid, commit.short_oid));
+        }
+        StartPoint::Named(start) => start,
+    };
+
+    let revision = match short_index(start) {
+        Some(index) if !git_repo.branch_exists(start) => {
+            match find_cached_branch(git_repo, index) {
+                Ok(branch) => branch.name,
+                // Past the end of the list: maybe a hash made of digits
+                Err(_) if git_repo.commit_exists(start) => start.to_string(),
+                Err(e) => return Err(e),
+            }
+        }
+        _ => start.to_string(),
+    };
+
+    if !git_repo.commit_exists(&revision) {
+        return Err(GitNavigatorError::start_point_not_found(revision));
+    }
+
+    Ok((revision.clone(), revision))
+}
+
+/// `start` as a list index: a number of at most four digits without a
+/// leading zero; longer numbers are left to revparse as commit hashes
+fn short_index(start: &str) -> Option<usize> {
+    if start.len() > 4 || start.starts_with('0') || !start.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    start.parse().ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_is_numeric_index() {
-        assert!(is_numeric_index("1"));
-        assert!(is_numeric_index("1,2,3"));
-        assert!(is_numeric_index("1-3"));
-        assert!(is_numeric_index("1 2 3"));
-        assert!(is_numeric_index("1-3,5"));
-        assert!(is_numeric_index("1 3-5,8"));
-
-        assert!(!is_numeric_index("main"));
-        assert!(!is_numeric_index("feature-branch"));
-        assert!(!is_numeric_index("fix/bug-123"));
-        assert!(!is_numeric_index("-b"));
-        assert!(!is_numeric_index("abc"));
+    fn test_is_selection() {
+        assert!(ArgsParser::is_selection("1"));
+        assert!(ArgsParser::is_selection("1,2,3"));
+        assert!(ArgsParser::is_selection("1-3"));
+        assert!(ArgsParser::is_selection("1 2 3"));
+        assert!(ArgsParser::is_selection("1-3,5"));
+        assert!(ArgsParser::is_selection("1 3-5,8"));
+        assert!(ArgsParser::is_selection("[02]"));
+
+        assert!(!ArgsParser::is_selection("main"));
+        assert!(!ArgsParser::is_selection("feature-branch"));
+        assert!(!ArgsParser::is_selection("fix/bug-123"));
+        assert!(!ArgsParser::is_selection("-b"));
+        assert!(!ArgsParser::is_selection("abc"));
+    }
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_short_index() {
+        assert_eq!(short_index("2"), Some(2));
+        assert_eq!(short_index("1234"), Some(1234));
+        assert_eq!(short_index("12345"), None);
+        assert_eq!(short_index("0123"), None);
+        assert_eq!(short_index("v1"), None);
+        assert_eq!(short_index(""), None);
+    }
+
+    #[test]
+    fn test_resolve_numeric_without_matching_branch() -> Result<()> {
+        let target = resolve_checkout_target(
+            &args(&["123"]),
+            CheckoutMode::Auto,
+            |_| false,
+            |_| false,
+            |_| false,
+        )?;
+        assert_eq!(target, CheckoutTarget::Files);
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_numeric_matching_branch_is_ambiguous() -> Result<()> {
+        let target = resolve_checkout_target(
+            &args(&["123"]),
+            CheckoutMode::Auto,
+            |name| name == "123",
+            |_| false,
+            |_| false,
+        )?;
+        assert_eq!(target, CheckoutTarget::Ambiguous("123".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_explicit_modes_skip_ambiguity() -> Result<()> {
+        let files = resolve_checkout_target(
+            &args(&["123"]),
+            CheckoutMode::Files,
+            |_| true,
+            |_| true,
+            |_| false,
+        )?;
+        assert_eq!(files, CheckoutTarget::Files);
+
+        let branch = resolve_checkout_target(
+            &args(&["123"]),
+            CheckoutMode::Branch,
+            |_| true,
+            |_| true,
+            |_| false,
+        )?;
+        assert_eq!(branch, CheckoutTarget::Branch("123".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_branch_name() -> Result<()> {
+        let target = resolve_checkout_target(
+            &args(&["dev"]),
+            CheckoutMode::Auto,
+            |_| false,
+            |_| false,
+            |_| false,
+        )?;
+        assert_eq!(target, CheckoutTarget::Branch("dev".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_mixed_arguments_rejected() {
+        let result = resolve_checkout_target(
+            &args(&["dev", "2"]),
+            CheckoutMode::Auto,
+            |_| false,
+            |_| false,
+            |_| false,
+        );
+        match result {
+            Err(GitNavigatorError::MixedCheckoutArguments { args }) => assert_eq!(args, "dev 2"),
+            other => panic!("Expected MixedCheckoutArguments error, got: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_resolve_indices_from_commit() -> Result<()> {
+        let is_commit = |name: &str| name == "HEAD~2" || name == "main";
+        let target = resolve_checkout_target(
+            &args(&["3", "HEAD~2"]),
+            CheckoutMode::Auto,
+            |_| false,
+            is_commit,
+            |_| false,
+        )?;
+        assert_eq!(target, CheckoutTarget::FilesFrom("HEAD~2".to_string()));
+
+        let target = resolve_checkout_target(
+            &args(&["1-2", "4", "main"]),
+            CheckoutMode::Auto,
+            |_| false,
+            is_commit,
+            |_| false,
+        )?;
+        assert_eq!(target, CheckoutTarget::FilesFrom("main".to_string()));
+
+        // Not a commit, so still a mix of names and indices
+        let result = resolve_checkout_target(
+            &args(&["3", "nope"]),
+            CheckoutMode::Auto,
+            |_| false,
+            is_commit,
+            |_| false,
+        );
+        assert!(matches!(
+            result,
+            Err(GitNavigatorError::MixedCheckoutArguments { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_paths_only_when_no_branch_or_commit() -> Result<()> {
+        let is_path = |name: &str| name == "docs" || name == "main" || name == "src/a.rs";
+        let is_branch = |name: &str| name == "docs" || name == "main";
+
+        // A directory named like a branch still switches branches
+        let target = resolve_checkout_target(
+            &args(&["docs"]),
+            CheckoutMode::Auto,
+            is_branch,
+            is_branch,
+            is_path,
+        )?;
+        assert_eq!(target, CheckoutTarget::Branch("docs".to_string()));
+        let target = resolve_checkout_target(
+            &args(&["3", "main"]),
+            CheckoutMode::Auto,
+            is_branch,
+            is_branch,
+            is_path,
+        )?;
+        assert_eq!(target, CheckoutTarget::FilesFrom("main".to_string()));
+
+        // Other paths name files
+        let target = resolve_checkout_target(
+            &args(&["src/a.rs"]),
+            CheckoutMode::Auto,
+            is_branch,
+            is_branch,
+            is_path,
+        )?;
+        assert_eq!(target, CheckoutTarget::Files);
+        let target = resolve_checkout_target(
+            &args(&["2", "src/a.rs"]),
+            CheckoutMode::Auto,
+            is_branch,
+            is_branch,
+            is_path,
+        )?;
+        assert_eq!(target, CheckoutTarget::Files);
+        Ok(())
     }
 
     #[test]
@@ -0,0 +1,45 @@
+use crate::commands::log::cached_commit;
+use crate::core::{
+    error::{GitNavigatorError, Result},
+    git::GitRepo,
+    index_parser::IndexParser,
+    is_plain_mode, print_success,
+};
+use clap::Parser;
+use colored::*;
+use std::env;
+
+#[derive(Parser)]
+pub struct CherryPickArgs {
+    /// Commit indices from the last `gl` listing (e.g., "2 4-6")
+    #[arg(required = true)]
+    pub indices: Vec<String>,
+}
+
+pub fn execute_cherry_pick(args: CherryPickArgs) -> Result<()> {
+    // Check if we're in a git repository
+    let current_dir = env::current_dir()?;
+    let git_repo = GitRepo::open(&current_dir).map_err(|_| GitNavigatorError::NotInGitRepo)?;
+
+    let mut indices = IndexParser::parse(&args.indices.join(" "))?;
+    // gl lists newest first; apply oldest first so history keeps its order
+    indices.sort_unstable_by(|a, b| b.cmp(a));
+    indices.dedup();
+
+    let commits = indices
+        .into_iter()
+        .map(|index| cached_commit(&git_repo, index))
+        .collect::<Result<Vec<_>>>()?;
+    let oids: Vec<&str> = commits.iter().map(|commit| commit.oid.as_str()).collect();
+    git_repo.cherry_pick(&oids)?;
+
+    print_success(&format!("Cherry-picked {} commit(s):", commits.len()));
+    for commit in &commits {
+        if is_plain_mode() {
+            println!("{} {}", commit.short_oid, commit.summary);
+        } else {
+            println!("   {} {}", commit.short_oid.blue(), commit.summary);
+        }
+    }
+    Ok(())
+}
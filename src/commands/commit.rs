@@ -0,0 +1,231 @@
+use crate::core::{
+    args_parser::ArgsParser,
+    command_init::load_cached_files,
+    conventional::{format_subject, MessageRules},
+    error::{GitNavigatorError, Result},
+    git::GitRepo,
+    git_status::GitStatus,
+    is_interactive, is_plain_mode, print_section_header, print_success,
+    templates::colored_index,
+};
+use clap::Parser;
+use colored::*;
+use std::env;
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+#[derive(Parser)]
+pub struct CommitArgs {
+    /// Commit message; with --type, the description after "type(scope): "
+    #[arg(long, short = 'm')]
+    pub message: Option<String>,
+
+    /// Conventional-commit type, e.g. feat or fix (see commit.types)
+    #[arg(long = "type", value_name = "TYPE", conflicts_with = "interactive")]
+    pub kind: Option<String>,
+
+    /// Conventional-commit scope, e.g. core
+    #[arg(long, requires = "kind")]
+    pub scope: Option<String>,
+
+    /// Mark the commit as a breaking change ("type!: ...")
+    #[arg(long, requires = "kind")]
+    pub breaking: bool,
+
+    /// Pick the type and scope from numbered lists
+    #[arg(long, short = 'i')]
+    pub interactive: bool,
+
+    /// Commit even if the message linter rejects the message
+    #[arg(long)]
+    pub no_lint: bool,
+
+    /// File indices to commit (e.g., "1 3-5"); other staged files stay staged
+    #[arg(allow_negative_numbers = true)]
+    pub indices: Vec<String>,
+}
+
+pub fn execute_commit(args: CommitArgs) -> Result<()> {
+    // Check if we're in a git repository
+    let current_dir = env::current_dir()?;
+    let git_repo = GitRepo::open(&current_dir).map_err(|_| GitNavigatorError::NotInGitRepo)?;
+
+    let files = git_repo.get_status()?;
+    if files.iter().any(|file| file.status == GitStatus::Unmerged) {
+        return Err(GitNavigatorError::commit_failed(
+            "resolve the merge conflicts first",
+        ));
+    }
+
+    // With indices or paths, commit just those files from the last gs listing
+    let selected: Option<(usize, Vec<PathBuf>)> = if args.indices.is_empty() {
+        None
+    } else {
+        let cached = load_cached_files(&git_repo)
+            .map_err(|e| GitNavigatorError::custom_cache_error("Cannot load file cache", e))?;
+        let selection = ArgsParser::parse_selection(args.indices.clone(), &cached, &git_repo)?;
+        // A rename is committed at both ends, or its old path stays behind
+        let mut paths: Vec<PathBuf> = selection
+            .indices
+            .iter()
+            .flat_map(|&index| cached[index - 1].affected_paths())
+            .collect();
+        let mut count = selection.indices.len();
+        // Paths gs did not list yet are looked up in the status just taken
+        for file in selection.unlisted_files(&files)? {
+            if !paths.contains(&file.path) {
+                paths.extend(file.affected_paths());
+                count += 1;
+            }
+        }
+        Some((count, paths))
+    };
+    if selected.is_none() && !files.iter().any(|file| file.staged) {
+        return Err(GitNavigatorError::commit_failed(
+            "nothing is staged; stage files with 'ga' first",
+        ));
+    }
+
+    let mut rules = MessageRules::from_settings(&git_repo.load_settings());
+    let message = if args.interactive {
+        if !is_interactive() {
+            return Err(GitNavigatorError::commit_failed(
+                "no terminal to ask, pass --type and --message instead",
+            ));
+        }
+        rules.conventional = true;
+        prompt_message(&rules, args.message.as_deref())?
+    } else if let Some(kind) = &args.kind {
+        let description = args.message.as_deref().ok_or_else(|| {
+            GitNavigatorError::commit_failed("--type needs a description, pass --message")
+        })?;
+        rules.conventional = true;
+        format_subject(kind, args.scope.as_deref(), args.breaking, description)
+    } else {
+        args.message
+            .clone()
+            .ok_or_else(|| GitNavigatorError::commit_failed("pass --message, --type or -i"))?
+    };
+
+    if !args.no_lint {
+        let problems = rules.lint(&message);
+        if !problems.is_empty() {
+            return Err(GitNavigatorError::CommitMessageRejected(
+                problems.join("; "),
+            ));
+        }
+    }
+
+    let subject = message.lines().next().unwrap_or_default();
+    match selected {
+        Some((count, paths)) => {
+            let short_oid = git_repo.commit_paths(&paths, &message)?;
+            print_success(&format!(
+                "Committed {count} file(s) as {short_oid}: {subject}"
+            ));
+        }
+        None => {
+            let short_oid = git_repo.commit(&message)?;
+            print_success(&format!("Committed {short_oid}: {subject}"));
+        }
+    }
+    Ok(())
+}
+
+/// Ask for the type, scope, breaking flag and description
+fn prompt_message(rules: &MessageRules, description: Option<&str>) -> Result<String> {
+    print_section_header("Commit Type");
+    print_choices(&rules.types);
+    let kind = loop {
+        let answer = read_answer("Type (number or name) >")?;
+        if answer.is_empty() {
+            return Err(GitNavigatorError::CommitCanceled);
+        }
+        match choose(&rules.types, &answer) {
+            Some(kind) => break kind,
+            None => println!("{}", "Pick one of the listed types".bright_black()),
+        }
+    };
+
+    let scope = if rules.scopes.is_empty() {
+        read_answer("Scope (Enter for none) >")?
+    } else {
+        print_section_header("Commit Scope");
+        print_choices(&rules.scopes);
+        loop {
+            let answer = read_answer("Scope (number or name, Enter for none) >")?;
+            if answer.is_empty() {
+                break answer;
+            }
+            match choose(&rules.scopes, &answer) {
+                Some(scope) => break scope,
+                None => println!("{}", "Pick one of the listed scopes".bright_black()),
+            }
+        }
+    };
+
+    let breaking = matches!(
+        read_answer("Breaking change? [y/N]:")?
+            .to_lowercase()
+            .as_str(),
+        "y" | "yes"
+    );
+
+    let description = match description {
+        Some(description) => description.to_string(),
+        None => read_answer("Description >")?,
+    };
+    if description.is_empty() {
+        return Err(GitNavigatorError::CommitCanceled);
+    }
+
+    Ok(format_subject(&kind, Some(&scope), breaking, &description))
+}
+
+fn print_choices(choices: &[String]) {
+    for (position, choice) in choices.iter().enumerate() {
+        if is_plain_mode() {
+            println!("Index {}: {choice}", position + 1);
+        } else {
+            println!("{} {choice}", colored_index(position + 1));
+        }
+    }
+
+    // Add spacing after choice list
+    println!();
+}
+
+/// Resolve a 1-based number or a name from `choices`
+fn choose(choices: &[String], answer: &str) -> Option<String> {
+    match answer.parse::<usize>() {
+        Ok(number) => number
+            .checked_sub(1)
+            .and_then(|position| choices.get(position))
+            .cloned(),
+        Err(_) => choices.iter().find(|choice| *choice == answer).cloned(),
+    }
+}
+
+fn read_answer(prompt: &str) -> Result<String> {
+    print!("{} ", prompt.blue());
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(input.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_choose() {
+        let choices = vec!["feat".to_string(), "fix".to_string()];
+        assert_eq!(choose(&choices, "2"), Some("fix".to_string()));
+        assert_eq!(choose(&choices, "feat"), Some("feat".to_string()));
+        assert_eq!(choose(&choices, "0"), None);
+        assert_eq!(choose(&choices, "3"), None);
+        assert_eq!(choose(&choices, "docs"), None);
+    }
+}
@@ -0,0 +1,108 @@
+use crate::core::{
+    dates::parse_since,
+    error::{GitNavigatorError, Result},
+    format_age,
+    git::GitRepo,
+    is_plain_mode, print_info, print_section_header,
+    state::Contributor,
+    templates::colored_index,
+};
+use clap::Parser;
+use colored::*;
+use std::env;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[derive(Parser)]
+pub struct ContributorsArgs {
+    /// Only count commits since DATE (YYYY-MM-DD, "2 weeks", "30d", ...)
+    #[arg(long, value_name = "DATE")]
+    pub since: Option<String>,
+}
+
+pub fn execute_contributors(args: ContributorsArgs) -> Result<()> {
+    // Check if we're in a git repository
+    let current_dir = env::current_dir()?;
+    let git_repo = GitRepo::open(&current_dir).map_err(|_| GitNavigatorError::NotInGitRepo)?;
+
+    let now = unix_now();
+    let since = args
+        .since
+        .as_deref()
+        .map(|since| parse_since(since, now))
+        .transpose()?;
+    let contributors = git_repo.contributors(since)?;
+
+    if contributors.is_empty() {
+        print_info(if since.is_some() {
+            "No commits in that period."
+        } else {
+            "No commits yet."
+        });
+        return Ok(());
+    }
+
+    print_section_header("Contributors");
+
+    let name_width = contributors
+        .iter()
+        .map(|contributor| identity(contributor).chars().count())
+        .max()
+        .unwrap_or(0);
+    for contributor in &contributors {
+        print_contributor(contributor, name_width, now);
+    }
+
+    // Add spacing after contributor list
+    println!();
+
+    // Save to cache so `log --author <index>` can resolve the numbers
+    if let Err(e) = git_repo
+        .cache_store()
+        .and_then(|store| store.save_contributors(&contributors))
+    {
+        // Log cache errors but don't fail the command
+        log::warn!("Contributor cache save failed: {e}");
+    }
+
+    Ok(())
+}
+
+fn identity(contributor: &Contributor) -> String {
+    format!("{} <{}>", contributor.name, contributor.email)
+}
+
+fn print_contributor(contributor: &Contributor, name_width: usize, now: i64) {
+    let commits = match contributor.commits {
+        1 => "1 commit".to_string(),
+        n => format!("{n} commits"),
+    };
+    let age = format!(
+        "{} ago",
+        format_age(Duration::from_secs(
+            (now - contributor.last_commit_time).max(0) as u64
+        ))
+    );
+
+    if is_plain_mode() {
+        println!(
+            "Index {}: {}, {commits}, last commit {age}",
+            contributor.index,
+            identity(contributor)
+        );
+    } else {
+        println!(
+            "{} {:<name_width$}  {:>11}  {}",
+            colored_index(contributor.index),
+            identity(contributor),
+            commits,
+            age.green()
+        );
+    }
+}
+
+/// Current Unix time in seconds
+pub(crate) fn unix_now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as i64)
+}
@@ -1,15 +1,26 @@
 use crate::core::{
     command_init::IndexCommandInit,
+    diff_tool::DiffTool,
     error::{GitNavigatorError, Result},
     git::GitRepo,
     git_status::GitStatus,
-    output::print_error,
+    hyperlink::link,
+    output::{format_error, is_plain_mode, print_paged},
     print_error_with_structured_usage,
     state::FileEntry,
+    templates::{colored_index, glyphs, templates},
 };
 use colored::*;
+use std::fmt::Write;
+use std::process::Command;
 
-pub fn execute_diff(indices_args: Vec<String>) -> Result<()> {
+/// Widest +/- bar in `gd --stat`; larger changes are scaled down to fit
+const STAT_BAR_WIDTH: usize = 40;
+
+/// Show the diffs of files by index, through `tool` (a command line, or
+/// "none") or else the configured `diff.tool`. With `stat`, a diffstat of
+/// the files instead of their patches.
+pub fn execute_diff(indices_args: Vec<String>, tool: Option<String>, stat: bool) -> Result<()> {
     // Initialize everything needed for this index-based command
     let context = match IndexCommandInit::initialize_with_messages(
         indices_args,
@@ -20,8 +31,12 @@ pub fn execute_diff(indices_args: Vec<String>) -> Result<()> {
         Err(GitNavigatorError::NoIndicesProvided) => {
             print_error_with_structured_usage(
                 "No file indices provided",
-                &["gd <index>..."],
-                &[("-h, --help", "Show this help message")],
+                "diff",
+                &["<index>...", "<index>... --stat"],
+                &[
+                    ("--stat", "Summarize the changes instead of showing them"),
+                    ("-h, --help", "Show this help message"),
+                ],
             );
             return Err(GitNavigatorError::NoIndicesProvided);
         }
@@ -30,69 +45,113 @@ pub fn execute_diff(indices_args: Vec<String>) -> Result<()> {
 
     // Get the files to diff
     let files_to_diff = context.get_selected_files();
+    let pager = context.git_repo.git_defaults().pager_command();
+    if stat {
+        let stats = files_to_diff
+            .iter()
+            .map(|file| file_stat(&context.git_repo, file))
+            .collect::<Result<Vec<_>>>()?;
+        print_paged(&render_diffstat(&stats), pager.as_deref());
+        return Ok(());
+    }
+
+    let tool = DiffTool::resolve(
+        tool.as_deref(),
+        &context.git_repo.load_settings(),
+        context.git_repo.git_defaults().diff_tool.as_deref(),
+    );
 
     let all_untracked = files_to_diff
         .iter()
         .all(|f| f.status == GitStatus::Untracked);
 
+    // Collect everything first so long diffs can go through the user's pager
+    let mut output = String::new();
+
     if !all_untracked {
-        println!("Showing diff for {} file(s):", files_to_diff.len());
+        let _ = writeln!(output, "Showing diff for {} file(s):", files_to_diff.len());
         for file in &files_to_diff {
-            println!("  [{}] {}", file.index, file.path.display());
+            if is_plain_mode() {
+                let _ = writeln!(output, "Index {}: {}", file.index, file.path.display());
+            } else {
+                let path = link(&file.path.display().to_string(), &file.path);
+                let _ = writeln!(output, "  {} {path}", colored_index(file.index));
+            }
         }
-        println!();
+        output.push('\n');
     }
 
     // Show diff for each file
     for (i, file) in files_to_diff.iter().enumerate() {
         if files_to_diff.len() > 1 {
             if i > 0 {
-                println!(); // Extra spacing between files
+                output.push('\n'); // Extra spacing between files
+            }
+            if is_plain_mode() {
+                let _ = writeln!(output, "File: {}", file.path.display());
+            } else {
+                let _ = writeln!(
+                    output,
+                    "{} {} {}",
+                    glyphs().rule.bright_blue().bold(),
+                    link(
+                        &file.path.to_string_lossy().bright_blue().bold().to_string(),
+                        &file.path
+                    ),
+                    glyphs().rule.bright_blue().bold()
+                );
             }
-            print!("{}", "═══ ".bright_blue().bold());
-            print!("{}", file.path.to_string_lossy().bright_blue().bold());
-            println!("{}", " ═══".bright_blue().bold());
         }
-        show_file_diff(&context.git_repo, file)?;
+        output.push_str(&show_file_diff_with(
+            &context.git_repo,
+            file,
+            tool.as_ref(),
+        )?);
     }
 
+    print_paged(&output, pager.as_deref());
+
     Ok(())
 }
 
-fn show_file_diff(git_repo: &GitRepo, file: &FileEntry) -> Result<()> {
-    let workdir = git_repo.get_repository().workdir().ok_or_else(|| {
-        crate::core::error::GitNavigatorError::custom_empty_files_error("No workdir found")
-    })?;
+/// Render the diff of a single file, ready to print
+pub(crate) fn show_file_diff(git_repo: &GitRepo, file: &FileEntry) -> Result<String> {
+    show_file_diff_with(git_repo, file, None)
+}
 
-    let mut cmd = std::process::Command::new("git");
-    cmd.current_dir(workdir);
+/// [`show_file_diff`] shown by an external diff tool
+pub(crate) fn show_file_diff_with(
+    git_repo: &GitRepo,
+    file: &FileEntry,
+    tool: Option<&DiffTool>,
+) -> Result<String> {
+    let mut cmd = git_diff_command(git_repo)?;
+    if let Some(tool) = tool {
+        tool.configure(&mut cmd);
+    }
+
+    // Follow color.ui / NO_COLOR instead of always forcing color
+    let color_flag = if colored::control::SHOULD_COLORIZE.should_colorize() {
+        "--color"
+    } else {
+        "--no-color"
+    };
 
     match file.status {
         GitStatus::Untracked => {
-            print_error(&format!(
+            return Ok(format_error(&format!(
                 "File is untracked: {}. No diff to show.",
                 file.path.display()
-            ));
-            return Ok(());
+            )) + "\n");
         }
-        GitStatus::Deleted => {
-            cmd.arg("diff")
-                .arg("--color")
-                .arg("HEAD")
-                .arg("--")
-                .arg(&file.path);
+        GitStatus::Submodule(_) => {
+            // The changes inside the submodule rather than two commit ids
+            cmd.arg(color_flag).arg("--submodule=diff");
+            add_revision_args(&mut cmd, file);
         }
         _ => {
-            if file.staged {
-                cmd.arg("diff")
-                    .arg("--cached")
-                    .arg("--color")
-                    .arg("HEAD")
-                    .arg("--")
-                    .arg(&file.path);
-            } else {
-                cmd.arg("diff").arg("--color").arg("--").arg(&file.path);
-            }
+            cmd.arg(color_flag);
+            add_revision_args(&mut cmd, file);
         }
     }
 
@@ -103,59 +162,244 @@ fn show_file_diff(git_repo: &GitRepo, file: &FileEntry) -> Result<()> {
     if output.status.success() {
         let diff_output = String::from_utf8_lossy(&output.stdout);
         if !diff_output.trim().is_empty() {
-            println!("{diff_output}");
+            let diff_output = match tool {
+                Some(tool) => tool.apply(&diff_output)?,
+                None => diff_output.into_owned(),
+            };
+            Ok(format!("{diff_output}\n"))
         } else {
-            println!("No changes to show for {}", file.path.display());
+            Ok(format!("No changes to show for {}\n", file.path.display()))
         }
     } else {
         let error_msg = String::from_utf8_lossy(&output.stderr);
-        return Err(
+        Err(
             crate::core::error::GitNavigatorError::custom_empty_files_error(format!(
                 "git diff failed: {}",
                 error_msg.trim()
             )),
+        )
+    }
+}
+
+/// `git diff` run from the top of the worktree
+fn git_diff_command(git_repo: &GitRepo) -> Result<Command> {
+    let workdir = git_repo.get_repository().workdir().ok_or_else(|| {
+        crate::core::error::GitNavigatorError::custom_empty_files_error("No workdir found")
+    })?;
+
+    let mut cmd = Command::new("git");
+    cmd.current_dir(workdir).arg("diff");
+    Ok(cmd)
+}
+
+/// What to compare `file` against: HEAD for staged and deleted files, else
+/// the index
+fn add_revision_args(cmd: &mut Command, file: &FileEntry) {
+    if file.status == GitStatus::Deleted {
+        cmd.arg("HEAD");
+    } else if file.staged {
+        cmd.arg("--cached").arg("HEAD");
+    }
+    match &file.old_path {
+        // Both ends, so git pairs them up again
+        Some(old_path) => {
+            cmd.arg("--find-copies")
+                .arg("--")
+                .arg(old_path)
+                .arg(&file.path);
+        }
+        None => {
+            cmd.arg("--").arg(&file.path);
+        }
+    }
+}
+
+/// One line of `gd --stat`
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FileStat {
+    index: usize,
+    path: String,
+    /// `None` for binary files
+    lines: Option<(usize, usize)>,
+}
+
+/// Lines added and removed in `file`, counted by `git diff --numstat`
+fn file_stat(git_repo: &GitRepo, file: &FileEntry) -> Result<FileStat> {
+    let mut cmd = git_diff_command(git_repo)?;
+    cmd.arg("--numstat");
+    if file.status == GitStatus::Untracked {
+        // Counted as added in full; --no-index exits 1 when the files differ
+        cmd.args(["--no-index", "--", "/dev/null"]).arg(&file.path);
+    } else {
+        add_revision_args(&mut cmd, file);
+    }
+
+    let output = cmd.output().map_err(GitNavigatorError::Io)?;
+    if !output.status.success() && output.status.code() != Some(1) {
+        let error_msg = String::from_utf8_lossy(&output.stderr);
+        return Err(GitNavigatorError::custom_empty_files_error(format!(
+            "git diff failed: {}",
+            error_msg.trim()
+        )));
+    }
+
+    let numstat = String::from_utf8_lossy(&output.stdout);
+    let mut counts = numstat.split_whitespace();
+    let lines = match (counts.next(), counts.next()) {
+        (Some("-"), Some("-")) => None,
+        (added, removed) => Some((
+            added.and_then(|n| n.parse().ok()).unwrap_or(0),
+            removed.and_then(|n| n.parse().ok()).unwrap_or(0),
+        )),
+    };
+    Ok(FileStat {
+        index: file.index,
+        path: file.path.display().to_string(),
+        lines,
+    })
+}
+
+/// `git diff --stat` style summary, with each file's index in front:
+///
+/// ```text
+///  [1] src/main.rs | 12 ++++++++----
+///  [2] logo.png    | Bin
+///  2 files changed, 8 insertions(+), 4 deletions(-)
+/// ```
+fn render_diffstat(stats: &[FileStat]) -> String {
+    let label = |stat: &FileStat| {
+        let number = templates().index_number(stat.index);
+        let plain = templates().index.replace("{n}", &number);
+        let width = plain.chars().count() + 1 + stat.path.chars().count();
+        (
+            format!("{} {}", colored_index(stat.index), stat.path),
+            width,
+        )
+    };
+    let name_width = stats.iter().map(|stat| label(stat).1).max().unwrap_or(0);
+    let changes = |stat: &FileStat| stat.lines.map(|(added, removed)| added + removed);
+    let most = stats.iter().filter_map(changes).max().unwrap_or(0);
+    let count_width = most.to_string().len();
+
+    let mut output = String::new();
+    let (mut insertions, mut deletions) = (0, 0);
+    for stat in stats {
+        let (name, width) = label(stat);
+        let padding = " ".repeat(name_width - width);
+        let Some((added, removed)) = stat.lines else {
+            let _ = writeln!(output, " {name}{padding} | Bin");
+            continue;
+        };
+        insertions += added;
+        deletions += removed;
+
+        let (plus, minus) = scale_bar(added, removed, most);
+        let _ = writeln!(
+            output,
+            " {name}{padding} | {:>count_width$} {}{}",
+            added + removed,
+            "+".repeat(plus).green(),
+            "-".repeat(minus).red()
         );
     }
 
-    Ok(())
+    let plural =
+        |n: usize, one: &str, many: &str| format!("{n} {}", if n == 1 { one } else { many });
+    let mut summary = plural(stats.len(), "file changed", "files changed");
+    if insertions > 0 || deletions == 0 {
+        summary.push_str(&format!(
+            ", {}",
+            plural(insertions, "insertion(+)", "insertions(+)")
+        ));
+    }
+    if deletions > 0 {
+        summary.push_str(&format!(
+            ", {}",
+            plural(deletions, "deletion(-)", "deletions(-)")
+        ));
+    }
+    let _ = writeln!(output, " {summary}");
+    output
+}
+
+/// The +/- bar lengths for a file, shrunk in proportion when the largest
+/// change (`most`) is wider than [`STAT_BAR_WIDTH`]
+fn scale_bar(added: usize, removed: usize, most: usize) -> (usize, usize) {
+    if most <= STAT_BAR_WIDTH {
+        return (added, removed);
+    }
+    // Keep at least one mark for any change so nothing disappears
+    let scale = |n: usize| match n {
+        0 => 0,
+        n => (n * STAT_BAR_WIDTH / most).max(1),
+    };
+    (scale(added), scale(removed))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::error::GitNavigatorError;
+    use crate::core::templates::strip_ansi_codes;
     use std::{env, fs};
     use tempfile::TempDir;
 
     fn setup_test_repo() -> Result<(TempDir, GitRepo)> {
-        let temp_dir = TempDir::new().map_err(|e| GitNavigatorError::Io(e))?;
+        let temp_dir = TempDir::new().map_err(GitNavigatorError::Io)?;
         let repo_path = temp_dir.path();
 
         std::process::Command::new("git")
             .args(["init"])
-            .current_dir(&repo_path)
+            .current_dir(repo_path)
             .output()
-            .map_err(|e| GitNavigatorError::Io(e))?;
+            .map_err(GitNavigatorError::Io)?;
 
         std::process::Command::new("git")
             .args(["config", "user.name", "Test User"])
-            .current_dir(&repo_path)
+            .current_dir(repo_path)
             .output()
-            .map_err(|e| GitNavigatorError::Io(e))?;
+            .map_err(GitNavigatorError::Io)?;
 
         std::process::Command::new("git")
             .args(["config", "user.email", "test@example.com"])
-            .current_dir(&repo_path)
+            .current_dir(repo_path)
             .output()
-            .map_err(|e| GitNavigatorError::Io(e))?;
+            .map_err(GitNavigatorError::Io)?;
 
-        let git_repo = GitRepo::open(&repo_path)?;
+        let git_repo = GitRepo::open(repo_path)?;
         Ok((temp_dir, git_repo))
     }
 
+    fn stat(index: usize, path: &str, lines: Option<(usize, usize)>) -> FileStat {
+        FileStat {
+            index,
+            path: path.to_string(),
+            lines,
+        }
+    }
+
+    #[test]
+    fn test_render_diffstat() {
+        let output = render_diffstat(&[
+            stat(1, "src/main.rs", Some((3, 1))),
+            stat(12, "logo.png", None),
+        ]);
+        assert_eq!(
+            strip_ansi_codes(&output),
+            " [1] src/main.rs | 4 +++-\n [12] logo.png   | Bin\n 2 files changed, 3 insertions(+), 1 deletion(-)\n"
+        );
+    }
+
+    #[test]
+    fn test_scale_bar() {
+        assert_eq!(scale_bar(3, 1, 4), (3, 1));
+        assert_eq!(scale_bar(100, 1, 200), (20, 1));
+        assert_eq!(scale_bar(0, 400, 400), (0, STAT_BAR_WIDTH));
+    }
+
     #[test]
     fn test_execute_diff_no_indices() {
-        let result = execute_diff(vec![]);
+        let result = execute_diff(vec![], None, false);
         assert!(result.is_err());
         let error_msg = result.unwrap_err().to_string();
         assert!(
@@ -166,25 +410,25 @@ mod tests {
 
     #[test]
     fn test_execute_diff_empty_indices() {
-        let result = execute_diff(vec!["".to_string()]);
+        let result = execute_diff(vec!["".to_string()], None, false);
         assert!(result.is_err());
     }
 
     #[test]
     fn test_execute_diff_invalid_indices() {
-        let result = execute_diff(vec!["abc".to_string()]);
+        let result = execute_diff(vec!["abc".to_string()], None, false);
         assert!(result.is_err());
     }
 
     #[test]
     fn test_execute_diff_not_in_git_repo() -> Result<()> {
-        let temp_dir = TempDir::new().map_err(|e| GitNavigatorError::Io(e))?;
+        let temp_dir = TempDir::new().map_err(GitNavigatorError::Io)?;
         let non_repo_path = temp_dir.path();
 
         let original_dir = env::current_dir()?;
         env::set_current_dir(non_repo_path)?;
 
-        let result = execute_diff(vec!["1".to_string()]);
+        let result = execute_diff(vec!["1".to_string()], None, false);
 
         env::set_current_dir(original_dir)?;
 
@@ -209,6 +453,7 @@ mod tests {
             status: GitStatus::Untracked,
             path: "test.txt".into(),
             staged: false,
+            old_path: None,
         };
 
         let result = show_file_diff(&git_repo, &file_entry);
@@ -223,27 +468,27 @@ mod tests {
         let workdir = git_repo.get_repository().workdir().unwrap();
 
         let test_file = workdir.join("test.txt");
-        std::fs::write(&test_file, "original content\n").map_err(|e| GitNavigatorError::Io(e))?;
+        std::fs::write(&test_file, "original content\n").map_err(GitNavigatorError::Io)?;
 
         std::process::Command::new("git")
             .args(["add", "test.txt"])
             .current_dir(workdir)
             .output()
-            .map_err(|e| GitNavigatorError::Io(e))?;
+            .map_err(GitNavigatorError::Io)?;
 
         std::process::Command::new("git")
             .args(["commit", "-m", "Initial commit"])
             .current_dir(workdir)
             .output()
-            .map_err(|e| GitNavigatorError::Io(e))?;
+            .map_err(GitNavigatorError::Io)?;
 
-        std::fs::write(&test_file, "modified content\n").map_err(|e| GitNavigatorError::Io(e))?;
+        std::fs::write(&test_file, "modified content\n").map_err(GitNavigatorError::Io)?;
 
         let output = std::process::Command::new("git")
             .args(["diff", "--", "test.txt"])
             .current_dir(workdir)
             .output()
-            .map_err(|e| GitNavigatorError::Io(e))?;
+            .map_err(GitNavigatorError::Io)?;
 
         let diff_output = String::from_utf8_lossy(&output.stdout);
         assert!(!diff_output.trim().is_empty());
@@ -264,6 +509,7 @@ mod tests {
             status: GitStatus::Modified,
             path: PathBuf::from("nonexistent.txt"),
             staged: false,
+            old_path: None,
         };
 
         assert_eq!(file_entry.path, PathBuf::from("nonexistent.txt"));
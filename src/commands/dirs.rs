@@ -0,0 +1,200 @@
+use crate::core::{
+    cache::CacheStore,
+    error::{GitNavigatorError, Result},
+    git::GitRepo,
+    is_plain_mode, print_info, print_section_header,
+    state::{DirEntry, FileEntry},
+    templates::colored_index,
+};
+use colored::*;
+use std::collections::BTreeMap;
+use std::env;
+use std::path::{Path, PathBuf};
+
+pub fn execute_dirs(index: Option<usize>) -> Result<()> {
+    // Check if we're in a git repository
+    let current_dir = env::current_dir()?;
+    let git_repo = GitRepo::open(&current_dir).map_err(|_| GitNavigatorError::NotInGitRepo)?;
+
+    if let Some(index) = index {
+        // Print the directory for the shell wrapper to cd into
+        let path = resolve_dir(&git_repo, index)?;
+        println!("{}", path.display());
+        Ok(())
+    } else {
+        list_dirs(&git_repo)
+    }
+}
+
+fn list_dirs(git_repo: &GitRepo) -> Result<()> {
+    let dirs = changed_dirs(&git_repo.get_status()?);
+
+    if dirs.is_empty() {
+        print_info("No changed files, so there are no directories to list.");
+        return Ok(());
+    }
+
+    print_section_header("Changed Directories");
+
+    for dir in &dirs {
+        let count = if dir.files == 1 {
+            "1 file".to_string()
+        } else {
+            format!("{} files", dir.files)
+        };
+
+        if is_plain_mode() {
+            println!("Index {}: {} ({count})", dir.index, dir.path.display());
+        } else {
+            println!(
+                "{} {} {}",
+                colored_index(dir.index),
+                dir.path.display().to_string().blue(),
+                format!("({count})").bright_black()
+            );
+        }
+    }
+
+    // Add spacing after directory list
+    println!();
+
+    // Save to cache so `dirs <index>` can resolve the numbers
+    #[cfg(not(test))]
+    {
+        if let Err(e) = git_repo
+            .cache_store()
+            .and_then(|store| store.save_dirs(&dirs))
+        {
+            // Log cache errors but don't fail the command
+            log::warn!("Directory cache save failed: {e}");
+        }
+    }
+
+    Ok(())
+}
+
+/// Absolute path of a directory numbered by the last `dirs`
+fn resolve_dir(git_repo: &GitRepo, index: usize) -> Result<PathBuf> {
+    let store = git_repo.cache_store()?;
+    let rebuild = || Ok(changed_dirs(&git_repo.get_status()?));
+    let dirs = store
+        .load_or_rebuild(CacheStore::load_dirs, CacheStore::save_dirs, rebuild)
+        .map_err(|e| {
+            log::warn!("Failed to load directory cache: {e}");
+            GitNavigatorError::custom_cache_error(
+                "Cannot load directory cache. Run 'dirs' first to list directories.",
+                e,
+            )
+        })?;
+
+    let dir = dirs
+        .into_iter()
+        .find(|dir| dir.index == index)
+        .ok_or_else(|| {
+            GitNavigatorError::custom_empty_files_error(format!(
+                "Directory index {index} not found"
+            ))
+        })?;
+
+    let workdir = git_repo
+        .get_repository()
+        .workdir()
+        .ok_or_else(|| GitNavigatorError::custom_empty_files_error("No workdir found"))?;
+    let path = if dir.path == Path::new(".") {
+        workdir.to_path_buf()
+    } else {
+        workdir.join(&dir.path)
+    };
+
+    if !path.is_dir() {
+        return Err(GitNavigatorError::custom_empty_files_error(format!(
+            "Directory '{}' no longer exists",
+            dir.path.display()
+        )));
+    }
+
+    Ok(path)
+}
+
+/// Directories containing the changed files, sorted and numbered
+///
+/// Untracked directories are reported by git as a single `dir/` entry and
+/// count as a change inside that directory.
+pub(crate) fn changed_dirs(files: &[FileEntry]) -> Vec<DirEntry> {
+    let mut counts: BTreeMap<PathBuf, usize> = BTreeMap::new();
+
+    for file in files {
+        let dir = if file.path.to_string_lossy().ends_with('/') {
+            file.path.components().collect::<PathBuf>()
+        } else {
+            file.path
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_default()
+        };
+        let dir = if dir.as_os_str().is_empty() {
+            PathBuf::from(".")
+        } else {
+            dir
+        };
+        *counts.entry(dir).or_default() += 1;
+    }
+
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(i, (path, files))| DirEntry {
+            index: i + 1,
+            path,
+            files,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::git_status::GitStatus;
+
+    fn file(index: usize, path: &str) -> FileEntry {
+        FileEntry {
+            index,
+            status: GitStatus::Modified,
+            path: PathBuf::from(path),
+            staged: false,
+            old_path: None,
+        }
+    }
+
+    #[test]
+    fn test_changed_dirs_groups_and_numbers() {
+        let files = vec![
+            file(1, "src/core/git.rs"),
+            file(2, "README.md"),
+            file(3, "src/core/cache.rs"),
+            file(4, "src/main.rs"),
+            file(5, "docs/new/"),
+        ];
+
+        let dirs = changed_dirs(&files);
+        let summary: Vec<_> = dirs
+            .iter()
+            .map(|dir| (dir.index, dir.path.to_string_lossy().to_string(), dir.files))
+            .collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                (1, ".".to_string(), 1),
+                (2, "docs/new".to_string(), 1),
+                (3, "src".to_string(), 1),
+                (4, "src/core".to_string(), 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_changed_dirs_empty() {
+        assert!(changed_dirs(&[]).is_empty());
+    }
+}
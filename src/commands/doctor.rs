@@ -0,0 +1,117 @@
+use crate::core::{
+    credentials::TOKEN_VARIABLES,
+    error::{GitNavigatorError, Result},
+    format_age,
+    git::GitRepo,
+    network::NetworkCheck,
+    print_error, print_info, print_section_header, print_success,
+};
+use clap::Parser;
+use std::env;
+
+#[derive(Parser)]
+pub struct DoctorArgs {
+    /// Only check this remote (default: every remote)
+    pub remote: Option<String>,
+}
+
+pub fn execute_doctor(args: DoctorArgs) -> Result<()> {
+    // Check if we're in a git repository
+    let current_dir = env::current_dir()?;
+    let git_repo = GitRepo::open(&current_dir).map_err(|_| GitNavigatorError::NotInGitRepo)?;
+
+    print_section_header("Credentials");
+    show_credential_sources(&git_repo);
+
+    print_section_header("Cache");
+    show_cache_ages(&git_repo)?;
+
+    let remotes = match args.remote {
+        Some(remote) => vec![remote],
+        None => git_repo.remote_names()?,
+    };
+    if remotes.is_empty() {
+        print_info("No remotes configured, nothing to check.");
+        return Ok(());
+    }
+
+    let network = NetworkCheck::for_repo(&git_repo);
+    let mut failures = 0;
+    for remote in &remotes {
+        let result = match git_repo.remote_url(remote, false) {
+            Some(url) => network
+                .ensure_remote_reachable(&url)
+                .and_then(|()| git_repo.check_remote_access(remote)),
+            None => Err(GitNavigatorError::RemoteNotFound(remote.clone())),
+        };
+        match result {
+            Ok(refs) => print_success(&format!("{remote}: access OK ({refs} refs advertised)")),
+            Err(e) => {
+                failures += 1;
+                print_error(&format!("{remote}: {e}"));
+            }
+        }
+    }
+
+    if failures > 0 {
+        return Err(GitNavigatorError::CredentialCheckFailed {
+            failed: failures,
+            total: remotes.len(),
+        });
+    }
+    Ok(())
+}
+
+/// How old each cache file is, and when it expires
+fn show_cache_ages(git_repo: &GitRepo) -> Result<()> {
+    let store = git_repo.cache_store()?;
+    let ttl = store.ttl();
+    let ttl_text = match ttl.max_age {
+        Some(max_age) if ttl.refresh => format!("{} (then rebuilt)", format_age(max_age)),
+        Some(max_age) => format!("{} (then listed again)", format_age(max_age)),
+        None => "none".to_string(),
+    };
+    println!("{:<19}{ttl_text}", "ttl:");
+
+    let ages = store.file_ages();
+    if ages.is_empty() {
+        println!("{:<19}none yet", "files:");
+    }
+    for (file_name, age) in ages {
+        let expired = ttl.max_age.is_some_and(|max_age| age > max_age);
+        println!(
+            "{:<19}updated {} ago{}",
+            format!("{file_name}:"),
+            format_age(age),
+            if expired { " (expired)" } else { "" }
+        );
+    }
+    Ok(())
+}
+
+/// Where credentials could come from
+fn show_credential_sources(git_repo: &GitRepo) {
+    let agent = match env::var("SSH_AUTH_SOCK") {
+        Ok(socket) if !socket.is_empty() => format!("running ({socket})"),
+        _ => "not running (SSH_AUTH_SOCK is unset)".to_string(),
+    };
+    let helper = git_repo
+        .config_string("credential.helper")
+        .unwrap_or_else(|| "not configured".to_string());
+    let tokens: Vec<&str> = TOKEN_VARIABLES
+        .iter()
+        .copied()
+        .filter(|name| env::var(name).is_ok_and(|value| !value.is_empty()))
+        .collect();
+
+    println!("ssh-agent:         {agent}");
+    println!("credential.helper: {helper}");
+    println!(
+        "tokens:            {}",
+        if tokens.is_empty() {
+            "none".to_string()
+        } else {
+            tokens.join(", ")
+        }
+    );
+}
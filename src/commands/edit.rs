@@ -0,0 +1,120 @@
+use crate::core::{
+    command_init::IndexCommandInit,
+    editor::Editor,
+    error::{GitNavigatorError, Result},
+    git::GitRepo,
+    git_status::GitStatus,
+    print_error_with_structured_usage,
+    state::FileEntry,
+};
+use clap::Parser;
+use std::path::{Path, PathBuf};
+
+#[derive(Parser)]
+pub struct EditArgs {
+    /// File indices to open (e.g., "1 3-5"), all in one editor
+    #[arg(allow_negative_numbers = true)]
+    pub indices: Vec<String>,
+
+    /// Open the files at their first changed line
+    #[arg(long)]
+    pub at_change: bool,
+
+    /// Open the files at this line
+    #[arg(short = 'n', long, value_name = "LINE", conflicts_with = "at_change")]
+    pub line: Option<u32>,
+}
+
+pub fn execute_edit(args: EditArgs) -> Result<()> {
+    let context = match IndexCommandInit::initialize_with_messages(
+        args.indices,
+        "Cannot load file cache",
+        "No files available to edit",
+    ) {
+        Ok(context) => context,
+        Err(GitNavigatorError::NoIndicesProvided) => {
+            print_error_with_structured_usage(
+                "No file index provided",
+                "edit",
+                &["<index>...", "<index>... --at-change", "-n <line> <index>"],
+                &[
+                    ("--at-change", "Open the files at their first changed line"),
+                    ("-n, --line <LINE>", "Open the files at this line"),
+                    ("-h, --help", "Show this help message"),
+                ],
+            );
+            return Err(GitNavigatorError::NoIndicesProvided);
+        }
+        Err(e) => return Err(e),
+    };
+
+    let git_repo = &context.git_repo;
+    let workdir = git_repo
+        .get_repository()
+        .workdir()
+        .ok_or_else(|| GitNavigatorError::custom_empty_files_error("No workdir found"))?;
+
+    let mut files: Vec<(PathBuf, Option<u32>)> = Vec::new();
+    for file in context.get_selected_files() {
+        let path = workdir.join(&file.path);
+        let line = match args.line {
+            Some(line) => Some(line),
+            None => start_line(git_repo, file, &path, args.at_change)?,
+        };
+        files.push((path, line));
+    }
+
+    let files: Vec<(&Path, Option<u32>)> = files
+        .iter()
+        .map(|(path, line)| (path.as_path(), *line))
+        .collect();
+    let editor = Editor::new(git_repo.git_defaults().editor_command());
+    editor.open_files(&git_repo.load_settings(), &files)
+}
+
+/// Where to open `file` without `-n`: at its first conflict when unmerged,
+/// at its first change with `at_change`
+fn start_line(
+    git_repo: &GitRepo,
+    file: &FileEntry,
+    path: &Path,
+    at_change: bool,
+) -> Result<Option<u32>> {
+    if file.status == GitStatus::Unmerged {
+        if let Some(line) = std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| first_conflict_line(&content))
+        {
+            return Ok(Some(line));
+        }
+    }
+    if !at_change {
+        return Ok(None);
+    }
+
+    // Prefer the side of the diff gs listed the file under, then the other one
+    match git_repo.first_changed_line(&file.path, file.staged)? {
+        Some(line) => Ok(Some(line)),
+        None => git_repo.first_changed_line(&file.path, !file.staged),
+    }
+}
+
+/// The line of the first `<<<<<<<` conflict marker, counting from 1
+fn first_conflict_line(content: &str) -> Option<u32> {
+    content
+        .lines()
+        .position(|line| line.starts_with("<<<<<<<"))
+        .map(|index| index as u32 + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_conflict_line() {
+        let content = "one\n<<<<<<< HEAD\nours\n=======\ntheirs\n>>>>>>> topic\n";
+        assert_eq!(first_conflict_line(content), Some(2));
+        assert_eq!(first_conflict_line("no conflicts\n"), None);
+    }
+}
@@ -0,0 +1,173 @@
+use crate::core::{
+    command_init::IndexCommandInit,
+    error::{GitNavigatorError, Result},
+    print_error_with_structured_usage,
+    state::FileEntry,
+};
+use clap::Parser;
+use std::process::Command;
+
+#[derive(Parser)]
+pub struct ExecArgs {
+    /// File indices whose paths go after the command (e.g., "1 3-5")
+    #[arg(allow_negative_numbers = true)]
+    pub indices: Vec<String>,
+
+    /// Command to run in the repository root, after `--`; `{n}` stands for
+    /// the path of file [n]
+    #[arg(last = true, required = true, value_name = "COMMAND")]
+    pub command: Vec<String>,
+}
+
+pub fn execute_exec(args: ExecArgs) -> Result<()> {
+    let placeholders: Vec<usize> = args
+        .command
+        .iter()
+        .flat_map(|word| placeholders(word))
+        .collect();
+
+    // The placeholders are indices too, checked against the list like the others
+    let mut indices_args = args.indices;
+    indices_args.extend(placeholders.iter().map(usize::to_string));
+    let context = match IndexCommandInit::initialize_with_messages(
+        indices_args,
+        "Cannot load file cache",
+        "No files available to run a command on",
+    ) {
+        Ok(context) => context,
+        Err(GitNavigatorError::NoIndicesProvided) => {
+            print_error_with_structured_usage(
+                "No file indices provided",
+                "exec",
+                &["<index>... -- <command>...", "-- <command> {<index>}..."],
+                &[("-h, --help", "Show this help message")],
+            );
+            return Err(GitNavigatorError::NoIndicesProvided);
+        }
+        Err(e) => return Err(e),
+    };
+
+    let workdir = context
+        .git_repo
+        .get_repository()
+        .workdir()
+        .ok_or_else(|| GitNavigatorError::custom_empty_files_error("No workdir found"))?;
+
+    let mut command: Vec<String> = args
+        .command
+        .iter()
+        .map(|word| substitute(word, &context.files))
+        .collect();
+    // Without placeholders, the selected paths go last, as with xargs
+    if placeholders.is_empty() {
+        command.extend(
+            context
+                .get_selected_files()
+                .iter()
+                .map(|file| file.path.to_string_lossy().into_owned()),
+        );
+    }
+
+    let (program, program_args) = command
+        .split_first()
+        .ok_or_else(|| GitNavigatorError::exec_failed("", "no command given"))?;
+    log::debug!("Running {command:?} in {}", workdir.display());
+    let status = Command::new(program)
+        .args(program_args)
+        .current_dir(workdir)
+        .status()
+        .map_err(|e| GitNavigatorError::exec_failed(program, e.to_string()))?;
+    if !status.success() {
+        return Err(GitNavigatorError::exec_failed(
+            program,
+            format!("exited with {status}"),
+        ));
+    }
+    Ok(())
+}
+
+/// The indices of the `{n}` placeholders in `word`, in order
+fn placeholders(word: &str) -> Vec<usize> {
+    let mut found = Vec::new();
+    let mut rest = word;
+    while let Some(start) = rest.find('{') {
+        rest = &rest[start + 1..];
+        if let Some((index, _)) = placeholder_at(rest) {
+            found.push(index);
+        }
+    }
+    found
+}
+
+/// The index of a `{n}` placeholder whose `{` was just before `text`, and
+/// the length of `n}`
+fn placeholder_at(text: &str) -> Option<(usize, usize)> {
+    let end = text.find('}')?;
+    let digits = &text[..end];
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some((digits.parse().ok()?, end + 1))
+}
+
+/// `word` with each `{n}` replaced by the path of file [n]; other braces
+/// (`{}`, `{a,b}`) are left alone
+fn substitute(word: &str, files: &[FileEntry]) -> String {
+    let mut result = String::with_capacity(word.len());
+    let mut rest = word;
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start + 1..];
+        match placeholder_at(rest).and_then(|(index, len)| {
+            files
+                .get(index.checked_sub(1)?)
+                .map(|file| (file.path.to_string_lossy(), len))
+        }) {
+            Some((path, len)) => {
+                result.push_str(&path);
+                rest = &rest[len..];
+            }
+            None => result.push('{'),
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::git_status::GitStatus;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_placeholders() {
+        assert_eq!(placeholders("{1}"), vec![1]);
+        assert_eq!(placeholders("--out={3}.bak,{12}"), vec![3, 12]);
+        assert!(placeholders("{}").is_empty());
+        assert!(placeholders("{a,b} {1 }").is_empty());
+    }
+
+    #[test]
+    fn test_substitute() {
+        let files: Vec<FileEntry> = ["src/main.rs", "my file.txt"]
+            .iter()
+            .enumerate()
+            .map(|(i, path)| FileEntry {
+                index: i + 1,
+                status: GitStatus::Modified,
+                path: PathBuf::from(path),
+                staged: false,
+                old_path: None,
+            })
+            .collect();
+
+        assert_eq!(substitute("{1}", &files), "src/main.rs");
+        assert_eq!(substitute("{2}", &files), "my file.txt");
+        assert_eq!(
+            substitute("--out={1}.bak{}", &files),
+            "--out=src/main.rs.bak{}"
+        );
+        assert_eq!(substitute("{{1}}", &files), "{src/main.rs}");
+    }
+}
@@ -0,0 +1,39 @@
+use crate::core::{
+    error::{GitNavigatorError, Result},
+    git::GitRepo,
+    network::NetworkCheck,
+    print_success,
+};
+use clap::Parser;
+use std::env;
+
+#[derive(Parser)]
+pub struct FetchArgs {
+    /// Remote to fetch (default: the current branch's upstream remote, or origin)
+    pub remote: Option<String>,
+}
+
+pub fn execute_fetch(args: FetchArgs) -> Result<()> {
+    // Check if we're in a git repository
+    let current_dir = env::current_dir()?;
+    let git_repo = GitRepo::open(&current_dir).map_err(|_| GitNavigatorError::NotInGitRepo)?;
+
+    let remote = match args.remote {
+        Some(remote) => remote,
+        None => git_repo
+            .get_upstream()?
+            .map(|upstream| upstream.remote)
+            .unwrap_or_else(|| "origin".to_string()),
+    };
+
+    let Some(url) = git_repo.remote_url(&remote, false) else {
+        return Err(GitNavigatorError::fetch_failed(format!(
+            "there is no '{remote}' remote"
+        )));
+    };
+    NetworkCheck::for_repo(&git_repo).ensure_remote_reachable(&url)?;
+
+    git_repo.fetch(&remote)?;
+    print_success(&format!("Fetched {remote}"));
+    Ok(())
+}
@@ -0,0 +1,238 @@
+use crate::core::config::Settings;
+use crate::core::error::Result;
+use crate::core::invocation::{configured_aliases, ALIAS_ENV};
+use clap::{Parser, ValueEnum};
+use std::path::PathBuf;
+
+#[derive(Parser)]
+pub struct InitArgs {
+    /// Shell to print the functions for
+    pub shell: Shell,
+
+    /// Also set $e1..$eN to the numbered files after each gs
+    #[arg(long)]
+    pub export_env: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+pub fn execute_init(args: InitArgs) -> Result<()> {
+    // The names live in the global settings: the functions are the same in every repository
+    let aliases = configured_aliases(&Settings::load());
+    print!("{}", init_script(args.shell, args.export_env, &aliases));
+    Ok(())
+}
+
+/// Functions for the short names, plus `gcd`, `grepo` and `gwt`, which have
+/// to be functions to change the shell's directory. Each function names itself in
+/// `GIT_NAVIGATOR_ALIAS` so usage text can show the short name.
+fn init_script(shell: Shell, export_env: bool, aliases: &[(String, &str)]) -> String {
+    let shell_name = shell
+        .to_possible_value()
+        .map(|value| value.get_name().to_string())
+        .unwrap_or_default();
+    let mut script = String::new();
+    match shell {
+        Shell::Bash | Shell::Zsh => {
+            // An alias with the same name would win over the function, and
+            // zsh refuses to define a function over one
+            let names: Vec<&str> = aliases.iter().map(|(name, _)| name.as_str()).collect();
+            script.push_str(&format!(
+                "for _gn_name in {} gcd grepo gwt; do\n    unalias \"$_gn_name\" 2>/dev/null\ndone\nunset _gn_name\n\n",
+                names.join(" ")
+            ));
+            for (name, command) in aliases {
+                if export_env && *command == "status" {
+                    script.push_str(&format!(
+                        "{name}() {{ {ALIAS_ENV}={name} git-navigator status \"$@\" || return; eval \"$(git-navigator status --export-env={shell_name})\"; }}\n"
+                    ));
+                } else {
+                    script.push_str(&format!(
+                        "{name}() {{ {ALIAS_ENV}={name} git-navigator {command} \"$@\"; }}\n"
+                    ));
+                }
+            }
+            script.push_str(concat!(
+                "gcd() {\n",
+                "    if [ $# -eq 0 ]; then\n",
+                "        git-navigator dirs\n",
+                "    else\n",
+                "        local dir\n",
+                "        dir=\"$(git-navigator dirs \"$1\")\" && cd \"$dir\" || printf '%s\\n' \"$dir\"\n",
+                "    fi\n",
+                "}\n",
+                "grepo() {\n",
+                "    if [ $# -eq 0 ]; then\n",
+                "        git-navigator repos\n",
+                "    else\n",
+                "        local dir\n",
+                "        dir=\"$(git-navigator repos \"$1\")\" && cd \"$dir\" || printf '%s\\n' \"$dir\"\n",
+                "    fi\n",
+                "}\n",
+                "gwt() {\n",
+                "    if [ $# -eq 0 ]; then\n",
+                "        git-navigator worktrees\n",
+                "    else\n",
+                "        local dir\n",
+                "        dir=\"$(git-navigator worktrees \"$1\")\" && cd \"$dir\" || printf '%s\\n' \"$dir\"\n",
+                "    fi\n",
+                "}\n",
+            ));
+        }
+        Shell::Fish => {
+            for (name, command) in aliases {
+                script.push_str(&format!(
+                    "function {name} --wraps 'git-navigator {command}'\n    set -lx {ALIAS_ENV} {name}\n    git-navigator {command} $argv\n"
+                ));
+                if export_env && *command == "status" {
+                    script.push_str(&format!(
+                        "    or return\n    git-navigator status --export-env={shell_name} | source\n"
+                    ));
+                }
+                script.push_str("end\n");
+            }
+            script.push_str(concat!(
+                "function gcd\n",
+                "    if test (count $argv) -eq 0\n",
+                "        git-navigator dirs\n",
+                "    else\n",
+                "        set -l dir (git-navigator dirs $argv[1]); and cd $dir; or printf '%s\\n' $dir\n",
+                "    end\n",
+                "end\n",
+                "function grepo\n",
+                "    if test (count $argv) -eq 0\n",
+                "        git-navigator repos\n",
+                "    else\n",
+                "        set -l dir (git-navigator repos $argv[1]); and cd $dir; or printf '%s\\n' $dir\n",
+                "    end\n",
+                "end\n",
+                "function gwt\n",
+                "    if test (count $argv) -eq 0\n",
+                "        git-navigator worktrees\n",
+                "    else\n",
+                "        set -l dir (git-navigator worktrees $argv[1]); and cd $dir; or printf '%s\\n' $dir\n",
+                "    end\n",
+                "end\n",
+            ));
+        }
+    }
+    script
+}
+
+/// Assignments of `$e<index>` to each numbered path, with any variables
+/// left over from a longer list (`stale`) removed
+pub(crate) fn export_env_script(
+    shell: Shell,
+    files: &[(usize, PathBuf)],
+    stale: &[String],
+) -> String {
+    let mut script = String::new();
+    for (index, path) in files {
+        let value = path.to_string_lossy();
+        match shell {
+            Shell::Bash | Shell::Zsh => {
+                script.push_str(&format!("export e{index}={}\n", quote_posix(&value)))
+            }
+            Shell::Fish => script.push_str(&format!("set -gx e{index} {}\n", quote_fish(&value))),
+        }
+    }
+    if !stale.is_empty() {
+        match shell {
+            Shell::Bash | Shell::Zsh => script.push_str(&format!("unset {}\n", stale.join(" "))),
+            Shell::Fish => script.push_str(&format!("set -e {}\n", stale.join(" "))),
+        }
+    }
+    script
+}
+
+fn quote_posix(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+fn quote_fish(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::invocation::DEFAULT_ALIASES;
+
+    fn init_script(shell: Shell, export_env: bool) -> String {
+        let aliases: Vec<(String, &str)> = DEFAULT_ALIASES
+            .iter()
+            .map(|&(name, command)| (name.to_string(), command))
+            .collect();
+        super::init_script(shell, export_env, &aliases)
+    }
+
+    #[test]
+    fn test_posix_functions() {
+        for shell in [Shell::Bash, Shell::Zsh] {
+            let script = init_script(shell, false);
+            assert!(script.contains("gs() { GIT_NAVIGATOR_ALIAS=gs git-navigator status \"$@\"; }"));
+            assert!(
+                script.contains("grs() { GIT_NAVIGATOR_ALIAS=grs git-navigator reset \"$@\"; }")
+            );
+            assert!(script.contains("unalias \"$_gn_name\""));
+            assert!(script.contains("gcd() {"));
+            assert!(script.contains("grepo() {"));
+            assert!(script.contains("gwt() {"));
+        }
+    }
+
+    #[test]
+    fn test_fish_functions() {
+        let script = init_script(Shell::Fish, false);
+        assert!(script.contains(
+            "function gco --wraps 'git-navigator checkout'\n    set -lx GIT_NAVIGATOR_ALIAS gco\n    git-navigator checkout $argv\nend"
+        ));
+        assert!(script.contains("function gcd\n"));
+        assert!(script.contains("function grepo\n"));
+        assert!(script.contains("function gwt\n"));
+        assert!(!script.contains("$@"));
+    }
+
+    #[test]
+    fn test_export_env_hook() {
+        let script = init_script(Shell::Zsh, true);
+        assert!(script.contains(
+            "gs() { GIT_NAVIGATOR_ALIAS=gs git-navigator status \"$@\" || return; eval \"$(git-navigator status --export-env=zsh)\"; }"
+        ));
+        assert!(script.contains("ga() { GIT_NAVIGATOR_ALIAS=ga git-navigator add \"$@\"; }"));
+
+        let script = init_script(Shell::Fish, true);
+        assert!(script.contains("git-navigator status --export-env=fish | source\n"));
+    }
+
+    #[test]
+    fn test_custom_names() {
+        let aliases = vec![("a".to_string(), "add"), ("gs".to_string(), "status")];
+        let script = super::init_script(Shell::Bash, false, &aliases);
+        assert!(script.contains("for _gn_name in a gs gcd grepo gwt; do"));
+        assert!(script.contains("a() { GIT_NAVIGATOR_ALIAS=a git-navigator add \"$@\"; }"));
+        assert!(!script.contains("gd()"));
+    }
+
+    #[test]
+    fn test_export_env_script_quotes_paths() {
+        let files = vec![
+            (1, PathBuf::from("/repo/it's.txt")),
+            (2, PathBuf::from("/repo/a b.txt")),
+        ];
+        let stale = vec!["e3".to_string(), "e4".to_string()];
+        assert_eq!(
+            export_env_script(Shell::Bash, &files, &stale),
+            "export e1='/repo/it'\\''s.txt'\nexport e2='/repo/a b.txt'\nunset e3 e4\n"
+        );
+        assert_eq!(
+            export_env_script(Shell::Fish, &files, &[]),
+            "set -gx e1 '/repo/it\\'s.txt'\nset -gx e2 '/repo/a b.txt'\n"
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use crate::commands::branches::local_branches;
+use crate::core::{
+    error::{GitNavigatorError, Result},
+    git::GitRepo,
+    state::{BranchEntry, FileEntry},
+};
+use clap::{Parser, ValueEnum};
+use serde::Serialize;
+use std::env;
+use std::path::Path;
+
+#[derive(Parser)]
+pub struct KeysArgs {
+    /// Output format
+    #[arg(long, value_enum, default_value_t = KeysFormat::Tsv)]
+    pub format: KeysFormat,
+
+    /// Renumber files and branches first, as `refresh` does, instead of
+    /// reporting the numbers of the last gs and gb
+    #[arg(long)]
+    pub refresh: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum KeysFormat {
+    /// One tab-separated line per index: kind, index, status, path or branch
+    Tsv,
+    /// A single JSON object with the repository root, files and branches
+    Json,
+}
+
+#[derive(Serialize)]
+struct Keys<'a> {
+    root: String,
+    files: Vec<FileKey<'a>>,
+    branches: Vec<BranchKey<'a>>,
+}
+
+#[derive(Serialize)]
+struct FileKey<'a> {
+    index: usize,
+    path: String,
+    status: &'a str,
+    staged: bool,
+}
+
+#[derive(Serialize)]
+struct BranchKey<'a> {
+    index: usize,
+    name: &'a str,
+    current: bool,
+}
+
+pub fn execute_keys(args: KeysArgs) -> Result<()> {
+    // Check if we're in a git repository
+    let current_dir = env::current_dir()?;
+    let git_repo = GitRepo::open(&current_dir).map_err(|_| GitNavigatorError::NotInGitRepo)?;
+    let store = git_repo.cache_store()?;
+
+    let (files, branches) = if args.refresh {
+        let files = git_repo.get_status()?;
+        git_repo.save_file_list(&files)?;
+        let branches = local_branches(&git_repo)?;
+        store.save_branches(&branches)?;
+        (files, branches)
+    } else {
+        // Nothing listed yet is an empty map, not an error
+        (
+            store.load_files().unwrap_or_default(),
+            store.load_branches().unwrap_or_default(),
+        )
+    };
+
+    let root = git_repo
+        .get_repository()
+        .workdir()
+        .unwrap_or_else(|| Path::new(""));
+    match args.format {
+        KeysFormat::Tsv => print!("{}", format_tsv(&files, &branches)),
+        KeysFormat::Json => println!("{}", format_json(root, &files, &branches)?),
+    }
+    Ok(())
+}
+
+fn format_tsv(files: &[FileEntry], branches: &[BranchEntry]) -> String {
+    let mut output = String::new();
+    for file in files {
+        let status = if file.staged {
+            format!("staged {}", file.status.description())
+        } else {
+            file.status.description().to_string()
+        };
+        output.push_str(&format!(
+            "file\t{}\t{status}\t{}\n",
+            file.index,
+            escape_tsv(&file.path.to_string_lossy())
+        ));
+    }
+    for branch in branches {
+        let status = if branch.is_current { "current" } else { "" };
+        output.push_str(&format!(
+            "branch\t{}\t{status}\t{}\n",
+            branch.index,
+            escape_tsv(&branch.name)
+        ));
+    }
+    output
+}
+
+/// Keep one record per line whatever the file names contain
+fn escape_tsv(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+}
+
+fn format_json(root: &Path, files: &[FileEntry], branches: &[BranchEntry]) -> Result<String> {
+    let keys = Keys {
+        root: root.to_string_lossy().trim_end_matches('/').to_string(),
+        files: files
+            .iter()
+            .map(|file| FileKey {
+                index: file.index,
+                path: file.path.to_string_lossy().into_owned(),
+                status: file.status.description(),
+                staged: file.staged,
+            })
+            .collect(),
+        branches: branches
+            .iter()
+            .map(|branch| BranchKey {
+                index: branch.index,
+                name: &branch.name,
+                current: branch.is_current,
+            })
+            .collect(),
+    };
+    Ok(serde_json::to_string_pretty(&keys)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::git_status::GitStatus;
+    use std::path::PathBuf;
+
+    fn sample() -> (Vec<FileEntry>, Vec<BranchEntry>) {
+        let files = vec![
+            FileEntry {
+                index: 1,
+                status: GitStatus::Added,
+                path: PathBuf::from("src/new.rs"),
+                staged: true,
+                old_path: None,
+            },
+            FileEntry {
+                index: 2,
+                status: GitStatus::Untracked,
+                path: PathBuf::from("odd\tname.txt"),
+                staged: false,
+                old_path: None,
+            },
+        ];
+        let branches = vec![BranchEntry {
+            index: 1,
+            name: "feature/login".to_string(),
+            is_current: false,
+        }];
+        (files, branches)
+    }
+
+    #[test]
+    fn test_format_tsv() {
+        let (files, branches) = sample();
+        assert_eq!(
+            format_tsv(&files, &branches),
+            "file\t1\tstaged new\tsrc/new.rs\nfile\t2\tuntracked\todd\\tname.txt\nbranch\t1\t\tfeature/login\n"
+        );
+    }
+
+    #[test]
+    fn test_format_json() {
+        let (files, branches) = sample();
+        let json = format_json(Path::new("/repo/"), &files, &branches).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["root"], "/repo");
+        assert_eq!(value["files"][0]["path"], "src/new.rs");
+        assert_eq!(value["files"][0]["staged"], true);
+        assert_eq!(value["files"][1]["status"], "untracked");
+        assert_eq!(value["branches"][0]["name"], "feature/login");
+    }
+}
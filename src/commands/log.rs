@@ -0,0 +1,163 @@
+use crate::commands::contributors::unix_now;
+use crate::core::{
+    command_init::load_cached_files,
+    error::{GitNavigatorError, Result},
+    format_age,
+    git::{AuthorMatch, GitRepo, LogEntry, LogQuery},
+    hyperlink::link_issues,
+    is_plain_mode, print_info,
+    state::CommitEntry,
+    templates::colored_index,
+};
+use clap::Parser;
+use colored::*;
+use std::env;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// How many commits `log` shows by default
+pub const DEFAULT_LOG_LIMIT: usize = 20;
+
+#[derive(Parser)]
+pub struct LogArgs {
+    /// Show at most this many commits
+    #[arg(long, short = 'n', value_name = "COUNT", default_value_t = DEFAULT_LOG_LIMIT)]
+    pub max_count: usize,
+
+    /// Only commits by this author: an index from `contributors`, or part of a name or email
+    #[arg(long, value_name = "INDEX|NAME")]
+    pub author: Option<String>,
+
+    /// Only commits touching file INDEX from `gs`, following renames
+    #[arg(long, value_name = "INDEX")]
+    pub file: Option<usize>,
+
+    /// Only commits whose message matches this regular expression
+    #[arg(long, value_name = "PATTERN")]
+    pub grep: Option<String>,
+
+    /// Only commits that add or remove this string (pickaxe search)
+    #[arg(short = 'S', value_name = "STRING")]
+    pub pickaxe: Option<String>,
+}
+
+pub fn execute_log(args: LogArgs) -> Result<()> {
+    // Check if we're in a git repository
+    let current_dir = env::current_dir()?;
+    let git_repo = GitRepo::open(&current_dir).map_err(|_| GitNavigatorError::NotInGitRepo)?;
+
+    let query = LogQuery {
+        limit: args.max_count,
+        author: args
+            .author
+            .map(|author| resolve_author(&git_repo, &author))
+            .transpose()?,
+        path: args
+            .file
+            .map(|index| resolve_file(&git_repo, index))
+            .transpose()?,
+        grep: args.grep,
+        pickaxe: args.pickaxe,
+        ..LogQuery::default()
+    };
+    let filtered = query.author.is_some() || query.grep.is_some() || query.pickaxe.is_some();
+    let entries = git_repo.log(&query)?;
+
+    if entries.is_empty() {
+        match &query.path {
+            Some(path) => print_info(&format!("No commits touch {}.", path.display())),
+            None if filtered => print_info("No commits match."),
+            None => print_info("No commits yet."),
+        }
+        return Ok(());
+    }
+
+    let now = unix_now();
+    for entry in &entries {
+        print_log_entry(entry, now);
+    }
+
+    // Add spacing after commit list
+    println!();
+
+    // The walk ran out of commits; in a shallow clone that is the cut, not the root
+    if entries.len() < query.limit && git_repo.get_repository().is_shallow() {
+        print_info("History stops here because this is a shallow clone. Run 'git fetch --deepen=<n>' or 'git fetch --unshallow' for older commits.");
+    }
+
+    // Save to cache so show-commit, cherry-pick, reword and gco --commit can resolve the numbers
+    let commits: Vec<_> = entries.into_iter().map(|entry| entry.commit).collect();
+    if let Err(e) = git_repo
+        .cache_store()
+        .and_then(|store| store.save_commits(&commits))
+    {
+        // Log cache errors but don't fail the command
+        log::warn!("Commit cache save failed: {e}");
+    }
+
+    Ok(())
+}
+
+/// A contributor index selects that person's email; anything else is a pattern
+fn resolve_author(git_repo: &GitRepo, author: &str) -> Result<AuthorMatch> {
+    let Ok(index) = author.parse::<usize>() else {
+        return Ok(AuthorMatch::Pattern(author.to_string()));
+    };
+
+    let contributors = git_repo
+        .cache_store()?
+        .load_contributors()
+        .map_err(|_| GitNavigatorError::ContributorNotFound(index))?;
+    contributors
+        .into_iter()
+        .find(|contributor| contributor.index == index)
+        .map(|contributor| AuthorMatch::Email(contributor.email))
+        .ok_or(GitNavigatorError::ContributorNotFound(index))
+}
+
+/// Commit `index` from the last numbered commit list (`gl` or `reword`)
+pub(crate) fn cached_commit(git_repo: &GitRepo, index: usize) -> Result<CommitEntry> {
+    let commits = git_repo
+        .cache_store()?
+        .load_commits()
+        .map_err(|_| GitNavigatorError::CommitIndexNotFound(index))?;
+    commits
+        .into_iter()
+        .find(|commit| commit.index == index)
+        .ok_or(GitNavigatorError::CommitIndexNotFound(index))
+}
+
+/// The path of file `index` in the last `gs` listing
+fn resolve_file(git_repo: &GitRepo, index: usize) -> Result<PathBuf> {
+    let files = load_cached_files(git_repo)
+        .map_err(|e| GitNavigatorError::custom_cache_error("Cannot load file cache", e))?;
+    files
+        .iter()
+        .find(|file| file.index == index)
+        .map(|file| file.path.clone())
+        .ok_or_else(|| GitNavigatorError::index_out_of_range(index, files.len()))
+}
+
+fn print_log_entry(entry: &LogEntry, now: i64) {
+    let commit = &entry.commit;
+    let age = format!(
+        "{} ago",
+        format_age(Duration::from_secs((now - entry.time).max(0) as u64))
+    );
+
+    if is_plain_mode() {
+        println!(
+            "Index {}: {} {} ({age}, {})",
+            commit.index, commit.short_oid, commit.summary, entry.author
+        );
+    } else {
+        println!(
+            "{} {} {} {} {}",
+            colored_index(commit.index),
+            commit.short_oid.blue(),
+            link_issues(&commit.summary),
+            format!("({age})").green(),
+            format!("<{}>", entry.author).bright_black()
+        );
+    }
+}
@@ -1,17 +1,83 @@
 pub mod add;
 pub mod branches;
 pub mod checkout;
+pub mod cherry_pick;
+pub mod commit;
+pub mod contributors;
 pub mod diff;
+pub mod dirs;
+pub mod doctor;
+pub mod edit;
+pub mod exec;
+pub mod fetch;
+pub mod init;
+pub mod keys;
+pub mod log;
+pub mod notes;
+pub mod open;
+pub mod packages;
+pub mod pick;
+pub mod prompt;
+pub mod pull;
+pub mod push;
+pub mod refresh;
+pub mod repos;
 pub mod reset;
+pub mod reveal;
+pub mod review;
+pub mod reword;
+pub mod rm;
 pub mod rollback;
+pub mod show_commit;
+pub mod since;
+pub mod sparse;
+pub mod split;
+pub mod stats;
 pub mod status;
+pub mod tags;
+pub mod uninstall;
 pub mod update;
+pub mod verify;
+pub mod worktrees;
 
 pub use add::*;
 pub use branches::*;
 pub use checkout::*;
+pub use cherry_pick::*;
+pub use commit::*;
+pub use contributors::*;
 pub use diff::*;
+pub use dirs::*;
+pub use doctor::*;
+pub use edit::*;
+pub use exec::*;
+pub use fetch::*;
+pub use init::*;
+pub use keys::*;
+pub use log::*;
+pub use notes::*;
+pub use open::*;
+pub use packages::*;
+pub use pick::*;
+pub use prompt::*;
+pub use pull::*;
+pub use push::*;
+pub use refresh::*;
+pub use repos::*;
 pub use reset::*;
+pub use reveal::*;
+pub use review::*;
+pub use reword::*;
+pub use rm::*;
 pub use rollback::*;
+pub use show_commit::*;
+pub use since::*;
+pub use sparse::*;
+pub use split::*;
+pub use stats::*;
 pub use status::*;
+pub use tags::*;
+pub use uninstall::*;
 pub use update::*;
+pub use verify::*;
+pub use worktrees::*;
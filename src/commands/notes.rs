@@ -0,0 +1,379 @@
+use crate::commands::log::cached_commit;
+use crate::commands::reword::clean_message;
+use crate::commands::tags::cached_tag;
+use crate::core::{
+    conventional::{section_for, ConventionalCommit, SECTIONS},
+    editor::Editor,
+    error::{GitNavigatorError, Result},
+    git::{GitRepo, LogQuery},
+    is_interactive, print_error_with_structured_usage, print_info, print_section_header,
+    print_success,
+    state::CommitEntry,
+    templates::{render_template, templates, TemplateContext},
+};
+use clap::{Parser, Subcommand};
+use std::env;
+use std::fmt::Write;
+
+#[derive(Parser)]
+pub struct NotesArgs {
+    #[command(subcommand)]
+    pub action: Option<NoteAction>,
+
+    /// Draft release notes starting after this tag: an index from `tags`, or any tag, branch or commit
+    #[arg(long, value_name = "TAG_INDEX|REF")]
+    pub since: Option<String>,
+
+    /// Notes ref for list, show, add and edit (default refs/notes/commits; "deploy" is refs/notes/deploy)
+    #[arg(long = "ref", value_name = "REF", global = true)]
+    pub notes_ref: Option<String>,
+}
+
+/// `git notes` on commits picked by their index from `gl` or `notes list`
+#[derive(Subcommand)]
+pub enum NoteAction {
+    /// List the commits that have a note, numbered for show, add and edit
+    List,
+    /// Show the note on a commit
+    Show {
+        /// Commit index from the last `gl` or `notes list`
+        index: usize,
+    },
+    /// Attach a note to a commit
+    Add {
+        /// Commit index from the last `gl` or `notes list`
+        index: usize,
+
+        /// The note's text
+        #[arg(long, short = 'm')]
+        message: String,
+
+        /// Replace the note the commit already has
+        #[arg(long, short = 'f')]
+        force: bool,
+    },
+    /// Edit a commit's note in your editor; emptying it removes the note
+    Edit {
+        /// Commit index from the last `gl` or `notes list`
+        index: usize,
+    },
+}
+
+pub fn execute_notes(args: NotesArgs) -> Result<()> {
+    // Check if we're in a git repository
+    let current_dir = env::current_dir()?;
+    let git_repo = GitRepo::open(&current_dir).map_err(|_| GitNavigatorError::NotInGitRepo)?;
+
+    let notes_ref = args.notes_ref.as_deref().map(full_notes_ref);
+    let notes_ref = notes_ref.as_deref();
+    match (args.action, args.since) {
+        (Some(NoteAction::List), _) => list_notes(&git_repo, notes_ref),
+        (Some(NoteAction::Show { index }), _) => show_note(&git_repo, notes_ref, index),
+        (
+            Some(NoteAction::Add {
+                index,
+                message,
+                force,
+            }),
+            _,
+        ) => add_note(&git_repo, notes_ref, index, &message, force),
+        (Some(NoteAction::Edit { index }), _) => edit_note(&git_repo, notes_ref, index),
+        (None, Some(since)) => release_notes(&git_repo, &since),
+        (None, None) => {
+            print_error_with_structured_usage(
+                "Nothing to do without a subcommand or --since",
+                "notes",
+                &[
+                    "--since <tag>",
+                    "list",
+                    "show <index>",
+                    "add <index> -m <text>",
+                    "edit <index>",
+                ],
+                &[
+                    ("--since", "Draft release notes since a tag"),
+                    ("--ref", "Notes ref to use instead of refs/notes/commits"),
+                    ("-h, --help", "Show this help message"),
+                ],
+            );
+            Ok(())
+        }
+    }
+}
+
+fn release_notes(git_repo: &GitRepo, since: &str) -> Result<()> {
+    let (label, since) = resolve_since(git_repo, since)?;
+    let entries = git_repo.log(&LogQuery {
+        limit: usize::MAX,
+        since: Some(since),
+        no_merges: true,
+        ..LogQuery::default()
+    })?;
+
+    if entries.is_empty() {
+        print_info(&format!("No commits since {label}."));
+        return Ok(());
+    }
+
+    let commits: Vec<CommitEntry> = entries.into_iter().map(|entry| entry.commit).collect();
+    print!("{}", render_notes(&label, &commits));
+
+    // Save to cache so the commits can be looked at with show-commit
+    save_commits(git_repo, &commits);
+    Ok(())
+}
+
+/// `deploy` is short for `refs/notes/deploy`, as with `git notes --ref`
+fn full_notes_ref(name: &str) -> String {
+    if name.starts_with("refs/") {
+        name.to_string()
+    } else if name.starts_with("notes/") {
+        format!("refs/{name}")
+    } else {
+        format!("refs/notes/{name}")
+    }
+}
+
+fn list_notes(git_repo: &GitRepo, notes_ref: Option<&str>) -> Result<()> {
+    let notes = git_repo.commit_notes(notes_ref)?;
+    if notes.is_empty() {
+        print_info("No commits have notes yet. Add one with 'notes add <index> -m <text>'.");
+        return Ok(());
+    }
+
+    print_section_header("Commit Notes");
+    for (commit, note) in &notes {
+        print_commit_note(commit, note);
+    }
+
+    // Add spacing after note list
+    println!();
+
+    let commits: Vec<CommitEntry> = notes.into_iter().map(|(commit, _)| commit).collect();
+    save_commits(git_repo, &commits);
+    Ok(())
+}
+
+fn show_note(git_repo: &GitRepo, notes_ref: Option<&str>, index: usize) -> Result<()> {
+    let commit = cached_commit(git_repo, index)?;
+    let note = git_repo
+        .commit_note(notes_ref, git2::Oid::from_str(&commit.oid)?)?
+        .ok_or_else(|| GitNavigatorError::NoteNotFound(commit.short_oid.clone()))?;
+    print_commit_note(&commit, &note);
+    Ok(())
+}
+
+fn add_note(
+    git_repo: &GitRepo,
+    notes_ref: Option<&str>,
+    index: usize,
+    message: &str,
+    force: bool,
+) -> Result<()> {
+    let commit = cached_commit(git_repo, index)?;
+    let message = clean_message(message);
+    if message.is_empty() {
+        return Err(GitNavigatorError::custom_empty_files_error(
+            "The note is empty",
+        ));
+    }
+
+    git_repo.set_commit_note(
+        notes_ref,
+        git2::Oid::from_str(&commit.oid)?,
+        &message,
+        force,
+    )?;
+    print_success(&format!("Added a note to {}.", commit.short_oid));
+    Ok(())
+}
+
+/// Open the note, or an empty one, in the editor git would use
+fn edit_note(git_repo: &GitRepo, notes_ref: Option<&str>, index: usize) -> Result<()> {
+    if !is_interactive() {
+        return Err(GitNavigatorError::NonInteractive(
+            "pass the text with 'notes add <index> -m <text> --force'".to_string(),
+        ));
+    }
+
+    let commit = cached_commit(git_repo, index)?;
+    let oid = git2::Oid::from_str(&commit.oid)?;
+    let old_note = git_repo.commit_note(notes_ref, oid)?;
+
+    let file = git_repo
+        .get_repository()
+        .path()
+        .join("GIT-NAVIGATOR-NOTES_EDITMSG");
+    std::fs::write(
+        &file,
+        format!(
+            "{}\n\n# Write the note for {} {}. Lines starting with '#' are\n# ignored, and an empty note removes it.\n",
+            old_note.as_deref().unwrap_or_default().trim_end(),
+            commit.short_oid,
+            commit.summary
+        ),
+    )?;
+    let editor = Editor::new(git_repo.git_defaults().editor_command());
+    let edited = editor
+        .open(&git_repo.load_settings(), &file, None)
+        .and_then(|()| Ok(std::fs::read_to_string(&file)?));
+    let _ = std::fs::remove_file(&file);
+    let note = clean_message(&edited?);
+
+    match old_note {
+        Some(_) if note.is_empty() => {
+            git_repo.remove_commit_note(notes_ref, oid)?;
+            print_success(&format!("Removed the note from {}.", commit.short_oid));
+        }
+        None if note.is_empty() => print_info("Empty note, nothing added."),
+        Some(old) if clean_message(&old) == note => print_info("Note unchanged."),
+        _ => {
+            git_repo.set_commit_note(notes_ref, oid, &note, true)?;
+            print_success(&format!("Saved the note on {}.", commit.short_oid));
+        }
+    }
+    Ok(())
+}
+
+/// The commit through `templates.note_line`, its note's lines under it
+fn print_commit_note(commit: &CommitEntry, note: &str) {
+    let context = TemplateContext {
+        n: Some(commit.index),
+        short_hash: Some(&commit.short_oid),
+        commit_message: Some(&commit.summary),
+        ..Default::default()
+    };
+    println!("{}", render_template(templates().note_line, &context));
+    for line in note.lines() {
+        let context = TemplateContext {
+            commit_message: Some(line),
+            ..Default::default()
+        };
+        println!("{}", render_template(templates().note_body, &context));
+    }
+}
+
+fn save_commits(git_repo: &GitRepo, commits: &[CommitEntry]) {
+    if let Err(e) = git_repo
+        .cache_store()
+        .and_then(|store| store.save_commits(commits))
+    {
+        // Log cache errors but don't fail the command
+        log::warn!("Commit cache save failed: {e}");
+    }
+}
+
+/// Name to show for `since` and the commit it points at
+fn resolve_since(git_repo: &GitRepo, since: &str) -> Result<(String, git2::Oid)> {
+    if let Ok(index) = since.parse::<usize>() {
+        let tag = cached_tag(git_repo, index)?;
+        return Ok((tag.name, git2::Oid::from_str(&tag.oid)?));
+    }
+
+    let commit = git_repo
+        .get_repository()
+        .revparse_single(since)
+        .and_then(|object| object.peel_to_commit())
+        .map_err(|_| GitNavigatorError::UnknownRevision(since.to_string()))?;
+    Ok((since.to_string(), commit.id()))
+}
+
+/// Markdown changelog draft, grouped by conventional-commit type
+fn render_notes(label: &str, commits: &[CommitEntry]) -> String {
+    let parsed: Vec<(&CommitEntry, Option<ConventionalCommit>)> = commits
+        .iter()
+        .map(|commit| (commit, ConventionalCommit::parse(&commit.summary)))
+        .collect();
+
+    let mut notes = format!("## Changes since {label}\n");
+
+    let breaking: Vec<String> = parsed
+        .iter()
+        .filter(|(_, conventional)| conventional.as_ref().is_some_and(|c| c.breaking))
+        .map(|(commit, conventional)| note_line(commit, conventional.as_ref()))
+        .collect();
+    push_section(&mut notes, "Breaking Changes", &breaking);
+
+    for (section, _) in SECTIONS {
+        let lines: Vec<String> = parsed
+            .iter()
+            .filter(|(_, conventional)| {
+                conventional
+                    .as_ref()
+                    .map_or("Other", |c| section_for(&c.kind))
+                    == section
+            })
+            .map(|(commit, conventional)| note_line(commit, conventional.as_ref()))
+            .collect();
+        push_section(&mut notes, section, &lines);
+    }
+
+    notes
+}
+
+fn note_line(commit: &CommitEntry, conventional: Option<&ConventionalCommit>) -> String {
+    match conventional {
+        Some(ConventionalCommit {
+            scope: Some(scope),
+            description,
+            ..
+        }) => format!("- **{scope}:** {description} ({})", commit.short_oid),
+        Some(conventional) => format!("- {} ({})", conventional.description, commit.short_oid),
+        None => format!("- {} ({})", commit.summary, commit.short_oid),
+    }
+}
+
+fn push_section(notes: &mut String, title: &str, lines: &[String]) {
+    if lines.is_empty() {
+        return;
+    }
+    let _ = writeln!(notes, "\n### {title}\n");
+    for line in lines {
+        let _ = writeln!(notes, "{line}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_full_notes_ref() {
+        assert_eq!(full_notes_ref("deploy"), "refs/notes/deploy");
+        assert_eq!(full_notes_ref("notes/review"), "refs/notes/review");
+        assert_eq!(full_notes_ref("refs/notes/commits"), "refs/notes/commits");
+    }
+
+    fn commit(short_oid: &str, summary: &str) -> CommitEntry {
+        CommitEntry {
+            index: 0,
+            oid: String::new(),
+            short_oid: short_oid.to_string(),
+            summary: summary.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_render_notes() {
+        let commits = [
+            commit("aaaaaaa", "fix(cache): handle missing files"),
+            commit("bbbbbbb", "feat!: drop the v1 config format"),
+            commit("ccccccc", "Update README"),
+            commit("ddddddd", "feat(log): add --grep"),
+        ];
+
+        assert_eq!(
+            render_notes("v1.0.0", &commits),
+            "## Changes since v1.0.0\n\
+             \n### Breaking Changes\n\n\
+             - drop the v1 config format (bbbbbbb)\n\
+             \n### Features\n\n\
+             - drop the v1 config format (bbbbbbb)\n\
+             - **log:** add --grep (ddddddd)\n\
+             \n### Bug Fixes\n\n\
+             - **cache:** handle missing files (aaaaaaa)\n\
+             \n### Other\n\n\
+             - Update README (ccccccc)\n"
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use crate::core::{
+    command_init::IndexCommandInit,
+    editor::EditorApp,
+    error::{GitNavigatorError, Result},
+    print_error_with_structured_usage, print_success,
+};
+use clap::Parser;
+
+#[derive(Parser)]
+pub struct OpenArgs {
+    /// File index to open
+    pub index: Option<String>,
+
+    /// Editor to open it in: code, idea, or any [editors.<name>] entry (default: open.app)
+    #[arg(long, value_name = "APP")]
+    pub app: Option<String>,
+}
+
+pub fn execute_open(args: OpenArgs) -> Result<()> {
+    let indices_args = args.index.into_iter().collect();
+    let context = match IndexCommandInit::initialize_with_messages(
+        indices_args,
+        "Cannot load file cache",
+        "No files available to open",
+    ) {
+        Ok(context) => context,
+        Err(GitNavigatorError::NoIndicesProvided) => {
+            print_error_with_structured_usage(
+                "No file index provided",
+                "open",
+                &["<index> --app <code|idea|name>"],
+                &[
+                    (
+                        "--app <APP>",
+                        "Editor from [editors.<name>] (default: open.app)",
+                    ),
+                    ("-h, --help", "Show this help message"),
+                ],
+            );
+            return Err(GitNavigatorError::NoIndicesProvided);
+        }
+        Err(e) => return Err(e),
+    };
+
+    let files = context.get_selected_files();
+    let [file] = files.as_slice() else {
+        return Err(GitNavigatorError::custom_empty_files_error(
+            "open takes a single file index",
+        ));
+    };
+
+    let git_repo = &context.git_repo;
+    let settings = git_repo.load_settings();
+    let Some(app_name) = args
+        .app
+        .or_else(|| settings.get_str("open.app").map(str::to_string))
+    else {
+        return Err(GitNavigatorError::config_error(
+            "pass --app or set open.app in the config file",
+        ));
+    };
+    let app = EditorApp::from_settings(&settings, &app_name)?;
+
+    let workdir = git_repo
+        .get_repository()
+        .workdir()
+        .ok_or_else(|| GitNavigatorError::custom_empty_files_error("No workdir found"))?;
+
+    // Jump to the first change, or the top of files without a textual diff
+    let line = match git_repo.first_changed_line(&file.path, file.staged)? {
+        Some(line) => line,
+        None => git_repo
+            .first_changed_line(&file.path, !file.staged)?
+            .unwrap_or(1),
+    };
+
+    app.launch(&workdir.join(&file.path), line)?;
+    print_success(&format!(
+        "Opened {}:{line} in {app_name}",
+        file.path.display()
+    ));
+    Ok(())
+}
@@ -0,0 +1,188 @@
+use crate::core::{
+    error::{GitNavigatorError, Result},
+    git::GitRepo,
+    is_plain_mode,
+    packages::{discover, package_for},
+    print_info, print_section_header,
+    state::PackageEntry,
+    templates::colored_index,
+};
+use clap::Parser;
+use colored::*;
+use std::collections::{BTreeMap, BTreeSet};
+use std::env;
+use std::path::{Path, PathBuf};
+
+#[derive(Parser)]
+pub struct PackagesArgs {
+    /// Package index to print the directory of (if provided)
+    pub index: Option<usize>,
+
+    /// Also count files changed on this branch since REF (default: its upstream)
+    #[arg(long, value_name = "REF")]
+    pub since: Option<String>,
+}
+
+pub fn execute_packages(args: PackagesArgs) -> Result<()> {
+    // Check if we're in a git repository
+    let current_dir = env::current_dir()?;
+    let git_repo = GitRepo::open(&current_dir).map_err(|_| GitNavigatorError::NotInGitRepo)?;
+
+    match args.index {
+        Some(index) => {
+            // Print the directory for the shell (or a build tool) to use
+            let path = resolve_package(&git_repo, index)?;
+            println!("{}", path.display());
+            Ok(())
+        }
+        None => list_packages(&git_repo, args.since.as_deref()),
+    }
+}
+
+fn list_packages(git_repo: &GitRepo, since: Option<&str>) -> Result<()> {
+    let workdir = git_repo
+        .get_repository()
+        .workdir()
+        .ok_or_else(|| GitNavigatorError::custom_empty_files_error("No workdir found"))?;
+    let packages = discover(workdir, &git_repo.load_settings());
+    if packages.is_empty() {
+        print_info("No workspace packages found. Set packages.globs, or use a Cargo or package.json workspace.");
+        return Ok(());
+    }
+
+    let (paths, base) = changed_paths(git_repo, since)?;
+    let mut counts: BTreeMap<&Path, usize> = BTreeMap::new();
+    let mut outside = 0;
+    for path in &paths {
+        match package_for(&packages, path) {
+            Some(package) => *counts.entry(&package.path).or_default() += 1,
+            None => outside += 1,
+        }
+    }
+
+    let entries: Vec<PackageEntry> = packages
+        .iter()
+        .filter_map(|package| {
+            let files = *counts.get(package.path.as_path())?;
+            Some((package, files))
+        })
+        .enumerate()
+        .map(|(i, (package, files))| PackageEntry {
+            index: i + 1,
+            name: package.name.clone(),
+            path: package.path.clone(),
+            files,
+        })
+        .collect();
+
+    let scope = base.map_or_else(String::new, |base| format!(" since {base}"));
+    if entries.is_empty() {
+        print_info(&format!("No package has changes{scope}."));
+    } else {
+        print_section_header(&format!("Changed Packages{scope}"));
+        for entry in &entries {
+            print_package(entry);
+        }
+
+        // Add spacing after package list
+        println!();
+    }
+
+    if outside > 0 {
+        print_info(&format!(
+            "{outside} changed file(s) are outside every package."
+        ));
+    }
+
+    // Save to cache so `packages <index>` can resolve the numbers
+    if !entries.is_empty() {
+        if let Err(e) = git_repo
+            .cache_store()
+            .and_then(|store| store.save_packages(&entries))
+        {
+            log::warn!("Package cache save failed: {e}");
+        }
+    }
+
+    Ok(())
+}
+
+fn print_package(entry: &PackageEntry) {
+    let count = if entry.files == 1 {
+        "1 file".to_string()
+    } else {
+        format!("{} files", entry.files)
+    };
+    let path = entry.path.display().to_string();
+    let details = if path == entry.name {
+        count
+    } else {
+        format!("{path}, {count}")
+    };
+
+    if is_plain_mode() {
+        println!("Index {}: {} ({details})", entry.index, entry.name);
+    } else {
+        println!(
+            "{} {} {}",
+            colored_index(entry.index),
+            entry.name.blue(),
+            format!("({details})").bright_black()
+        );
+    }
+}
+
+/// Pending changes, plus what the branch changed since `since` or its upstream
+///
+/// Returns the paths and the name of the base they were compared with, if any.
+pub(crate) fn changed_paths(
+    git_repo: &GitRepo,
+    since: Option<&str>,
+) -> Result<(Vec<PathBuf>, Option<String>)> {
+    let mut paths: BTreeSet<PathBuf> = git_repo
+        .get_status()?
+        .into_iter()
+        .map(|file| file.path)
+        .collect();
+
+    let base = match since {
+        Some(since) => {
+            let commit = git_repo
+                .get_repository()
+                .revparse_single(since)
+                .and_then(|object| object.peel_to_commit())
+                .map_err(|_| GitNavigatorError::UnknownRevision(since.to_string()))?;
+            Some((since.to_string(), commit.id()))
+        }
+        None => git_repo
+            .get_upstream()?
+            .and_then(|upstream| Some((upstream.tracking, upstream.oid?))),
+    };
+
+    if let Some((_, oid)) = &base {
+        paths.extend(git_repo.paths_changed_since(*oid)?);
+    }
+    Ok((paths.into_iter().collect(), base.map(|(name, _)| name)))
+}
+
+/// Absolute directory of a package numbered by the last `packages`
+fn resolve_package(git_repo: &GitRepo, index: usize) -> Result<PathBuf> {
+    let packages = git_repo
+        .cache_store()?
+        .load_packages()
+        .map_err(|_| GitNavigatorError::PackageIndexNotFound(index))?;
+    let package = packages
+        .into_iter()
+        .find(|package| package.index == index)
+        .ok_or(GitNavigatorError::PackageIndexNotFound(index))?;
+
+    let workdir = git_repo
+        .get_repository()
+        .workdir()
+        .ok_or_else(|| GitNavigatorError::custom_empty_files_error("No workdir found"))?;
+    Ok(if package.path == Path::new(".") {
+        workdir.to_path_buf()
+    } else {
+        workdir.join(&package.path)
+    })
+}
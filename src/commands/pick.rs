@@ -0,0 +1,354 @@
+use crate::commands::diff::show_file_diff;
+use crate::commands::status::print_files_only;
+use crate::core::{
+    error::{GitNavigatorError, Result},
+    git::GitRepo,
+    is_interactive, is_plain_mode, print_info, print_paged,
+    state::FileEntry,
+    templates::{render_template, templates, TemplateContext},
+};
+use clap::Parser;
+use colored::*;
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute, queue,
+    style::Print,
+    terminal::{self, ClearType},
+};
+use std::collections::BTreeSet;
+use std::env;
+use std::io::{self, IsTerminal, Write};
+use std::path::PathBuf;
+
+#[derive(Parser)]
+pub struct PickArgs {}
+
+/// What a key press asks the picker to do
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    /// Cursor or selection changed, redraw
+    Redraw,
+    Stage,
+    Unstage,
+    Diff,
+    Quit,
+}
+
+/// The numbered file list with a cursor and a multi-selection
+#[derive(Debug)]
+struct Picker {
+    files: Vec<FileEntry>,
+    cursor: usize,
+    /// Positions in `files` of the selected rows
+    selected: BTreeSet<usize>,
+}
+
+impl Picker {
+    fn new(files: Vec<FileEntry>) -> Self {
+        Self {
+            files,
+            cursor: 0,
+            selected: BTreeSet::new(),
+        }
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            return Some(Action::Quit);
+        }
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.cursor = self.cursor.saturating_sub(1);
+                Some(Action::Redraw)
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.cursor = (self.cursor + 1).min(self.files.len().saturating_sub(1));
+                Some(Action::Redraw)
+            }
+            KeyCode::Char(' ') => {
+                if !self.selected.remove(&self.cursor) {
+                    self.selected.insert(self.cursor);
+                }
+                Some(Action::Redraw)
+            }
+            KeyCode::Char('a') => Some(Action::Stage),
+            KeyCode::Char('r') => Some(Action::Unstage),
+            KeyCode::Char('d') => Some(Action::Diff),
+            KeyCode::Char('q') | KeyCode::Esc => Some(Action::Quit),
+            _ => None,
+        }
+    }
+
+    /// Files an action applies to: the selection, or the row under the cursor
+    fn targets(&self) -> Vec<&FileEntry> {
+        if self.selected.is_empty() {
+            self.files.get(self.cursor).into_iter().collect()
+        } else {
+            self.selected.iter().map(|&row| &self.files[row]).collect()
+        }
+    }
+
+    /// Show a fresh status after staging or unstaging
+    fn replace_files(&mut self, files: Vec<FileEntry>) {
+        self.files = files;
+        self.selected.clear();
+        self.cursor = self.cursor.min(self.files.len().saturating_sub(1));
+    }
+}
+
+pub fn execute_pick(_args: PickArgs) -> Result<()> {
+    // Check if we're in a git repository
+    let current_dir = env::current_dir()?;
+    let git_repo = GitRepo::open(&current_dir).map_err(|_| GitNavigatorError::NotInGitRepo)?;
+
+    let files = git_repo.get_status()?;
+    if files.is_empty() {
+        print_info("No changes to pick from.");
+        return Ok(());
+    }
+    save_files(&git_repo, &files);
+
+    // Without a terminal to draw on, show the list for ga/grs/gd instead
+    if is_plain_mode() || !is_interactive() || !io::stdout().is_terminal() {
+        print_files_only(&files);
+        print_info("pick needs a terminal; use ga, grs or gd with these numbers.");
+        return Ok(());
+    }
+
+    let mut picker = Picker::new(files);
+    let mut message = String::new();
+    let mut screen = Screen::enter()?;
+    loop {
+        screen.draw(&picker, &message)?;
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        let paths: Vec<PathBuf> = picker
+            .targets()
+            .iter()
+            .map(|file| file.path.clone())
+            .collect();
+        // Unstaging a rename has to reset both of its ends
+        let mut affected: Vec<PathBuf> = Vec::new();
+        for path in picker
+            .targets()
+            .iter()
+            .flat_map(|file| file.affected_paths())
+        {
+            if !affected.contains(&path) {
+                affected.push(path);
+            }
+        }
+        message = match picker.handle_key(key) {
+            None | Some(Action::Redraw) => continue,
+            Some(Action::Quit) => break,
+            Some(Action::Stage) => {
+                git_repo.add_files(&paths)?;
+                format!("Staged {} file(s)", paths.len())
+            }
+            Some(Action::Unstage) => {
+                git_repo.reset_files(&affected)?;
+                format!("Unstaged {} file(s)", paths.len())
+            }
+            Some(Action::Diff) => {
+                screen.suspend()?;
+                show_diffs(&git_repo, &picker.targets())?;
+                screen = Screen::enter()?;
+                continue;
+            }
+        };
+
+        let files = git_repo.get_status()?;
+        save_files(&git_repo, &files);
+        if files.is_empty() {
+            drop(screen);
+            print_info("No changes left.");
+            return Ok(());
+        }
+        picker.replace_files(files);
+    }
+
+    drop(screen);
+    print_files_only(&picker.files);
+    Ok(())
+}
+
+/// Keep the cache in step so `ga`/`grs`/`gd` numbers match the picker's
+fn save_files(git_repo: &GitRepo, files: &[FileEntry]) {
+    if let Err(e) = git_repo.save_file_list(files) {
+        log::warn!("File cache save failed: {e}");
+    }
+}
+
+/// Print the diffs outside the picker, then wait for a key to go back
+fn show_diffs(git_repo: &GitRepo, files: &[&FileEntry]) -> Result<()> {
+    let mut output = String::new();
+    for file in files {
+        output.push_str(&show_file_diff(git_repo, file)?);
+    }
+    print_paged(&output, git_repo.git_defaults().pager_command().as_deref());
+
+    println!("{}", "Press Enter to return to the picker".bright_black());
+    let mut line = String::new();
+    io::stdin().read_line(&mut line)?;
+    Ok(())
+}
+
+/// The alternate screen in raw mode, restored when dropped
+struct Screen {
+    active: bool,
+}
+
+impl Screen {
+    fn enter() -> Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(Self { active: true })
+    }
+
+    /// Go back to the normal screen, e.g. to run a pager
+    fn suspend(&mut self) -> Result<()> {
+        if self.active {
+            self.active = false;
+            execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen)?;
+            terminal::disable_raw_mode()?;
+        }
+        Ok(())
+    }
+
+    fn draw(&mut self, picker: &Picker, message: &str) -> Result<()> {
+        let mut stdout = io::stdout();
+        queue!(
+            stdout,
+            cursor::MoveTo(0, 0),
+            terminal::Clear(ClearType::All),
+            Print(
+                "↑/↓ move  space select  a stage  r unstage  d diff  q quit"
+                    .bright_black()
+                    .to_string()
+            ),
+            Print("\r\n\r\n")
+        )?;
+
+        for (row, file) in picker.files.iter().enumerate() {
+            let filename = file.path.to_string_lossy();
+            let context = TemplateContext {
+                file_status: Some(file.status.description()),
+                n: Some(file.index),
+                filename: Some(&filename),
+                file_path: Some(&file.path),
+                git_status: Some(file.status),
+                ..Default::default()
+            };
+            let mark = if picker.selected.contains(&row) {
+                "●".green().to_string()
+            } else {
+                " ".to_string()
+            };
+            let line = format!("{mark}{}", render_template(templates().file_line, &context));
+            let line = if row == picker.cursor {
+                line.reversed().to_string()
+            } else {
+                line
+            };
+            queue!(stdout, Print(line), Print("\r\n"))?;
+        }
+
+        if !message.is_empty() {
+            queue!(stdout, Print("\r\n"), Print(message.green().to_string()))?;
+        }
+        stdout.flush()?;
+        Ok(())
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = self.suspend();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::git_status::GitStatus;
+
+    fn files(count: usize) -> Vec<FileEntry> {
+        (1..=count)
+            .map(|index| FileEntry {
+                index,
+                status: GitStatus::Modified,
+                path: PathBuf::from(format!("file{index}.txt")),
+                staged: false,
+                old_path: None,
+            })
+            .collect()
+    }
+
+    fn press(picker: &mut Picker, code: KeyCode) -> Option<Action> {
+        picker.handle_key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    fn target_indices(picker: &Picker) -> Vec<usize> {
+        picker.targets().iter().map(|file| file.index).collect()
+    }
+
+    #[test]
+    fn test_cursor_stays_in_list() {
+        let mut picker = Picker::new(files(3));
+        press(&mut picker, KeyCode::Up);
+        assert_eq!(picker.cursor, 0);
+        for _ in 0..5 {
+            press(&mut picker, KeyCode::Char('j'));
+        }
+        assert_eq!(picker.cursor, 2);
+        assert_eq!(target_indices(&picker), vec![3]);
+    }
+
+    #[test]
+    fn test_selection_takes_over_cursor() {
+        let mut picker = Picker::new(files(3));
+        press(&mut picker, KeyCode::Char(' '));
+        press(&mut picker, KeyCode::Down);
+        press(&mut picker, KeyCode::Down);
+        press(&mut picker, KeyCode::Char(' '));
+        assert_eq!(target_indices(&picker), vec![1, 3]);
+
+        // Space again deselects
+        press(&mut picker, KeyCode::Char(' '));
+        assert_eq!(target_indices(&picker), vec![1]);
+    }
+
+    #[test]
+    fn test_action_keys() {
+        let mut picker = Picker::new(files(2));
+        assert_eq!(press(&mut picker, KeyCode::Char('a')), Some(Action::Stage));
+        assert_eq!(
+            press(&mut picker, KeyCode::Char('r')),
+            Some(Action::Unstage)
+        );
+        assert_eq!(press(&mut picker, KeyCode::Char('d')), Some(Action::Diff));
+        assert_eq!(press(&mut picker, KeyCode::Esc), Some(Action::Quit));
+        assert_eq!(
+            picker.handle_key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+            Some(Action::Quit)
+        );
+        assert_eq!(press(&mut picker, KeyCode::Char('x')), None);
+    }
+
+    #[test]
+    fn test_replace_files_clamps_cursor() {
+        let mut picker = Picker::new(files(3));
+        press(&mut picker, KeyCode::Down);
+        press(&mut picker, KeyCode::Down);
+        press(&mut picker, KeyCode::Char(' '));
+        picker.replace_files(files(1));
+        assert_eq!(picker.cursor, 0);
+        assert!(picker.selected.is_empty());
+    }
+}
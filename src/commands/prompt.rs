@@ -0,0 +1,187 @@
+use crate::core::{
+    error::{GitNavigatorError, Result},
+    git::GitRepo,
+    git_status::GitStatus,
+    state::FileEntry,
+};
+use clap::Parser;
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Stdio;
+use std::time::{Duration, SystemTime};
+
+#[derive(Parser)]
+pub struct PromptArgs {
+    /// Print from the last saved status right away and refresh it in the
+    /// background, so the prompt never waits on a slow status scan
+    #[arg(long = "async")]
+    pub async_refresh: bool,
+
+    /// Rescan and save the status for the next prompt, printing nothing
+    #[arg(long, hide = true)]
+    pub refresh: bool,
+}
+
+/// Lock held while a background refresh runs, so prompts drawn in quick
+/// succession don't start a scan each
+const REFRESH_LOCK: &str = "prompt.lock";
+
+/// A lock older than this was left behind by a refresh that died
+const REFRESH_LOCK_TIMEOUT: Duration = Duration::from_secs(60);
+
+pub fn execute_prompt(args: PromptArgs) -> Result<()> {
+    // Check if we're in a git repository
+    let current_dir = env::current_dir()?;
+    let git_repo = GitRepo::open(&current_dir).map_err(|_| GitNavigatorError::NotInGitRepo)?;
+
+    if args.refresh {
+        return refresh(&git_repo);
+    }
+
+    let files = if args.async_refresh {
+        let files = git_repo
+            .cache_store()
+            .and_then(|store| store.load_prompt_files())
+            .unwrap_or_default();
+        spawn_refresh(&git_repo);
+        files
+    } else {
+        let files = git_repo.get_status()?;
+        if let Err(e) = git_repo
+            .cache_store()
+            .and_then(|store| store.save_prompt_files(&files))
+        {
+            log::warn!("Prompt cache save failed: {e}");
+        }
+        files
+    };
+
+    let branch = git_repo.get_current_branch().unwrap_or_default();
+    println!("{}", format_prompt(&branch, &files));
+    Ok(())
+}
+
+/// Scan the worktree and save it for the next `prompt --async`
+fn refresh(git_repo: &GitRepo) -> Result<()> {
+    let store = git_repo.cache_store()?;
+    let result = git_repo
+        .get_status()
+        .and_then(|files| store.save_prompt_files(&files));
+    let _ = fs::remove_file(store.dir().join(REFRESH_LOCK));
+    result
+}
+
+/// Start `prompt --refresh` detached, unless one is already running
+fn spawn_refresh(git_repo: &GitRepo) {
+    let Ok(store) = git_repo.cache_store() else {
+        return;
+    };
+    if !take_lock(&store.dir().join(REFRESH_LOCK)) {
+        log::debug!("A prompt refresh is already running");
+        return;
+    }
+
+    let spawned = env::current_exe().and_then(|exe| {
+        let mut cmd = std::process::Command::new(exe);
+        cmd.args(["prompt", "--refresh"])
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null());
+        if let Some(workdir) = git_repo.get_repository().workdir() {
+            cmd.current_dir(workdir);
+        }
+        cmd.spawn()
+    });
+    if let Err(e) = spawned {
+        log::debug!("Could not start the prompt refresh: {e}");
+        let _ = fs::remove_file(store.dir().join(REFRESH_LOCK));
+    }
+}
+
+/// Create the lock file, replacing one abandoned by a refresh that died
+fn take_lock(lock: &Path) -> bool {
+    if let Some(dir) = lock.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+    let abandoned = fs::metadata(lock)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|age| age > REFRESH_LOCK_TIMEOUT);
+    if abandoned {
+        let _ = fs::remove_file(lock);
+    }
+    fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(lock)
+        .is_ok()
+}
+
+/// "main +2 ~3 ?1": the branch, then staged, unstaged, untracked and
+/// conflicted counts, leaving out the empty ones
+fn format_prompt(branch: &str, files: &[FileEntry]) -> String {
+    let count = |matches: fn(&FileEntry) -> bool| files.iter().filter(|file| matches(file)).count();
+    let counts = [
+        ('+', count(|file| file.staged)),
+        (
+            '~',
+            count(|file| {
+                !file.staged && !matches!(file.status, GitStatus::Untracked | GitStatus::Unmerged)
+            }),
+        ),
+        ('?', count(|file| file.status == GitStatus::Untracked)),
+        ('!', count(|file| file.status == GitStatus::Unmerged)),
+    ];
+
+    let mut prompt = branch.to_string();
+    for (symbol, n) in counts {
+        if n > 0 {
+            prompt.push_str(&format!(" {symbol}{n}"));
+        }
+    }
+    prompt
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn entry(status: GitStatus, staged: bool) -> FileEntry {
+        FileEntry {
+            index: 1,
+            status,
+            path: PathBuf::from("file.txt"),
+            staged,
+            old_path: None,
+        }
+    }
+
+    #[test]
+    fn test_format_prompt() {
+        let files = [
+            entry(GitStatus::Added, true),
+            entry(GitStatus::Modified, true),
+            entry(GitStatus::Modified, false),
+            entry(GitStatus::Deleted, false),
+            entry(GitStatus::Untracked, false),
+            entry(GitStatus::Unmerged, false),
+        ];
+        assert_eq!(format_prompt("main", &files), "main +2 ~2 ?1 !1");
+    }
+
+    #[test]
+    fn test_format_prompt_clean() {
+        assert_eq!(format_prompt("main", &[]), "main");
+    }
+
+    #[test]
+    fn test_take_lock_once() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let lock = temp_dir.path().join("cache").join(REFRESH_LOCK);
+        assert!(take_lock(&lock));
+        assert!(!take_lock(&lock));
+    }
+}
@@ -0,0 +1,31 @@
+use crate::core::{
+    error::{GitNavigatorError, Result},
+    git::GitRepo,
+    network::NetworkCheck,
+    print_success,
+};
+use clap::Parser;
+use std::env;
+
+#[derive(Parser)]
+pub struct PullArgs {}
+
+pub fn execute_pull(_args: PullArgs) -> Result<()> {
+    // Check if we're in a git repository
+    let current_dir = env::current_dir()?;
+    let git_repo = GitRepo::open(&current_dir).map_err(|_| GitNavigatorError::NotInGitRepo)?;
+
+    let Some(upstream) = git_repo.get_upstream()? else {
+        return Err(GitNavigatorError::pull_failed(
+            "the current branch has no upstream; push it first or set one with 'git branch --set-upstream-to'",
+        ));
+    };
+
+    if let Some(url) = git_repo.remote_url(&upstream.remote, false) {
+        NetworkCheck::for_repo(&git_repo).ensure_remote_reachable(&url)?;
+    }
+
+    git_repo.pull()?;
+    print_success(&format!("Pulled {}", upstream.tracking));
+    Ok(())
+}
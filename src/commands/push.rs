@@ -0,0 +1,138 @@
+use crate::core::{
+    error::{GitNavigatorError, Result},
+    git::GitRepo,
+    is_interactive, is_plain_mode,
+    network::NetworkCheck,
+    print_info, print_success, print_warning,
+    state::CommitEntry,
+    templates::colored_index,
+};
+use clap::Parser;
+use colored::*;
+use std::env;
+use std::io::{self, Write};
+
+#[derive(Parser)]
+pub struct PushArgs {
+    /// Overwrite the remote branch, but only while it is where the last fetch saw it
+    #[arg(long)]
+    pub force_with_lease: bool,
+
+    /// Discard remote commits without asking first
+    #[arg(long, short = 'y')]
+    pub yes: bool,
+}
+
+pub fn execute_push(args: PushArgs) -> Result<()> {
+    // Check if we're in a git repository
+    let current_dir = env::current_dir()?;
+    let git_repo = GitRepo::open(&current_dir).map_err(|_| GitNavigatorError::NotInGitRepo)?;
+
+    if git_repo.get_detached_head()?.is_some() {
+        return Err(GitNavigatorError::push_failed(
+            "HEAD is detached; switch to a branch first",
+        ));
+    }
+    let branch = git_repo.get_current_branch()?;
+
+    let Some(upstream) = git_repo.get_upstream()? else {
+        // First push of this branch: publish it to origin and track it
+        if git_repo.get_repository().find_remote("origin").is_err() {
+            return Err(GitNavigatorError::push_failed(format!(
+                "'{branch}' has no upstream and there is no 'origin' remote"
+            )));
+        }
+        if let Some(url) = git_repo.remote_url("origin", true) {
+            NetworkCheck::for_repo(&git_repo).ensure_remote_reachable(&url)?;
+        }
+        git_repo.push_branch("origin", &branch, None, true)?;
+        print_success(&format!(
+            "Pushed '{branch}' to origin/{branch} and set it as upstream"
+        ));
+        return Ok(());
+    };
+
+    if let Some(url) = git_repo.remote_url(&upstream.remote, true) {
+        NetworkCheck::for_repo(&git_repo).ensure_remote_reachable(&url)?;
+    }
+
+    let lease = if args.force_with_lease {
+        let Some(expected) = upstream.oid else {
+            return Err(GitNavigatorError::push_failed(format!(
+                "{} has never been fetched; fetch before force pushing",
+                upstream.tracking
+            )));
+        };
+
+        let discarded = git_repo.commits_missing_from_head(expected)?;
+        if discarded.is_empty() {
+            print_info(&format!(
+                "No commits on {} would be discarded.",
+                upstream.tracking
+            ));
+        } else {
+            show_discarded(&upstream.tracking, &discarded);
+            if !args.yes && !confirm_discard(discarded.len())? {
+                return Err(GitNavigatorError::PushCanceled);
+            }
+        }
+        Some(expected)
+    } else {
+        None
+    };
+
+    git_repo.push_branch(&upstream.remote, &upstream.branch, lease, false)?;
+    print_success(&format!(
+        "{} '{branch}' to {}",
+        if lease.is_some() {
+            "Force pushed"
+        } else {
+            "Pushed"
+        },
+        upstream.tracking
+    ));
+    Ok(())
+}
+
+/// List the remote commits a force push would throw away
+fn show_discarded(tracking: &str, commits: &[CommitEntry]) {
+    print_warning(&format!(
+        "Force pushing will discard {} commit(s) from {tracking} (as of the last fetch):",
+        commits.len()
+    ));
+    for commit in commits {
+        if is_plain_mode() {
+            println!(
+                "Index {}: {} {}",
+                commit.index, commit.short_oid, commit.summary
+            );
+        } else {
+            println!(
+                "   {} {} {}",
+                colored_index(commit.index),
+                commit.short_oid.blue(),
+                commit.summary
+            );
+        }
+    }
+}
+
+/// Ask before discarding remote commits
+///
+/// Without an interactive terminal there is nobody to ask, so an error
+/// pointing at `--yes` is returned.
+fn confirm_discard(count: usize) -> Result<bool> {
+    if !is_interactive() {
+        return Err(GitNavigatorError::push_failed(format!(
+            "{count} remote commit(s) would be discarded; pass --yes to confirm"
+        )));
+    }
+
+    print!("\n{} ", "Discard them and force push? [y/N]:".blue());
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+
+    Ok(matches!(input.trim().to_lowercase().as_str(), "y" | "yes"))
+}
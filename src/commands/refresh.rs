@@ -0,0 +1,58 @@
+use crate::commands::branches::local_branches;
+use crate::commands::dirs::changed_dirs;
+use crate::commands::tags::{numbered_tags, TagSort};
+use crate::core::{
+    error::{GitNavigatorError, Result},
+    git::GitRepo,
+    print_success,
+};
+use clap::Parser;
+use std::env;
+
+#[derive(Parser)]
+pub struct RefreshArgs {
+    /// Print nothing on success (for shell prompts and scripts)
+    #[arg(long, short = 'q')]
+    pub quiet: bool,
+}
+
+pub fn execute_refresh(args: RefreshArgs) -> Result<()> {
+    // Check if we're in a git repository
+    let current_dir = env::current_dir()?;
+    let git_repo = GitRepo::open(&current_dir).map_err(|_| GitNavigatorError::NotInGitRepo)?;
+    let store = git_repo.cache_store()?;
+
+    // Number everything the way gs, gb, dirs and tags would, without printing it
+    let files = git_repo.get_status()?;
+    git_repo.save_file_list(&files)?;
+
+    let branches = local_branches(&git_repo)?;
+    store.save_branches(&branches)?;
+
+    let dirs = changed_dirs(&files);
+    store.save_dirs(&dirs)?;
+
+    let tags = numbered_tags(&git_repo, TagSort::from_settings(&git_repo.load_settings()))?;
+    store.save_tags(&tags)?;
+
+    if !args.quiet {
+        print_success(&format!(
+            "Refreshed {}, {}, {} and {}",
+            count(files.len(), "file"),
+            count(branches.iter().filter(|b| b.index > 0).count(), "branch"),
+            count(dirs.len(), "directory"),
+            count(tags.len(), "tag"),
+        ));
+    }
+    Ok(())
+}
+
+/// "1 file", "3 branches", "2 directories"
+fn count(n: usize, noun: &str) -> String {
+    match (n, noun) {
+        (1, _) => format!("1 {noun}"),
+        (_, "branch") => format!("{n} branches"),
+        (_, "directory") => format!("{n} directories"),
+        _ => format!("{n} {noun}s"),
+    }
+}
@@ -0,0 +1,199 @@
+use crate::core::{
+    cache::{cached_repositories, CacheStore},
+    error::{GitNavigatorError, Result},
+    git::GitRepo,
+    is_plain_mode, print_info, print_section_header,
+    state::RepoEntry,
+    templates::{colored_index, colored_index_label, templates},
+};
+use clap::Parser;
+use colored::*;
+use std::env;
+use std::path::{Path, PathBuf};
+
+#[derive(Parser)]
+pub struct ReposArgs {
+    /// Repository index to print the path of (for the grepo shell function to cd into)
+    pub index: Option<usize>,
+}
+
+pub fn execute_repos(args: ReposArgs) -> Result<()> {
+    match args.index {
+        Some(index) => {
+            // Print the working directory for the shell wrapper to cd into
+            let path = resolve_repo(index)?;
+            println!("{}", path.display());
+            Ok(())
+        }
+        None => list_repos(),
+    }
+}
+
+fn list_repos() -> Result<()> {
+    let repos = known_repos();
+    if repos.is_empty() {
+        print_info(
+            "No repositories yet: they show up once git-navigator numbers something in them (gs, gb, gl, ...).",
+        );
+        return Ok(());
+    }
+
+    let current = env::current_dir()
+        .ok()
+        .and_then(|dir| GitRepo::open(dir).ok())
+        .and_then(|git_repo| git_repo.get_repository().workdir().map(Path::to_path_buf));
+
+    print_section_header("Repositories");
+    for repo in &repos {
+        let is_current = current.as_deref() == Some(repo.path.as_path());
+        let (branch, changed) = match GitRepo::open(&repo.path) {
+            Ok(git_repo) => (
+                git_repo
+                    .get_current_branch()
+                    .unwrap_or_else(|_| "(no branch)".to_string()),
+                git_repo.get_status().map(|files| files.len()).unwrap_or(0),
+            ),
+            Err(e) => {
+                log::debug!("Cannot open {}: {e}", repo.path.display());
+                ("(unreadable)".to_string(), 0)
+            }
+        };
+        let changed_text = match changed {
+            0 => String::new(),
+            1 => "1 changed".to_string(),
+            n => format!("{n} changed"),
+        };
+
+        if is_plain_mode() {
+            let current_text = if is_current { " (current)" } else { "" };
+            let details = if changed_text.is_empty() {
+                branch
+            } else {
+                format!("{branch}, {changed_text}")
+            };
+            println!(
+                "Index {}: {} ({details}){current_text}",
+                repo.index,
+                repo.path.display()
+            );
+            continue;
+        }
+
+        let index = if is_current {
+            colored_index_label(&format!("{}*", templates().index_number(repo.index)))
+        } else {
+            colored_index(repo.index)
+        };
+        println!(
+            "{} {} {} {}",
+            index,
+            repo.path.display().to_string().blue(),
+            branch.green(),
+            changed_text.yellow()
+        );
+    }
+    println!();
+
+    #[cfg(not(test))]
+    {
+        if let Err(e) = CacheStore::global().save_repos(&repos) {
+            // Log cache errors but don't fail the command
+            log::warn!("Repository cache save failed: {e}");
+        }
+    }
+
+    Ok(())
+}
+
+/// Working directory of a repository numbered by the last `repos`
+fn resolve_repo(index: usize) -> Result<PathBuf> {
+    let repos = CacheStore::global()
+        .load_or_rebuild(CacheStore::load_repos, CacheStore::save_repos, || {
+            Ok(known_repos())
+        })
+        .or_else(|e| match e {
+            // Numbered the way `repos` would number them
+            GitNavigatorError::CacheFileNotFound { .. } => Ok(known_repos()),
+            e => Err(e),
+        })
+        .map_err(|e| {
+            log::warn!("Failed to load repository cache: {e}");
+            GitNavigatorError::custom_cache_error(
+                "Cannot load repository cache. Run 'repos' first to list repositories.",
+                e,
+            )
+        })?;
+
+    let repo = repos
+        .into_iter()
+        .find(|repo| repo.index == index)
+        .ok_or_else(|| {
+            GitNavigatorError::custom_empty_files_error(format!(
+                "Repository index {index} not found"
+            ))
+        })?;
+
+    if !repo.path.is_dir() {
+        return Err(GitNavigatorError::custom_empty_files_error(format!(
+            "Repository '{}' no longer exists",
+            repo.path.display()
+        )));
+    }
+    Ok(repo.path)
+}
+
+/// Every repository with a cache that is still on disk, numbered in path order
+fn known_repos() -> Vec<RepoEntry> {
+    number_repos(
+        cached_repositories()
+            .iter()
+            .filter_map(|git_dir| workdir_of(git_dir))
+            .collect(),
+    )
+}
+
+/// The working directory of the repository at `git_dir`, if it still exists
+/// and has one
+///
+/// Opened without discovery: a deleted `.git` must not turn into whatever
+/// repository encloses it.
+fn workdir_of(git_dir: &Path) -> Option<PathBuf> {
+    if !git_dir.is_dir() {
+        return None;
+    }
+    let repo = git2::Repository::open(git_dir).ok()?;
+    let workdir = repo.workdir()?;
+    Some(workdir.components().collect())
+}
+
+fn number_repos(mut paths: Vec<PathBuf>) -> Vec<RepoEntry> {
+    paths.sort();
+    paths.dedup();
+    paths
+        .into_iter()
+        .enumerate()
+        .map(|(i, path)| RepoEntry { index: i + 1, path })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number_repos() {
+        let repos = number_repos(vec![
+            PathBuf::from("/work/zeta"),
+            PathBuf::from("/work/alpha"),
+            PathBuf::from("/work/zeta"),
+        ]);
+        let numbered: Vec<(usize, &Path)> = repos
+            .iter()
+            .map(|repo| (repo.index, repo.path.as_path()))
+            .collect();
+        assert_eq!(
+            numbered,
+            vec![(1, Path::new("/work/alpha")), (2, Path::new("/work/zeta"))]
+        );
+    }
+}
@@ -1,9 +1,98 @@
 use crate::commands::status::execute_status;
 use crate::core::{
+    args_parser::ArgsParser,
     command_init::IndexCommandInit,
     error::{GitNavigatorError, Result},
-    print_success,
+    git::{GitRepo, ResetMode},
+    git_status::GitStatus,
+    is_interactive, print_success, print_warning,
+    state::{CommitEntry, FileStat},
+    templates::colored_index,
 };
+use clap::Parser;
+use colored::*;
+use std::env;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+#[derive(Parser)]
+pub struct ResetArgs {
+    /// Move HEAD to the commit, keeping the index and the worktree
+    #[arg(long, conflicts_with_all = ["mixed", "hard"])]
+    pub soft: bool,
+
+    /// Move HEAD to the commit and reset the index (the default)
+    #[arg(long, conflicts_with = "hard")]
+    pub mixed: bool,
+
+    /// Also throw away the changes in the worktree
+    #[arg(long)]
+    pub hard: bool,
+
+    /// Discard changes and commits without asking first
+    #[arg(long, short = 'y')]
+    pub yes: bool,
+
+    /// File indices to reset (e.g., "1 3-5,8") or a commit to reset to (e.g., "HEAD~1")
+    #[arg(allow_negative_numbers = true)]
+    pub indices: Vec<String>,
+}
+
+/// What the positional arguments of `grs` name
+#[derive(Debug, PartialEq, Eq)]
+enum ResetTarget {
+    Files,
+    Commit(String),
+}
+
+pub fn execute_reset_with_args(args: ResetArgs) -> Result<()> {
+    let mode = if args.soft {
+        ResetMode::Soft
+    } else if args.hard {
+        ResetMode::Hard
+    } else {
+        ResetMode::Mixed
+    };
+    let explicit_mode = args.soft || args.mixed || args.hard;
+
+    // An existing path names files unless it resolves as a commit too
+    let git_repo = GitRepo::open(&env::current_dir()?).ok();
+    let is_file_path = |arg: &str| {
+        Path::new(arg).exists()
+            && !git_repo
+                .as_ref()
+                .is_some_and(|repo| repo.commit_exists(arg))
+    };
+
+    match reset_target(&args.indices, explicit_mode, is_file_path) {
+        ResetTarget::Commit(target) => reset_to_commit(&target, mode, args.yes),
+        ResetTarget::Files => match mode {
+            ResetMode::Soft => Err(GitNavigatorError::reset_failed(
+                "--soft only moves HEAD; give it a commit, not file indices",
+            )),
+            ResetMode::Mixed => execute_reset(args.indices),
+            ResetMode::Hard => discard_files_by_indices(args.indices, args.yes),
+        },
+    }
+}
+
+/// Index lists and paths name files and a single other argument a commit
+/// (`HEAD~1`, `main`, a hash); `--soft`, `--mixed` or `--hard` alone reset to HEAD
+///
+/// `is_file_path` tells whether an argument is a path rather than a commit.
+fn reset_target(
+    args: &[String],
+    explicit_mode: bool,
+    is_file_path: impl Fn(&str) -> bool,
+) -> ResetTarget {
+    match args {
+        [] if explicit_mode => ResetTarget::Commit("HEAD".to_string()),
+        [arg] if !arg.is_empty() && !ArgsParser::is_selection(arg) && !is_file_path(arg) => {
+            ResetTarget::Commit(arg.clone())
+        }
+        _ => ResetTarget::Files,
+    }
+}
 
 pub fn execute_reset(indices_args: Vec<String>) -> Result<()> {
     // Initialize everything needed for this index-based command
@@ -20,8 +109,7 @@ pub fn execute_reset(indices_args: Vec<String>) -> Result<()> {
     // so we can't avoid the clone, but we can at least do it efficiently
     let paths_to_reset: Vec<_> = selected_files
         .iter()
-        .map(|file| &file.path)
-        .cloned()
+        .flat_map(|file| file.affected_paths())
         .collect();
 
     if paths_to_reset.is_empty() {
@@ -48,6 +136,201 @@ pub fn execute_reset(indices_args: Vec<String>) -> Result<()> {
     Ok(())
 }
 
+/// `grs --hard <index>...`: throw away the staged and unstaged changes to
+/// the files, after listing them and asking
+///
+/// Untracked files are left alone, as `git reset --hard` leaves them.
+fn discard_files_by_indices(indices_args: Vec<String>, yes: bool) -> Result<()> {
+    let context = IndexCommandInit::initialize_with_messages(
+        indices_args,
+        "Cannot load file cache",
+        "No files available to reset",
+    )?;
+
+    let selected_files = context.get_selected_files();
+    let untracked = selected_files
+        .iter()
+        .filter(|file| file.status == GitStatus::Untracked)
+        .count();
+    if untracked > 0 {
+        print_warning(&format!(
+            "Leaving {untracked} untracked file(s) alone; --hard only resets tracked files."
+        ));
+    }
+
+    let mut paths: Vec<PathBuf> = Vec::new();
+    for file in selected_files {
+        if file.status == GitStatus::Untracked {
+            continue;
+        }
+        for path in file.affected_paths() {
+            if !paths.contains(&path) {
+                paths.push(path);
+            }
+        }
+    }
+    if paths.is_empty() {
+        return Err(GitNavigatorError::NoValidFilesSelected);
+    }
+
+    let lost = uncommitted_changes(&context.git_repo, Some(&paths))?;
+    if !lost.is_empty() {
+        show_lost_changes(&lost);
+        if !yes && !confirm_discard()? {
+            return Err(GitNavigatorError::ResetCanceled);
+        }
+    }
+
+    context.git_repo.discard_files(&paths)?;
+    print_success(&format!(
+        "Discarded the changes to {} file(s).",
+        paths.len()
+    ));
+
+    println!("Updated status:");
+    execute_status()?;
+
+    Ok(())
+}
+
+/// `grs [--soft|--mixed|--hard] <commit>`: move the current branch to `target`
+///
+/// The commits taken off the branch are listed first. A hard reset also
+/// lists the uncommitted changes it throws away, and asks before going ahead
+/// when either list is not empty.
+fn reset_to_commit(target: &str, mode: ResetMode, yes: bool) -> Result<()> {
+    let current_dir = env::current_dir()?;
+    let git_repo = GitRepo::open(&current_dir).map_err(|_| GitNavigatorError::NotInGitRepo)?;
+    if git_repo.get_repository().head().is_err() {
+        return Err(GitNavigatorError::reset_failed(
+            "there are no commits to reset yet",
+        ));
+    }
+
+    let oid = git_repo
+        .get_repository()
+        .revparse_single(target)
+        .and_then(|object| object.peel_to_commit())
+        .map(|commit| commit.id())
+        .map_err(|_| {
+            GitNavigatorError::reset_failed(format!(
+                "'{target}' is neither a file index nor a commit"
+            ))
+        })?;
+
+    let dropped = git_repo.commits_missing_from(oid)?;
+    if !dropped.is_empty() {
+        show_dropped_commits(&dropped, mode);
+    }
+    let lost = if mode == ResetMode::Hard {
+        uncommitted_changes(&git_repo, None)?
+    } else {
+        Vec::new()
+    };
+    if !lost.is_empty() {
+        show_lost_changes(&lost);
+    }
+    let discards = mode == ResetMode::Hard && (!dropped.is_empty() || !lost.is_empty());
+    if discards && !yes && !confirm_discard()? {
+        return Err(GitNavigatorError::ResetCanceled);
+    }
+
+    git_repo.reset_to(&oid.to_string(), mode)?;
+    let (short_oid, subject) = git_repo.get_parent_commit_info()?;
+    print_success(&format!("HEAD is now at {short_oid} {subject}"));
+
+    println!("Updated status:");
+    execute_status()?;
+
+    Ok(())
+}
+
+/// Tracked files with staged or unstaged changes, with their line counts
+///
+/// `only` narrows the list to some paths. Binary files count no lines.
+fn uncommitted_changes(git_repo: &GitRepo, only: Option<&[PathBuf]>) -> Result<Vec<FileStat>> {
+    let line_stats = git_repo.line_stats()?;
+    let mut changes: Vec<FileStat> = Vec::new();
+    for file in git_repo.get_status()? {
+        if file.status == GitStatus::Untracked
+            || only.is_some_and(|paths| !paths.contains(&file.path))
+            || changes.iter().any(|change| change.path == file.path)
+        {
+            continue;
+        }
+        let stat = line_stats
+            .iter()
+            .find(|stat| stat.path == file.path)
+            .cloned()
+            .unwrap_or(FileStat {
+                path: file.path,
+                added: 0,
+                removed: 0,
+            });
+        changes.push(stat);
+    }
+    Ok(changes)
+}
+
+/// List the commits a reset takes off the current branch
+fn show_dropped_commits(commits: &[CommitEntry], mode: ResetMode) {
+    print_warning(&format!(
+        "Resetting takes {} commit(s) off the current branch:",
+        commits.len()
+    ));
+    for commit in commits {
+        println!(
+            "   {} {} {}",
+            colored_index(commit.index),
+            commit.short_oid.blue(),
+            commit.summary
+        );
+    }
+    let kept = match mode {
+        ResetMode::Soft => "Their changes stay staged.",
+        ResetMode::Mixed => "Their changes stay in the worktree, unstaged.",
+        ResetMode::Hard => return,
+    };
+    println!("{}", kept.bright_black());
+}
+
+/// List the uncommitted changes a hard reset throws away
+fn show_lost_changes(files: &[FileStat]) {
+    print_warning(&format!(
+        "Uncommitted changes to {} file(s) will be lost:",
+        files.len()
+    ));
+    for file in files {
+        if file.lines() == 0 {
+            println!("   {}", file.path.display());
+        } else {
+            println!(
+                "   {} {} {}",
+                file.path.display(),
+                format!("+{}", file.added).green(),
+                format!("-{}", file.removed).red()
+            );
+        }
+    }
+}
+
+/// Ask before a hard reset throws changes or commits away
+fn confirm_discard() -> Result<bool> {
+    if !is_interactive() {
+        return Err(GitNavigatorError::NonInteractive(
+            "pass --yes to discard them".to_string(),
+        ));
+    }
+
+    print!("\n{} ", "Discard them and reset? [y/N]:".blue());
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+
+    Ok(matches!(input.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -87,27 +370,69 @@ mod tests {
         assert!(error_msg.contains("Invalid index format"));
     }
 
+    #[test]
+    fn test_reset_target() {
+        let args = |values: &[&str]| values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            reset_target(&args(&["1", "3-5,8"]), false, |_| false),
+            ResetTarget::Files
+        );
+        assert_eq!(
+            reset_target(&args(&["2"]), true, |_| false),
+            ResetTarget::Files
+        );
+        assert_eq!(
+            reset_target(&args(&["HEAD~1"]), false, |_| false),
+            ResetTarget::Commit("HEAD~1".to_string())
+        );
+        assert_eq!(
+            reset_target(&[], true, |_| false),
+            ResetTarget::Commit("HEAD".to_string())
+        );
+        assert_eq!(reset_target(&[], false, |_| false), ResetTarget::Files);
+        assert_eq!(
+            reset_target(&args(&[""]), false, |_| false),
+            ResetTarget::Files
+        );
+        assert_eq!(
+            reset_target(&args(&["[02]"]), false, |_| false),
+            ResetTarget::Files
+        );
+        // Paths name files too, unless they are commits as well
+        assert_eq!(
+            reset_target(&args(&["src/a.rs"]), false, |arg| arg == "src/a.rs"),
+            ResetTarget::Files
+        );
+        assert_eq!(
+            reset_target(&args(&["main"]), false, |_| false),
+            ResetTarget::Commit("main".to_string())
+        );
+    }
+
     #[test]
     fn test_memory_efficient_path_collection() {
         // Test that our path collection is memory efficient
-        let files = vec![
+        let files = [
             FileEntry {
                 index: 1,
                 status: GitStatus::Modified,
                 path: PathBuf::from("file1.txt"),
                 staged: false,
+                old_path: None,
             },
             FileEntry {
                 index: 2,
                 status: GitStatus::Added,
                 path: PathBuf::from("file2.txt"),
                 staged: true,
+                old_path: None,
             },
             FileEntry {
                 index: 3,
                 status: GitStatus::Untracked,
                 path: PathBuf::from("very/long/path/to/file3.txt"),
                 staged: false,
+                old_path: None,
             },
         ];
 
@@ -136,18 +461,20 @@ mod tests {
     #[test]
     fn test_vector_preallocation_efficiency() {
         // Test that pre-allocation with known capacity is more efficient
-        let files = vec![
+        let files = [
             FileEntry {
                 index: 1,
                 status: GitStatus::Modified,
                 path: PathBuf::from("file1.txt"),
                 staged: false,
+                old_path: None,
             },
             FileEntry {
                 index: 2,
                 status: GitStatus::Added,
                 path: PathBuf::from("file2.txt"),
                 staged: true,
+                old_path: None,
             },
         ];
 
@@ -0,0 +1,121 @@
+use crate::core::{
+    command_init::IndexCommandInit,
+    error::{GitNavigatorError, Result},
+    git::GitRepo,
+    opener::open_path,
+    print_error_with_structured_usage, print_success,
+    state::FileEntry,
+};
+use clap::Parser;
+use std::path::PathBuf;
+
+#[derive(Parser)]
+pub struct RevealArgs {
+    /// File index whose directory to open
+    pub index: Option<String>,
+
+    /// Print the directory instead of opening it
+    #[arg(long)]
+    pub print: bool,
+}
+
+pub fn execute_reveal(args: RevealArgs) -> Result<()> {
+    let indices_args = args.index.into_iter().collect();
+    let context = match IndexCommandInit::initialize_with_messages(
+        indices_args,
+        "Cannot load file cache",
+        "No files available to reveal",
+    ) {
+        Ok(context) => context,
+        Err(GitNavigatorError::NoIndicesProvided) => {
+            print_error_with_structured_usage(
+                "No file index provided",
+                "reveal",
+                &["<index>", "<index> --print"],
+                &[
+                    ("--print", "Print the directory instead of opening it"),
+                    ("-h, --help", "Show this help message"),
+                ],
+            );
+            return Err(GitNavigatorError::NoIndicesProvided);
+        }
+        Err(e) => return Err(e),
+    };
+
+    let files = context.get_selected_files();
+    let [file] = files.as_slice() else {
+        return Err(GitNavigatorError::custom_empty_files_error(
+            "reveal takes a single file index",
+        ));
+    };
+
+    let dir = containing_dir(&context.git_repo, file)?;
+
+    if args.print {
+        println!("{}", dir.display());
+        return Ok(());
+    }
+
+    open_path(&dir)?;
+    print_success(&format!("Opened '{}'", dir.display()));
+    Ok(())
+}
+
+/// Directory containing a file, or its nearest existing ancestor
+///
+/// A deleted file's directory may be gone too, so the walk stops at the
+/// first directory that still exists (at worst the repository root).
+fn containing_dir(git_repo: &GitRepo, file: &FileEntry) -> Result<PathBuf> {
+    let workdir = git_repo
+        .get_repository()
+        .workdir()
+        .ok_or_else(|| GitNavigatorError::custom_empty_files_error("No workdir found"))?;
+
+    let full_path = workdir.join(&file.path);
+    let dir = full_path
+        .ancestors()
+        .skip(1)
+        .find(|dir| dir.is_dir() && dir.starts_with(workdir))
+        .unwrap_or(workdir);
+
+    Ok(dir.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::git_status::GitStatus;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn entry(path: &str) -> FileEntry {
+        FileEntry {
+            index: 1,
+            status: GitStatus::Deleted,
+            path: PathBuf::from(path),
+            staged: false,
+            old_path: None,
+        }
+    }
+
+    #[test]
+    fn test_containing_dir_walks_up_to_existing_directory() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        git2::Repository::init(temp_dir.path())?;
+        fs::create_dir_all(temp_dir.path().join("src/core"))?;
+        let git_repo = GitRepo::open(temp_dir.path())?;
+        let workdir = git_repo.get_repository().workdir().unwrap().to_path_buf();
+
+        assert_eq!(
+            containing_dir(&git_repo, &entry("src/core/git.rs"))?,
+            workdir.join("src/core")
+        );
+        // The whole directory was removed
+        assert_eq!(
+            containing_dir(&git_repo, &entry("src/gone/old.rs"))?,
+            workdir.join("src")
+        );
+        assert_eq!(containing_dir(&git_repo, &entry("README.md"))?, workdir);
+        Ok(())
+    }
+}
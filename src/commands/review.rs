@@ -0,0 +1,333 @@
+use crate::commands::log::cached_commit;
+use crate::core::{
+    editor::Editor,
+    error::{GitNavigatorError, Result},
+    git::{CommitFile, GitRepo},
+    git_status::GitStatus,
+    is_interactive, is_plain_mode, print_info, print_paged, print_section_header, print_success,
+    print_warning,
+    state::CommitEntry,
+    templates::{colored_index, glyphs},
+};
+use clap::Parser;
+use colored::*;
+use std::collections::BTreeSet;
+use std::env;
+use std::io::{self, Write};
+
+#[derive(Parser)]
+pub struct ReviewArgs {
+    /// Commit index from the last `gl` listing
+    pub index: usize,
+
+    /// Show only this file of the commit, by its number in the review list
+    pub file: Option<usize>,
+}
+
+/// What the reviewer asked for at the prompt
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    /// Mark the file as reviewed and go to the next one
+    Accept,
+    Next,
+    Previous,
+    /// Open the file in the editor, then show it again
+    Edit,
+    /// Jump to a file by its number
+    Goto(usize),
+    Quit,
+}
+
+impl Action {
+    /// Enter alone moves on; a number jumps to that file
+    fn parse(input: &str) -> Option<Self> {
+        match input.trim().to_lowercase().as_str() {
+            "a" | "accept" => Some(Action::Accept),
+            "" | "n" | "next" => Some(Action::Next),
+            "p" | "prev" | "previous" => Some(Action::Previous),
+            "e" | "edit" => Some(Action::Edit),
+            "q" | "quit" => Some(Action::Quit),
+            other => other.parse().ok().map(Action::Goto),
+        }
+    }
+}
+
+/// The files of a commit, the one being looked at and those accepted so far
+#[derive(Debug)]
+struct Review {
+    files: Vec<CommitFile>,
+    position: usize,
+    /// Indices of the accepted files
+    accepted: BTreeSet<usize>,
+}
+
+impl Review {
+    fn new(files: Vec<CommitFile>) -> Self {
+        Self {
+            files,
+            position: 0,
+            accepted: BTreeSet::new(),
+        }
+    }
+
+    /// The file under review, `None` once past the last one
+    fn current(&self) -> Option<&CommitFile> {
+        self.files.get(self.position)
+    }
+
+    /// Move according to `action`; returns false for a number that isn't listed
+    fn apply(&mut self, action: Action) -> bool {
+        match action {
+            Action::Accept => {
+                if let Some(file) = self.current() {
+                    self.accepted.insert(file.index);
+                }
+                self.position += 1;
+            }
+            Action::Next => self.position += 1,
+            Action::Previous => self.position = self.position.saturating_sub(1),
+            Action::Goto(index) => match self.files.iter().position(|f| f.index == index) {
+                Some(position) => self.position = position,
+                None => return false,
+            },
+            Action::Quit => self.position = self.files.len(),
+            Action::Edit => {}
+        }
+        true
+    }
+
+    fn pending(&self) -> Vec<&CommitFile> {
+        self.files
+            .iter()
+            .filter(|file| !self.accepted.contains(&file.index))
+            .collect()
+    }
+}
+
+pub fn execute_review(args: ReviewArgs) -> Result<()> {
+    // Check if we're in a git repository
+    let current_dir = env::current_dir()?;
+    let git_repo = GitRepo::open(&current_dir).map_err(|_| GitNavigatorError::NotInGitRepo)?;
+
+    let commit = cached_commit(&git_repo, args.index)?;
+    let files = git_repo.commit_files(&commit.oid)?;
+    if files.is_empty() {
+        print_info(&format!("{} changes no files.", commit.short_oid));
+        return Ok(());
+    }
+
+    if let Some(index) = args.file {
+        let file = files
+            .iter()
+            .find(|file| file.index == index)
+            .ok_or_else(|| GitNavigatorError::index_out_of_range(index, files.len()))?;
+        let output = git_repo.show_commit_file(&commit.oid, file)?;
+        print_paged(&output, git_repo.git_defaults().pager_command().as_deref());
+        return Ok(());
+    }
+
+    // Without anyone to answer the prompt, list the files to pick from instead
+    if !is_interactive() {
+        print_section_header(&commit_title(&commit));
+        for file in &files {
+            println!("{}", file_label(file));
+        }
+        print_info(&format!(
+            "review needs a terminal; use 'review {} <file>' to see one file's diff.",
+            args.index
+        ));
+        return Ok(());
+    }
+
+    let mut review = Review::new(files);
+    review_loop(&git_repo, &commit, &mut review)?;
+
+    let pending = review.pending();
+    if pending.is_empty() {
+        print_success(&format!("Accepted all {} file(s)", review.files.len()));
+    } else {
+        print_warning(&format!(
+            "Accepted {} of {} file(s); not accepted:",
+            review.accepted.len(),
+            review.files.len()
+        ));
+        for file in pending {
+            println!("{}", file_label(file));
+        }
+    }
+    Ok(())
+}
+
+fn review_loop(git_repo: &GitRepo, commit: &CommitEntry, review: &mut Review) -> Result<()> {
+    let pager = git_repo.git_defaults().pager_command();
+    let total = review.files.len();
+    let mut show = true;
+
+    while let Some(file) = review.current() {
+        if show {
+            let mut output = file_header(file, total, review.accepted.contains(&file.index));
+            output.push_str(&git_repo.show_commit_file(&commit.oid, file)?);
+            print_paged(&output, pager.as_deref());
+        }
+
+        let Some(input) = read_answer("[a]ccept, [n]ext, [p]revious, [e]dit, [q]uit or a number:")?
+        else {
+            // stdin closed
+            break;
+        };
+        let Some(action) = Action::parse(&input) else {
+            print_warning(&format!("Unknown answer '{}'", input.trim()));
+            show = false;
+            continue;
+        };
+
+        if action == Action::Edit {
+            open_in_editor(git_repo, file)?;
+        }
+        show = review.apply(action);
+        if !show {
+            print_warning(&format!("No file {input} in this commit (1-{total})"));
+        }
+    }
+    Ok(())
+}
+
+/// Open the worktree copy of `file` at the first line the commit changed
+fn open_in_editor(git_repo: &GitRepo, file: &CommitFile) -> Result<()> {
+    if file.status == GitStatus::Deleted {
+        print_warning(&format!(
+            "{} was deleted by this commit, so there is nothing to open",
+            file.path.display()
+        ));
+        return Ok(());
+    }
+    let workdir = git_repo
+        .get_repository()
+        .workdir()
+        .ok_or_else(|| GitNavigatorError::custom_empty_files_error("No workdir found"))?;
+
+    let editor = Editor::new(git_repo.git_defaults().editor_command());
+    editor.open(
+        &git_repo.load_settings(),
+        &workdir.join(&file.path),
+        file.first_line,
+    )
+}
+
+fn commit_title(commit: &CommitEntry) -> String {
+    format!("Files in {} {}", commit.short_oid, commit.summary)
+}
+
+fn file_header(file: &CommitFile, total: usize, accepted: bool) -> String {
+    if is_plain_mode() {
+        let accepted = if accepted { ", accepted" } else { "" };
+        return format!(
+            "File {} of {total}: {} ({}{accepted})\n",
+            file.index,
+            file.path.display(),
+            file.status.description()
+        );
+    }
+    let mark = if accepted {
+        format!(" {}", glyphs().success.green())
+    } else {
+        String::new()
+    };
+    format!(
+        "{} {}/{total} {} {}{mark} {}\n",
+        glyphs().rule.bright_blue().bold(),
+        file.index,
+        file.path.display().to_string().bright_blue().bold(),
+        file.status.description().bright_black(),
+        glyphs().rule.bright_blue().bold()
+    )
+}
+
+pub(crate) fn file_label(file: &CommitFile) -> String {
+    let path = match &file.old_path {
+        Some(old_path) => format!("{} -> {}", old_path.display(), file.path.display()),
+        None => file.path.display().to_string(),
+    };
+    if is_plain_mode() {
+        format!(
+            "Index {}: {path} ({})",
+            file.index,
+            file.status.description()
+        )
+    } else {
+        format!(
+            "   {} {path} {}",
+            colored_index(file.index),
+            file.status.description().bright_black()
+        )
+    }
+}
+
+/// Read one answer; `None` when stdin is closed
+fn read_answer(prompt: &str) -> Result<Option<String>> {
+    print!("{} ", prompt.blue());
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    if io::stdin().read_line(&mut input)? == 0 {
+        return Ok(None);
+    }
+    Ok(Some(input.trim().to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn files(count: usize) -> Vec<CommitFile> {
+        (1..=count)
+            .map(|index| CommitFile {
+                index,
+                status: GitStatus::Modified,
+                path: PathBuf::from(format!("file{index}.rs")),
+                old_path: None,
+                first_line: Some(1),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_action() {
+        assert_eq!(Action::parse("a"), Some(Action::Accept));
+        assert_eq!(Action::parse(""), Some(Action::Next));
+        assert_eq!(Action::parse(" P "), Some(Action::Previous));
+        assert_eq!(Action::parse("edit"), Some(Action::Edit));
+        assert_eq!(Action::parse("3"), Some(Action::Goto(3)));
+        assert_eq!(Action::parse("q"), Some(Action::Quit));
+        assert_eq!(Action::parse("x"), None);
+    }
+
+    #[test]
+    fn test_review_walks_files() {
+        let mut review = Review::new(files(3));
+        review.apply(Action::Accept);
+        review.apply(Action::Next);
+        assert_eq!(review.current().map(|f| f.index), Some(3));
+        review.apply(Action::Previous);
+        review.apply(Action::Accept);
+        assert_eq!(review.current().map(|f| f.index), Some(3));
+        review.apply(Action::Next);
+        assert!(review.current().is_none());
+
+        let pending: Vec<usize> = review.pending().iter().map(|f| f.index).collect();
+        assert_eq!(pending, vec![3]);
+    }
+
+    #[test]
+    fn test_review_goto_and_quit() {
+        let mut review = Review::new(files(3));
+        assert!(review.apply(Action::Goto(2)));
+        assert_eq!(review.current().map(|f| f.index), Some(2));
+        assert!(!review.apply(Action::Goto(7)));
+        assert_eq!(review.current().map(|f| f.index), Some(2));
+        review.apply(Action::Quit);
+        assert!(review.current().is_none());
+        assert_eq!(review.pending().len(), 3);
+    }
+}
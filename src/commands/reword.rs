@@ -0,0 +1,199 @@
+use crate::core::{
+    editor::Editor,
+    error::{GitNavigatorError, Result},
+    git::GitRepo,
+    is_plain_mode, print_info, print_section_header, print_success, print_warning,
+    state::CommitEntry,
+    templates::colored_index,
+};
+use clap::Parser;
+use colored::*;
+use std::env;
+
+/// How many commits `reword` lists
+const RECENT_COMMITS: usize = 10;
+
+#[derive(Parser)]
+pub struct RewordArgs {
+    /// Commit index from the list shown by `reword` (1 is HEAD)
+    pub index: Option<usize>,
+
+    /// Reword the commit even if it is already on the upstream branch
+    #[arg(long)]
+    pub force_accept: bool,
+}
+
+pub fn execute_reword(args: RewordArgs) -> Result<()> {
+    // Check if we're in a git repository
+    let current_dir = env::current_dir()?;
+    let git_repo = GitRepo::open(&current_dir).map_err(|_| GitNavigatorError::NotInGitRepo)?;
+
+    match args.index {
+        Some(index) => reword_by_index(&git_repo, index, args.force_accept),
+        None => list_commits(&git_repo),
+    }
+}
+
+fn list_commits(git_repo: &GitRepo) -> Result<()> {
+    let commits = git_repo.recent_commits(RECENT_COMMITS)?;
+
+    if commits.is_empty() {
+        print_info("No commits yet, so there is nothing to reword.");
+        return Ok(());
+    }
+
+    print_section_header("Recent Commits");
+
+    for commit in &commits {
+        if is_plain_mode() {
+            println!(
+                "Index {}: {} {}",
+                commit.index, commit.short_oid, commit.summary
+            );
+        } else {
+            println!(
+                "{} {} {}",
+                colored_index(commit.index),
+                commit.short_oid.blue(),
+                commit.summary
+            );
+        }
+    }
+
+    // Add spacing after commit list
+    println!();
+
+    save_commits(git_repo, &commits);
+    Ok(())
+}
+
+fn reword_by_index(git_repo: &GitRepo, index: usize, force_accept: bool) -> Result<()> {
+    let commits = git_repo
+        .cache_store()?
+        .load_commits()
+        .map_err(|_| GitNavigatorError::reword_failed("run 'reword' first to list commits"))?;
+    let entry = commits
+        .iter()
+        .find(|commit| commit.index == index)
+        .ok_or_else(|| {
+            GitNavigatorError::reword_failed(format!("commit index {index} not found"))
+        })?;
+
+    let oid = git2::Oid::from_str(&entry.oid)?;
+    if !force_accept {
+        if let Some(upstream) = git_repo.upstream_containing(oid)? {
+            return Err(GitNavigatorError::RewordPublished {
+                short_oid: entry.short_oid.clone(),
+                upstream,
+            });
+        }
+    }
+
+    let commit = git_repo.get_repository().find_commit(oid)?;
+    let old_message = commit
+        .message()
+        .ok_or_else(|| GitNavigatorError::reword_failed("its message is not valid UTF-8"))?
+        .to_string();
+    let new_message = edit_message(git_repo, entry, &old_message)?;
+
+    if new_message.trim_end() == old_message.trim_end() {
+        print_info("Message unchanged, nothing to reword.");
+        return Ok(());
+    }
+
+    let unsigned = git_repo.reword_commit(oid, &new_message)?;
+    print_success(&format!(
+        "Reworded {}: {}",
+        entry.short_oid,
+        new_message.lines().next().unwrap_or_default()
+    ));
+    if unsigned > 0 {
+        print_warning(&format!(
+            "{unsigned} rewritten commit(s) were signed; their signatures were dropped."
+        ));
+    }
+
+    // The rewritten commits have new ids, so renumber them
+    save_commits(git_repo, &git_repo.recent_commits(RECENT_COMMITS)?);
+    Ok(())
+}
+
+/// Let the user edit `message` in their editor and return the cleaned result
+fn edit_message(git_repo: &GitRepo, entry: &CommitEntry, message: &str) -> Result<String> {
+    let file = git_repo
+        .get_repository()
+        .path()
+        .join("GIT-NAVIGATOR-REWORD_EDITMSG");
+    std::fs::write(
+        &file,
+        format!(
+            "{}\n\n# Enter the new message for {}. Lines starting with '#' are\n# ignored, and an empty message aborts the reword.\n",
+            message.trim_end(),
+            entry.short_oid
+        ),
+    )?;
+
+    let editor = Editor::new(git_repo.git_defaults().editor_command());
+    let edited = editor
+        .open(&git_repo.load_settings(), &file, None)
+        .and_then(|()| Ok(std::fs::read_to_string(&file)?));
+    let _ = std::fs::remove_file(&file);
+
+    let message = clean_message(&edited?);
+    if message.is_empty() {
+        return Err(GitNavigatorError::reword_failed(
+            "empty commit message, aborting",
+        ));
+    }
+    Ok(message)
+}
+
+/// Strip comments and surplus blank lines like git's default message cleanup
+pub(crate) fn clean_message(text: &str) -> String {
+    let mut lines: Vec<&str> = Vec::new();
+    for line in text.lines() {
+        if line.starts_with('#') {
+            continue;
+        }
+        let line = line.trim_end();
+        if line.is_empty() && lines.last().is_none_or(|last| last.is_empty()) {
+            continue;
+        }
+        lines.push(line);
+    }
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+
+    if lines.is_empty() {
+        String::new()
+    } else {
+        lines.join("\n") + "\n"
+    }
+}
+
+fn save_commits(git_repo: &GitRepo, commits: &[CommitEntry]) {
+    // Save to cache so `reword <index>` can resolve the numbers
+    if let Err(e) = git_repo
+        .cache_store()
+        .and_then(|store| store.save_commits(commits))
+    {
+        // Log cache errors but don't fail the command
+        log::warn!("Commit cache save failed: {e}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_message() {
+        assert_eq!(
+            clean_message("Fix parser  \n\n\n# comment\nBody line\n\n# trailer\n\n"),
+            "Fix parser\n\nBody line\n"
+        );
+        assert_eq!(clean_message("\n\nSubject\n"), "Subject\n");
+        assert_eq!(clean_message("# only comments\n\n"), "");
+    }
+}
@@ -0,0 +1,178 @@
+use crate::commands::status::execute_status;
+use crate::core::{
+    command_init::IndexCommandInit,
+    error::{GitNavigatorError, Result},
+    git_status::GitStatus,
+    is_interactive, print_error_with_structured_usage, print_success, print_warning,
+    state::FileEntry,
+};
+use clap::Parser;
+use colored::*;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+#[derive(Parser)]
+pub struct RmArgs {
+    /// File indices to remove (e.g., "1 3-5,8")
+    #[arg(allow_negative_numbers = true)]
+    pub indices: Vec<String>,
+
+    /// Only remove the files from the index, keeping them on disk
+    #[arg(long)]
+    pub cached: bool,
+
+    /// Remove directories and what is in them
+    #[arg(short = 'r')]
+    pub recursive: bool,
+
+    /// Delete the files without asking first
+    #[arg(long, short = 'y')]
+    pub yes: bool,
+}
+
+pub fn execute_rm(args: RmArgs) -> Result<()> {
+    let context = match IndexCommandInit::initialize_with_messages(
+        args.indices,
+        "Cannot load file cache",
+        "No files available to remove",
+    ) {
+        Ok(context) => context,
+        Err(GitNavigatorError::NoIndicesProvided) => {
+            print_error_with_structured_usage(
+                "No file indices provided",
+                "rm",
+                &["<index>...", "--cached <index>...", "-r <index>..."],
+                &[
+                    ("--cached", "Only remove the files from the index"),
+                    ("-r", "Remove directories and what is in them"),
+                    ("-y, --yes", "Delete the files without asking first"),
+                    ("-h, --help", "Show this help message"),
+                ],
+            );
+            return Err(GitNavigatorError::NoIndicesProvided);
+        }
+        Err(e) => return Err(e),
+    };
+
+    let selected_files = context.get_selected_files();
+    let untracked = selected_files
+        .iter()
+        .filter(|file| file.status == GitStatus::Untracked)
+        .count();
+    if untracked > 0 {
+        print_warning(&format!(
+            "Leaving {untracked} untracked file(s) alone; git only removes tracked files."
+        ));
+    }
+
+    let paths = removable_paths(&selected_files);
+    if paths.is_empty() {
+        return Err(GitNavigatorError::NoValidFilesSelected);
+    }
+
+    // --cached leaves the files on disk; otherwise git refuses to delete
+    // files with uncommitted changes, so ask here and force it instead
+    if !args.cached {
+        let workdir = context
+            .git_repo
+            .get_repository()
+            .workdir()
+            .unwrap_or(Path::new("."));
+        let on_disk: Vec<&PathBuf> = paths
+            .iter()
+            .filter(|path| workdir.join(path).exists())
+            .collect();
+        if !on_disk.is_empty() {
+            show_deleted_files(&on_disk);
+            if !args.yes && !confirm_delete()? {
+                return Err(GitNavigatorError::RemoveCanceled);
+            }
+        }
+    }
+
+    context
+        .git_repo
+        .remove_files(&paths, args.cached, args.recursive, !args.cached)?;
+    if args.cached {
+        print_success(&format!(
+            "Removed {} file(s) from git index, keeping them on disk.",
+            paths.len()
+        ));
+    } else {
+        print_success(&format!("Removed {} file(s).", paths.len()));
+    }
+
+    // Show updated status
+    println!("Updated status:");
+    execute_status()?;
+
+    Ok(())
+}
+
+/// The paths of the tracked files among `files`, once each even when gs
+/// listed a file as both staged and unstaged; git has nothing to remove for
+/// untracked ones
+fn removable_paths(files: &[&FileEntry]) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = Vec::new();
+    for file in files {
+        if file.status != GitStatus::Untracked && !paths.contains(&file.path) {
+            paths.push(file.path.clone());
+        }
+    }
+    paths
+}
+
+fn show_deleted_files(paths: &[&PathBuf]) {
+    print_warning(&format!(
+        "{} file(s) will be deleted from disk, with any uncommitted changes:",
+        paths.len()
+    ));
+    for path in paths {
+        println!("   {}", path.display());
+    }
+}
+
+/// Ask before deleting files from the worktree
+fn confirm_delete() -> Result<bool> {
+    if !is_interactive() {
+        return Err(GitNavigatorError::NonInteractive(
+            "pass --yes to delete them, or --cached to keep them on disk".to_string(),
+        ));
+    }
+
+    print!("\n{} ", "Delete them? [y/N]:".blue());
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+
+    Ok(matches!(input.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_removable_paths_skips_untracked_files() {
+        let entry = |index: usize, status: GitStatus, path: &str| FileEntry {
+            index,
+            status,
+            path: PathBuf::from(path),
+            staged: false,
+            old_path: None,
+        };
+        let files = [
+            entry(1, GitStatus::Modified, "src/main.rs"),
+            entry(2, GitStatus::Untracked, "notes.txt"),
+            entry(3, GitStatus::Deleted, "old.rs"),
+            entry(4, GitStatus::Modified, "src/main.rs"),
+        ];
+        let selected: Vec<&FileEntry> = files.iter().collect();
+
+        assert_eq!(
+            removable_paths(&selected),
+            vec![PathBuf::from("src/main.rs"), PathBuf::from("old.rs")]
+        );
+    }
+}
@@ -4,7 +4,7 @@ use clap::Parser;
 use semver::Version;
 use crate::core::error::GitNavigatorError;
 use crate::core::dirs::get_config_directory;
-use crate::core::{print_info, print_section_header, print_success};
+use crate::core::{is_interactive, print_info, print_section_header, print_success};
 use colored::*;
 
 #[derive(Parser)]
@@ -45,20 +45,18 @@ fn list_available_backups() -> Result<(), GitNavigatorError> {
     print_section_header("Available backups");
     
     let mut backups = Vec::new();
-    for entry in std::fs::read_dir(backup_dir)? {
-        if let Ok(entry) = entry {
-            let path = entry.path();
-            if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
-                if name.starts_with("git-navigator-v") {
-                    let version = name.strip_prefix("git-navigator-v").unwrap();
-                    let metadata = entry.metadata()?;
-                    backups.push(BackupInfo {
-                        version: version.to_string(),
-                        path,
-                        size: metadata.len(),
-                        created: metadata.modified()?,
-                    });
-                }
+    for entry in std::fs::read_dir(backup_dir)?.flatten() {
+        let path = entry.path();
+        if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
+            if name.starts_with("git-navigator-v") {
+                let version = name.strip_prefix("git-navigator-v").unwrap();
+                let metadata = entry.metadata()?;
+                backups.push(BackupInfo {
+                    version: version.to_string(),
+                    path,
+                    size: metadata.len(),
+                    created: metadata.modified()?,
+                });
             }
         }
     }
@@ -96,14 +94,12 @@ fn interactive_rollback() -> Result<(), GitNavigatorError> {
     }
     
     let mut backups = Vec::new();
-    for entry in std::fs::read_dir(backup_dir)? {
-        if let Ok(entry) = entry {
-            let path = entry.path();
-            if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
-                if name.starts_with("git-navigator-v") {
-                    let version = name.strip_prefix("git-navigator-v").unwrap();
-                    backups.push((version.to_string(), path));
-                }
+    for entry in std::fs::read_dir(backup_dir)?.flatten() {
+        let path = entry.path();
+        if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
+            if name.starts_with("git-navigator-v") {
+                let version = name.strip_prefix("git-navigator-v").unwrap();
+                backups.push((version.to_string(), path));
             }
         }
     }
@@ -117,6 +113,10 @@ fn interactive_rollback() -> Result<(), GitNavigatorError> {
             .cmp(&Version::parse(&a.0).unwrap_or_else(|_| Version::new(0, 0, 0)))
     });
     
+    if !is_interactive() {
+        return Err(GitNavigatorError::NonInteractive("pass --version to pick a backup (see --list)".to_string()));
+    }
+    
     print_section_header("Select version to restore");
     for (i, (version, _)) in backups.iter().enumerate() {
         println!("  {} {}", format!("[{}]", i + 1).bright_black(), format!("v{}", version).blue());
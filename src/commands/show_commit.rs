@@ -0,0 +1,27 @@
+use crate::commands::log::cached_commit;
+use crate::core::{
+    error::{GitNavigatorError, Result},
+    git::GitRepo,
+    print_paged,
+};
+use clap::Parser;
+use std::env;
+
+#[derive(Parser)]
+pub struct ShowCommitArgs {
+    /// Commit index from the last `gl` listing
+    pub index: usize,
+}
+
+pub fn execute_show_commit(args: ShowCommitArgs) -> Result<()> {
+    // Check if we're in a git repository
+    let current_dir = env::current_dir()?;
+    let git_repo = GitRepo::open(&current_dir).map_err(|_| GitNavigatorError::NotInGitRepo)?;
+
+    let commit = cached_commit(&git_repo, args.index)?;
+    let output = git_repo.show_commit(&commit.oid)?;
+
+    let pager = git_repo.git_defaults().pager_command();
+    print_paged(&output, pager.as_deref());
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use crate::commands::contributors::unix_now;
+use crate::commands::review::file_label;
+use crate::core::{
+    dates::parse_since,
+    error::{GitNavigatorError, Result},
+    git::GitRepo,
+    print_info, print_paged, print_section_header,
+};
+use chrono::{Local, TimeZone};
+use clap::Parser;
+use std::env;
+
+#[derive(Parser)]
+pub struct SinceArgs {
+    /// Start time: "today", "09:30", YYYY-MM-DD, "3 hours", "2 days ago", ...
+    pub when: String,
+
+    /// Show only this file's diff, by its number in the list
+    pub file: Option<usize>,
+}
+
+#[derive(Parser)]
+pub struct TodayArgs {
+    /// Show only this file's diff, by its number in the list
+    pub file: Option<usize>,
+}
+
+pub fn execute_today(args: TodayArgs) -> Result<()> {
+    execute_since(SinceArgs {
+        when: "today".to_string(),
+        file: args.file,
+    })
+}
+
+pub fn execute_since(args: SinceArgs) -> Result<()> {
+    // Check if we're in a git repository
+    let current_dir = env::current_dir()?;
+    let git_repo = GitRepo::open(&current_dir).map_err(|_| GitNavigatorError::NotInGitRepo)?;
+
+    let since = parse_since(&args.when, unix_now())?;
+    // Everything committed after `since` plus what isn't committed yet
+    let base = git_repo.last_commit_before(since)?;
+    let base_oid = base.as_ref().map(|commit| commit.oid.as_str());
+    let files = git_repo.files_changed_since(base_oid)?;
+    if files.is_empty() {
+        print_info(&format!("Nothing changed since {}.", format_time(since)));
+        return Ok(());
+    }
+
+    if let Some(index) = args.file {
+        let file = files
+            .iter()
+            .find(|file| file.index == index)
+            .ok_or_else(|| GitNavigatorError::index_out_of_range(index, files.len()))?;
+        let output = git_repo.diff_since_file(base_oid, file)?;
+        print_paged(&output, git_repo.git_defaults().pager_command().as_deref());
+        return Ok(());
+    }
+
+    let against = match &base {
+        Some(commit) => format!("{} {}", commit.short_oid, commit.summary),
+        None => "no earlier commit".to_string(),
+    };
+    print_section_header(&format!("Changed since {} ({against})", format_time(since)));
+    for file in &files {
+        println!("{}", file_label(file));
+    }
+    Ok(())
+}
+
+fn format_time(time: i64) -> String {
+    Local.timestamp_opt(time, 0).single().map_or_else(
+        || time.to_string(),
+        |time| time.format("%Y-%m-%d %H:%M").to_string(),
+    )
+}
@@ -0,0 +1,136 @@
+use crate::core::{
+    args_parser::ArgsParser,
+    command_init::load_cached_files,
+    error::{GitNavigatorError, Result},
+    git::GitRepo,
+    is_plain_mode, print_info, print_section_header, print_success, print_warning,
+    state::FileEntry,
+};
+use clap::{Parser, Subcommand};
+use colored::*;
+use std::env;
+use std::path::{Path, PathBuf};
+
+#[derive(Parser)]
+pub struct SparseArgs {
+    #[command(subcommand)]
+    pub action: Option<SparseAction>,
+}
+
+#[derive(Subcommand)]
+pub enum SparseAction {
+    /// Add directories to the cone and check their files out
+    Add {
+        /// File indices from gs (their directories are added) or directory
+        /// paths from the repository root; write a numeric directory as `2024/`
+        #[arg(required = true)]
+        targets: Vec<String>,
+    },
+}
+
+pub fn execute_sparse(args: SparseArgs) -> Result<()> {
+    // Check if we're in a git repository
+    let current_dir = env::current_dir()?;
+    let git_repo = GitRepo::open(&current_dir).map_err(|_| GitNavigatorError::NotInGitRepo)?;
+
+    match args.action {
+        None => show_sparse(&git_repo),
+        Some(SparseAction::Add { targets }) => add_to_sparse(&git_repo, &targets),
+    }
+}
+
+fn show_sparse(git_repo: &GitRepo) -> Result<()> {
+    let Some(sparse) = git_repo.sparse_checkout() else {
+        print_info("This worktree is not a sparse checkout.");
+        return Ok(());
+    };
+
+    if sparse.cone {
+        print_section_header("Sparse Checkout Cone");
+    } else {
+        print_section_header("Sparse Checkout Patterns");
+    }
+    if is_plain_mode() {
+        for entry in sparse.entries() {
+            println!("{entry}");
+        }
+    } else {
+        for entry in sparse.entries() {
+            println!("   {}", entry.blue());
+        }
+    }
+
+    // Add spacing after entry list
+    println!();
+
+    if sparse.cone {
+        print_info("Files at the root and directly inside parents of these directories are checked out too.");
+    } else {
+        print_info("Pattern mode: gs cannot tell which changed files are outside it.");
+    }
+    Ok(())
+}
+
+fn add_to_sparse(git_repo: &GitRepo, targets: &[String]) -> Result<()> {
+    if git_repo.sparse_checkout().is_none() {
+        return Err(GitNavigatorError::NotSparse);
+    }
+
+    let (indices, paths): (Vec<&String>, Vec<&String>) = targets
+        .iter()
+        .partition(|target| ArgsParser::is_selection(target));
+    let mut dirs: Vec<PathBuf> = paths.into_iter().map(PathBuf::from).collect();
+    if !indices.is_empty() {
+        let cached = load_cached_files(git_repo)
+            .map_err(|e| GitNavigatorError::custom_cache_error("Cannot load file cache", e))?;
+        let indices =
+            ArgsParser::parse_file_indices(indices.into_iter().cloned().collect(), &cached)?;
+        for index in indices {
+            // Files at the root are always checked out
+            match cached[index - 1].path.parent() {
+                Some(dir) if dir != Path::new("") => dirs.push(dir.to_path_buf()),
+                _ => {}
+            }
+        }
+    }
+    dirs.sort();
+    dirs.dedup();
+    if dirs.is_empty() {
+        print_info("Those files are at the root, which is always checked out.");
+        return Ok(());
+    }
+
+    git_repo.sparse_add(&dirs)?;
+    let names: Vec<String> = dirs.iter().map(|dir| dir.display().to_string()).collect();
+    print_success(&format!(
+        "Added {} to the sparse checkout",
+        names.join(", ")
+    ));
+    Ok(())
+}
+
+/// Warn that git keeps these files out of the index and worktree until
+/// their directories are added to the sparse checkout
+pub(crate) fn warn_outside_sparse(git_repo: &GitRepo, files: &[&FileEntry]) {
+    for warning in outside_sparse_warnings(git_repo, files) {
+        print_warning(&warning);
+    }
+}
+
+/// The warnings [`warn_outside_sparse`] prints, one per file outside
+pub(crate) fn outside_sparse_warnings(git_repo: &GitRepo, files: &[&FileEntry]) -> Vec<String> {
+    let Some(sparse) = git_repo.sparse_checkout() else {
+        return Vec::new();
+    };
+    files
+        .iter()
+        .filter(|file| !sparse.includes(&file.path))
+        .map(|file| {
+            format!(
+                "{} is outside the sparse checkout, so git leaves it alone. Run 'sparse add {}' first to include its directory",
+                file.path.display(),
+                file.index
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,259 @@
+use crate::core::{
+    error::{GitNavigatorError, Result},
+    git::GitRepo,
+    git_status::GitStatus,
+    index_parser::IndexParser,
+    is_interactive, is_plain_mode, print_info, print_section_header, print_success,
+    state::FileEntry,
+    templates::colored_index,
+};
+use clap::Parser;
+use colored::*;
+use std::collections::BTreeMap;
+use std::env;
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+#[derive(Parser)]
+pub struct SplitArgs {
+    /// Commit to create, as "INDICES:MESSAGE"; repeat for each commit, in order
+    #[arg(long = "commit", short = 'c', value_name = "INDICES:MESSAGE")]
+    pub commits: Vec<String>,
+}
+
+/// A commit to create from some of the staged files
+#[derive(Debug, Clone, PartialEq)]
+struct Bucket {
+    message: String,
+    /// Number of staged files assigned to the commit
+    files: usize,
+    /// Their paths, with both ends of a rename
+    paths: Vec<PathBuf>,
+}
+
+pub fn execute_split(args: SplitArgs) -> Result<()> {
+    // Check if we're in a git repository
+    let current_dir = env::current_dir()?;
+    let git_repo = GitRepo::open(&current_dir).map_err(|_| GitNavigatorError::NotInGitRepo)?;
+
+    let files = git_repo.get_status()?;
+    if files.iter().any(|file| file.status == GitStatus::Unmerged) {
+        return Err(GitNavigatorError::split_failed(
+            "resolve the merge conflicts first",
+        ));
+    }
+    let staged: Vec<&FileEntry> = files.iter().filter(|file| file.staged).collect();
+    if staged.is_empty() {
+        return Err(GitNavigatorError::split_failed("nothing is staged"));
+    }
+
+    let buckets = if !args.commits.is_empty() {
+        parse_plan(&args.commits, &staged)?
+    } else if is_interactive() {
+        prompt_buckets(&staged)?
+    } else {
+        return Err(GitNavigatorError::split_failed(
+            "no terminal to ask, pass --commit \"INDICES:MESSAGE\" for each commit",
+        ));
+    };
+
+    for bucket in &buckets {
+        git_repo.commit_staged_paths(&bucket.paths, &bucket.message)?;
+        print_success(&format!(
+            "Committed {} file(s): {}",
+            bucket.files, bucket.message
+        ));
+    }
+
+    let committed: usize = buckets.iter().map(|bucket| bucket.files).sum();
+    if committed < staged.len() {
+        print_info(&format!(
+            "{} staged file(s) were not assigned and are still staged.",
+            staged.len() - committed
+        ));
+    }
+
+    Ok(())
+}
+
+/// Build the commits from `--commit "INDICES:MESSAGE"` arguments
+fn parse_plan(specs: &[String], staged: &[&FileEntry]) -> Result<Vec<Bucket>> {
+    let mut assigned = Vec::new();
+    let mut buckets = Vec::new();
+
+    for spec in specs {
+        let Some((indices, message)) = spec.split_once(':') else {
+            return Err(GitNavigatorError::split_failed(format!(
+                "'{spec}' should look like \"1 3-5:Commit message\""
+            )));
+        };
+        let message = message.trim();
+        if message.is_empty() {
+            return Err(GitNavigatorError::split_failed(format!(
+                "'{spec}' needs a commit message after ':'"
+            )));
+        }
+
+        let indices = IndexParser::parse(indices)?;
+        if indices.is_empty() {
+            return Err(GitNavigatorError::split_failed(format!(
+                "'{spec}' names no files"
+            )));
+        }
+
+        let files = indices.len();
+        let mut paths = Vec::new();
+        for index in indices {
+            let file = staged
+                .iter()
+                .find(|file| file.index == index)
+                .ok_or_else(|| {
+                    GitNavigatorError::split_failed(format!("[{index}] is not a staged file"))
+                })?;
+            if assigned.contains(&index) {
+                return Err(GitNavigatorError::split_failed(format!(
+                    "[{index}] is assigned to more than one commit"
+                )));
+            }
+            assigned.push(index);
+            paths.extend(file.affected_paths());
+        }
+
+        buckets.push(Bucket {
+            message: message.to_string(),
+            files,
+            paths,
+        });
+    }
+
+    Ok(buckets)
+}
+
+/// Ask which commit each staged file goes to, then for the commit messages
+fn prompt_buckets(staged: &[&FileEntry]) -> Result<Vec<Bucket>> {
+    print_section_header("Split Staged Changes");
+    println!("Assign each file to a commit number (Enter for 1, 0 to leave it staged).");
+
+    let mut groups: BTreeMap<usize, Vec<&FileEntry>> = BTreeMap::new();
+    for file in staged {
+        let label = file_label(file);
+        let number = loop {
+            let answer = read_answer(&format!("{label} >"))?;
+            if answer.is_empty() {
+                break 1;
+            }
+            match answer.parse::<usize>() {
+                Ok(number) => break number,
+                Err(_) => println!("{}", "Enter a commit number".bright_black()),
+            }
+        };
+        if number > 0 {
+            groups.entry(number).or_default().push(file);
+        }
+    }
+
+    if groups.is_empty() {
+        return Err(GitNavigatorError::SplitCanceled);
+    }
+
+    let mut buckets = Vec::new();
+    for (position, files) in groups.into_values().enumerate() {
+        let message = read_answer(&format!(
+            "Message for commit {} ({} file(s)):",
+            position + 1,
+            files.len()
+        ))?;
+        if message.is_empty() {
+            return Err(GitNavigatorError::SplitCanceled);
+        }
+        buckets.push(Bucket {
+            message,
+            files: files.len(),
+            paths: files
+                .iter()
+                .flat_map(|file| file.affected_paths())
+                .collect(),
+        });
+    }
+
+    let confirm = read_answer(&format!("Create {} commit(s)? [y/N]:", buckets.len()))?;
+    if !matches!(confirm.to_lowercase().as_str(), "y" | "yes") {
+        return Err(GitNavigatorError::SplitCanceled);
+    }
+
+    Ok(buckets)
+}
+
+fn file_label(file: &FileEntry) -> String {
+    if is_plain_mode() {
+        format!("Index {}: {}", file.index, file.path.display())
+    } else {
+        format!("   {} {}", colored_index(file.index), file.path.display())
+    }
+}
+
+fn read_answer(prompt: &str) -> Result<String> {
+    print!("{} ", prompt.blue());
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(input.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn staged(index: usize, path: &str) -> FileEntry {
+        FileEntry {
+            index,
+            status: GitStatus::Modified,
+            path: PathBuf::from(path),
+            staged: true,
+            old_path: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_plan() -> Result<()> {
+        let files = [staged(1, "a.rs"), staged(2, "b.rs"), staged(3, "c.rs")];
+        let staged: Vec<&FileEntry> = files.iter().collect();
+
+        let buckets = parse_plan(
+            &["1,3: Add parser".to_string(), "2:Fix docs".to_string()],
+            &staged,
+        )?;
+        assert_eq!(
+            buckets,
+            vec![
+                Bucket {
+                    message: "Add parser".to_string(),
+                    files: 2,
+                    paths: vec![PathBuf::from("a.rs"), PathBuf::from("c.rs")],
+                },
+                Bucket {
+                    message: "Fix docs".to_string(),
+                    files: 1,
+                    paths: vec![PathBuf::from("b.rs")],
+                },
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_plan_rejects_bad_specs() {
+        let files = [staged(1, "a.rs"), staged(2, "b.rs")];
+        let staged: Vec<&FileEntry> = files.iter().collect();
+        let parse = |specs: &[&str]| {
+            let specs: Vec<String> = specs.iter().map(|s| s.to_string()).collect();
+            parse_plan(&specs, &staged).unwrap_err().to_string()
+        };
+
+        assert!(parse(&["1 Add parser"]).contains("should look like"));
+        assert!(parse(&["1:"]).contains("needs a commit message"));
+        assert!(parse(&["5:Nope"]).contains("[5] is not a staged file"));
+        assert!(parse(&["1:One", "1-2:Two"]).contains("more than one commit"));
+    }
+}
@@ -0,0 +1,191 @@
+use crate::core::{
+    config::Settings,
+    error::{GitNavigatorError, Result},
+    format_size,
+    git::{BigObject, GitRepo},
+    invocation::configured_aliases,
+    is_plain_mode, print_info, print_section_header,
+    templates::colored_index,
+    usage::{self, UsageStats},
+};
+use clap::Parser;
+use colored::*;
+use std::env;
+
+/// How many blobs `--big-objects` lists by default
+pub const DEFAULT_BIG_OBJECTS: usize = 10;
+
+#[derive(Parser)]
+pub struct StatsArgs {
+    /// List the COUNT largest blobs with the path and commit that introduced them (default 10)
+    #[arg(long, value_name = "COUNT", num_args = 0..=1)]
+    pub big_objects: Option<Option<usize>>,
+
+    /// Show how often you ran each command and how long it took (see [usage] in the settings)
+    #[arg(long = "self", conflicts_with = "big_objects")]
+    pub self_usage: bool,
+}
+
+pub fn execute_stats(args: StatsArgs) -> Result<()> {
+    // Your own usage is not about any repository
+    if args.self_usage {
+        return show_self_usage(&Settings::discover());
+    }
+
+    // Check if we're in a git repository
+    let current_dir = env::current_dir()?;
+    let git_repo = GitRepo::open(&current_dir).map_err(|_| GitNavigatorError::NotInGitRepo)?;
+
+    match args.big_objects {
+        Some(count) => show_big_objects(&git_repo, count.unwrap_or(DEFAULT_BIG_OBJECTS)),
+        None => show_totals(&git_repo),
+    }
+}
+
+fn show_totals(git_repo: &GitRepo) -> Result<()> {
+    let totals = git_repo.object_totals()?;
+
+    print_section_header("Repository Objects");
+    println!("Objects: {}", totals.objects);
+    println!(
+        "Blobs:   {} ({})",
+        totals.blobs,
+        format_size(totals.blob_bytes)
+    );
+    println!();
+
+    print_info("Run 'stats --big-objects' to see the largest files in history.");
+    Ok(())
+}
+
+/// The counts `usage.collect` keeps, most used command first, and the
+/// aliases never typed
+fn show_self_usage(settings: &Settings) -> Result<()> {
+    let path = usage::usage_file()?;
+    let enabled = usage::is_enabled(settings);
+    let Some(stats) = UsageStats::load(&path)? else {
+        if enabled {
+            print_info("No runs recorded yet.");
+        } else {
+            print_info(&format!(
+                "Usage statistics are off. Add collect = true under [usage] in the settings to count your runs in {}; nothing leaves this machine.",
+                path.display()
+            ));
+        }
+        return Ok(());
+    };
+
+    print_section_header(&format!(
+        "Your Usage (since {})",
+        stats.since.with_timezone(&chrono::Local).format("%Y-%m-%d")
+    ));
+    let mut commands: Vec<_> = stats.commands.iter().collect();
+    commands.sort_by(|a, b| b.1.runs.cmp(&a.1.runs).then(a.0.cmp(b.0)));
+    let name_width = commands
+        .iter()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or(0);
+    let runs_width = commands
+        .iter()
+        .map(|(_, usage)| usage.runs.to_string().len())
+        .max()
+        .unwrap_or(0);
+    for (name, usage) in &commands {
+        let mut typed: Vec<_> = usage.typed.iter().collect();
+        typed.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+        let typed = typed
+            .iter()
+            .map(|(as_typed, runs)| format!("{as_typed} {runs}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        println!(
+            "{}  {:>runs_width$} run(s)  avg {} ms  {}",
+            format!("{name:<name_width$}").blue(),
+            usage.runs,
+            usage.average_ms(),
+            format!("({typed})").bright_black()
+        );
+    }
+    println!();
+
+    let never_typed: Vec<String> = configured_aliases(settings)
+        .into_iter()
+        .map(|(alias, _)| alias)
+        .filter(|alias| {
+            !stats
+                .commands
+                .values()
+                .any(|usage| usage.typed.contains_key(alias))
+        })
+        .collect();
+    if !never_typed.is_empty() {
+        print_info(&format!(
+            "Aliases you never typed: {}",
+            never_typed.join(", ")
+        ));
+    }
+    if !enabled {
+        print_info("Collecting is off now; these counts are from before. Set collect = true under [usage] to resume.");
+    }
+    Ok(())
+}
+
+fn show_big_objects(git_repo: &GitRepo, count: usize) -> Result<()> {
+    let blobs = git_repo.largest_blobs(count)?;
+    if blobs.is_empty() {
+        print_info("The repository has no files yet.");
+        return Ok(());
+    }
+
+    print_section_header("Largest Objects");
+    let size_width = blobs
+        .iter()
+        .map(|blob| format_size(blob.size).len())
+        .max()
+        .unwrap_or(0);
+    for (i, blob) in blobs.iter().enumerate() {
+        print_big_object(git_repo, i + 1, blob, size_width);
+    }
+
+    // Add spacing after object list
+    println!();
+
+    print_info(
+        "To purge a file from history use 'git filter-repo --invert-paths --path <path>', \
+         or move it to Git LFS with 'git lfs migrate import --include=<path>'.",
+    );
+    Ok(())
+}
+
+fn print_big_object(git_repo: &GitRepo, index: usize, blob: &BigObject, size_width: usize) {
+    let size = format_size(blob.size);
+    let path = blob.path.as_ref().map_or_else(
+        || format!("(unreferenced blob {})", git_repo.short_oid(blob.oid)),
+        |path| path.display().to_string(),
+    );
+    let commit = blob
+        .introduced_by
+        .as_ref()
+        .map(|(short_oid, summary)| (short_oid.as_str(), summary.as_str()));
+
+    if is_plain_mode() {
+        let introduced = commit
+            .map(|(short_oid, summary)| format!(" (added in {short_oid} {summary})"))
+            .unwrap_or_default();
+        println!("Index {index}: {size} {path}{introduced}");
+    } else {
+        let introduced = commit
+            .map(|(short_oid, summary)| {
+                format!("  {} {}", short_oid.blue(), summary.bright_black())
+            })
+            .unwrap_or_default();
+        println!(
+            "{} {:>size_width$}  {}{}",
+            colored_index(index),
+            size.yellow(),
+            path,
+            introduced
+        );
+    }
+}
@@ -0,0 +1,428 @@
+use crate::commands::branches::refresh_branch_cache;
+use crate::commands::checkout::switch_branch;
+use crate::commands::contributors::unix_now;
+use crate::commands::log::cached_commit;
+use crate::core::{
+    cache::CacheStore,
+    config::Settings,
+    error::{GitNavigatorError, Result},
+    format_age,
+    git::GitRepo,
+    is_interactive, is_plain_mode, print_info, print_section_header, print_success, print_warning,
+    state::TagEntry,
+    templates::colored_index,
+};
+use clap::{Parser, Subcommand, ValueEnum};
+use colored::*;
+use std::env;
+use std::io::{self, Write};
+use std::time::Duration;
+
+#[derive(Parser)]
+pub struct TagsArgs {
+    #[command(subcommand)]
+    pub action: Option<TagAction>,
+
+    /// Order of the numbered list (default: tags.sort, else date)
+    #[arg(long, value_enum, global = true)]
+    pub sort: Option<TagSort>,
+}
+
+/// How `tags` orders the tags it numbers
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum TagSort {
+    /// Newest tagged commit first
+    #[default]
+    Date,
+    /// Highest version first (v1.10.0 before v1.9.2); other names follow by date
+    Version,
+}
+
+impl TagSort {
+    /// Read `tags.sort` (`date` or `version`)
+    pub fn from_settings(settings: &Settings) -> Self {
+        match settings.get_str("tags.sort") {
+            Some(value) => Self::from_str(value, true).unwrap_or_else(|_| {
+                log::warn!("Ignoring unknown tags.sort value '{value}'");
+                Self::default()
+            }),
+            None => Self::default(),
+        }
+    }
+}
+
+#[derive(Subcommand)]
+pub enum TagAction {
+    /// Create a tag; with -m it is an annotated tag
+    Create {
+        /// Tag name, e.g. v1.2.0
+        name: String,
+
+        /// Annotation message (makes an annotated tag)
+        #[arg(long, short = 'm')]
+        message: Option<String>,
+
+        /// Commit index from the last `gl` listing (default: HEAD)
+        #[arg(long, value_name = "COMMIT_INDEX")]
+        at: Option<usize>,
+    },
+    /// Show a tag's annotation and the commit it points at
+    Show {
+        /// Tag index from the last `tags` listing
+        index: usize,
+    },
+    /// Check out a tag by index, detaching HEAD at its commit
+    Checkout {
+        /// Tag index from the last `tags` listing
+        index: usize,
+    },
+    /// Delete a tag by index from the last `tags` listing
+    Delete {
+        /// Tag index to delete
+        index: usize,
+
+        /// Delete without asking, even if a remote has the tag
+        #[arg(long, short = 'y')]
+        yes: bool,
+    },
+}
+
+pub fn execute_tags(args: TagsArgs) -> Result<()> {
+    // Check if we're in a git repository
+    let current_dir = env::current_dir()?;
+    let git_repo = GitRepo::open(&current_dir).map_err(|_| GitNavigatorError::NotInGitRepo)?;
+
+    let sort = args
+        .sort
+        .unwrap_or_else(|| TagSort::from_settings(&git_repo.load_settings()));
+
+    match args.action {
+        None => list_tags(&git_repo, sort),
+        Some(TagAction::Create { name, message, at }) => {
+            create_tag(&git_repo, &name, message.as_deref(), at, sort)
+        }
+        Some(TagAction::Show { index }) => show_tag(&git_repo, index),
+        Some(TagAction::Checkout { index }) => checkout_tag(&git_repo, index),
+        Some(TagAction::Delete { index, yes }) => delete_tag(&git_repo, index, yes, sort),
+    }
+}
+
+fn list_tags(git_repo: &GitRepo, sort: TagSort) -> Result<()> {
+    let tags = numbered_tags(git_repo, sort)?;
+    if tags.is_empty() {
+        print_info("No tags yet. Create one with 'tags create <name>'.");
+        return Ok(());
+    }
+
+    print_section_header("Tags");
+
+    let now = unix_now();
+    let name_width = tags.iter().map(|tag| tag.name.len()).max().unwrap_or(0);
+    for tag in &tags {
+        print_tag(tag, name_width, now);
+    }
+
+    // Add spacing after tag list
+    println!();
+
+    save_tags(git_repo, &tags);
+    Ok(())
+}
+
+fn print_tag(tag: &TagEntry, name_width: usize, now: i64) {
+    let age = format!(
+        "{} ago",
+        format_age(Duration::from_secs((now - tag.time).max(0) as u64))
+    );
+    let kind = if tag.annotated {
+        "annotated"
+    } else {
+        "lightweight"
+    };
+
+    if is_plain_mode() {
+        println!(
+            "Index {}: {} {} ({kind}, {age})",
+            tag.index, tag.name, tag.short_oid
+        );
+    } else {
+        println!(
+            "{} {:<name_width$}  {}  {}",
+            colored_index(tag.index),
+            tag.name.yellow(),
+            tag.short_oid.blue(),
+            format!("{kind}, {age}").bright_black()
+        );
+    }
+}
+
+fn create_tag(
+    git_repo: &GitRepo,
+    name: &str,
+    message: Option<&str>,
+    at: Option<usize>,
+    sort: TagSort,
+) -> Result<()> {
+    let (oid, short_oid) = match at {
+        Some(index) => {
+            let commit = cached_commit(git_repo, index)?;
+            (git2::Oid::from_str(&commit.oid)?, commit.short_oid)
+        }
+        None => {
+            let head = git_repo.get_repository().head()?.peel_to_commit()?;
+            (head.id(), git_repo.short_oid(head.id()))
+        }
+    };
+
+    git_repo.create_tag(name, oid, message)?;
+    print_success(&format!(
+        "Created {} tag '{name}' at {short_oid}",
+        if message.is_some() {
+            "annotated"
+        } else {
+            "lightweight"
+        }
+    ));
+
+    // Renumber so the new tag can be used by index right away
+    save_tags(git_repo, &numbered_tags(git_repo, sort)?);
+    Ok(())
+}
+
+fn show_tag(git_repo: &GitRepo, index: usize) -> Result<()> {
+    let tag = cached_tag(git_repo, index)?;
+    let commit = git_repo
+        .get_repository()
+        .find_commit(git2::Oid::from_str(&tag.oid)?)?;
+    let subject = commit.summary().unwrap_or("").to_string();
+
+    print_section_header(&format!("Tag {}", tag.name));
+    if is_plain_mode() {
+        println!("Commit: {} {subject}", tag.short_oid);
+    } else {
+        println!(
+            "{} {} {subject}",
+            "Commit:".bright_black(),
+            tag.short_oid.blue()
+        );
+    }
+
+    let Some(annotation) = git_repo.tag_annotation(&tag.name)? else {
+        println!();
+        print_info("Lightweight tag: there is no annotation to show.");
+        return Ok(());
+    };
+    if let Some(tagger) = &annotation.tagger {
+        let age = annotation
+            .time
+            .map(|time| {
+                format!(
+                    ", {} ago",
+                    format_age(Duration::from_secs((unix_now() - time).max(0) as u64))
+                )
+            })
+            .unwrap_or_default();
+        if is_plain_mode() {
+            println!("Tagger: {tagger}{age}");
+        } else {
+            println!(
+                "{} {tagger}{}",
+                "Tagger:".bright_black(),
+                age.bright_black()
+            );
+        }
+    }
+    println!();
+    for line in annotation.message.lines() {
+        if line.is_empty() {
+            println!();
+        } else {
+            println!("    {line}");
+        }
+    }
+    println!();
+    Ok(())
+}
+
+/// Detach HEAD at a tag, with the same safety checks as switching branches
+fn checkout_tag(git_repo: &GitRepo, index: usize) -> Result<()> {
+    let tag = cached_tag(git_repo, index)?;
+    switch_branch(git_repo, &format!("refs/tags/{}", tag.name), false)?;
+    refresh_branch_cache(git_repo);
+    println!(
+        "HEAD is now at {} (tag '{}'), not on any branch",
+        tag.short_oid, tag.name
+    );
+    print_info("Commits made here belong to no branch; 'gco -b <name>' starts one.");
+    Ok(())
+}
+
+fn delete_tag(git_repo: &GitRepo, index: usize, yes: bool, sort: TagSort) -> Result<()> {
+    let tag = cached_tag(git_repo, index)?;
+
+    let mut published = Vec::new();
+    if !yes {
+        let (found, unknown) = git_repo.remotes_with_tag(&tag.name)?;
+        if !found.is_empty() {
+            print_warning(&format!(
+                "Tag '{}' is on {}; deleting it here leaves those copies in place.",
+                tag.name,
+                found.join(", ")
+            ));
+        }
+        if !unknown.is_empty() {
+            print_warning(&format!(
+                "Could not check whether {} has tag '{}'.",
+                unknown.join(", "),
+                tag.name
+            ));
+        }
+        if (!found.is_empty() || !unknown.is_empty()) && !confirm_delete()? {
+            return Err(GitNavigatorError::TagDeleteCanceled);
+        }
+        published = found;
+    }
+
+    git_repo.delete_tag(&tag.name)?;
+    print_success(&format!(
+        "Deleted tag '{}' (was {})",
+        tag.name, tag.short_oid
+    ));
+    for remote in &published {
+        print_info(&format!(
+            "To delete it on {remote} too: git push {remote} :refs/tags/{}",
+            tag.name
+        ));
+    }
+
+    save_tags(git_repo, &numbered_tags(git_repo, sort)?);
+    Ok(())
+}
+
+/// Ask before deleting a tag that may have been pushed
+///
+/// Without an interactive terminal there is nobody to ask, so an error
+/// pointing at `--yes` is returned.
+fn confirm_delete() -> Result<bool> {
+    if !is_interactive() {
+        return Err(GitNavigatorError::TagDeleteFailed(
+            "it may have been pushed; pass --yes to confirm".to_string(),
+        ));
+    }
+
+    print!("\n{} ", "Delete the local tag anyway? [y/N]:".blue());
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+
+    Ok(matches!(input.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+/// Look up a tag by its index in the cache written by the last `tags`
+pub(crate) fn cached_tag(git_repo: &GitRepo, index: usize) -> Result<TagEntry> {
+    let tags = git_repo
+        .cache_store()?
+        .load_or_rebuild(CacheStore::load_tags, CacheStore::save_tags, || {
+            numbered_tags(git_repo, TagSort::from_settings(&git_repo.load_settings()))
+        })
+        .map_err(|_| GitNavigatorError::TagIndexNotFound(index))?;
+    tags.into_iter()
+        .find(|tag| tag.index == index)
+        .ok_or(GitNavigatorError::TagIndexNotFound(index))
+}
+
+/// Tags numbered in `sort` order
+pub(crate) fn numbered_tags(git_repo: &GitRepo, sort: TagSort) -> Result<Vec<TagEntry>> {
+    let mut tags = git_repo.list_tags()?;
+    if sort == TagSort::Version {
+        sort_by_version(&mut tags);
+    }
+    Ok(tags)
+}
+
+/// Highest version first, renumbered; names that are no version keep their
+/// date order after them
+fn sort_by_version(tags: &mut [TagEntry]) {
+    tags.sort_by_cached_key(|tag| {
+        let version = parse_version(&tag.name);
+        (version.is_none(), std::cmp::Reverse(version))
+    });
+    for (i, tag) in tags.iter_mut().enumerate() {
+        tag.index = i + 1;
+    }
+}
+
+/// The version a tag name stands for: `v1.2.3`, `1.2.3-rc.1`, or `v1.2`
+/// read as 1.2.0
+fn parse_version(name: &str) -> Option<semver::Version> {
+    let version = name.strip_prefix(['v', 'V']).unwrap_or(name);
+    semver::Version::parse(version).ok().or_else(|| {
+        let missing = 3usize.checked_sub(version.split('.').count())?;
+        semver::Version::parse(&format!("{version}{}", ".0".repeat(missing))).ok()
+    })
+}
+
+fn save_tags(git_repo: &GitRepo, tags: &[TagEntry]) {
+    // Save to cache so `tags delete <index>` can resolve the numbers
+    if let Err(e) = git_repo
+        .cache_store()
+        .and_then(|store| store.save_tags(tags))
+    {
+        // Log cache errors but don't fail the command
+        log::warn!("Tag cache save failed: {e}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tag(name: &str, time: i64) -> TagEntry {
+        TagEntry {
+            index: 0,
+            name: name.to_string(),
+            oid: String::new(),
+            short_oid: String::new(),
+            annotated: false,
+            time,
+        }
+    }
+
+    #[test]
+    fn test_sort_by_version() {
+        // Newest first, as list_tags returns them
+        let mut tags = vec![
+            tag("nightly", 50),
+            tag("v1.9.2", 40),
+            tag("v1.10.0-rc.1", 30),
+            tag("stable", 20),
+            tag("v1.10.0", 10),
+            tag("v2", 5),
+        ];
+        sort_by_version(&mut tags);
+        let numbered: Vec<(usize, &str)> = tags
+            .iter()
+            .map(|tag| (tag.index, tag.name.as_str()))
+            .collect();
+        assert_eq!(
+            numbered,
+            vec![
+                (1, "v2"),
+                (2, "v1.10.0"),
+                (3, "v1.10.0-rc.1"),
+                (4, "v1.9.2"),
+                (5, "nightly"),
+                (6, "stable"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(parse_version("v1.2"), Some(semver::Version::new(1, 2, 0)));
+        assert_eq!(parse_version("1.2.3"), Some(semver::Version::new(1, 2, 3)));
+        assert_eq!(parse_version("release-5"), None);
+        assert_eq!(parse_version("v1.2.3.4"), None);
+    }
+}
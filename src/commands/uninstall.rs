@@ -0,0 +1,256 @@
+use crate::core::config::InstallConfig;
+use crate::core::dirs::{
+    get_cache_directory, get_config_directory, get_install_directory, get_shell_rc_files,
+};
+use crate::core::error::{GitNavigatorError, Result};
+use crate::core::{format_size, is_interactive, print_info, print_section_header, print_success};
+use clap::Parser;
+use colored::*;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+#[derive(Parser)]
+pub struct UninstallArgs {
+    /// Remove every component without asking
+    #[arg(long, short = 'y')]
+    pub yes: bool,
+
+    /// Only show what would be removed
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+/// Something uninstall can remove, confirmed on its own
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Component {
+    /// "Binary", "Backups", ...
+    name: &'static str,
+    /// Files and directories removed together
+    paths: Vec<PathBuf>,
+}
+
+impl Component {
+    fn size(&self) -> u64 {
+        self.paths.iter().map(|path| disk_usage(path)).sum()
+    }
+}
+
+pub fn execute_uninstall(args: UninstallArgs) -> Result<()> {
+    let config_dir = get_config_directory()?;
+    let components = find_components(&config_dir, &get_cache_directory()?);
+
+    if components.is_empty() {
+        print_info("Nothing of git-navigator is left to remove.");
+    } else if args.dry_run {
+        print_section_header("Would remove");
+        for component in &components {
+            print_component(component);
+        }
+    } else {
+        if !args.yes && !is_interactive() {
+            return Err(GitNavigatorError::NonInteractive(
+                "pass --yes to remove everything, or --dry-run to look first".to_string(),
+            ));
+        }
+
+        print_section_header("Uninstall git-navigator");
+        let mut removed = 0;
+        for component in &components {
+            print_component(component);
+            if !args.yes
+                && !confirm(&format!(
+                    "Remove the {}? [y/N]:",
+                    component.name.to_lowercase()
+                ))?
+            {
+                continue;
+            }
+            for path in &component.paths {
+                remove(path)?;
+            }
+            removed += 1;
+        }
+        // The config directory goes once nothing is left in it
+        let _ = std::fs::remove_dir(&config_dir);
+
+        if removed == 0 {
+            return Err(GitNavigatorError::UninstallCanceled);
+        }
+        print_success(&format!(
+            "Removed {removed} of {} component(s).",
+            components.len()
+        ));
+    }
+
+    if let Some(binary) = foreign_binary() {
+        print_info(&format!(
+            "{} was not installed by install.sh or `update`; remove it with the tool that installed it (cargo uninstall git-navigator, a package manager, ...).",
+            binary.display()
+        ));
+    }
+    print_shell_lines();
+    Ok(())
+}
+
+/// Everything there is to remove, the binary last so a failure leaves the
+/// command in place to try again
+fn find_components(config_dir: &Path, cache_dir: &Path) -> Vec<Component> {
+    let backups = config_dir.join("backups");
+    let settings = std::fs::read_dir(config_dir)
+        .map(|entries| {
+            let mut paths: Vec<PathBuf> = entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| *path != backups)
+                .collect();
+            paths.sort();
+            paths
+        })
+        .unwrap_or_default();
+
+    let candidates = [
+        Component {
+            name: "Settings",
+            paths: settings,
+        },
+        Component {
+            name: "Backups",
+            paths: vec![backups],
+        },
+        Component {
+            name: "Caches",
+            paths: vec![cache_dir.to_path_buf()],
+        },
+        Component {
+            name: "Binary",
+            paths: installed_binary(config_dir).into_iter().collect(),
+        },
+    ];
+    candidates
+        .into_iter()
+        .filter(|component| !component.paths.is_empty())
+        .filter(|component| component.paths.iter().any(|path| path.exists()))
+        .collect()
+}
+
+/// The running binary, when install.sh or `update` put it there
+fn installed_binary(config_dir: &Path) -> Option<PathBuf> {
+    let exe = std::env::current_exe().ok()?;
+    let recorded = std::fs::read_to_string(config_dir.join("config.json"))
+        .ok()
+        .and_then(|content| serde_json::from_str::<InstallConfig>(&content).ok())
+        .map(|config| config.binary_path);
+    let self_installed = recorded.as_deref() == Some(exe.as_path())
+        || exe.parent() == Some(get_install_directory().as_path());
+    self_installed.then_some(exe)
+}
+
+/// The running binary, when something other than us installed it
+fn foreign_binary() -> Option<PathBuf> {
+    let exe = std::env::current_exe().ok()?;
+    (exe.exists() && installed_binary(&get_config_directory().ok()?).is_none()).then_some(exe)
+}
+
+fn print_component(component: &Component) {
+    println!(
+        "  {} ({})",
+        component.name.blue(),
+        format_size(component.size()).bright_black()
+    );
+    for path in &component.paths {
+        println!("    {}", path.display());
+    }
+}
+
+/// Lines in the shell startup files that still mention git-navigator
+fn print_shell_lines() {
+    let mut found = Vec::new();
+    for rc_file in get_shell_rc_files() {
+        let Ok(content) = std::fs::read_to_string(&rc_file) else {
+            continue;
+        };
+        for (number, line) in content.lines().enumerate() {
+            if line.contains("git-navigator") {
+                found.push(format!(
+                    "{}:{}: {}",
+                    rc_file.display(),
+                    number + 1,
+                    line.trim()
+                ));
+            }
+        }
+    }
+    if found.is_empty() {
+        return;
+    }
+
+    print_info("Delete these shell integration lines by hand:");
+    for line in found {
+        println!("  {line}");
+    }
+}
+
+fn remove(path: &Path) -> Result<()> {
+    let removed = if path.is_dir() {
+        std::fs::remove_dir_all(path)
+    } else {
+        std::fs::remove_file(path)
+    };
+    match removed {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => {
+            Err(GitNavigatorError::uninstall_failed(path, e))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Bytes under `path`, 0 when it is gone
+fn disk_usage(path: &Path) -> u64 {
+    let Ok(metadata) = std::fs::symlink_metadata(path) else {
+        return 0;
+    };
+    if !metadata.is_dir() {
+        return metadata.len();
+    }
+    std::fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| disk_usage(&entry.path()))
+                .sum()
+        })
+        .unwrap_or(0)
+}
+
+fn confirm(prompt: &str) -> Result<bool> {
+    print!("{} ", prompt.blue());
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(matches!(input.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_find_components() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let config_dir = temp_dir.path().join("config");
+        let cache_dir = temp_dir.path().join("cache");
+        std::fs::create_dir_all(config_dir.join("backups"))?;
+        std::fs::write(config_dir.join("config.toml"), "[ui]\n")?;
+        std::fs::write(config_dir.join("backups/git-navigator-v0.1.0"), "old")?;
+
+        let components = find_components(&config_dir, &cache_dir);
+        let names: Vec<&str> = components.iter().map(|c| c.name).collect();
+        // No cache yet, and the test binary was not self-installed
+        assert_eq!(names, ["Settings", "Backups"]);
+        assert_eq!(components[0].paths, [config_dir.join("config.toml")]);
+        assert_eq!(components[1].size(), 3);
+        Ok(())
+    }
+}
@@ -2,8 +2,9 @@ use std::io::{self, Write};
 use clap::Parser;
 use semver::Version;
 use crate::core::error::GitNavigatorError;
-use crate::core::config::InstallConfig;
-use crate::core::{print_info, print_section_header, print_success};
+use crate::core::config::{InstallConfig, Settings};
+use crate::core::network::NetworkCheck;
+use crate::core::{is_interactive, print_info, print_section_header, print_success};
 use colored::*;
 
 // Repository configuration constants
@@ -39,6 +40,7 @@ pub fn execute_update(args: UpdateArgs) -> Result<(), GitNavigatorError> {
     }
     
     print_info("Checking for updates...");
+    NetworkCheck::from_settings(&Settings::discover()).ensure_reachable("api.github.com", 443)?;
     
     // Load config to get repository settings, fallback to constants if config fails
     let config = InstallConfig::load_or_create().unwrap_or_else(|_| InstallConfig {
@@ -83,6 +85,9 @@ pub fn execute_update(args: UpdateArgs) -> Result<(), GitNavigatorError> {
         return Ok(());
     }
     
+    if !args.yes && !is_interactive() {
+        return Err(GitNavigatorError::NonInteractive("pass --yes to install the update".to_string()));
+    }
     if !args.yes && !confirm_update(current_version, &latest.version) {
         return Err(GitNavigatorError::UpdateCanceled);
     }
@@ -101,7 +106,7 @@ pub fn execute_update(args: UpdateArgs) -> Result<(), GitNavigatorError> {
     match status.updated() {
         true => {
             print_success(&format!("Successfully updated to v{}\n", status.version()));
-            update_config_after_update(&status.version())?;
+            update_config_after_update(status.version())?;
         },
         false => {
             print_success(&format!("Already up to date (v{current_version})\n"));
@@ -0,0 +1,260 @@
+use crate::commands::packages::changed_paths;
+use crate::core::{
+    config::Settings,
+    error::{GitNavigatorError, Result},
+    git::GitRepo,
+    is_plain_mode, print_section_header, print_success,
+    templates::glyphs,
+};
+use clap::Parser;
+use colored::*;
+use std::env;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+#[derive(Parser)]
+pub struct VerifyArgs {
+    /// Steps to run, by name (default: every step in verify.steps)
+    pub steps: Vec<String>,
+
+    /// Give steps that use {files} only the changed files instead of "."
+    #[arg(long)]
+    pub changed: bool,
+
+    /// Stop at the first failing step
+    #[arg(long)]
+    pub fail_fast: bool,
+}
+
+/// A named shell command from the `[verify]` settings
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Step {
+    name: String,
+    command: String,
+}
+
+impl Step {
+    /// Whether the command takes the files to check
+    fn uses_files(&self) -> bool {
+        self.command.contains("{files}")
+    }
+
+    /// Shell script for `sh -c`, with `{files}` standing for the arguments
+    fn script(&self) -> String {
+        self.command.replace("{files}", "\"$@\"")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Outcome {
+    Passed(Duration),
+    Failed(Duration, String),
+    Skipped(&'static str),
+}
+
+pub fn execute_verify(args: VerifyArgs) -> Result<()> {
+    // Check if we're in a git repository
+    let current_dir = env::current_dir()?;
+    let git_repo = GitRepo::open(&current_dir).map_err(|_| GitNavigatorError::NotInGitRepo)?;
+    let workdir = git_repo
+        .get_repository()
+        .workdir()
+        .ok_or_else(|| GitNavigatorError::custom_empty_files_error("No workdir found"))?
+        .to_path_buf();
+
+    let steps = select_steps(&configured_steps(&git_repo.load_settings())?, &args.steps)?;
+
+    let files: Vec<PathBuf> = if args.changed {
+        // Deleted files have nothing left to check
+        let (paths, _) = changed_paths(&git_repo, None)?;
+        paths
+            .into_iter()
+            .filter(|path| workdir.join(path).exists())
+            .collect()
+    } else {
+        vec![PathBuf::from(".")]
+    };
+
+    let mut outcomes = Vec::new();
+    let mut failed = false;
+    for (position, step) in steps.iter().enumerate() {
+        let outcome = if failed && args.fail_fast {
+            Outcome::Skipped("an earlier step failed")
+        } else if step.uses_files() && files.is_empty() {
+            Outcome::Skipped("no changed files")
+        } else {
+            print_section_header(&format!(
+                "Step {}/{}: {}",
+                position + 1,
+                steps.len(),
+                step.name
+            ));
+            run_step(step, &workdir, &files)?
+        };
+        failed |= matches!(outcome, Outcome::Failed(..));
+        outcomes.push(outcome);
+    }
+
+    print_summary(&steps, &outcomes);
+
+    let failures = outcomes
+        .iter()
+        .filter(|outcome| matches!(outcome, Outcome::Failed(..)))
+        .count();
+    if failures > 0 {
+        return Err(GitNavigatorError::VerifyFailed {
+            failed: failures,
+            total: steps.len(),
+        });
+    }
+    print_success(&format!("All {} verify step(s) passed", steps.len()));
+    Ok(())
+}
+
+/// Steps listed in `verify.steps`, each with its `verify.<name>` command
+fn configured_steps(settings: &Settings) -> Result<Vec<Step>> {
+    let names = settings
+        .get_list("verify.steps")
+        .filter(|names| !names.is_empty())
+        .ok_or(GitNavigatorError::VerifyNotConfigured)?;
+
+    names
+        .into_iter()
+        .map(|name| {
+            let command = settings
+                .get_str(&format!("verify.{name}"))
+                .ok_or_else(|| GitNavigatorError::UnknownVerifyStep(name.clone()))?;
+            Ok(Step {
+                command: command.to_string(),
+                name,
+            })
+        })
+        .collect()
+}
+
+/// The steps named on the command line, in configured order, or all of them
+fn select_steps(steps: &[Step], wanted: &[String]) -> Result<Vec<Step>> {
+    if let Some(unknown) = wanted
+        .iter()
+        .find(|name| !steps.iter().any(|step| &step.name == *name))
+    {
+        return Err(GitNavigatorError::UnknownVerifyStep(unknown.clone()));
+    }
+    Ok(steps
+        .iter()
+        .filter(|step| wanted.is_empty() || wanted.contains(&step.name))
+        .cloned()
+        .collect())
+}
+
+/// Run a step through the shell, streaming its output
+fn run_step(step: &Step, workdir: &Path, files: &[PathBuf]) -> Result<Outcome> {
+    println!("{}", format!("$ {}", step.command).bright_black());
+    io::stdout().flush()?;
+
+    let mut cmd = Command::new("sh");
+    cmd.arg("-c")
+        .arg(step.script())
+        .arg(&step.name)
+        .current_dir(workdir);
+    if step.uses_files() {
+        cmd.args(files);
+    }
+
+    let started = Instant::now();
+    let outcome = match cmd.status() {
+        Ok(status) if status.success() => Outcome::Passed(started.elapsed()),
+        Ok(status) => Outcome::Failed(started.elapsed(), status.to_string()),
+        Err(e) => Outcome::Failed(started.elapsed(), format!("cannot run: {e}")),
+    };
+    Ok(outcome)
+}
+
+fn print_summary(steps: &[Step], outcomes: &[Outcome]) {
+    print_section_header("Verify Summary");
+
+    for (step, outcome) in steps.iter().zip(outcomes) {
+        let (word, details) = match outcome {
+            Outcome::Passed(took) => ("passed", format!("{:.1}s", took.as_secs_f64())),
+            Outcome::Failed(took, reason) => {
+                ("failed", format!("{reason}, {:.1}s", took.as_secs_f64()))
+            }
+            Outcome::Skipped(reason) => ("skipped", reason.to_string()),
+        };
+
+        if is_plain_mode() {
+            println!("{}: {word} ({details})", step.name);
+            continue;
+        }
+        let mark = match outcome {
+            Outcome::Passed(_) => glyphs().success.green(),
+            Outcome::Failed(..) => glyphs().error.red(),
+            Outcome::Skipped(_) => "-".bright_black(),
+        };
+        println!(
+            "   {mark} {} {}",
+            step.name.white(),
+            format!("({word}, {details})").bright_black()
+        );
+    }
+
+    // Add spacing after summary list
+    println!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step(name: &str, command: &str) -> Step {
+        Step {
+            name: name.to_string(),
+            command: command.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_configured_steps() -> Result<()> {
+        let settings = Settings::parse(
+            "[verify]\nsteps = [\"fmt\", \"lint\"]\nfmt = \"cargo fmt --check\"\nlint = \"eslint {files}\"",
+        )?;
+        let steps = configured_steps(&settings)?;
+        assert_eq!(
+            steps,
+            vec![
+                step("fmt", "cargo fmt --check"),
+                step("lint", "eslint {files}")
+            ]
+        );
+        assert!(!steps[0].uses_files());
+        assert_eq!(steps[1].script(), "eslint \"$@\"");
+
+        assert!(matches!(
+            configured_steps(&Settings::parse("")?),
+            Err(GitNavigatorError::VerifyNotConfigured)
+        ));
+        assert!(matches!(
+            configured_steps(&Settings::parse("[verify]\nsteps = [\"test\"]")?),
+            Err(GitNavigatorError::UnknownVerifyStep(name)) if name == "test"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_steps() -> Result<()> {
+        let steps = vec![step("fmt", "a"), step("clippy", "b"), step("test", "c")];
+
+        assert_eq!(select_steps(&steps, &[])?, steps);
+        assert_eq!(
+            select_steps(&steps, &["test".to_string(), "fmt".to_string()])?,
+            vec![step("fmt", "a"), step("test", "c")]
+        );
+        assert!(matches!(
+            select_steps(&steps, &["bench".to_string()]),
+            Err(GitNavigatorError::UnknownVerifyStep(name)) if name == "bench"
+        ));
+        Ok(())
+    }
+}
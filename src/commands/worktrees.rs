@@ -0,0 +1,270 @@
+use crate::commands::branches::find_cached_branch;
+use crate::core::{
+    cache::CacheStore,
+    error::{GitNavigatorError, Result},
+    git::{GitRepo, Worktree},
+    is_plain_mode, print_info, print_section_header, print_success,
+    state::WorktreeEntry,
+    templates::{colored_index, colored_index_label, templates},
+};
+use clap::{Parser, Subcommand};
+use colored::*;
+use std::env;
+use std::path::{Path, PathBuf};
+
+#[derive(Parser)]
+pub struct WorktreesArgs {
+    /// Worktree index to print the path of (for the gwt shell function to cd into)
+    pub index: Option<usize>,
+
+    #[command(subcommand)]
+    pub action: Option<WorktreeAction>,
+}
+
+#[derive(Subcommand)]
+pub enum WorktreeAction {
+    /// Check out a branch from the last `gb` listing in a new worktree
+    Add {
+        /// Branch index from the last `gb` listing
+        branch: usize,
+
+        /// Where to put it (default: next to the main worktree, named
+        /// <repository>-<branch>)
+        path: Option<PathBuf>,
+    },
+    /// Remove a worktree by index from the last `worktrees` listing
+    Remove {
+        /// Worktree index to remove
+        index: usize,
+
+        /// Remove it even with uncommitted or untracked files
+        #[arg(long, short = 'f')]
+        force: bool,
+    },
+}
+
+pub fn execute_worktrees(args: WorktreesArgs) -> Result<()> {
+    // Check if we're in a git repository
+    let current_dir = env::current_dir()?;
+    let git_repo = GitRepo::open(&current_dir).map_err(|_| GitNavigatorError::NotInGitRepo)?;
+
+    match (args.action, args.index) {
+        (Some(WorktreeAction::Add { branch, path }), _) => {
+            add_worktree(&git_repo, branch, path.as_deref())
+        }
+        (Some(WorktreeAction::Remove { index, force }), _) => {
+            remove_worktree(&git_repo, index, force)
+        }
+        (None, Some(index)) => {
+            // Print the path for the shell wrapper to cd into
+            let worktree = cached_worktree(&git_repo, index)?;
+            if !worktree.path.is_dir() {
+                return Err(GitNavigatorError::custom_empty_files_error(format!(
+                    "Worktree '{}' no longer exists; 'git worktree prune' forgets it",
+                    worktree.path.display()
+                )));
+            }
+            println!("{}", worktree.path.display());
+            Ok(())
+        }
+        (None, None) => list_worktrees(&git_repo),
+    }
+}
+
+fn list_worktrees(git_repo: &GitRepo) -> Result<()> {
+    let worktrees = git_repo.worktrees()?;
+    let entries = number_worktrees(worktrees.clone());
+    let current = git_repo.get_repository().workdir().map(Path::to_path_buf);
+
+    print_section_header("Worktrees");
+    for (entry, worktree) in entries.iter().zip(&worktrees) {
+        let is_current = current.as_deref() == Some(entry.path.as_path());
+        let checked_out = match (&entry.branch, &worktree.head) {
+            (Some(branch), _) => branch.clone(),
+            (None, Some(head)) => match git2::Oid::from_str(head) {
+                Ok(oid) => format!("detached at {}", git_repo.short_oid(oid)),
+                Err(_) => "detached".to_string(),
+            },
+            (None, None) => "bare".to_string(),
+        };
+        let mut notes = Vec::new();
+        if entry.is_main {
+            notes.push("main worktree");
+        }
+        if worktree.prunable {
+            notes.push("missing");
+        }
+        let notes = if notes.is_empty() {
+            String::new()
+        } else {
+            format!(" ({})", notes.join(", "))
+        };
+
+        if is_plain_mode() {
+            let current_text = if is_current { " (current)" } else { "" };
+            println!(
+                "Index {}: {} [{checked_out}]{notes}{current_text}",
+                entry.index,
+                entry.path.display()
+            );
+            continue;
+        }
+
+        let index = if is_current {
+            colored_index_label(&format!("{}*", templates().index_number(entry.index)))
+        } else {
+            colored_index(entry.index)
+        };
+        println!(
+            "{} {} {}{}",
+            index,
+            entry.path.display().to_string().blue(),
+            checked_out.green(),
+            notes.bright_black()
+        );
+    }
+    println!();
+
+    save_worktrees(git_repo, &entries);
+    Ok(())
+}
+
+fn add_worktree(git_repo: &GitRepo, branch_index: usize, path: Option<&Path>) -> Result<()> {
+    let branch = find_cached_branch(git_repo, branch_index)?;
+    let path = match path {
+        Some(path) => path.to_path_buf(),
+        None => default_worktree_path(git_repo, &branch.name)?,
+    };
+
+    git_repo.add_worktree(&path, &branch.name)?;
+    print_success(&format!(
+        "Checked out '{}' in a new worktree at {}",
+        branch.name,
+        path.display()
+    ));
+
+    // Renumber so the new worktree can be used by index right away
+    let entries = numbered_worktrees(git_repo)?;
+    save_worktrees(git_repo, &entries);
+    if let Some(entry) = entries.iter().find(|entry| same_path(&entry.path, &path)) {
+        print_info(&format!("'gwt {}' takes you there.", entry.index));
+    }
+    Ok(())
+}
+
+fn remove_worktree(git_repo: &GitRepo, index: usize, force: bool) -> Result<()> {
+    let worktree = cached_worktree(git_repo, index)?;
+    if worktree.is_main {
+        return Err(GitNavigatorError::custom_empty_files_error(format!(
+            "'{}' is the main worktree and cannot be removed",
+            worktree.path.display()
+        )));
+    }
+
+    git_repo.remove_worktree(&worktree.path, force)?;
+    print_success(&format!("Removed worktree {}", worktree.path.display()));
+
+    let removed_current = git_repo
+        .get_repository()
+        .workdir()
+        .is_some_and(|workdir| same_path(workdir, &worktree.path) || !workdir.exists());
+    if removed_current {
+        // git cannot run in the directory that just went away; renumber from
+        // the main worktree, which the shell has to move to as well
+        let main = GitRepo::open(git_repo.get_repository().commondir())?;
+        save_worktrees(&main, &numbered_worktrees(&main)?);
+        if let Some(workdir) = main.get_repository().workdir() {
+            print_info(&format!(
+                "You were in it: 'cd {}' gets you back to the main worktree.",
+                workdir.display()
+            ));
+        }
+        return Ok(());
+    }
+
+    save_worktrees(git_repo, &numbered_worktrees(git_repo)?);
+    Ok(())
+}
+
+/// `<main worktree>-<branch>` next to the main worktree, with the slashes of
+/// the branch name turned into dashes
+fn default_worktree_path(git_repo: &GitRepo, branch: &str) -> Result<PathBuf> {
+    let main = git_repo
+        .worktrees()?
+        .into_iter()
+        .next()
+        .map(|worktree| worktree.path)
+        .ok_or_else(|| GitNavigatorError::custom_empty_files_error("No main worktree found"))?;
+    let name = main
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "worktree".to_string());
+    Ok(main.with_file_name(format!("{name}-{}", branch.replace('/', "-"))))
+}
+
+fn numbered_worktrees(git_repo: &GitRepo) -> Result<Vec<WorktreeEntry>> {
+    Ok(number_worktrees(git_repo.worktrees()?))
+}
+
+/// Number worktrees in the order git lists them, the main one first
+fn number_worktrees(worktrees: Vec<Worktree>) -> Vec<WorktreeEntry> {
+    worktrees
+        .into_iter()
+        .enumerate()
+        .map(|(i, worktree)| WorktreeEntry {
+            index: i + 1,
+            path: worktree.path,
+            branch: worktree.branch,
+            is_main: i == 0,
+        })
+        .collect()
+}
+
+/// Look up a worktree by its index in the cache written by the last `worktrees`
+fn cached_worktree(git_repo: &GitRepo, index: usize) -> Result<WorktreeEntry> {
+    let worktrees = git_repo
+        .cache_store()?
+        .load_or_rebuild(
+            CacheStore::load_worktrees,
+            CacheStore::save_worktrees,
+            || numbered_worktrees(git_repo),
+        )
+        .or_else(|e| match e {
+            // Numbered the way `worktrees` would number them
+            GitNavigatorError::CacheFileNotFound { .. } => numbered_worktrees(git_repo),
+            e => Err(e),
+        })
+        .map_err(|e| {
+            log::warn!("Failed to load worktree cache: {e}");
+            GitNavigatorError::custom_cache_error(
+                "Cannot load worktree cache. Run 'worktrees' first to list worktrees.",
+                e,
+            )
+        })?;
+
+    worktrees
+        .into_iter()
+        .find(|worktree| worktree.index == index)
+        .ok_or_else(|| {
+            GitNavigatorError::custom_empty_files_error(format!("Worktree index {index} not found"))
+        })
+}
+
+fn save_worktrees(git_repo: &GitRepo, worktrees: &[WorktreeEntry]) {
+    // Save to cache so `worktrees <index>` can resolve the numbers
+    if let Err(e) = git_repo
+        .cache_store()
+        .and_then(|store| store.save_worktrees(worktrees))
+    {
+        // Log cache errors but don't fail the command
+        log::warn!("Worktree cache save failed: {e}");
+    }
+}
+
+/// Whether two paths name the same directory, however they were spelled
+fn same_path(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
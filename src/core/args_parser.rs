@@ -7,6 +7,15 @@
 //! # Public API
 //! - [`ArgsParser`]: Main parser that validates indices against available files
 //!
+//! Given the listed files ([`ArgsParser::parse_file_indices`]), the name of a
+//! status section selects the files listed in it: `ga unstaged`, `gd staged`,
+//! `gco untracked`, also next to indices and as an exclusion (`ga all ^untracked`).
+//!
+//! [`ArgsParser::parse_selection`] also takes paths next to the indices
+//! (`ga 1 3 src/new_file.rs`): files, directories or globs, relative to the
+//! current directory. A path picks the listed files it names, or files that
+//! changed since `gs` listed them (see [`Selection`]).
+//!
 //! # Features
 //! - **Unified parsing**: Handles all argument formats in one call
 //! - **Validation**: Ensures indices are within valid file bounds
@@ -15,9 +24,60 @@
 
 use crate::core::{
     error::{GitNavigatorError, Result},
+    git::GitRepo,
     index_parser::IndexParser,
+    state::{pathspec_matches, FileEntry, StatusGroup},
 };
 
+/// Files picked on the command line: by index, or by a path
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Selection {
+    /// Indices into the list from the last `gs`, paths that name listed files
+    /// included
+    pub indices: Vec<usize>,
+    /// Paths that exist but name no listed file, relative to the top of the
+    /// worktree: files that changed since the list was made, for
+    /// [`Selection::unlisted_files`] to find in a fresh one
+    pub unlisted: Vec<String>,
+}
+
+impl Selection {
+    /// The files in `files` that the unlisted paths name
+    ///
+    /// Fails for a path that names none of them: there is nothing there for
+    /// a command to act on.
+    pub fn unlisted_files<'a>(&self, files: &'a [FileEntry]) -> Result<Vec<&'a FileEntry>> {
+        let mut found = Vec::new();
+        for pathspec in &self.unlisted {
+            let before = found.len();
+            found.extend(files.iter().filter(|file| names_file(pathspec, file)));
+            if found.len() == before {
+                return Err(GitNavigatorError::custom_empty_files_error(format!(
+                    "No changed files at '{pathspec}'"
+                )));
+            }
+        }
+        Ok(found)
+    }
+
+    /// [`Self::indices`] and the indices of [`Self::unlisted_files`] in
+    /// `files`, sorted and without duplicates
+    pub fn resolve(&self, files: &[FileEntry]) -> Result<Vec<usize>> {
+        let mut indices = self.indices.clone();
+        indices.extend(self.unlisted_files(files)?.iter().map(|file| file.index));
+        indices.sort();
+        indices.dedup();
+        Ok(indices)
+    }
+}
+
+/// Whether `pathspec` names `file`, where it is now or where it was renamed from
+fn names_file(pathspec: &str, file: &FileEntry) -> bool {
+    std::iter::once(&file.path)
+        .chain(&file.old_path)
+        .any(|path| pathspec_matches(pathspec, path))
+}
+
 /// Centralized argument parsing for commands that take file indices
 pub struct ArgsParser;
 
@@ -28,7 +88,7 @@ impl ArgsParser {
     /// Handles all the conversion and validation logic in one place.
     ///
     /// # Arguments
-    /// * `args` - Command line arguments from clap (e.g., ["1", "3-5", "8"])
+    /// * `args` - Command line arguments from clap (e.g., ["1", "3-5", "8"] or ["all"])
     /// * `file_count` - Total number of files available for validation
     ///
     /// # Returns
@@ -59,7 +119,7 @@ impl ArgsParser {
         let indices_str = args.join(" ");
 
         // Parse the indices string using the existing IndexParser
-        let indices = IndexParser::parse(&indices_str)
+        let indices = IndexParser::parse_with_max(&indices_str, file_count)
             .map_err(|e| GitNavigatorError::invalid_index_format(e.to_string()))?;
 
         // Check if parsing resulted in empty indices (could happen with empty strings)
@@ -74,6 +134,107 @@ impl ArgsParser {
         Ok(indices)
     }
 
+    /// Like [`Self::parse_indices`], with section names (`staged`, `unstaged`,
+    /// `untracked`, `unmerged`) standing for the indices `files` lists in them
+    pub fn parse_file_indices(args: Vec<String>, files: &[FileEntry]) -> Result<Vec<usize>> {
+        let args = args
+            .iter()
+            .map(|arg| Self::expand_groups(arg, files))
+            .collect::<Result<Vec<_>>>()?;
+        Self::parse_indices(args, files.len())
+    }
+
+    /// Like [`Self::parse_file_indices`], with paths next to the indices
+    ///
+    /// An argument that does not read as indices or section names is a path
+    /// relative to the current directory; write a numeric one as `./2024`.
+    /// It picks the listed files it names, or, when it names none of them but
+    /// exists, goes to [`Selection::unlisted`].
+    pub fn parse_selection(
+        args: Vec<String>,
+        files: &[FileEntry],
+        git_repo: &GitRepo,
+    ) -> Result<Selection> {
+        if args.is_empty() {
+            return Err(GitNavigatorError::NoIndicesProvided);
+        }
+
+        let (index_args, path_args): (Vec<String>, Vec<String>) =
+            args.into_iter().partition(|arg| Self::is_selection(arg));
+        let mut selection = Selection::default();
+        if !index_args.is_empty() {
+            selection.indices = Self::parse_file_indices(index_args, files)?;
+        }
+
+        let workdir = git_repo.get_repository().workdir();
+        let pathspecs = git_repo.repo_pathspecs(&path_args)?;
+        for (arg, pathspec) in path_args.iter().zip(pathspecs) {
+            let listed: Vec<usize> = files
+                .iter()
+                .filter(|file| names_file(&pathspec, file))
+                .map(|file| file.index)
+                .collect();
+            if !listed.is_empty() {
+                selection.indices.extend(listed);
+            } else if workdir.is_some_and(|workdir| workdir.join(&pathspec).exists()) {
+                selection.unlisted.push(pathspec);
+            } else {
+                return Err(GitNavigatorError::custom_empty_files_error(format!(
+                    "'{arg}' is neither a file index nor a path"
+                )));
+            }
+        }
+
+        selection.indices.sort();
+        selection.indices.dedup();
+        Ok(selection)
+    }
+
+    /// Whether `arg` selects files rather than naming something else: indices
+    /// ("1 3-5,8", "all") or section names ("staged")
+    pub fn is_selection(arg: &str) -> bool {
+        let mut parts = arg
+            .split([' ', ','])
+            .filter(|part| !part.is_empty())
+            .peekable();
+        parts.peek().is_some()
+            && parts.all(|part| {
+                let name = part.strip_prefix(['^', '-']).unwrap_or(part);
+                StatusGroup::from_name(name).is_some() || IndexParser::is_index_list(part)
+            })
+    }
+
+    /// Replace each section name in `arg` by the indices listed in it, keeping
+    /// a leading `^` or `-` on every one of them
+    fn expand_groups(arg: &str, files: &[FileEntry]) -> Result<String> {
+        let parts = arg
+            .split([' ', ','])
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                let (prefix, name) = match part.strip_prefix(['^', '-']) {
+                    Some(name) => (&part[..1], name),
+                    None => ("", part),
+                };
+                let Some(group) = StatusGroup::from_name(name) else {
+                    return Ok(part.to_string());
+                };
+                let indices: Vec<String> = files
+                    .iter()
+                    .filter(|file| StatusGroup::of(file) == group)
+                    .map(|file| format!("{prefix}{}", file.index))
+                    .collect();
+                if indices.is_empty() && prefix.is_empty() {
+                    return Err(GitNavigatorError::custom_empty_files_error(format!(
+                        "No {} files in the last gs listing",
+                        group.name()
+                    )));
+                }
+                Ok(indices.join(" "))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(parts.join(" "))
+    }
+
     /// Check if arguments were provided (for better error messages)
     pub fn has_args(args: &[String]) -> bool {
         !args.is_empty()
@@ -88,6 +249,8 @@ impl ArgsParser {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::git_status::GitStatus;
+    use std::path::PathBuf;
 
     #[test]
     fn test_parse_single_index() -> Result<()> {
@@ -129,6 +292,15 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_all() -> Result<()> {
+        let result = ArgsParser::parse_indices(vec!["all".to_string()], 3)?;
+        assert_eq!(result, vec![1, 2, 3]);
+        let result = ArgsParser::parse_indices(vec!["a".to_string()], 2)?;
+        assert_eq!(result, vec![1, 2]);
+        Ok(())
+    }
+
     #[test]
     fn test_parse_empty_args() {
         let args = vec![];
@@ -159,18 +331,64 @@ mod tests {
         assert!(result.unwrap_err().to_string().contains("out of range"));
     }
 
+    fn file(index: usize, status: GitStatus, staged: bool) -> FileEntry {
+        FileEntry {
+            index,
+            status,
+            path: PathBuf::from(format!("file{index}.txt")),
+            old_path: None,
+            staged,
+        }
+    }
+
+    #[test]
+    fn test_parse_status_groups() -> Result<()> {
+        let files = vec![
+            file(1, GitStatus::Modified, true),
+            file(2, GitStatus::Added, true),
+            file(3, GitStatus::Modified, false),
+            file(4, GitStatus::Untracked, false),
+            file(5, GitStatus::Untracked, false),
+        ];
+        let parse = |args: &[&str]| {
+            ArgsParser::parse_file_indices(args.iter().map(|a| a.to_string()).collect(), &files)
+        };
+
+        assert_eq!(parse(&["staged"])?, vec![1, 2]);
+        assert_eq!(parse(&["Untracked", "3"])?, vec![3, 4, 5]);
+        assert_eq!(parse(&["all", "^untracked"])?, vec![1, 2, 3]);
+        assert_eq!(parse(&["unstaged,untracked", "^5"])?, vec![3, 4]);
+        // Nothing to exclude is fine, nothing to select is not
+        assert_eq!(parse(&["staged", "^unmerged"])?, vec![1, 2]);
+        assert!(parse(&["unmerged"])
+            .unwrap_err()
+            .to_string()
+            .contains("No unmerged files in the last gs listing"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_selection() {
+        assert!(ArgsParser::is_selection("1 3-5"));
+        assert!(ArgsParser::is_selection("staged"));
+        assert!(ArgsParser::is_selection("all,^untracked"));
+        assert!(!ArgsParser::is_selection("main"));
+        assert!(!ArgsParser::is_selection("staged-fix"));
+        assert!(!ArgsParser::is_selection(""));
+    }
+
     #[test]
     fn test_has_args() {
-        assert!(ArgsParser::has_args(&vec!["1".to_string()]));
-        assert!(!ArgsParser::has_args(&vec![]));
+        assert!(ArgsParser::has_args(&["1".to_string()]));
+        assert!(!ArgsParser::has_args(&[]));
     }
 
     #[test]
     fn test_arg_count() {
         assert_eq!(
-            ArgsParser::arg_count(&vec!["1".to_string(), "2".to_string()]),
+            ArgsParser::arg_count(&["1".to_string(), "2".to_string()]),
             2
         );
-        assert_eq!(ArgsParser::arg_count(&vec![]), 0);
+        assert_eq!(ArgsParser::arg_count(&[]), 0);
     }
 }
@@ -0,0 +1,168 @@
+//! Branch naming conventions driven by settings.
+//!
+//! Teams can describe how new branches should be named with two settings:
+//!
+//! ```toml
+//! [branch]
+//! template = "feature/{user}/{slug}"
+//! pattern = "^(feature|fix|chore)/"
+//! ```
+//!
+//! `gco -b <name>` expands a short slug (a name without `/`) through the
+//! template, and rejects the final name when it does not match the pattern.
+//!
+//! # Placeholders
+//! - `{slug}`: the name given on the command line, slugified
+//! - `{user}`: git's `user.name`, slugified
+
+use crate::core::config::Settings;
+use crate::core::error::{GitNavigatorError, Result};
+use regex::Regex;
+
+/// Compiled branch naming rules
+#[derive(Debug, Clone, Default)]
+pub struct BranchNaming {
+    template: Option<String>,
+    pattern: Option<Regex>,
+}
+
+impl BranchNaming {
+    /// Build the rules from `branch.template` and `branch.pattern`
+    pub fn from_settings(settings: &Settings) -> Result<Self> {
+        let pattern = settings
+            .get_str("branch.pattern")
+            .map(|pattern| {
+                Regex::new(pattern).map_err(|e| {
+                    GitNavigatorError::config_error(format!("invalid branch.pattern: {e}"))
+                })
+            })
+            .transpose()?;
+
+        Ok(Self {
+            template: settings.get_str("branch.template").map(str::to_string),
+            pattern,
+        })
+    }
+
+    /// Expand `name` through the template and validate the result
+    ///
+    /// Names that already contain a `/` are taken as-is so a full branch
+    /// name can always be given explicitly; they are still validated.
+    pub fn resolve(&self, name: &str, user: Option<&str>) -> Result<String> {
+        let branch_name = match &self.template {
+            Some(template) if !name.contains('/') => expand_template(template, name, user)?,
+            _ => name.to_string(),
+        };
+
+        if let Some(pattern) = &self.pattern {
+            if !pattern.is_match(&branch_name) {
+                return Err(GitNavigatorError::invalid_branch_name(
+                    branch_name,
+                    pattern.as_str(),
+                ));
+            }
+        }
+
+        Ok(branch_name)
+    }
+}
+
+fn expand_template(template: &str, slug: &str, user: Option<&str>) -> Result<String> {
+    let mut expanded = template.replace("{slug}", &slugify(slug));
+
+    if expanded.contains("{user}") {
+        let user = user
+            .map(slugify)
+            .filter(|user| !user.is_empty())
+            .ok_or_else(|| {
+                GitNavigatorError::config_error(
+                    "branch.template uses {user} but git user.name is not set",
+                )
+            })?;
+        expanded = expanded.replace("{user}", &user);
+    }
+
+    Ok(expanded)
+}
+
+/// Turn free text into a branch-friendly slug
+///
+/// Lowercases ASCII letters, keeps `.`, `_` and `-`, and collapses any other
+/// run of characters into a single `-`.
+pub fn slugify(text: &str) -> String {
+    let mut slug = String::with_capacity(text.len());
+    let mut pending_dash = false;
+
+    for ch in text.chars() {
+        if ch.is_ascii_alphanumeric() || matches!(ch, '.' | '_' | '-') {
+            if pending_dash && !slug.is_empty() {
+                slug.push('-');
+            }
+            pending_dash = false;
+            slug.push(ch.to_ascii_lowercase());
+        } else {
+            pending_dash = true;
+        }
+    }
+
+    slug
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naming(content: &str) -> BranchNaming {
+        BranchNaming::from_settings(&Settings::parse(content).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("Fix Login Bug"), "fix-login-bug");
+        assert_eq!(slugify("  Jane  Doe "), "jane-doe");
+        assert_eq!(slugify("v1.2_rc-1"), "v1.2_rc-1");
+        assert_eq!(slugify("über cool"), "ber-cool");
+    }
+
+    #[test]
+    fn test_template_expands_short_slug() {
+        let naming = naming("[branch]\ntemplate = \"feature/{user}/{slug}\"");
+        assert_eq!(
+            naming.resolve("login form", Some("Jane Doe")).unwrap(),
+            "feature/jane-doe/login-form"
+        );
+        // Full names bypass the template
+        assert_eq!(
+            naming.resolve("hotfix/urgent", Some("Jane Doe")).unwrap(),
+            "hotfix/urgent"
+        );
+    }
+
+    #[test]
+    fn test_template_requires_user_when_used() {
+        let naming = naming("[branch]\ntemplate = \"{user}/{slug}\"");
+        assert!(naming.resolve("thing", None).is_err());
+    }
+
+    #[test]
+    fn test_pattern_validation() {
+        let naming = naming("[branch]\npattern = \"^(feature|fix)/\"");
+        assert_eq!(naming.resolve("fix/crash", None).unwrap(), "fix/crash");
+        let err = naming.resolve("crash", None).unwrap_err();
+        assert!(err.to_string().contains("does not match"));
+    }
+
+    #[test]
+    fn test_invalid_pattern_is_config_error() {
+        let settings = Settings::parse("[branch]\npattern = \"(unclosed\"").unwrap();
+        assert!(BranchNaming::from_settings(&settings).is_err());
+    }
+
+    #[test]
+    fn test_no_rules_keeps_name() {
+        assert_eq!(
+            BranchNaming::default().resolve("anything", None).unwrap(),
+            "anything"
+        );
+    }
+}
@@ -0,0 +1,1146 @@
+//! Persistent per-repository cache of numbered files, branches and directories.
+//!
+//! `gs`, `gb` and `dirs` number what they print and save it here so that
+//! follow-up commands (`ga 3`, `gb 2`, ...) can resolve indices without
+//! rescanning. `refresh` writes the file, branch, directory and tag lists in
+//! one go without printing them.
+//!
+//! # Layout
+//! ```text
+//! $XDG_CACHE_HOME/git-navigator/
+//! └── repos.json     # written by repos, across repositories
+//! $XDG_CACHE_HOME/git-navigator/<md5 of .git path>/
+//! ├── files.json     # written by gs
+//! ├── branches.json  # written by gb
+//! ├── remote-branches.json # written by gb -r and gb -a
+//! ├── dirs.json      # written by dirs
+//! ├── commits.json   # written by reword and log
+//! ├── contributors.json # written by contributors
+//! ├── tags.json      # written by tags
+//! ├── worktrees.json # written by worktrees
+//! ├── packages.json  # written by packages
+//! ├── prompt.json    # written by prompt, apart from gs's numbering
+//! └── stats.json     # line stats for gs --review-budget
+//! ```
+//!
+//! Every file records the repository it belongs to, so the directories double
+//! as the list of repositories `repos` switches between
+//! ([`cached_repositories`]).
+//!
+//! Writes can be switched off for the whole process with
+//! [`CacheStore::disable_writes`] (the global `--no-cache-write` flag); reads
+//! keep working so existing numbering stays usable.
+//!
+//! # Concurrent writers
+//! Each save goes to a temporary file next to the cache that is then renamed
+//! over it, so two `gs` runs racing each other leave one complete file rather
+//! than a torn mix of both.
+//!
+//! # Corrupt files
+//! A cache file that still fails to parse (say, from an older version killed
+//! mid-write) is renamed to `<name>.corrupt-<unix time>` and logged. Lists
+//! that can be recomputed without the listing command's options (files,
+//! branches, directories, tags) are then rebuilt on the spot with
+//! [`CacheStore::load_or_rebuild`]; the others are simply listed again.
+//! Either way the command fails with [`GitNavigatorError::CacheRebuilt`], as
+//! the numbers the user typed came from a list that is gone.
+//!
+//! # Expiry
+//! Every file records when it was written (`last_updated`). With
+//! `cache.ttl_minutes` set, a list older than that is no longer trusted:
+//! lookups fail with [`GitNavigatorError::CacheExpired`] so the list gets
+//! shown again, or, with `cache.expired = "refresh"`, the lists that
+//! [`CacheStore::load_or_rebuild`] can recompute are rebuilt instead. A
+//! rebuilt list that no longer matches the expired one is saved but refused
+//! with [`GitNavigatorError::CacheRebuilt`], like a corrupt one.
+//! `doctor` shows the age of each file.
+//!
+//! # Read-only filesystems
+//! Every save is also kept in memory for the rest of the process. When the
+//! cache directory cannot be written, a single warning is printed and later
+//! steps of the same invocation keep resolving indices from memory.
+
+use crate::core::config::Settings;
+use crate::core::error::{GitNavigatorError, Result};
+use crate::core::output::{format_age, print_warning};
+use crate::core::state::{
+    BranchEntry, CacheSnapshot, CommitEntry, Contributor, DirEntry, FileEntry, FileStat,
+    PackageEntry, RepoEntry, StateCache, StatusView, TagEntry, WorktreeEntry,
+};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, SystemTime};
+
+const FILES_CACHE: &str = "files.json";
+const VIEW_CACHE: &str = "view.json";
+const BRANCHES_CACHE: &str = "branches.json";
+const REMOTE_BRANCHES_CACHE: &str = "remote-branches.json";
+const DIRS_CACHE: &str = "dirs.json";
+const STATS_CACHE: &str = "stats.json";
+const COMMITS_CACHE: &str = "commits.json";
+const CONTRIBUTORS_CACHE: &str = "contributors.json";
+const TAGS_CACHE: &str = "tags.json";
+const WORKTREES_CACHE: &str = "worktrees.json";
+const PACKAGES_CACHE: &str = "packages.json";
+const PROMPT_CACHE: &str = "prompt.json";
+const REPOS_CACHE: &str = "repos.json";
+
+/// Every cache file, in the order `doctor` lists them
+pub const CACHE_FILES: [&str; 12] = [
+    FILES_CACHE,
+    VIEW_CACHE,
+    BRANCHES_CACHE,
+    REMOTE_BRANCHES_CACHE,
+    DIRS_CACHE,
+    COMMITS_CACHE,
+    CONTRIBUTORS_CACHE,
+    TAGS_CACHE,
+    WORKTREES_CACHE,
+    PACKAGES_CACHE,
+    PROMPT_CACHE,
+    STATS_CACHE,
+];
+
+static WRITES_DISABLED: AtomicBool = AtomicBool::new(false);
+static DOWNGRADE_WARNED: AtomicBool = AtomicBool::new(false);
+
+/// `git-navigator` in `$XDG_CACHE_HOME`, else the platform cache directory
+pub fn cache_root() -> PathBuf {
+    let cache_home = std::env::var("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| dirs::cache_dir().unwrap_or_else(|| PathBuf::from("/tmp")));
+    cache_home.join("git-navigator")
+}
+
+/// Git directories of every repository with a cache, in path order
+///
+/// Each cache directory is named after a hash, so the path is read back from
+/// whichever of its files parses first.
+pub fn cached_repositories() -> Vec<PathBuf> {
+    #[derive(serde::Deserialize)]
+    struct Owner {
+        repo_path: PathBuf,
+    }
+
+    let Ok(entries) = fs::read_dir(cache_root()) else {
+        return Vec::new();
+    };
+    let mut repos = std::collections::BTreeSet::new();
+    for dir in entries.flatten().map(|entry| entry.path()) {
+        if !dir.is_dir() {
+            continue;
+        }
+        let owner = CACHE_FILES.iter().find_map(|file_name| {
+            let content = fs::read_to_string(dir.join(file_name)).ok()?;
+            serde_json::from_str::<Owner>(&content).ok()
+        });
+        match owner {
+            Some(owner) => {
+                repos.insert(owner.repo_path);
+            }
+            None => log::debug!("No readable cache file in {}", dir.display()),
+        }
+    }
+    repos.into_iter().collect()
+}
+
+/// Caches saved during this process, keyed by cache file path
+fn memory() -> &'static Mutex<HashMap<PathBuf, StateCache>> {
+    static MEMORY: OnceLock<Mutex<HashMap<PathBuf, StateCache>>> = OnceLock::new();
+    MEMORY.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// How long cached lists stay usable, from the `[cache]` settings
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheTtl {
+    /// Lists older than this are expired (`cache.ttl_minutes`; no limit by default)
+    pub max_age: Option<Duration>,
+    /// Rebuild expired lists instead of failing (`cache.expired = "refresh"`)
+    pub refresh: bool,
+}
+
+impl CacheTtl {
+    pub fn from_settings(settings: &Settings) -> Self {
+        let max_age = settings
+            .get_int("cache.ttl_minutes")
+            .and_then(|minutes| u64::try_from(minutes).ok())
+            .filter(|minutes| *minutes > 0)
+            .map(|minutes| Duration::from_secs(minutes * 60));
+        Self {
+            max_age,
+            refresh: settings.get_str("cache.expired") == Some("refresh"),
+        }
+    }
+}
+
+/// Cache files for a single repository
+#[derive(Debug, Clone)]
+pub struct CacheStore {
+    dir: PathBuf,
+    repo_path: PathBuf,
+    ttl: CacheTtl,
+}
+
+impl CacheStore {
+    /// Cache for the repository whose git directory is `repo_path`
+    pub fn for_repo(repo_path: &Path) -> Result<Self> {
+        // Create a hash of the repo path for unique cache directory
+        let repo_hash = format!("{:x}", md5::compute(repo_path.to_string_lossy().as_bytes()));
+
+        log::debug!("CacheStore: repo_path = {repo_path:?}");
+
+        Ok(Self::with_dir(cache_root().join(repo_hash), repo_path))
+    }
+
+    /// Cache for lists that span repositories, kept in the cache root
+    pub fn global() -> Self {
+        Self::with_dir(cache_root(), Path::new(""))
+    }
+
+    /// Cache stored in an explicit directory
+    pub fn with_dir(dir: impl Into<PathBuf>, repo_path: &Path) -> Self {
+        Self {
+            dir: dir.into(),
+            repo_path: repo_path.to_path_buf(),
+            ttl: CacheTtl::default(),
+        }
+    }
+
+    /// Apply an expiry policy to the lists this store loads
+    pub fn with_ttl(mut self, ttl: CacheTtl) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// The expiry policy in effect
+    pub fn ttl(&self) -> CacheTtl {
+        self.ttl
+    }
+
+    /// Age of each cache file on disk, whatever its expiry
+    pub fn file_ages(&self) -> Vec<(&'static str, Duration)> {
+        CACHE_FILES
+            .iter()
+            .filter_map(|file_name| {
+                let cache = self.read_unchecked(file_name).ok()?;
+                Some((*file_name, age_of(&cache)))
+            })
+            .collect()
+    }
+
+    /// Directory holding this repository's cache files
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Stop every cache store in this process from writing to disk
+    pub fn disable_writes() {
+        WRITES_DISABLED.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether saves actually reach the disk
+    pub fn writes_enabled() -> bool {
+        !WRITES_DISABLED.load(Ordering::Relaxed)
+    }
+
+    /// Save the numbered file list shown by `gs`
+    pub fn save_files(&self, files: &[FileEntry]) -> Result<()> {
+        log::debug!("Attempting to save {} files to cache", files.len());
+        let mut cache = StateCache::new(self.repo_path.clone());
+        cache.files = files.to_vec();
+        self.write(FILES_CACHE, &cache)
+    }
+
+    /// Load the numbered file list saved by the last `gs`
+    pub fn load_files(&self) -> Result<Vec<FileEntry>> {
+        let cache = self.read(FILES_CACHE)?;
+        log::debug!("Successfully loaded {} files from cache", cache.files.len());
+
+        if cache.files.is_empty() {
+            log::debug!("Cache file exists but contains no files");
+            return Err(GitNavigatorError::NoCachedFiles);
+        }
+        Ok(cache.files)
+    }
+
+    /// Save the file list together with the fingerprint it was computed from
+    pub fn save_status(&self, files: &[FileEntry], fingerprint: &str) -> Result<()> {
+        let mut cache = StateCache::new(self.repo_path.clone());
+        cache.files = files.to_vec();
+        cache.fingerprint = Some(fingerprint.to_string());
+        self.write(FILES_CACHE, &cache)
+    }
+
+    /// The cached file list, if it was saved with a fingerprint
+    ///
+    /// Unlike [`CacheStore::load_files`] an empty list is returned as-is,
+    /// since a clean worktree is a valid cached status.
+    pub fn load_status(&self) -> Option<(Vec<FileEntry>, String)> {
+        let cache = self.read(FILES_CACHE).ok()?;
+        Some((cache.files, cache.fingerprint?))
+    }
+
+    /// Save the file list with the repository state it was computed from, the
+    /// part of the status it shows, and the `status.fast` fingerprint when
+    /// there is one
+    ///
+    /// The view goes to a file of its own, so a torn `files.json` can still
+    /// be rebuilt the way `gs` limited it.
+    pub fn save_file_list(
+        &self,
+        files: &[FileEntry],
+        snapshot: CacheSnapshot,
+        view: &StatusView,
+        fingerprint: Option<String>,
+    ) -> Result<()> {
+        let mut view_cache = StateCache::new(self.repo_path.clone());
+        view_cache.view = view.clone();
+        self.write(VIEW_CACHE, &view_cache)?;
+
+        let mut cache = StateCache::new(self.repo_path.clone());
+        cache.files = files.to_vec();
+        cache.snapshot = Some(snapshot);
+        cache.fingerprint = fingerprint;
+        self.write(FILES_CACHE, &cache)
+    }
+
+    /// The repository state the cached file list was computed from, if saved
+    pub fn load_snapshot(&self) -> Option<CacheSnapshot> {
+        self.read(FILES_CACHE).ok()?.snapshot
+    }
+
+    /// The part of the status the cached file list shows (`gs --staged`,
+    /// `gs src/`, ...), all of it when nothing was saved
+    pub fn load_view(&self) -> StatusView {
+        self.read_unchecked(VIEW_CACHE)
+            .map(|cache| cache.view)
+            .unwrap_or_default()
+    }
+
+    /// Save the numbered branch list shown by `gb`
+    pub fn save_branches(&self, branches: &[BranchEntry]) -> Result<()> {
+        log::debug!("Attempting to save {} branches to cache", branches.len());
+        let mut cache = StateCache::new(self.repo_path.clone());
+        cache.branches = branches.to_vec();
+        self.write(BRANCHES_CACHE, &cache)
+    }
+
+    /// Load the numbered branch list saved by the last `gb`
+    pub fn load_branches(&self) -> Result<Vec<BranchEntry>> {
+        let cache = self.read(BRANCHES_CACHE)?;
+        log::debug!(
+            "Successfully loaded {} branches from cache",
+            cache.branches.len()
+        );
+
+        if cache.branches.is_empty() {
+            log::debug!("Branch cache file exists but contains no branches");
+            return Err(GitNavigatorError::NoCachedFiles);
+        }
+        Ok(cache.branches)
+    }
+
+    /// Save the numbered remote-tracking branch list shown by `gb -r`
+    ///
+    /// Remote branches are numbered apart from the local ones, so `gb -r 2`
+    /// and `gb 2` never get in each other's way.
+    pub fn save_remote_branches(&self, branches: &[BranchEntry]) -> Result<()> {
+        let mut cache = StateCache::new(self.repo_path.clone());
+        cache.branches = branches.to_vec();
+        self.write(REMOTE_BRANCHES_CACHE, &cache)
+    }
+
+    /// Load the numbered remote-tracking branch list saved by the last `gb -r`
+    pub fn load_remote_branches(&self) -> Result<Vec<BranchEntry>> {
+        let cache = self.read(REMOTE_BRANCHES_CACHE)?;
+        if cache.branches.is_empty() {
+            return Err(GitNavigatorError::NoCachedFiles);
+        }
+        Ok(cache.branches)
+    }
+
+    /// Save the numbered repository list shown by `repos`
+    pub fn save_repos(&self, repos: &[RepoEntry]) -> Result<()> {
+        let mut cache = StateCache::new(self.repo_path.clone());
+        cache.repos = repos.to_vec();
+        self.write(REPOS_CACHE, &cache)
+    }
+
+    /// Load the numbered repository list saved by the last `repos`
+    pub fn load_repos(&self) -> Result<Vec<RepoEntry>> {
+        let cache = self.read(REPOS_CACHE)?;
+        if cache.repos.is_empty() {
+            return Err(GitNavigatorError::NoCachedFiles);
+        }
+        Ok(cache.repos)
+    }
+
+    /// Save the numbered directory list shown by `dirs`
+    pub fn save_dirs(&self, dirs: &[DirEntry]) -> Result<()> {
+        let mut cache = StateCache::new(self.repo_path.clone());
+        cache.dirs = dirs.to_vec();
+        self.write(DIRS_CACHE, &cache)
+    }
+
+    /// Load the numbered directory list saved by the last `dirs`
+    pub fn load_dirs(&self) -> Result<Vec<DirEntry>> {
+        let cache = self.read(DIRS_CACHE)?;
+        if cache.dirs.is_empty() {
+            return Err(GitNavigatorError::NoCachedFiles);
+        }
+        Ok(cache.dirs)
+    }
+
+    /// Save the numbered list of recent commits
+    pub fn save_commits(&self, commits: &[CommitEntry]) -> Result<()> {
+        let mut cache = StateCache::new(self.repo_path.clone());
+        cache.commits = commits.to_vec();
+        self.write(COMMITS_CACHE, &cache)
+    }
+
+    /// Load the numbered list of recent commits
+    pub fn load_commits(&self) -> Result<Vec<CommitEntry>> {
+        let cache = self.read(COMMITS_CACHE)?;
+        if cache.commits.is_empty() {
+            return Err(GitNavigatorError::NoCachedFiles);
+        }
+        Ok(cache.commits)
+    }
+
+    /// Save the numbered contributor list
+    pub fn save_contributors(&self, contributors: &[Contributor]) -> Result<()> {
+        let mut cache = StateCache::new(self.repo_path.clone());
+        cache.contributors = contributors.to_vec();
+        self.write(CONTRIBUTORS_CACHE, &cache)
+    }
+
+    /// Load the numbered contributor list
+    pub fn load_contributors(&self) -> Result<Vec<Contributor>> {
+        let cache = self.read(CONTRIBUTORS_CACHE)?;
+        if cache.contributors.is_empty() {
+            return Err(GitNavigatorError::NoCachedFiles);
+        }
+        Ok(cache.contributors)
+    }
+
+    /// Save the numbered tag list
+    pub fn save_tags(&self, tags: &[TagEntry]) -> Result<()> {
+        let mut cache = StateCache::new(self.repo_path.clone());
+        cache.tags = tags.to_vec();
+        self.write(TAGS_CACHE, &cache)
+    }
+
+    /// Load the numbered tag list
+    pub fn load_tags(&self) -> Result<Vec<TagEntry>> {
+        let cache = self.read(TAGS_CACHE)?;
+        if cache.tags.is_empty() {
+            return Err(GitNavigatorError::NoCachedFiles);
+        }
+        Ok(cache.tags)
+    }
+
+    /// Save the numbered worktree list shown by `worktrees`
+    pub fn save_worktrees(&self, worktrees: &[WorktreeEntry]) -> Result<()> {
+        let mut cache = StateCache::new(self.repo_path.clone());
+        cache.worktrees = worktrees.to_vec();
+        self.write(WORKTREES_CACHE, &cache)
+    }
+
+    /// Load the numbered worktree list saved by the last `worktrees`
+    pub fn load_worktrees(&self) -> Result<Vec<WorktreeEntry>> {
+        let cache = self.read(WORKTREES_CACHE)?;
+        if cache.worktrees.is_empty() {
+            return Err(GitNavigatorError::NoCachedFiles);
+        }
+        Ok(cache.worktrees)
+    }
+
+    /// Save the numbered list of changed packages
+    pub fn save_packages(&self, packages: &[PackageEntry]) -> Result<()> {
+        let mut cache = StateCache::new(self.repo_path.clone());
+        cache.packages = packages.to_vec();
+        self.write(PACKAGES_CACHE, &cache)
+    }
+
+    /// Load the numbered package list saved by the last `packages`
+    pub fn load_packages(&self) -> Result<Vec<PackageEntry>> {
+        let cache = self.read(PACKAGES_CACHE)?;
+        if cache.packages.is_empty() {
+            return Err(GitNavigatorError::NoCachedFiles);
+        }
+        Ok(cache.packages)
+    }
+
+    /// Save the status `prompt` summarizes
+    ///
+    /// Kept apart from `files.json` so a background refresh never renumbers
+    /// the list `ga` and friends resolve against.
+    pub fn save_prompt_files(&self, files: &[FileEntry]) -> Result<()> {
+        let mut cache = StateCache::new(self.repo_path.clone());
+        cache.files = files.to_vec();
+        self.write(PROMPT_CACHE, &cache)
+    }
+
+    /// The status `prompt` last saved; empty for a clean worktree
+    pub fn load_prompt_files(&self) -> Result<Vec<FileEntry>> {
+        Ok(self.read(PROMPT_CACHE)?.files)
+    }
+
+    /// Save per-file line stats with the fingerprint they were computed at
+    pub fn save_line_stats(&self, stats: &[FileStat], fingerprint: &str) -> Result<()> {
+        let mut cache = StateCache::new(self.repo_path.clone());
+        cache.line_stats = stats.to_vec();
+        cache.fingerprint = Some(fingerprint.to_string());
+        self.write(STATS_CACHE, &cache)
+    }
+
+    /// The cached line stats and the fingerprint they belong to
+    pub fn load_line_stats(&self) -> Option<(Vec<FileStat>, String)> {
+        let cache = self.read(STATS_CACHE).ok()?;
+        Some((cache.line_stats, cache.fingerprint?))
+    }
+
+    fn write(&self, file_name: &str, cache: &StateCache) -> Result<()> {
+        let cache_file = self.dir.join(file_name);
+        if let Ok(mut memory) = memory().lock() {
+            memory.insert(cache_file.clone(), cache.clone());
+        }
+
+        if !Self::writes_enabled() {
+            log::debug!("Cache writes disabled, skipping {}", cache_file.display());
+            return Ok(());
+        }
+
+        let json = serde_json::to_string_pretty(cache).map_err(|e| {
+            log::error!("Failed to serialize cache data: {e}");
+            GitNavigatorError::cache_serialization_failed(e)
+        })?;
+
+        let result = fs::create_dir_all(&self.dir)
+            .map_err(|e| GitNavigatorError::cache_directory_creation_failed(&self.dir, e))
+            .and_then(|()| {
+                write_atomically(&self.dir, file_name, &json)
+                    .map_err(|e| GitNavigatorError::cache_write_failed(&cache_file, e))
+            });
+
+        match result {
+            Ok(()) => {
+                log::debug!("Saved cache file {}", cache_file.display());
+            }
+            Err(e) => {
+                // The in-memory copy keeps this invocation working
+                log::warn!("Falling back to in-memory cache: {e}");
+                self.warn_downgrade();
+            }
+        }
+        Ok(())
+    }
+
+    /// Tell the user once per process that the cache is memory-only
+    fn warn_downgrade(&self) {
+        if !DOWNGRADE_WARNED.swap(true, Ordering::Relaxed) {
+            print_warning(&format!(
+                "Cache directory '{}' is not writable; indices are kept for this command only",
+                self.dir.display()
+            ));
+        }
+    }
+
+    /// Read a cache file, failing once it is older than the TTL
+    fn read(&self, file_name: &str) -> Result<StateCache> {
+        let cache = self.read_unchecked(file_name)?;
+        let age = age_of(&cache);
+        log::debug!("Cache {file_name} was updated {} ago", format_age(age));
+
+        match self.ttl.max_age {
+            Some(max_age) if age > max_age => Err(GitNavigatorError::CacheExpired {
+                path: self.dir.join(file_name),
+                age: format_age(age),
+            }),
+            _ => Ok(cache),
+        }
+    }
+
+    fn read_unchecked(&self, file_name: &str) -> Result<StateCache> {
+        let cache_file = self.dir.join(file_name);
+
+        let remembered = memory()
+            .lock()
+            .ok()
+            .and_then(|memory| memory.get(&cache_file).cloned());
+        if let Some(cache) = remembered {
+            log::debug!("Using in-memory cache for {}", cache_file.display());
+            return Ok(cache);
+        }
+
+        log::debug!("Looking for cache file: {}", cache_file.display());
+
+        if !cache_file.exists() {
+            log::debug!("Cache file does not exist: {}", cache_file.display());
+            return Err(GitNavigatorError::cache_file_not_found(&cache_file));
+        }
+
+        let content = fs::read_to_string(&cache_file).map_err(|e| {
+            log::error!(
+                "Failed to read cache file '{}': {}",
+                cache_file.display(),
+                e
+            );
+            GitNavigatorError::cache_read_failed(&cache_file, e)
+        })?;
+
+        serde_json::from_str(&content).map_err(|e| {
+            log::error!(
+                "Failed to parse cache file '{}': {}",
+                cache_file.display(),
+                e
+            );
+            quarantine(&cache_file);
+            GitNavigatorError::cache_parse_failed(&cache_file, e)
+        })
+    }
+
+    /// Load a list with `load`, rebuilding and saving it when its file was
+    /// corrupt, or expired under `cache.expired = "refresh"`
+    ///
+    /// A corrupt list is saved again but not returned: the user's indices
+    /// point into a list nobody can read anymore, so the lookup fails with
+    /// [`GitNavigatorError::CacheRebuilt`] for them to list it again. An
+    /// expired list is only carried on when the rebuilt one is the same.
+    /// A rebuilt list that turns out empty is reported as [`GitNavigatorError::NoCachedFiles`],
+    /// like an empty cache would be.
+    pub fn load_or_rebuild<T: PartialEq>(
+        &self,
+        load: impl Fn(&Self) -> Result<Vec<T>>,
+        save: impl FnOnce(&Self, &[T]) -> Result<()>,
+        rebuild: impl FnOnce() -> Result<Vec<T>>,
+    ) -> Result<Vec<T>> {
+        match load(self) {
+            Err(GitNavigatorError::CacheExpired { path, .. }) if self.ttl.refresh => {
+                log::debug!("Rebuilding {} after it expired", path.display());
+                let expired = load(&self.clone().with_ttl(CacheTtl::default())).ok();
+                let entries = self.rebuild(save, rebuild)?;
+                if expired.as_ref() == Some(&entries) {
+                    return Ok(entries);
+                }
+                Err(GitNavigatorError::CacheRebuilt {
+                    path,
+                    reason: "expired and changed".to_string(),
+                })
+            }
+            Err(GitNavigatorError::CacheParseFailed { path, .. }) => {
+                log::warn!("Rebuilding {} after it failed to parse", path.display());
+                self.rebuild(save, rebuild)?;
+                Err(GitNavigatorError::CacheRebuilt {
+                    path,
+                    reason: "could not be read".to_string(),
+                })
+            }
+            result => result,
+        }
+    }
+
+    fn rebuild<T>(
+        &self,
+        save: impl FnOnce(&Self, &[T]) -> Result<()>,
+        rebuild: impl FnOnce() -> Result<Vec<T>>,
+    ) -> Result<Vec<T>> {
+        let entries = rebuild()?;
+        if let Err(e) = save(self, &entries) {
+            log::warn!("Cache save failed: {e}");
+        }
+        if entries.is_empty() {
+            return Err(GitNavigatorError::NoCachedFiles);
+        }
+        Ok(entries)
+    }
+}
+
+/// Time since a cache was written; zero if the clock went backwards
+fn age_of(cache: &StateCache) -> Duration {
+    SystemTime::now()
+        .duration_since(cache.last_updated)
+        .unwrap_or_default()
+}
+
+/// Move a corrupt cache file aside as `<name>.corrupt-<unix time>`
+///
+/// Keeping it (rather than deleting it) leaves something to look at when
+/// reporting a bug; the next save writes a fresh file in its place.
+fn quarantine(cache_file: &Path) {
+    let timestamp = SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    let mut name = cache_file.as_os_str().to_os_string();
+    name.push(format!(".corrupt-{timestamp}"));
+    let target = PathBuf::from(name);
+
+    match fs::rename(cache_file, &target) {
+        Ok(()) => log::warn!(
+            "Moved corrupt cache file '{}' to '{}'",
+            cache_file.display(),
+            target.display()
+        ),
+        Err(e) => log::warn!(
+            "Could not move corrupt cache file '{}' aside: {e}",
+            cache_file.display()
+        ),
+    }
+}
+
+/// Write `dir/file_name` through a temporary file renamed into place
+///
+/// The temporary name includes the process id so concurrent writers never
+/// share one; the rename replaces the old file in a single step.
+fn write_atomically(dir: &Path, file_name: &str, content: &str) -> std::io::Result<()> {
+    let temp_file = dir.join(format!(".{file_name}.{}.tmp", std::process::id()));
+    let result =
+        fs::write(&temp_file, content).and_then(|()| fs::rename(&temp_file, dir.join(file_name)));
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_file);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::git_status::GitStatus;
+    use crate::core::state::StatusGroup;
+    use tempfile::TempDir;
+
+    fn test_store(temp_dir: &TempDir) -> CacheStore {
+        CacheStore::with_dir(temp_dir.path().join("cache"), Path::new("/test/repo/.git"))
+    }
+
+    fn test_files() -> Vec<FileEntry> {
+        vec![FileEntry {
+            index: 1,
+            status: GitStatus::Modified,
+            path: PathBuf::from("test.txt"),
+            staged: false,
+            old_path: None,
+        }]
+    }
+
+    #[test]
+    fn test_for_repo_dir() -> Result<()> {
+        let store = CacheStore::for_repo(Path::new("/test/repo/path"))?;
+
+        assert!(store.dir().to_string_lossy().contains("git-navigator"));
+        assert!(store.dir().is_absolute());
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_files_nonexistent_file() {
+        let store = CacheStore::with_dir("/non/existent/cache", Path::new("/repo"));
+
+        match store.load_files().unwrap_err() {
+            GitNavigatorError::CacheFileNotFound { path } => {
+                assert!(path.to_string_lossy().contains("files.json"));
+            }
+            error => panic!("Expected CacheFileNotFound error, got: {error}"),
+        }
+    }
+
+    #[test]
+    fn test_load_branches_nonexistent_file() {
+        let store = CacheStore::with_dir("/non/existent/cache", Path::new("/repo"));
+
+        match store.load_branches().unwrap_err() {
+            GitNavigatorError::CacheFileNotFound { path } => {
+                assert!(path.to_string_lossy().contains("branches.json"));
+            }
+            error => panic!("Expected CacheFileNotFound error, got: {error}"),
+        }
+    }
+
+    #[test]
+    fn test_save_files_creates_directory() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let store = test_store(&temp_dir);
+
+        store.save_files(&test_files())?;
+
+        assert!(store.dir().join("files.json").exists());
+        assert_eq!(store.load_files()?, test_files());
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_atomically_replaces_file() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        fs::write(temp_dir.path().join("files.json"), "{\"torn\":")?;
+
+        write_atomically(temp_dir.path(), "files.json", "{}")?;
+
+        assert_eq!(
+            fs::read_to_string(temp_dir.path().join("files.json"))?,
+            "{}"
+        );
+        let leftovers: Vec<_> = fs::read_dir(temp_dir.path())?
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_name().to_string_lossy().ends_with(".tmp"))
+            .collect();
+        assert!(leftovers.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip_branches() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let store = test_store(&temp_dir);
+        let branches = vec![BranchEntry {
+            index: 1,
+            name: "feature".to_string(),
+            is_current: false,
+        }];
+
+        store.save_branches(&branches)?;
+        assert_eq!(store.load_branches()?, branches);
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip_dirs() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let store = test_store(&temp_dir);
+        let dirs = vec![DirEntry {
+            index: 1,
+            path: PathBuf::from("src/core"),
+            files: 2,
+        }];
+
+        store.save_dirs(&dirs)?;
+        assert_eq!(store.load_dirs()?, dirs);
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip_commits() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let store = test_store(&temp_dir);
+        assert!(store.load_commits().is_err());
+
+        let commits = vec![CommitEntry {
+            index: 1,
+            oid: "0123456789abcdef0123456789abcdef01234567".to_string(),
+            short_oid: "0123456".to_string(),
+            summary: "Fix parser".to_string(),
+        }];
+        store.save_commits(&commits)?;
+        assert_eq!(store.load_commits()?, commits);
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip_contributors() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let store = test_store(&temp_dir);
+        assert!(store.load_contributors().is_err());
+
+        let contributors = vec![Contributor {
+            index: 1,
+            name: "Ada".to_string(),
+            email: "ada@example.com".to_string(),
+            commits: 12,
+            last_commit_time: 1_700_000_000,
+        }];
+        store.save_contributors(&contributors)?;
+        assert_eq!(store.load_contributors()?, contributors);
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip_tags() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let store = test_store(&temp_dir);
+        assert!(store.load_tags().is_err());
+
+        let tags = vec![TagEntry {
+            index: 1,
+            name: "v1.0.0".to_string(),
+            oid: "0123456789abcdef0123456789abcdef01234567".to_string(),
+            short_oid: "0123456".to_string(),
+            annotated: true,
+            time: 1_700_000_000,
+        }];
+        store.save_tags(&tags)?;
+        assert_eq!(store.load_tags()?, tags);
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip_packages() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let store = test_store(&temp_dir);
+        assert!(store.load_packages().is_err());
+
+        let packages = vec![PackageEntry {
+            index: 1,
+            name: "nav-core".to_string(),
+            path: PathBuf::from("crates/core"),
+            files: 2,
+        }];
+        store.save_packages(&packages)?;
+        assert_eq!(store.load_packages()?, packages);
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip_line_stats() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let store = test_store(&temp_dir);
+        assert!(store.load_line_stats().is_none());
+
+        let stats = vec![FileStat {
+            path: PathBuf::from("src/main.rs"),
+            added: 12,
+            removed: 3,
+        }];
+        store.save_line_stats(&stats, "abc123")?;
+        assert_eq!(store.load_line_stats(), Some((stats, "abc123".to_string())));
+        Ok(())
+    }
+
+    #[test]
+    fn test_status_round_trip_keeps_fingerprint() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let store = test_store(&temp_dir);
+        assert!(store.load_status().is_none());
+
+        store.save_files(&test_files())?;
+        assert!(store.load_status().is_none());
+
+        store.save_status(&[], "abc123")?;
+        assert_eq!(
+            store.load_status(),
+            Some((Vec::new(), "abc123".to_string()))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_file_list_round_trip_keeps_snapshot() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let store = test_store(&temp_dir);
+        let snapshot = CacheSnapshot {
+            head: "abc123".to_string(),
+            index_mtime: Some(SystemTime::UNIX_EPOCH),
+            status_hash: CacheSnapshot::status_hash(&test_files()),
+        };
+
+        store.save_file_list(
+            &test_files(),
+            snapshot.clone(),
+            &StatusView::default(),
+            None,
+        )?;
+        assert_eq!(store.load_files()?, test_files());
+        assert_eq!(store.load_snapshot(), Some(snapshot.clone()));
+        assert!(store.load_status().is_none());
+        assert!(store.load_view().is_all());
+
+        let view = StatusView {
+            groups: vec![StatusGroup::Staged],
+            pathspecs: vec!["src".to_string()],
+        };
+        store.save_file_list(&test_files(), snapshot, &view, None)?;
+        assert_eq!(store.load_view(), view);
+        Ok(())
+    }
+
+    #[test]
+    fn test_unwritable_dir_falls_back_to_memory() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        // A regular file where the cache directory should be can't be written,
+        // even with elevated permissions
+        let blocker = temp_dir.path().join("blocker");
+        fs::write(&blocker, "")?;
+        let store = CacheStore::with_dir(blocker.join("cache"), Path::new("/test/repo/.git"));
+
+        store.save_files(&test_files())?;
+
+        assert!(!store.dir().exists());
+        assert_eq!(store.load_files()?, test_files());
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_files_corrupted_json() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let store = test_store(&temp_dir);
+        fs::create_dir_all(store.dir())?;
+        fs::write(store.dir().join("files.json"), "{ invalid json")?;
+
+        assert!(matches!(
+            store.load_files(),
+            Err(GitNavigatorError::CacheParseFailed { .. })
+        ));
+
+        // The broken file is set aside, so the next read finds no cache
+        assert!(!store.dir().join("files.json").exists());
+        let quarantined: Vec<String> = fs::read_dir(store.dir())?
+            .map(|entry| Ok(entry?.file_name().to_string_lossy().into_owned()))
+            .collect::<std::io::Result<_>>()?;
+        assert_eq!(quarantined.len(), 1);
+        assert!(quarantined[0].starts_with("files.json.corrupt-"));
+        assert!(matches!(
+            store.load_files(),
+            Err(GitNavigatorError::CacheFileNotFound { .. })
+        ));
+        Ok(())
+    }
+
+    /// Write a branch cache that claims to be `age` old
+    fn write_aged_branches(store: &CacheStore, age: Duration) -> Result<Vec<BranchEntry>> {
+        let mut cache = StateCache::new(PathBuf::from("/test/repo/.git"));
+        cache.branches = vec![BranchEntry {
+            index: 1,
+            name: "old".to_string(),
+            is_current: false,
+        }];
+        cache.last_updated = SystemTime::now() - age;
+        fs::create_dir_all(store.dir())?;
+        fs::write(
+            store.dir().join(BRANCHES_CACHE),
+            serde_json::to_string(&cache).unwrap(),
+        )?;
+        Ok(cache.branches)
+    }
+
+    #[test]
+    fn test_cache_ttl_from_settings() -> Result<()> {
+        let ttl = CacheTtl::from_settings(&Settings::parse(
+            "[cache]\nttl_minutes = 90\nexpired = \"refresh\"",
+        )?);
+        assert_eq!(ttl.max_age, Some(Duration::from_secs(90 * 60)));
+        assert!(ttl.refresh);
+        assert_eq!(
+            CacheTtl::from_settings(&Settings::parse("[cache]\nttl_minutes = 0")?),
+            CacheTtl::default()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_expired_cache() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let ttl = CacheTtl {
+            max_age: Some(Duration::from_secs(3600)),
+            refresh: false,
+        };
+        let store = test_store(&temp_dir).with_ttl(ttl);
+
+        let branches = write_aged_branches(&store, Duration::from_secs(600))?;
+        assert_eq!(store.load_branches()?, branches);
+
+        write_aged_branches(&store, Duration::from_secs(7200))?;
+        match store.load_branches().unwrap_err() {
+            GitNavigatorError::CacheExpired { age, .. } => assert_eq!(age, "2h"),
+            error => panic!("Expected CacheExpired error, got: {error}"),
+        }
+        assert!(matches!(
+            store.load_or_rebuild(CacheStore::load_branches, CacheStore::save_branches, || {
+                panic!("rebuilt without cache.expired = refresh")
+            }),
+            Err(GitNavigatorError::CacheExpired { .. })
+        ));
+
+        // Ages are reported whether or not the list expired
+        let ages = store.file_ages();
+        assert_eq!(ages.len(), 1);
+        assert_eq!(ages[0].0, BRANCHES_CACHE);
+        assert!(ages[0].1 >= Duration::from_secs(7200));
+        Ok(())
+    }
+
+    #[test]
+    fn test_expired_cache_refresh() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let ttl = CacheTtl {
+            max_age: Some(Duration::from_secs(3600)),
+            refresh: true,
+        };
+        let store = test_store(&temp_dir).with_ttl(ttl);
+        write_aged_branches(&store, Duration::from_secs(7200))?;
+        let fresh = vec![BranchEntry {
+            index: 1,
+            name: "fresh".to_string(),
+            is_current: false,
+        }];
+
+        // The expired list differs, so the rebuilt one is saved but refused
+        assert!(matches!(
+            store.load_or_rebuild(CacheStore::load_branches, CacheStore::save_branches, || {
+                Ok(fresh.clone())
+            }),
+            Err(GitNavigatorError::CacheRebuilt { .. })
+        ));
+        assert_eq!(
+            store
+                .clone()
+                .with_ttl(CacheTtl::default())
+                .load_branches()?,
+            fresh
+        );
+
+        // Rebuilding an unchanged list carries on with it
+        let temp_dir = TempDir::new()?;
+        let store = test_store(&temp_dir).with_ttl(ttl);
+        let branches = write_aged_branches(&store, Duration::from_secs(7200))?;
+        let loaded =
+            store.load_or_rebuild(CacheStore::load_branches, CacheStore::save_branches, || {
+                Ok(branches.clone())
+            })?;
+        assert_eq!(loaded, branches);
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_or_rebuild_corrupted_cache() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let store = test_store(&temp_dir);
+        fs::create_dir_all(store.dir())?;
+        fs::write(store.dir().join("branches.json"), "{ invalid json")?;
+        let branches = vec![BranchEntry {
+            index: 1,
+            name: "feature".to_string(),
+            is_current: false,
+        }];
+
+        // Saved again, but the lookup is refused
+        assert!(matches!(
+            store.load_or_rebuild(CacheStore::load_branches, CacheStore::save_branches, || {
+                Ok(branches.clone())
+            }),
+            Err(GitNavigatorError::CacheRebuilt { .. })
+        ));
+        assert_eq!(store.load_branches()?, branches);
+
+        // A readable cache is used as-is
+        let loaded =
+            store.load_or_rebuild(CacheStore::load_branches, CacheStore::save_branches, || {
+                panic!("rebuilt a readable cache")
+            })?;
+        assert_eq!(loaded, branches);
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_files_empty_files() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let store = test_store(&temp_dir);
+        store.save_files(&[])?;
+
+        assert!(matches!(
+            store.load_files(),
+            Err(GitNavigatorError::NoCachedFiles)
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+//! Code owners from a `CODEOWNERS` file.
+//!
+//! The file is looked up where GitHub and GitLab look for it: `.github/`,
+//! the repository root, `docs/` and `.gitlab/`, first match wins. Each line
+//! is a gitignore-style pattern followed by owners; the last matching line
+//! decides a path's owners, and a pattern without owners leaves it unowned.
+//! GitLab `[Section]` headers are skipped.
+
+use regex::Regex;
+use std::path::Path;
+
+/// Where `CODEOWNERS` is looked for, in order
+pub const CODEOWNERS_LOCATIONS: [&str; 4] = [
+    ".github/CODEOWNERS",
+    "CODEOWNERS",
+    "docs/CODEOWNERS",
+    ".gitlab/CODEOWNERS",
+];
+
+#[derive(Debug, Clone)]
+struct Rule {
+    pattern: Regex,
+    owners: Vec<String>,
+}
+
+/// Parsed `CODEOWNERS` rules
+#[derive(Debug, Clone, Default)]
+pub struct CodeOwners {
+    rules: Vec<Rule>,
+}
+
+impl CodeOwners {
+    /// Load the first `CODEOWNERS` file found under `workdir`
+    pub fn load(workdir: &Path) -> Option<Self> {
+        CODEOWNERS_LOCATIONS.iter().find_map(|location| {
+            let content = std::fs::read_to_string(workdir.join(location)).ok()?;
+            Some(Self::parse(&content))
+        })
+    }
+
+    pub fn parse(content: &str) -> Self {
+        let rules = content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#') && !line.starts_with('['))
+            .filter_map(|line| {
+                let mut parts = line.split_whitespace();
+                let pattern = parts.next()?;
+                let owners = parts
+                    .take_while(|part| !part.starts_with('#'))
+                    .map(str::to_string)
+                    .collect();
+                match Regex::new(&pattern_regex(pattern)) {
+                    Ok(pattern) => Some(Rule { pattern, owners }),
+                    Err(e) => {
+                        log::debug!("Skipping CODEOWNERS pattern '{pattern}': {e}");
+                        None
+                    }
+                }
+            })
+            .collect();
+        Self { rules }
+    }
+
+    /// Owners of a repository-relative path; empty when nobody owns it
+    pub fn owners(&self, path: &Path) -> &[String] {
+        let path = path.to_string_lossy().replace('\\', "/");
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| rule.pattern.is_match(&path))
+            .map_or(&[], |rule| &rule.owners)
+    }
+
+    /// Whether `owner` (with or without the leading `@`) owns `path`
+    pub fn is_owned_by(&self, path: &Path, owner: &str) -> bool {
+        let wanted = owner.trim_start_matches('@');
+        self.owners(path).iter().any(|candidate| {
+            candidate
+                .trim_start_matches('@')
+                .eq_ignore_ascii_case(wanted)
+        })
+    }
+}
+
+/// Regex matching the paths a gitignore-style pattern covers
+///
+/// Patterns without a slash (other than a trailing one) match at any depth;
+/// a pattern naming a directory covers everything below it.
+fn pattern_regex(pattern: &str) -> String {
+    let dir_only = pattern.ends_with('/');
+    let trimmed = pattern.trim_end_matches('/');
+    let anchored = trimmed.contains('/');
+    let trimmed = trimmed.trim_start_matches('/');
+
+    let mut regex = String::from(if anchored { "^" } else { "^(?:.*/)?" });
+    let mut chars = trimmed.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    regex.push_str("(?:.*/)?");
+                } else {
+                    regex.push_str(".*");
+                }
+            }
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    regex.push_str(if dir_only { "/.*$" } else { "(?:/.*)?$" });
+    regex
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CODEOWNERS: &str = "\
+# Default owners
+*                 @org/everyone
+*.rs              @org/rust
+/docs/            @org/docs docs@example.com
+src/core/         @org/core # the engine
+build/**/out.txt  @org/build
+[Frontend]
+web/              @org/web
+web/vendor/
+";
+
+    fn owners_of(path: &str) -> Vec<String> {
+        CodeOwners::parse(CODEOWNERS)
+            .owners(Path::new(path))
+            .to_vec()
+    }
+
+    #[test]
+    fn test_last_matching_rule_wins() {
+        assert_eq!(owners_of("README.md"), vec!["@org/everyone"]);
+        assert_eq!(owners_of("src/main.rs"), vec!["@org/rust"]);
+        assert_eq!(owners_of("src/core/git.rs"), vec!["@org/core"]);
+        assert_eq!(owners_of("src/core/sub/x.txt"), vec!["@org/core"]);
+        assert_eq!(owners_of("web/app.js"), vec!["@org/web"]);
+        assert!(owners_of("web/vendor/lib.js").is_empty());
+    }
+
+    #[test]
+    fn test_pattern_anchoring() {
+        assert_eq!(
+            owners_of("docs/guide.md"),
+            vec!["@org/docs", "docs@example.com"]
+        );
+        // "/docs/" is anchored at the root
+        assert_eq!(owners_of("src/docs/guide.md"), vec!["@org/everyone"]);
+        assert_eq!(owners_of("build/a/b/out.txt"), vec!["@org/build"]);
+        assert_eq!(owners_of("build/out.txt"), vec!["@org/build"]);
+        assert_eq!(owners_of("other/build/out.txt"), vec!["@org/everyone"]);
+    }
+
+    #[test]
+    fn test_is_owned_by() {
+        let owners = CodeOwners::parse(CODEOWNERS);
+        assert!(owners.is_owned_by(Path::new("src/core/git.rs"), "org/core"));
+        assert!(owners.is_owned_by(Path::new("src/core/git.rs"), "@ORG/core"));
+        assert!(!owners.is_owned_by(Path::new("src/main.rs"), "org/core"));
+    }
+}
@@ -18,23 +18,92 @@
 //! - **Renamed/Copied**: Blue for file operations
 //! - **Untracked**: Cyan for new untracked files
 //! - **Unmerged**: Red bold for conflict resolution needed
+//!
+//! # Overrides
+//! Each status color can be replaced from settings with a git-style spec
+//! (see [`crate::core::style`]), e.g. `color.status.modified = "bold yellow"`.
+//! Keys: `modified`, `added`, `deleted`, `renamed`, `copied`, `typechanged`,
+//! `untracked`, `unmerged`, plus git's `changed` (modified) and `updated`
+//! (added) names.
 
+use crate::core::config::Settings;
+use crate::core::error::{GitNavigatorError, Result};
 use crate::core::git_status::GitStatus;
+use crate::core::style::Style;
 use colored::*;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+static STATUS_STYLE_OVERRIDES: OnceLock<HashMap<GitStatus, Style>> = OnceLock::new();
+
+/// Built-in style for a git status
+pub fn default_status_style(status: GitStatus) -> Style {
+    match status {
+        GitStatus::Modified => Style::fg(Color::Yellow),
+        GitStatus::Untracked => Style::fg(Color::Cyan),
+        GitStatus::Deleted => Style::fg(Color::Red),
+        GitStatus::Added => Style::fg(Color::Green),
+        GitStatus::Renamed => Style::fg(Color::Blue),
+        GitStatus::Copied => Style::fg(Color::Blue),
+        GitStatus::TypeChanged => Style::fg(Color::Magenta),
+        GitStatus::Unmerged => Style::fg(Color::Red).bold(),
+        GitStatus::Submodule(_) => Style::fg(Color::Magenta),
+    }
+}
+
+/// Parse the `color.status.*` overrides from settings
+pub fn status_styles_from_settings(settings: &Settings) -> Result<HashMap<GitStatus, Style>> {
+    const KEYS: &[(&str, GitStatus)] = &[
+        ("changed", GitStatus::Modified),
+        ("modified", GitStatus::Modified),
+        ("updated", GitStatus::Added),
+        ("added", GitStatus::Added),
+        ("deleted", GitStatus::Deleted),
+        ("renamed", GitStatus::Renamed),
+        ("copied", GitStatus::Copied),
+        ("typechanged", GitStatus::TypeChanged),
+        ("untracked", GitStatus::Untracked),
+        ("unmerged", GitStatus::Unmerged),
+    ];
+
+    let mut styles = HashMap::new();
+    for (name, status) in KEYS {
+        let key = format!("color.status.{name}");
+        if let Some(spec) = settings.get_str(&key) {
+            let style = Style::parse(spec)
+                .map_err(|e| GitNavigatorError::config_error(format!("{key}: {e}")))?;
+            styles.insert(*status, style);
+        }
+    }
+    Ok(styles)
+}
+
+/// Install the `color.status.*` overrides for the rest of the process
+///
+/// Only the first call takes effect. An invalid spec is reported and the
+/// built-in colors are kept.
+pub fn configure_status_colors(settings: &Settings) {
+    match status_styles_from_settings(settings) {
+        Ok(styles) => {
+            let _ = STATUS_STYLE_OVERRIDES.set(styles);
+        }
+        Err(e) => log::warn!("Ignoring status colors: {e}"),
+    }
+}
+
+/// Effective style for a git status, including configured overrides
+pub fn status_style(status: GitStatus) -> Style {
+    STATUS_STYLE_OVERRIDES
+        .get()
+        .and_then(|styles| styles.get(&status).copied())
+        .unwrap_or_else(|| default_status_style(status))
+}
 
 /// Single function to apply color styling based on git status
 /// Returns a closure that can be applied to any text to get the appropriate color
 pub fn get_status_color_style(status: GitStatus) -> Box<dyn Fn(&str) -> ColoredString> {
-    match status {
-        GitStatus::Modified => Box::new(|text: &str| text.yellow()),
-        GitStatus::Untracked => Box::new(|text: &str| text.cyan()),
-        GitStatus::Deleted => Box::new(|text: &str| text.red()),
-        GitStatus::Added => Box::new(|text: &str| text.green()),
-        GitStatus::Renamed => Box::new(|text: &str| text.blue()),
-        GitStatus::Copied => Box::new(|text: &str| text.blue()),
-        GitStatus::TypeChanged => Box::new(|text: &str| text.magenta()),
-        GitStatus::Unmerged => Box::new(|text: &str| text.red().bold()),
-    }
+    let style = status_style(status);
+    Box::new(move |text: &str| style.apply(text))
 }
 
 /// Legacy function for string-based status (backward compatibility during migration)
@@ -159,6 +228,43 @@ mod tests {
         assert!(legend_status.to_string().contains("M "));
     }
 
+    #[test]
+    fn test_default_styles_match_color_scheme() {
+        assert_eq!(
+            get_status_color_style(GitStatus::Unmerged)("UU").to_string(),
+            "UU".red().bold().to_string()
+        );
+        assert_eq!(
+            get_status_color_style(GitStatus::Untracked)("??").to_string(),
+            "??".cyan().to_string()
+        );
+    }
+
+    #[test]
+    fn test_status_styles_from_settings() {
+        let settings =
+            Settings::parse("[color.status]\nmodified = \"bold yellow\"\nupdated = \"208\"\n")
+                .unwrap();
+        let styles = status_styles_from_settings(&settings).unwrap();
+
+        assert_eq!(
+            styles.get(&GitStatus::Modified),
+            Some(&Style::fg(Color::Yellow).bold())
+        );
+        assert_eq!(
+            styles.get(&GitStatus::Added),
+            Some(&Style::fg(Color::AnsiColor(208)))
+        );
+        assert!(!styles.contains_key(&GitStatus::Deleted));
+    }
+
+    #[test]
+    fn test_status_styles_invalid_spec() {
+        let settings = Settings::parse("[color.status]\nmodified = \"glittery\"\n").unwrap();
+        let err = status_styles_from_settings(&settings).unwrap_err();
+        assert!(err.to_string().contains("color.status.modified"));
+    }
+
     #[test]
     fn test_status_color_style_consistency() {
         // Test that the color style function returns consistent results
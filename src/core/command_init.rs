@@ -13,21 +13,27 @@
 //! 1. **Git repository validation**: Ensure we're in a valid git repository
 //! 2. **Cache loading**: Load previously cached file list from `gs` command
 //! 3. **File validation**: Ensure files are available to operate on
-//! 4. **Index parsing**: Parse and validate user-provided indices
+//! 4. **Index parsing**: Parse and validate user-provided indices and paths
+//! 5. **Staleness check**: Rescan when the worktree changed since the list
+//!    was saved (see [`refresh_if_stale`])
+//!
+//! A `files.json` that fails to parse is rebuilt from a status scan (see
+//! [`load_cached_files`]), so a torn cache only costs one more `gs`.
 //!
 //! # Error Handling
 //! - **Custom messages**: Support for command-specific error messages
 //! - **Comprehensive validation**: All failure modes are handled gracefully
 //! - **User guidance**: Error messages guide users to run `gs` first
 
-use crate::commands::status::load_files_cache;
 use crate::core::{
     args_parser::ArgsParser,
+    cache::CacheStore,
     error::{GitNavigatorError, Result},
     git::GitRepo,
-    state::FileEntry,
+    state::{CacheSnapshot, FileEntry},
 };
 use std::env;
+use std::path::Path;
 
 /// Initialization context for commands that work with file indices
 pub struct IndexCommandContext {
@@ -70,7 +76,7 @@ impl IndexCommandInit {
 
         // Step 2: Load cached files from previous gs command
         log::debug!("Loading cached files for index-based command");
-        let files = load_files_cache(&git_repo.get_repo_path()).map_err(|e| {
+        let files = load_cached_files(&git_repo).map_err(|e| {
             log::warn!("Failed to load cache: {e}");
             GitNavigatorError::cache_load_error(e)
         })?;
@@ -80,8 +86,13 @@ impl IndexCommandInit {
             return Err(GitNavigatorError::NoAvailableFiles);
         }
 
-        // Step 4: Parse and validate indices using the centralized parser
-        let indices = ArgsParser::parse_indices(indices_args, files.len())?;
+        // Step 4: Parse and validate indices and paths using the centralized parser
+        let selection = ArgsParser::parse_selection(indices_args, &files, &git_repo)?;
+
+        // Step 5: Make sure the indices still point at what gs showed, and
+        // find files that changed since at the paths given
+        let files = refresh_if_stale(&git_repo, files, &selection.indices)?;
+        let indices = selection.resolve(&files)?;
 
         log::debug!(
             "Successfully initialized index command with {} files and {} selected indices",
@@ -102,6 +113,18 @@ impl IndexCommandInit {
         indices_args: Vec<String>,
         cache_error_msg: &str,
         empty_files_msg: &str,
+    ) -> Result<IndexCommandContext> {
+        let current_dir = env::current_dir()?;
+        Self::initialize_in(&current_dir, indices_args, cache_error_msg, empty_files_msg)
+    }
+
+    /// Like [`Self::initialize_with_messages`], for the repository containing
+    /// `repo_dir` rather than the current directory
+    pub fn initialize_in(
+        repo_dir: &Path,
+        indices_args: Vec<String>,
+        cache_error_msg: &str,
+        empty_files_msg: &str,
     ) -> Result<IndexCommandContext> {
         // NEW: Step 0: Check if no indices provided
         if indices_args.is_empty() {
@@ -109,12 +132,11 @@ impl IndexCommandInit {
         }
 
         // Step 1: Check if we're in a git repository
-        let current_dir = env::current_dir()?;
-        let git_repo = GitRepo::open(&current_dir).map_err(|_| GitNavigatorError::NotInGitRepo)?;
+        let git_repo = GitRepo::open(repo_dir).map_err(|_| GitNavigatorError::NotInGitRepo)?;
 
         // Step 2: Load cached files from previous gs command
         log::debug!("Loading cached files for index-based command with custom messages");
-        let files = load_files_cache(&git_repo.get_repo_path()).map_err(|e| {
+        let files = load_cached_files(&git_repo).map_err(|e| {
             log::warn!("Failed to load cache: {e}");
             GitNavigatorError::custom_cache_error(cache_error_msg, e)
         })?;
@@ -124,8 +146,13 @@ impl IndexCommandInit {
             return Err(GitNavigatorError::custom_empty_files_error(empty_files_msg));
         }
 
-        // Step 4: Parse and validate indices using the centralized parser
-        let indices = ArgsParser::parse_indices(indices_args, files.len())?;
+        // Step 4: Parse and validate indices and paths using the centralized parser
+        let selection = ArgsParser::parse_selection(indices_args, &files, &git_repo)?;
+
+        // Step 5: Make sure the indices still point at what gs showed, and
+        // find files that changed since at the paths given
+        let files = refresh_if_stale(&git_repo, files, &selection.indices)?;
+        let indices = selection.resolve(&files)?;
 
         log::debug!(
             "Successfully initialized index command with {} files and {} selected indices",
@@ -167,6 +194,88 @@ impl IndexCommandContext {
     }
 }
 
+/// Load the file list saved by the last `gs`
+///
+/// A cache file that no longer parses (cut short by a crash or a full disk)
+/// is replaced by a fresh scan limited like the last `gs` (`gs --staged`,
+/// `gs src/`). The indices typed were read off the lost list, so the command
+/// still fails with [`GitNavigatorError::CacheRebuilt`] for the user to run
+/// `gs` and look at the new numbers.
+pub fn load_cached_files(git_repo: &GitRepo) -> Result<Vec<FileEntry>> {
+    let store = git_repo.cache_store()?;
+    let view = store.load_view();
+    store.load_or_rebuild(
+        CacheStore::load_files,
+        |_, files| git_repo.save_file_list_in_view(files, &view),
+        || git_repo.get_status_in(&view),
+    )
+}
+
+/// Compare the cached list with a fresh status scan
+///
+/// When files changed since the list was saved, the fresh list is saved in
+/// its place. The command then goes ahead on it only if every requested
+/// index still names the same file; otherwise it fails with
+/// [`GitNavigatorError::StaleFileList`] instead of acting on a file the user
+/// never saw under that number. With `status.fast` on, a matching
+/// fingerprint is trusted without a scan, as `gs` does.
+pub fn refresh_if_stale(
+    git_repo: &GitRepo,
+    files: Vec<FileEntry>,
+    indices: &[usize],
+) -> Result<Vec<FileEntry>> {
+    let store = git_repo.cache_store()?;
+    let fast = git_repo
+        .load_settings()
+        .get_bool("status.fast")
+        .unwrap_or(false);
+    if fast {
+        if let Some((_, fingerprint)) = store.load_status() {
+            if git_repo.status_fingerprint(&files)? == fingerprint {
+                return Ok(files);
+            }
+        }
+    }
+
+    let snapshot = store.load_snapshot();
+    let cached_hash = snapshot.as_ref().map_or_else(
+        || CacheSnapshot::status_hash(&files),
+        |snapshot| snapshot.status_hash.clone(),
+    );
+    // A list limited to part of the status (gs --staged, gs src/) stays so
+    let view = store.load_view();
+    let current = git_repo.get_status_in(&view)?;
+    if CacheSnapshot::status_hash(&current) == cached_hash {
+        return Ok(files);
+    }
+
+    let reason = match &snapshot {
+        Some(snapshot) => snapshot.describe_change(&git_repo.cache_snapshot(&current)),
+        None => "files changed",
+    };
+    log::debug!("Cached file list is stale: {reason}");
+    if let Err(e) = git_repo.save_file_list_in_view(&current, &view) {
+        log::warn!("Cache save failed: {e}");
+    }
+
+    for &index in indices {
+        let was = &files[index - 1];
+        match current.get(index - 1) {
+            Some(now) if now.path == was.path => {}
+            now => {
+                return Err(GitNavigatorError::StaleFileList {
+                    reason: reason.to_string(),
+                    index,
+                    was: was.path.display().to_string(),
+                    now: now
+                        .map_or_else(|| "gone".to_string(), |now| now.path.display().to_string()),
+                })
+            }
+        }
+    }
+    Ok(current)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -227,18 +336,20 @@ mod tests {
     #[test]
     fn test_context_methods() {
         // Create a mock context for testing helper methods
-        let files = vec![
+        let files = [
             FileEntry {
                 index: 1,
                 status: crate::core::git_status::GitStatus::Modified,
                 path: "file1.txt".into(),
                 staged: false,
+                old_path: None,
             },
             FileEntry {
                 index: 2,
                 status: crate::core::git_status::GitStatus::Added,
                 path: "file2.txt".into(),
                 staged: true,
+                old_path: None,
             },
         ];
 
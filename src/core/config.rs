@@ -1,4 +1,5 @@
-use std::path::PathBuf;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use serde::{Deserialize, Serialize};
 use crate::core::error::GitNavigatorError;
 use crate::core::dirs::get_config_directory;
@@ -20,7 +21,7 @@ impl Default for RepositoryConfig {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Default)]
 pub struct UpdateConfig {
     pub last_check: Option<chrono::DateTime<chrono::Utc>>,
     pub auto_check_enabled: bool,
@@ -79,12 +80,317 @@ impl InstallConfig {
     }
 }
 
-impl Default for UpdateConfig {
-    fn default() -> Self {
-        Self {
-            last_check: None,
-            auto_check_enabled: false,
-            skip_version: None,
+/// Per-repository settings file at the worktree root
+pub const REPO_SETTINGS_FILE: &str = ".git-navigator.toml";
+
+/// A single value read from a settings file
+#[derive(Debug, Clone, PartialEq)]
+pub enum SettingValue {
+    String(String),
+    Bool(bool),
+    Integer(i64),
+    Float(f64),
+    List(Vec<String>),
+}
+
+/// User settings read from `config.toml` in the config directory
+///
+/// The file is TOML. Keys are flattened git-config style, so
+/// `autostash = true` under `[checkout]` is looked up as `checkout.autostash`;
+/// inline tables flatten the same way. Lists keep their items as text.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Settings {
+    values: BTreeMap<String, SettingValue>,
+}
+
+impl Settings {
+    /// Load the global settings file, falling back to defaults
+    ///
+    /// A missing file is not an error. An unreadable or malformed file is
+    /// logged and ignored so a typo never blocks everyday commands.
+    pub fn load() -> Self {
+        match get_config_directory() {
+            Ok(dir) => Self::load_or_default(&dir.join("config.toml")),
+            Err(e) => {
+                log::warn!("Could not determine config directory: {e}");
+                Self::default()
+            }
+        }
+    }
+
+    /// Load the global settings overlaid with the repository's settings
+    ///
+    /// Layers, lowest precedence first:
+    /// 1. the global `config.toml`
+    /// 2. [`REPO_SETTINGS_FILE`] at the worktree root, usually committed
+    /// 3. `git-navigator/config.toml` inside the git directory, private to the clone
+    ///
+    /// Each layer is loaded like [`Settings::load`]: missing or broken files
+    /// are skipped.
+    pub fn load_layered(git_dir: &Path, workdir: Option<&Path>) -> Self {
+        let mut settings = Self::load();
+        if let Some(workdir) = workdir {
+            settings.overlay(Self::load_or_default(&workdir.join(REPO_SETTINGS_FILE)));
+        }
+        settings.overlay(Self::load_or_default(
+            &git_dir.join("git-navigator").join("config.toml"),
+        ));
+        settings
+    }
+
+    /// Load the layered settings for the repository around the current
+    /// directory, or just the global settings outside a repository
+    pub fn discover() -> Self {
+        match git2::Repository::discover(".") {
+            Ok(repo) => Self::load_layered(repo.path(), repo.workdir()),
+            Err(_) => Self::load(),
+        }
+    }
+
+    /// Override these settings with every key set in `other`
+    pub fn overlay(&mut self, other: Settings) {
+        self.values.extend(other.values);
+    }
+
+    fn load_or_default(path: &Path) -> Self {
+        match Self::load_from(path) {
+            Ok(settings) => settings,
+            Err(e) => {
+                log::warn!("Ignoring settings file '{}': {e}", path.display());
+                Self::default()
+            }
+        }
+    }
+
+    /// Load settings from a specific file; a missing file yields defaults
+    pub fn load_from(path: &Path) -> Result<Self, GitNavigatorError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)?;
+        Self::parse(&content)
+    }
+
+    /// Parse settings from TOML text
+    pub fn parse(content: &str) -> Result<Self, GitNavigatorError> {
+        let table: toml::Table = toml::from_str(content).map_err(|e| {
+            let message = e.message().trim_end();
+            match e.span() {
+                Some(span) => {
+                    let line = content[..span.start].matches('\n').count() + 1;
+                    GitNavigatorError::config_error(format!("line {line}: {message}"))
+                }
+                None => GitNavigatorError::config_error(message),
+            }
+        })?;
+
+        let mut values = BTreeMap::new();
+        flatten("", table, &mut values);
+        Ok(Self { values })
+    }
+
+    /// Get the raw value for a flattened key
+    pub fn get(&self, key: &str) -> Option<&SettingValue> {
+        self.values.get(key)
+    }
+
+    /// Get a string value
+    pub fn get_str(&self, key: &str) -> Option<&str> {
+        match self.values.get(key) {
+            Some(SettingValue::String(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Get a boolean value
+    pub fn get_bool(&self, key: &str) -> Option<bool> {
+        match self.values.get(key) {
+            Some(SettingValue::Bool(value)) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Get an integer value
+    pub fn get_int(&self, key: &str) -> Option<i64> {
+        match self.values.get(key) {
+            Some(SettingValue::Integer(value)) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Get a list value; a single string is treated as a one-element list
+    pub fn get_list(&self, key: &str) -> Option<Vec<String>> {
+        match self.values.get(key) {
+            Some(SettingValue::List(values)) => Some(values.clone()),
+            Some(SettingValue::String(value)) => Some(vec![value.clone()]),
+            _ => None,
+        }
+    }
+}
+
+/// Add every value under `table` to `values`, with keys flattened to
+/// `prefix.key`; nested and inline tables go one level deeper each
+fn flatten(prefix: &str, table: toml::Table, values: &mut BTreeMap<String, SettingValue>) {
+    for (key, value) in table {
+        let key = if prefix.is_empty() {
+            key
+        } else {
+            format!("{prefix}.{key}")
+        };
+        let value = match value {
+            toml::Value::Table(table) => {
+                flatten(&key, table, values);
+                continue;
+            }
+            toml::Value::String(value) => SettingValue::String(value),
+            toml::Value::Boolean(value) => SettingValue::Bool(value),
+            toml::Value::Integer(value) => SettingValue::Integer(value),
+            toml::Value::Float(value) => SettingValue::Float(value),
+            toml::Value::Datetime(value) => SettingValue::String(value.to_string()),
+            toml::Value::Array(items) => {
+                SettingValue::List(items.into_iter().filter_map(list_item).collect())
+            }
+        };
+        values.insert(key, value);
+    }
+}
+
+/// A list item as text: strings as they are, other scalars as written in
+/// TOML; tables have no text form and are left out
+fn list_item(item: toml::Value) -> Option<String> {
+    match item {
+        toml::Value::String(value) => Some(value),
+        toml::Value::Table(_) | toml::Value::Array(_) => {
+            log::debug!("Skipping a non-scalar list item in the settings");
+            None
         }
+        other => Some(other.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tables_and_values() -> Result<(), GitNavigatorError> {
+        let settings = Settings::parse(
+            r#"
+# Global options
+verbose = true
+
+[checkout]
+autostash = true   # stash before switching
+
+[branch]
+template = "feature/{slug}"
+max = 3
+
+[commit]
+scopes = ["core", 'cli']
+"#,
+        )?;
+
+        assert_eq!(settings.get_bool("verbose"), Some(true));
+        assert_eq!(settings.get_bool("checkout.autostash"), Some(true));
+        assert_eq!(settings.get_str("branch.template"), Some("feature/{slug}"));
+        assert_eq!(
+            settings.get_list("commit.scopes"),
+            Some(vec!["core".to_string(), "cli".to_string()])
+        );
+        assert_eq!(settings.get_int("branch.max"), Some(3));
+        assert_eq!(settings.get_str("missing"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_dotted_keys_and_escapes() -> Result<(), GitNavigatorError> {
+        let settings = Settings::parse(
+            r#"
+[color]
+status.modified = "bold \"yellow\" # not a comment"
+"#,
+        )?;
+
+        assert_eq!(
+            settings.get_str("color.status.modified"),
+            Some("bold \"yellow\" # not a comment")
+        );
+        Ok(())
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_parse_multiline_arrays_floats_and_inline_tables() -> Result<(), GitNavigatorError> {
+        let settings = Settings::parse(
+            r#"
+[packages]
+globs = [
+    "crates/*",
+    "tools/*",  # trailing comma and comments are fine
+]
+
+[status]
+ratio = 0.5
+
+[color]
+status = { modified = "yellow", added = "green" }
+"#,
+        )?;
+
+        assert_eq!(
+            settings.get_list("packages.globs"),
+            Some(vec!["crates/*".to_string(), "tools/*".to_string()])
+        );
+        assert_eq!(
+            settings.get("status.ratio"),
+            Some(&SettingValue::Float(0.5))
+        );
+        assert_eq!(settings.get_str("color.status.modified"), Some("yellow"));
+        assert_eq!(settings.get_str("color.status.added"), Some("green"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_reports_line_number() {
+        let result = Settings::parse("[checkout]\nautostash\n");
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("line 2"), "unexpected error: {err}");
+    }
+
+    #[test]
+    fn test_load_from_missing_file_is_default() -> Result<(), GitNavigatorError> {
+        let settings = Settings::load_from(Path::new("/non/existent/config.toml"))?;
+        assert_eq!(settings, Settings::default());
+        Ok(())
+    }
+
+    #[test]
+    fn test_overlay_overrides_keys() -> Result<(), GitNavigatorError> {
+        let mut settings = Settings::parse("[branch]\nbase = \"main\"\npattern = \"^f/\"\n")?;
+        settings.overlay(Settings::parse("[branch]\nbase = \"develop\"\n")?);
+
+        assert_eq!(settings.get_str("branch.base"), Some("develop"));
+        assert_eq!(settings.get_str("branch.pattern"), Some("^f/"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_layered_prefers_git_dir_file() -> Result<(), GitNavigatorError> {
+        let workdir = tempfile::TempDir::new()?;
+        let git_dir = workdir.path().join(".git");
+        std::fs::create_dir_all(git_dir.join("git-navigator"))?;
+        std::fs::write(
+            workdir.path().join(REPO_SETTINGS_FILE),
+            "[layered_test]\nshared = \"repo\"\nprivate = \"repo\"\n",
+        )?;
+        std::fs::write(
+            git_dir.join("git-navigator/config.toml"),
+            "[layered_test]\nprivate = \"clone\"\n",
+        )?;
+
+        let settings = Settings::load_layered(&git_dir, Some(workdir.path()));
+        assert_eq!(settings.get_str("layered_test.shared"), Some("repo"));
+        assert_eq!(settings.get_str("layered_test.private"), Some("clone"));
+        Ok(())
+    }
+}
@@ -0,0 +1,269 @@
+//! Conventional Commits: parsing subjects like `feat(core)!: add x`.
+//!
+//! Used to group commits into release notes sections, and by the commit
+//! message linter. The allowed types and scopes can be set per repository:
+//!
+//! ```toml
+//! [commit]
+//! types = ["feat", "fix", "docs", "chore"]  # default: the usual eleven
+//! scopes = ["core", "cli"]                  # default: any scope
+//! conventional = true                       # lint every message, not just --type ones
+//! max_subject_length = 72
+//! ```
+
+use crate::core::config::Settings;
+
+/// Commit types allowed when `commit.types` is not set
+pub const DEFAULT_TYPES: [&str; 11] = [
+    "feat", "fix", "docs", "style", "refactor", "perf", "test", "build", "ci", "chore", "revert",
+];
+
+/// Longest subject line the linter accepts by default
+pub const DEFAULT_MAX_SUBJECT_LENGTH: usize = 72;
+
+/// A parsed `type(scope)!: description` subject line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConventionalCommit {
+    pub kind: String,
+    pub scope: Option<String>,
+    pub breaking: bool,
+    pub description: String,
+}
+
+impl ConventionalCommit {
+    /// Parse a commit subject, or `None` if it does not follow the format
+    pub fn parse(subject: &str) -> Option<Self> {
+        let (header, description) = subject.split_once(':')?;
+        let description = description.trim();
+        if description.is_empty() {
+            return None;
+        }
+
+        let (header, breaking) = match header.strip_suffix('!') {
+            Some(header) => (header, true),
+            None => (header, false),
+        };
+        let (kind, scope) = match header.split_once('(') {
+            Some((kind, rest)) => {
+                let scope = rest.strip_suffix(')')?;
+                if scope.is_empty() || scope.contains(['(', ')']) {
+                    return None;
+                }
+                (kind, Some(scope.to_string()))
+            }
+            None => (header, None),
+        };
+        if kind.is_empty() || !kind.chars().all(|c| c.is_ascii_alphabetic()) {
+            return None;
+        }
+
+        Some(Self {
+            kind: kind.to_lowercase(),
+            scope,
+            breaking,
+            description: description.to_string(),
+        })
+    }
+}
+
+/// Build a `type(scope)!: description` subject
+pub fn format_subject(
+    kind: &str,
+    scope: Option<&str>,
+    breaking: bool,
+    description: &str,
+) -> String {
+    let scope = scope
+        .filter(|scope| !scope.is_empty())
+        .map(|scope| format!("({scope})"))
+        .unwrap_or_default();
+    let bang = if breaking { "!" } else { "" };
+    format!("{kind}{scope}{bang}: {}", description.trim())
+}
+
+/// What the commit message linter checks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageRules {
+    pub types: Vec<String>,
+    /// Allowed scopes; empty allows any
+    pub scopes: Vec<String>,
+    /// Require the conventional format and check type and scope
+    pub conventional: bool,
+    pub max_subject_length: usize,
+}
+
+impl MessageRules {
+    /// Read the `[commit]` settings
+    pub fn from_settings(settings: &Settings) -> Self {
+        Self {
+            types: settings
+                .get_list("commit.types")
+                .unwrap_or_else(|| DEFAULT_TYPES.iter().map(|kind| kind.to_string()).collect()),
+            scopes: settings.get_list("commit.scopes").unwrap_or_default(),
+            conventional: settings.get_bool("commit.conventional").unwrap_or(false),
+            max_subject_length: settings
+                .get_int("commit.max_subject_length")
+                .and_then(|length| usize::try_from(length).ok())
+                .unwrap_or(DEFAULT_MAX_SUBJECT_LENGTH),
+        }
+    }
+
+    /// Problems with `message`; empty when it passes
+    pub fn lint(&self, message: &str) -> Vec<String> {
+        let mut problems = Vec::new();
+        let mut lines = message.lines();
+        let subject = lines.next().unwrap_or_default().trim_end();
+
+        if subject.trim().is_empty() {
+            problems.push("the subject line is empty".to_string());
+            return problems;
+        }
+        let length = subject.chars().count();
+        if length > self.max_subject_length {
+            problems.push(format!(
+                "the subject line is {length} characters long (limit {})",
+                self.max_subject_length
+            ));
+        }
+        if lines.next().is_some_and(|line| !line.trim().is_empty()) {
+            problems.push("leave a blank line between the subject and the body".to_string());
+        }
+
+        if !self.conventional {
+            return problems;
+        }
+        let Some(parsed) = ConventionalCommit::parse(subject) else {
+            problems.push("the subject should look like 'type(scope): description'".to_string());
+            return problems;
+        };
+        if !self.types.contains(&parsed.kind) {
+            problems.push(format!(
+                "unknown type '{}' (allowed: {})",
+                parsed.kind,
+                self.types.join(", ")
+            ));
+        }
+        if let Some(scope) = &parsed.scope {
+            if !self.scopes.is_empty() && !self.scopes.contains(scope) {
+                problems.push(format!(
+                    "unknown scope '{scope}' (allowed: {})",
+                    self.scopes.join(", ")
+                ));
+            }
+        }
+        if parsed.description.ends_with('.') {
+            problems.push("drop the period at the end of the description".to_string());
+        }
+        problems
+    }
+}
+
+/// Release notes sections in the order they are printed, with the types they collect
+pub const SECTIONS: [(&str, &[&str]); 9] = [
+    ("Features", &["feat", "feature"]),
+    ("Bug Fixes", &["fix", "bugfix"]),
+    ("Performance", &["perf"]),
+    ("Refactoring", &["refactor"]),
+    ("Documentation", &["docs", "doc"]),
+    ("Tests", &["test", "tests"]),
+    ("Build and CI", &["build", "ci"]),
+    ("Chores", &["chore", "style", "revert"]),
+    ("Other", &[]),
+];
+
+/// Section a commit type belongs to; unknown types go to "Other"
+pub fn section_for(kind: &str) -> &'static str {
+    SECTIONS
+        .iter()
+        .find(|(_, kinds)| kinds.contains(&kind))
+        .map_or("Other", |(section, _)| section)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_subjects() {
+        assert_eq!(
+            ConventionalCommit::parse("feat(core)!: drop the old cache format"),
+            Some(ConventionalCommit {
+                kind: "feat".to_string(),
+                scope: Some("core".to_string()),
+                breaking: true,
+                description: "drop the old cache format".to_string(),
+            })
+        );
+        assert_eq!(
+            ConventionalCommit::parse("Fix: handle empty repos"),
+            Some(ConventionalCommit {
+                kind: "fix".to_string(),
+                scope: None,
+                breaking: false,
+                description: "handle empty repos".to_string(),
+            })
+        );
+
+        assert_eq!(ConventionalCommit::parse("Add parser"), None);
+        assert_eq!(ConventionalCommit::parse("feat:"), None);
+        assert_eq!(ConventionalCommit::parse("feat(): empty scope"), None);
+        assert_eq!(ConventionalCommit::parse("Merge branch 'x': y"), None);
+    }
+
+    fn rules(toml: &str) -> MessageRules {
+        MessageRules::from_settings(&Settings::parse(toml).unwrap())
+    }
+
+    #[test]
+    fn test_format_subject() {
+        assert_eq!(
+            format_subject("feat", Some("core"), false, " add x "),
+            "feat(core): add x"
+        );
+        assert_eq!(format_subject("fix", None, true, "y"), "fix!: y");
+        assert_eq!(format_subject("fix", Some(""), false, "y"), "fix: y");
+    }
+
+    #[test]
+    fn test_lint_plain_messages() {
+        let rules = rules("");
+        assert!(rules.lint("Add parser\n\nLonger body").is_empty());
+        assert_eq!(
+            rules.lint(&"x".repeat(80)),
+            vec!["the subject line is 80 characters long (limit 72)"]
+        );
+        assert_eq!(
+            rules.lint("Add parser\nno blank line"),
+            vec!["leave a blank line between the subject and the body"]
+        );
+        assert_eq!(rules.lint("  "), vec!["the subject line is empty"]);
+    }
+
+    #[test]
+    fn test_lint_conventional_messages() {
+        let rules = rules(
+            "[commit]\nconventional = true\ntypes = [\"feat\", \"fix\"]\nscopes = [\"core\"]",
+        );
+        assert!(rules.lint("feat(core): add x").is_empty());
+        assert!(rules.lint("fix: y").is_empty());
+        assert_eq!(
+            rules.lint("Add parser"),
+            vec!["the subject should look like 'type(scope): description'"]
+        );
+        assert_eq!(
+            rules.lint("chore(cli): tidy."),
+            vec![
+                "unknown type 'chore' (allowed: feat, fix)",
+                "unknown scope 'cli' (allowed: core)",
+                "drop the period at the end of the description",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_section_for() {
+        assert_eq!(section_for("feat"), "Features");
+        assert_eq!(section_for("ci"), "Build and CI");
+        assert_eq!(section_for("wip"), "Other");
+    }
+}
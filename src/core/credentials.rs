@@ -0,0 +1,237 @@
+//! Credentials for remote operations done through libgit2.
+//!
+//! git itself finds credentials through ssh-agent and `credential.helper`;
+//! libgit2 only does what its credential callback tells it. The callback here
+//! tries, in order and at most once each:
+//!
+//! 1. the username from the URL (SSH asks for it before anything else)
+//! 2. the keys loaded in ssh-agent
+//! 3. a token from the environment: `GIT_NAVIGATOR_TOKEN` for any host,
+//!    `GH_TOKEN`/`GITHUB_TOKEN` for github.com and `GITLAB_TOKEN` for gitlab.com
+//! 4. the configured `credential.helper`
+//! 5. the platform default (Kerberos/NTLM)
+//!
+//! Once the callback has been asked, a failed connection is reported as an
+//! authentication failure rather than a missing or broken remote.
+
+use crate::core::error::GitNavigatorError;
+use git2::{Cred, CredentialType, ErrorClass, ErrorCode, RemoteCallbacks, Repository};
+use std::cell::Cell;
+use std::env;
+
+/// Environment variables holding access tokens, in the order they are tried
+pub const TOKEN_VARIABLES: [&str; 4] = [
+    "GIT_NAVIGATOR_TOKEN",
+    "GH_TOKEN",
+    "GITHUB_TOKEN",
+    "GITLAB_TOKEN",
+];
+
+/// Username and token to send over HTTPS for `url`
+///
+/// `var` looks up an environment variable so the selection can be tested
+/// without touching the process environment.
+pub fn token_for(
+    url: &str,
+    username_from_url: Option<&str>,
+    var: impl Fn(&str) -> Option<String>,
+) -> Option<(String, String)> {
+    let host = url
+        .split_once("://")
+        .map_or(url, |(_, rest)| rest)
+        .split('/')
+        .next()
+        .unwrap_or_default()
+        .rsplit('@')
+        .next()
+        .unwrap_or_default();
+    let host = host.split(':').next().unwrap_or_default();
+
+    let candidates: &[(&str, &str)] = match host {
+        "github.com" => &[
+            ("GIT_NAVIGATOR_TOKEN", "x-access-token"),
+            ("GH_TOKEN", "x-access-token"),
+            ("GITHUB_TOKEN", "x-access-token"),
+        ],
+        "gitlab.com" => &[
+            ("GIT_NAVIGATOR_TOKEN", "oauth2"),
+            ("GITLAB_TOKEN", "oauth2"),
+        ],
+        _ => &[("GIT_NAVIGATOR_TOKEN", "x-access-token")],
+    };
+
+    candidates.iter().find_map(|(name, default_user)| {
+        let token = var(name).filter(|token| !token.is_empty())?;
+        let user = username_from_url.unwrap_or(default_user);
+        Some((user.to_string(), token))
+    })
+}
+
+/// Credential callbacks for one remote operation
+pub struct Credentials {
+    config: Option<git2::Config>,
+    asked: Cell<bool>,
+}
+
+impl Credentials {
+    pub fn new(repo: &Repository) -> Self {
+        Self {
+            config: repo.config().ok(),
+            asked: Cell::new(false),
+        }
+    }
+
+    /// Callbacks to pass to `connect_auth`, `fetch` or `push`
+    pub fn callbacks(&self) -> RemoteCallbacks<'_> {
+        let mut tried = Vec::new();
+        let mut callbacks = RemoteCallbacks::new();
+        callbacks.credentials(move |url, username_from_url, allowed| {
+            self.asked.set(true);
+            let username = username_from_url.unwrap_or("git");
+
+            if allowed.contains(CredentialType::USERNAME) && !tried.contains(&"username") {
+                tried.push("username");
+                return Cred::username(username);
+            }
+            if allowed.contains(CredentialType::SSH_KEY) && !tried.contains(&"ssh-agent") {
+                tried.push("ssh-agent");
+                return Cred::ssh_key_from_agent(username);
+            }
+            if allowed.contains(CredentialType::USER_PASS_PLAINTEXT) {
+                if !tried.contains(&"token") {
+                    tried.push("token");
+                    if let Some((user, token)) =
+                        token_for(url, username_from_url, |name| env::var(name).ok())
+                    {
+                        return Cred::userpass_plaintext(&user, &token);
+                    }
+                }
+                if !tried.contains(&"credential helper") {
+                    tried.push("credential helper");
+                    if let Some(config) = &self.config {
+                        if let Ok(cred) = Cred::credential_helper(config, url, username_from_url) {
+                            return Ok(cred);
+                        }
+                    }
+                }
+            }
+            if allowed.contains(CredentialType::DEFAULT) && !tried.contains(&"default") {
+                tried.push("default");
+                return Cred::default();
+            }
+
+            let methods: Vec<&str> = tried
+                .iter()
+                .copied()
+                .filter(|method| *method != "username")
+                .collect();
+            Err(git2::Error::from_str(&if methods.is_empty() {
+                "no supported credential type offered".to_string()
+            } else {
+                format!("no credentials accepted (tried {})", methods.join(", "))
+            }))
+        });
+        callbacks
+    }
+
+    /// Whether the remote asked for credentials
+    pub fn were_requested(&self) -> bool {
+        self.asked.get()
+    }
+
+    /// Turn a libgit2 error from a remote operation into a domain error
+    pub fn classify(&self, remote: &str, error: git2::Error) -> GitNavigatorError {
+        let reason = error.message().to_string();
+        let auth = error.code() == ErrorCode::Auth
+            || (self.were_requested() && error.class() != ErrorClass::Net);
+
+        if auth {
+            GitNavigatorError::AuthenticationFailed {
+                remote: remote.to_string(),
+                reason,
+            }
+        } else {
+            GitNavigatorError::RemoteAccessFailed {
+                remote: remote.to_string(),
+                reason,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn env_of(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn test_token_for_host() {
+        let env = env_of(&[("GH_TOKEN", "gh"), ("GITLAB_TOKEN", "gl")]);
+
+        assert_eq!(
+            token_for("https://github.com/blitux/git-navigator.git", None, &env),
+            Some(("x-access-token".to_string(), "gh".to_string()))
+        );
+        assert_eq!(
+            token_for("https://me@gitlab.com:443/group/repo.git", Some("me"), &env),
+            Some(("me".to_string(), "gl".to_string()))
+        );
+        assert_eq!(token_for("https://git.example.com/repo", None, &env), None);
+    }
+
+    #[test]
+    fn test_generic_token_wins() {
+        let env = env_of(&[("GIT_NAVIGATOR_TOKEN", "mine"), ("GH_TOKEN", "gh")]);
+
+        assert_eq!(
+            token_for("https://github.com/org/repo", None, &env),
+            Some(("x-access-token".to_string(), "mine".to_string()))
+        );
+        assert_eq!(
+            token_for("https://git.example.com/repo", None, &env),
+            Some(("x-access-token".to_string(), "mine".to_string()))
+        );
+        assert_eq!(
+            token_for(
+                "https://github.com/org/repo",
+                None,
+                env_of(&[("GIT_NAVIGATOR_TOKEN", "")])
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_classify_auth_errors() {
+        let repo_dir = tempfile::TempDir::new().unwrap();
+        let repo = Repository::init(repo_dir.path()).unwrap();
+        let credentials = Credentials::new(&repo);
+
+        let error = git2::Error::new(ErrorCode::Auth, ErrorClass::Http, "401");
+        assert!(matches!(
+            credentials.classify("origin", error),
+            GitNavigatorError::AuthenticationFailed { .. }
+        ));
+
+        let error = git2::Error::new(ErrorCode::GenericError, ErrorClass::Ssh, "denied");
+        assert!(matches!(
+            credentials.classify("origin", error),
+            GitNavigatorError::RemoteAccessFailed { .. }
+        ));
+
+        credentials.asked.set(true);
+        let error = git2::Error::new(ErrorCode::GenericError, ErrorClass::Ssh, "denied");
+        assert!(matches!(
+            credentials.classify("origin", error),
+            GitNavigatorError::AuthenticationFailed { .. }
+        ));
+    }
+}
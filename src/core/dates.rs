@@ -0,0 +1,132 @@
+//! Parsing of `--since` style dates.
+//!
+//! Accepts an absolute `YYYY-MM-DD` (midnight, local time), `today` or a time
+//! of day such as `09:30`, or a relative time the way people type it:
+//! `2 weeks`, `3 days ago`, `2.weeks.ago` or `30d`.
+
+use crate::core::error::{GitNavigatorError, Result};
+use chrono::{Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
+
+const MINUTE: i64 = 60;
+const HOUR: i64 = 60 * MINUTE;
+const DAY: i64 = 24 * HOUR;
+
+/// Unix time `input` refers to, with relative times counted back from `now`
+pub fn parse_since(input: &str, now: i64) -> Result<i64> {
+    let invalid = || GitNavigatorError::InvalidDate(input.to_string());
+    let trimmed = input.trim();
+
+    let local = |time: NaiveDateTime| {
+        Local
+            .from_local_datetime(&time)
+            .earliest()
+            .map(|time| time.timestamp())
+            .ok_or_else(invalid)
+    };
+
+    if let Ok(date) = NaiveDate::parse_from_str(trimmed, "%Y-%m-%d") {
+        return local(date.and_time(NaiveTime::MIN));
+    }
+
+    // "today" and "09:30" count from the local date of `now`
+    let time_of_day = if trimmed.eq_ignore_ascii_case("today") {
+        Some(NaiveTime::MIN)
+    } else {
+        NaiveTime::parse_from_str(trimmed, "%H:%M").ok()
+    };
+    if let Some(time_of_day) = time_of_day {
+        let today = Local
+            .timestamp_opt(now, 0)
+            .single()
+            .ok_or_else(invalid)?
+            .date_naive();
+        return local(today.and_time(time_of_day));
+    }
+
+    // "2.weeks.ago" and "2 weeks ago" mean the same thing
+    let normalized = trimmed.to_lowercase().replace('.', " ");
+    let normalized = normalized.strip_suffix("ago").unwrap_or(&normalized).trim();
+    let split = normalized
+        .find(|c: char| !c.is_ascii_digit())
+        .ok_or_else(invalid)?;
+    let (amount, unit) = normalized.split_at(split);
+    let amount: i64 = amount.parse().map_err(|_| invalid())?;
+
+    let unit_seconds = match unit.trim().trim_end_matches('s') {
+        "" | "sec" | "second" => 1,
+        "m" | "min" | "minute" => MINUTE,
+        "h" | "hour" => HOUR,
+        "d" | "day" => DAY,
+        "w" | "week" => 7 * DAY,
+        "mo" | "month" => 30 * DAY,
+        "y" | "year" => 365 * DAY,
+        _ => return Err(invalid()),
+    };
+    Ok(now - amount * unit_seconds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000;
+
+    #[test]
+    fn test_relative_dates() -> Result<()> {
+        assert_eq!(parse_since("30d", NOW)?, NOW - 30 * DAY);
+        assert_eq!(parse_since("2 weeks", NOW)?, NOW - 14 * DAY);
+        assert_eq!(parse_since("2.weeks.ago", NOW)?, NOW - 14 * DAY);
+        assert_eq!(parse_since("3 days ago", NOW)?, NOW - 3 * DAY);
+        assert_eq!(parse_since("1 year", NOW)?, NOW - 365 * DAY);
+        assert_eq!(parse_since("6mo", NOW)?, NOW - 180 * DAY);
+        assert_eq!(parse_since("45m", NOW)?, NOW - 45 * MINUTE);
+        Ok(())
+    }
+
+    #[test]
+    fn test_absolute_date() -> Result<()> {
+        let expected = Local
+            .with_ymd_and_hms(2024, 3, 1, 0, 0, 0)
+            .earliest()
+            .unwrap()
+            .timestamp();
+        assert_eq!(parse_since("2024-03-01", NOW)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_today_and_time_of_day() -> Result<()> {
+        let today = Local.timestamp_opt(NOW, 0).unwrap().date_naive();
+        let at = |hour, minute| {
+            Local
+                .from_local_datetime(&today.and_hms_opt(hour, minute, 0).unwrap())
+                .earliest()
+                .unwrap()
+                .timestamp()
+        };
+        assert_eq!(parse_since("today", NOW)?, at(0, 0));
+        assert_eq!(parse_since("Today", NOW)?, at(0, 0));
+        assert_eq!(parse_since("09:30", NOW)?, at(9, 30));
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_dates() {
+        for input in [
+            "",
+            "yesterday",
+            "2 fortnights",
+            "weeks",
+            "2024-13-01",
+            "25:00",
+        ] {
+            assert!(
+                matches!(
+                    parse_since(input, NOW),
+                    Err(GitNavigatorError::InvalidDate(_))
+                ),
+                "{input} should be rejected"
+            );
+        }
+    }
+}
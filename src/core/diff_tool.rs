@@ -0,0 +1,165 @@
+//! External diff viewers for `gd`.
+//!
+//! `[diff] tool = "delta"` (or `gd --tool delta`) sends each file's diff
+//! through a filter such as delta, diff-so-fancy or ydiff: `git diff` output
+//! goes to the tool's stdin and what it prints is shown instead. difftastic
+//! doesn't read diffs, so `difft` runs as git's external diff
+//! (`GIT_EXTERNAL_DIFF`) and compares the two versions itself. `--tool none`
+//! shows git's own diff for one run. Without `[diff] tool`, git's own
+//! `diff.tool` is used when it names difftastic; the other tools git's
+//! difftool knows open windows of their own and are left to `git difftool`.
+
+use crate::core::config::Settings;
+use crate::core::error::{GitNavigatorError, Result};
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// How a configured tool gets to see the changes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffTool {
+    /// Reads a unified diff on stdin, like delta
+    Filter(String),
+    /// Run by git with the old and new file, like difftastic
+    External(String),
+}
+
+/// Programs that compare files instead of reading a diff
+const EXTERNAL_DIFF_PROGRAMS: &[&str] = &["difft", "difftastic"];
+
+impl DiffTool {
+    /// The tool a command line names, `None` for "none" or an empty one
+    pub fn parse(command: &str) -> Option<Self> {
+        let command = command.trim();
+        let program = command.split_whitespace().next()?;
+        if program == "none" {
+            return None;
+        }
+
+        let name = program.rsplit('/').next().unwrap_or(program);
+        if EXTERNAL_DIFF_PROGRAMS.contains(&name) {
+            Some(Self::External(command.to_string()))
+        } else {
+            Some(Self::Filter(command.to_string()))
+        }
+    }
+
+    /// `--tool` when given, else `[diff] tool`, else git's `diff.tool`
+    pub fn resolve(
+        flag: Option<&str>,
+        settings: &Settings,
+        git_tool: Option<&str>,
+    ) -> Option<Self> {
+        match (flag, settings.get_str("diff.tool")) {
+            (Some(command), _) | (None, Some(command)) => Self::parse(command),
+            (None, None) => git_tool.and_then(Self::from_git_tool),
+        }
+    }
+
+    /// The viewer a git `diff.tool` name stands for, if `gd` can show it
+    fn from_git_tool(name: &str) -> Option<Self> {
+        EXTERNAL_DIFF_PROGRAMS
+            .contains(&name.trim())
+            .then(|| Self::External("difft".to_string()))
+    }
+
+    /// Prepare a `git diff` command for the tool; a filter needs nothing
+    pub fn configure(&self, cmd: &mut Command) {
+        if let Self::External(command) = self {
+            cmd.arg("--ext-diff").env("GIT_EXTERNAL_DIFF", command);
+        }
+    }
+
+    /// What the tool makes of `diff`; external tools already ran inside git
+    pub fn apply(&self, diff: &str) -> Result<String> {
+        let Self::Filter(command) = self else {
+            return Ok(diff.to_string());
+        };
+
+        let failed = |reason: String| GitNavigatorError::diff_tool_failed(command, reason);
+        let mut child = Command::new("sh")
+            .args(["-c", command])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| failed(e.to_string()))?;
+
+        // Fed from a thread so a tool writing as it reads can't block us
+        let writer = child.stdin.take().map(|mut stdin| {
+            let input = diff.to_string();
+            std::thread::spawn(move || {
+                let _ = stdin.write_all(input.as_bytes());
+            })
+        });
+        let output = child
+            .wait_with_output()
+            .map_err(|e| failed(e.to_string()))?;
+        if let Some(writer) = writer {
+            let _ = writer.join();
+        }
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(failed(if stderr.trim().is_empty() {
+                output.status.to_string()
+            } else {
+                stderr.trim().to_string()
+            }));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            DiffTool::parse("delta --side-by-side"),
+            Some(DiffTool::Filter("delta --side-by-side".to_string()))
+        );
+        assert_eq!(
+            DiffTool::parse("/usr/bin/difft --color=always"),
+            Some(DiffTool::External(
+                "/usr/bin/difft --color=always".to_string()
+            ))
+        );
+        assert_eq!(DiffTool::parse("none"), None);
+        assert_eq!(DiffTool::parse("  "), None);
+    }
+
+    #[test]
+    fn test_resolve_prefers_flag() {
+        let settings = Settings::parse("[diff]\ntool = \"delta\"\n").unwrap();
+        assert_eq!(
+            DiffTool::resolve(None, &settings, Some("difftastic")),
+            Some(DiffTool::Filter("delta".to_string()))
+        );
+        assert_eq!(DiffTool::resolve(Some("none"), &settings, None), None);
+        assert_eq!(DiffTool::resolve(None, &Settings::default(), None), None);
+    }
+
+    #[test]
+    fn test_resolve_falls_back_to_git_diff_tool() {
+        let settings = Settings::default();
+        assert_eq!(
+            DiffTool::resolve(None, &settings, Some("difftastic")),
+            Some(DiffTool::External("difft".to_string()))
+        );
+        // GUI difftools are left to `git difftool`
+        assert_eq!(DiffTool::resolve(None, &settings, Some("meld")), None);
+    }
+
+    #[test]
+    fn test_filter_output() -> Result<()> {
+        let tool = DiffTool::Filter("tr a-z A-Z".to_string());
+        assert_eq!(tool.apply("+added line\n")?, "+ADDED LINE\n");
+
+        let failing = DiffTool::Filter("echo broken >&2; exit 3".to_string());
+        let error = failing.apply("diff").unwrap_err().to_string();
+        assert!(error.contains("broken"), "{error}");
+        Ok(())
+    }
+}
@@ -41,4 +41,24 @@ pub fn get_cache_directory() -> Result<PathBuf, GitNavigatorError> {
     };
     
     Ok(base.join("git-navigator"))
-}
\ No newline at end of file
+}
+
+/// Where install.sh puts the binary
+pub fn get_install_directory() -> PathBuf {
+    dirs::home_dir().unwrap_or_default().join(".local/bin")
+}
+
+/// Shell startup files the installer or an `init` line may have been added to
+pub fn get_shell_rc_files() -> Vec<PathBuf> {
+    let home = dirs::home_dir().unwrap_or_default();
+    let config = std::env::var("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| home.join(".config"));
+
+    vec![
+        home.join(".bashrc"),
+        home.join(".zshrc"),
+        home.join(".profile"),
+        config.join("fish/config.fish"),
+    ]
+}
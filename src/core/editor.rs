@@ -0,0 +1,296 @@
+//! Launching the user's editor, optionally at a given line.
+//!
+//! Editors disagree on how to jump to a line: terminal editors take
+//! `+<line> <file>`, VS Code wants `-g <file>:<line>`, and so on. The
+//! built-in table covers common editors and can be extended or overridden
+//! per editor in the settings:
+//!
+//! ```toml
+//! [editor.line_syntax]
+//! myeditor = "--line {line} {file}"
+//! ```
+//!
+//! The key is the editor's program name without its directory.
+//!
+//! # GUI editors
+//! `open --app <name>` launches an [`EditorApp`] from an `[editors.<name>]`
+//! table, either a command or a URL handed to the system opener:
+//!
+//! ```toml
+//! [editors.code]
+//! command = "code -g {file}:{line}"
+//!
+//! [editors.idea]
+//! url = "idea://open?file={file}&line={line}"
+//! ```
+
+use crate::core::config::Settings;
+use crate::core::error::{GitNavigatorError, Result};
+use crate::core::opener::open_url;
+use std::path::Path;
+use std::process::Command;
+
+/// Substitute `{file}` and `{line}` in each word of a command template
+///
+/// Substituting per word keeps paths with spaces a single argument.
+fn expand_args(template: &str, file: &str, line: u32) -> Vec<String> {
+    template
+        .split_whitespace()
+        .map(|word| {
+            word.replace("{file}", file)
+                .replace("{line}", &line.to_string())
+        })
+        .collect()
+}
+
+/// Percent-encode a path for use inside a URL, keeping `/` readable
+pub(crate) fn encode_url_path(path: &str) -> String {
+    let mut encoded = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        if byte.is_ascii_alphanumeric() || b"/-_.~".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+/// How a GUI editor opens a file at a line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EditorApp {
+    /// A command line such as `code -g {file}:{line}`
+    Command(String),
+    /// A URL such as `vscode://file{file}:{line}`, handed to the system opener
+    Url(String),
+}
+
+impl EditorApp {
+    /// Look up `[editors.<name>]`, falling back to the built-in `code` and `idea`
+    pub fn from_settings(settings: &Settings, name: &str) -> Result<Self> {
+        if let Some(url) = settings.get_str(&format!("editors.{name}.url")) {
+            return Ok(Self::Url(url.to_string()));
+        }
+        if let Some(command) = settings.get_str(&format!("editors.{name}.command")) {
+            return Ok(Self::Command(command.to_string()));
+        }
+
+        match name {
+            "code" => Ok(Self::Command("code -g {file}:{line}".to_string())),
+            "idea" => Ok(Self::Command("idea --line {line} {file}".to_string())),
+            _ => Err(GitNavigatorError::config_error(format!(
+                "no editor named '{name}'; add an [editors.{name}] table with a command or url"
+            ))),
+        }
+    }
+
+    /// Open `file` at `line`
+    pub fn launch(&self, file: &Path, line: u32) -> Result<()> {
+        let file_str = file.to_string_lossy();
+        match self {
+            Self::Url(template) => {
+                let url = template
+                    .replace("{file}", &encode_url_path(&file_str))
+                    .replace("{line}", &line.to_string());
+                open_url(&url)
+            }
+            Self::Command(template) => {
+                let args = expand_args(template, &file_str, line);
+                let Some((program, args)) = args.split_first() else {
+                    return Err(GitNavigatorError::config_error("empty editor command"));
+                };
+
+                let status = Command::new(program).args(args).status().map_err(|e| {
+                    GitNavigatorError::open_failed(
+                        file_str.as_ref(),
+                        format!("cannot run '{program}': {e}"),
+                    )
+                })?;
+                if !status.success() {
+                    return Err(GitNavigatorError::open_failed(
+                        file_str.as_ref(),
+                        format!("'{program}' exited with {status}"),
+                    ));
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Arguments that open `{file}` at `{line}` for well-known editors
+fn builtin_line_syntax(editor: &str) -> &'static str {
+    match editor {
+        "code" | "code-insiders" | "codium" | "cursor" => "-g {file}:{line}",
+        "subl" | "sublime_text" | "zed" | "hx" | "helix" => "{file}:{line}",
+        "idea" | "pycharm" | "webstorm" | "goland" | "clion" | "rubymine" | "rustrover" => {
+            "--line {line} {file}"
+        }
+        "mate" => "-l {line} {file}",
+        // vi, vim, nvim, nano, emacs, micro, kak, ...
+        _ => "+{line} {file}",
+    }
+}
+
+/// An editor command such as `nvim` or `code --wait`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Editor {
+    command: String,
+}
+
+impl Editor {
+    pub fn new(command: impl Into<String>) -> Self {
+        Self {
+            command: command.into(),
+        }
+    }
+
+    /// Program name used to pick the line syntax, e.g. `nvim` for `/usr/bin/nvim -p`
+    pub fn name(&self) -> &str {
+        let program = self.command.split_whitespace().next().unwrap_or_default();
+        Path::new(program)
+            .file_name()
+            .and_then(|name| name.to_str())
+            .map(|name| name.trim_end_matches(".exe"))
+            .unwrap_or(program)
+    }
+
+    /// Arguments that open `file`, at `line` when given
+    pub fn file_args(&self, settings: &Settings, file: &Path, line: Option<u32>) -> Vec<String> {
+        let file = file.to_string_lossy();
+        let Some(line) = line else {
+            return vec![file.into_owned()];
+        };
+
+        let key = format!("editor.line_syntax.{}", self.name());
+        let syntax = settings
+            .get_str(&key)
+            .unwrap_or_else(|| builtin_line_syntax(self.name()));
+
+        expand_args(syntax, &file, line)
+    }
+
+    /// Run the editor on `file` and wait for it to exit
+    ///
+    /// Like git, the command goes through the shell so `EDITOR="code --wait"`
+    /// works as expected.
+    pub fn open(&self, settings: &Settings, file: &Path, line: Option<u32>) -> Result<()> {
+        self.open_files(settings, &[(file, line)])
+    }
+
+    /// Run the editor once on all of `files`, each at its line when given
+    ///
+    /// The arguments of the files follow each other, so editors that take a
+    /// line per file (`code -g a:3 -g b:7`) open each at its own; terminal
+    /// editors jump in the first file.
+    pub fn open_files(&self, settings: &Settings, files: &[(&Path, Option<u32>)]) -> Result<()> {
+        let args: Vec<String> = files
+            .iter()
+            .flat_map(|&(file, line)| self.file_args(settings, file, line))
+            .collect();
+        let file = files
+            .iter()
+            .map(|(file, _)| file.display().to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let status = Command::new("sh")
+            .arg("-c")
+            .arg(format!("{} \"$@\"", self.command))
+            .arg(&self.command)
+            .args(&args)
+            .status()
+            .map_err(|e| {
+                GitNavigatorError::open_failed(
+                    file.as_str(),
+                    format!("cannot run editor '{}': {e}", self.command),
+                )
+            })?;
+
+        if !status.success() {
+            return Err(GitNavigatorError::open_failed(
+                file,
+                format!("editor '{}' exited with {status}", self.command),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_editor_name() {
+        assert_eq!(Editor::new("nvim").name(), "nvim");
+        assert_eq!(Editor::new("/usr/local/bin/code --wait").name(), "code");
+        assert_eq!(Editor::new("hx.exe").name(), "hx");
+    }
+
+    #[test]
+    fn test_file_args_for_builtin_editors() {
+        let settings = Settings::default();
+        let file = Path::new("src/main.rs");
+
+        assert_eq!(
+            Editor::new("vim").file_args(&settings, file, Some(12)),
+            ["+12", "src/main.rs"]
+        );
+        assert_eq!(
+            Editor::new("code --wait").file_args(&settings, file, Some(12)),
+            ["-g", "src/main.rs:12"]
+        );
+        assert_eq!(
+            Editor::new("hx").file_args(&settings, file, Some(12)),
+            ["src/main.rs:12"]
+        );
+        assert_eq!(
+            Editor::new("vim").file_args(&settings, file, None),
+            ["src/main.rs"]
+        );
+    }
+
+    #[test]
+    fn test_editor_app_from_settings() {
+        let settings = Settings::parse(
+            "[editors.web]\nurl = \"myapp://open?file={file}&line={line}\"\n\
+             [editors.code]\ncommand = \"code-insiders -g {file}:{line}\"",
+        )
+        .unwrap();
+
+        assert_eq!(
+            EditorApp::from_settings(&settings, "web").unwrap(),
+            EditorApp::Url("myapp://open?file={file}&line={line}".to_string())
+        );
+        // Configured entries override the built-ins
+        assert_eq!(
+            EditorApp::from_settings(&settings, "code").unwrap(),
+            EditorApp::Command("code-insiders -g {file}:{line}".to_string())
+        );
+        assert_eq!(
+            EditorApp::from_settings(&Settings::default(), "idea").unwrap(),
+            EditorApp::Command("idea --line {line} {file}".to_string())
+        );
+        assert!(EditorApp::from_settings(&Settings::default(), "unknown").is_err());
+    }
+
+    #[test]
+    fn test_encode_url_path() {
+        assert_eq!(encode_url_path("/repo/src/main.rs"), "/repo/src/main.rs");
+        assert_eq!(
+            encode_url_path("/repo/my file#1.rs"),
+            "/repo/my%20file%231.rs"
+        );
+    }
+
+    #[test]
+    fn test_file_args_from_settings() {
+        let settings = Settings::parse("[editor.line_syntax]\nvim = \"{file} -c {line}\"").unwrap();
+        assert_eq!(
+            Editor::new("vim").file_args(&settings, Path::new("my file.txt"), Some(3)),
+            ["my file.txt", "-c", "3"]
+        );
+    }
+}
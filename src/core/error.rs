@@ -107,6 +107,12 @@ pub enum GitNavigatorError {
         source: std::io::Error,
     },
 
+    #[error("The list cached in '{path}' is {age} old, past cache.ttl_minutes; list it again")]
+    CacheExpired { path: PathBuf, age: String },
+
+    #[error("The list cached in '{path}' {reason} and was rebuilt, so its numbers may have changed; list it again")]
+    CacheRebuilt { path: PathBuf, reason: String },
+
     #[error("Failed to parse cache file '{path}': {source}")]
     CacheParseFailed {
         path: PathBuf,
@@ -116,6 +122,14 @@ pub enum GitNavigatorError {
     #[error("No cached files found. Run 'gs' first to generate file list.")]
     NoCachedFiles,
 
+    #[error("The file list is out of date ({reason}): [{index}] was {was}, now {now}. It has been renumbered; run 'gs' to see it and try again")]
+    StaleFileList {
+        reason: String,
+        index: usize,
+        was: String,
+        now: String,
+    },
+
     #[error("No files available. Run 'gs' first to see available files.")]
     NoAvailableFiles,
 
@@ -138,28 +152,214 @@ pub enum GitNavigatorError {
     #[error("Failed to add files to git index: {source}")]
     GitAddFailed { source: git2::Error },
 
+    // Checkout errors
+    #[error(
+        "'{arg}' matches both a file index and a branch name. Use --files or --branch to choose"
+    )]
+    AmbiguousCheckoutTarget { arg: String },
+
+    #[error("Cannot switch to '{branch}': local changes to {count} file(s) would be overwritten. Commit or stash them first, or set checkout.autostash")]
+    CheckoutBlocked { branch: String, count: usize },
+
+    #[error("Checkout canceled")]
+    CheckoutCanceled,
+
+    // Resetting files and HEAD
+    #[error("Cannot reset: {0}")]
+    ResetFailed(String),
+
+    #[error("Reset canceled")]
+    ResetCanceled,
+
+    #[error("Remove canceled")]
+    RemoveCanceled,
+
+    #[error(
+        "Cannot mix branch names and file indices ({args}). Use --files or --branch to choose"
+    )]
+    MixedCheckoutArguments { args: String },
+
+    #[error("Start point '{start}' does not exist")]
+    StartPointNotFound { start: String },
+
+    #[error("'{path}' does not exist in {revision}")]
+    FileNotInRevision { path: String, revision: String },
+
+    #[error("Branch name '{name}' does not match the required pattern '{pattern}'")]
+    InvalidBranchName { name: String, pattern: String },
+
+    // Splitting staged changes into commits
+    #[error("Cannot split staged changes: {0}")]
+    SplitFailed(String),
+
+    #[error("Split canceled")]
+    SplitCanceled,
+
+    // Code owners
+    #[error(
+        "No CODEOWNERS file found (looked in .github/, the repository root, docs/ and .gitlab/)"
+    )]
+    CodeOwnersNotFound,
+
+    // Monorepo packages
+    #[error("Package index {0} not found. Run 'packages' to list them")]
+    PackageIndexNotFound(usize),
+
+    // Non-interactive mode
+    #[error("Nobody to ask in non-interactive mode: {0}")]
+    NonInteractive(String),
+
+    // Sparse checkouts
+    #[error(
+        "This worktree is not a sparse checkout. Start one with 'git sparse-checkout set <dir>...'"
+    )]
+    NotSparse,
+
+    // Verify steps
+    #[error("No verify steps configured. Add steps = [\"test\"] and test = \"cargo test\" under [verify] in the settings")]
+    VerifyNotConfigured,
+
+    #[error("No verify step named '{0}'")]
+    UnknownVerifyStep(String),
+
+    #[error("{failed} of {total} verify step(s) failed")]
+    VerifyFailed { failed: usize, total: usize },
+
+    // Committing
+    #[error("Cannot commit: {0}")]
+    CommitFailed(String),
+
+    #[error("Commit message rejected: {0}. Fix the message or pass --no-lint")]
+    CommitMessageRejected(String),
+
+    #[error("Commit canceled")]
+    CommitCanceled,
+
+    // Rewording commits
+    #[error("Commit {short_oid} is already on {upstream}; rewording it rewrites published history. Use --force-accept to reword it anyway")]
+    RewordPublished { short_oid: String, upstream: String },
+
+    #[error("Cannot reword commit: {0}")]
+    RewordFailed(String),
+
+    // Browsing history
+    #[error(
+        "Cannot understand date '{0}'. Use YYYY-MM-DD, 'today', HH:MM or a relative time like '2 weeks' or '30d'"
+    )]
+    InvalidDate(String),
+
+    #[error("Contributor index {0} not found. Run 'contributors' to list them")]
+    ContributorNotFound(usize),
+
+    #[error("'{0}' is not a tag index, tag or commit")]
+    UnknownRevision(String),
+
+    #[error("Invalid --grep pattern '{pattern}': {reason}")]
+    InvalidSearchPattern { pattern: String, reason: String },
+
+    #[error("Commit index {0} not found. Run 'gl' to list commits")]
+    CommitIndexNotFound(usize),
+
+    #[error("This shallow clone has too little history to compare with the upstream. Run 'git fetch --deepen=<n>' or 'git fetch --unshallow' to fetch more")]
+    ShallowHistory,
+
+    #[error("Object {0} was left out of this partial clone. Run the git command directly, which fetches it on demand")]
+    ObjectNotFetched(String),
+
+    #[error("Cherry-pick stopped: {0}. Resolve the conflicts and run 'git cherry-pick --continue', or 'git cherry-pick --abort' to give up")]
+    CherryPickFailed(String),
+
+    // Tags
+    #[error("Tag '{0}' already exists")]
+    TagExists(String),
+
+    #[error("'{0}' is not a valid tag name")]
+    InvalidTagName(String),
+
+    #[error("Tag index {0} not found. Run 'tags' to list them")]
+    TagIndexNotFound(usize),
+
+    #[error("Cannot delete tag: {0}")]
+    TagDeleteFailed(String),
+
+    #[error("Tag deletion canceled")]
+    TagDeleteCanceled,
+
+    // Commit notes
+    #[error("Commit {0} already has a note. Pass --force to replace it, or use 'notes edit'")]
+    NoteExists(String),
+
+    #[error("Commit {0} has no note. Add one with 'notes add <index> -m <text>'")]
+    NoteNotFound(String),
+
+    // Network commands
+    #[error("Network unavailable: cannot reach {host} ({reason}). Check your connection, VPN or proxy and try again; commands that only touch the local repository keep working. Set network.check = false to skip this check")]
+    NetworkUnavailable { host: String, reason: String },
+
+    #[error("No remote named '{0}'")]
+    RemoteNotFound(String),
+
+    #[error("Authentication to '{remote}' failed: {reason}. Check that ssh-agent holds your key (ssh-add -l), that credential.helper is set, or export GIT_NAVIGATOR_TOKEN")]
+    AuthenticationFailed { remote: String, reason: String },
+
+    #[error("Cannot access remote '{remote}': {reason}")]
+    RemoteAccessFailed { remote: String, reason: String },
+
+    #[error("{failed} of {total} remote(s) failed the credentials check")]
+    CredentialCheckFailed { failed: usize, total: usize },
+
+    #[error("Fetch failed: {0}")]
+    FetchFailed(String),
+
+    #[error("Pull failed: {0}")]
+    PullFailed(String),
+
+    #[error("Push failed: {0}")]
+    PushFailed(String),
+
+    #[error("Push canceled")]
+    PushCanceled,
+
+    // Opening files and directories
+    #[error("Could not open '{target}': {reason}")]
+    OpenFailed { target: String, reason: String },
+
+    // Commands run on numbered files
+    #[error("Command '{command}' failed: {reason}")]
+    ExecFailed { command: String, reason: String },
+
+    // External diff viewers
+    #[error("Diff tool '{tool}' failed: {reason}. Check [diff] tool in the settings, or pass --tool none")]
+    DiffToolFailed { tool: String, reason: String },
+
     // Self-update errors
     #[error("Update failed: {0}")]
     UpdateFailed(String),
-    
+
     #[error("Already up to date (v{current})")]
     AlreadyUpToDate { current: String },
-    
+
     #[error("Update canceled by user")]
     UpdateCanceled,
-    
+
     #[error("Config error: {0}")]
     ConfigError(String),
-    
+
     #[error("Self-update error: {0}")]
     SelfUpdateError(#[from] Box<dyn std::error::Error + Send + Sync>),
-    
+
     #[error("Rollback failed: {0}")]
     RollbackFailed(String),
-    
+
     #[error("Version {version} not found in backups")]
     VersionNotFound { version: String },
 
+    #[error("Cannot remove {path}: {reason}")]
+    UninstallFailed { path: String, reason: String },
+
+    #[error("Uninstall canceled")]
+    UninstallCanceled,
+
     // JSON serialization errors
     #[error("JSON serialization error: {0}")]
     Json(#[from] serde_json::Error),
@@ -242,6 +442,100 @@ impl GitNavigatorError {
         Self::GitAddFailed { source }
     }
 
+    /// Create an ambiguous checkout target error
+    pub fn ambiguous_checkout_target(arg: impl Into<String>) -> Self {
+        Self::AmbiguousCheckoutTarget { arg: arg.into() }
+    }
+
+    /// Create a mixed checkout arguments error
+    pub fn mixed_checkout_arguments(args: &[String]) -> Self {
+        Self::MixedCheckoutArguments {
+            args: args.join(" "),
+        }
+    }
+
+    /// Create a checkout blocked error
+    pub fn checkout_blocked(branch: impl Into<String>, count: usize) -> Self {
+        Self::CheckoutBlocked {
+            branch: branch.into(),
+            count,
+        }
+    }
+
+    /// Create a start point not found error
+    pub fn start_point_not_found(start: impl Into<String>) -> Self {
+        Self::StartPointNotFound {
+            start: start.into(),
+        }
+    }
+
+    /// Create an invalid branch name error
+    pub fn invalid_branch_name(name: impl Into<String>, pattern: impl Into<String>) -> Self {
+        Self::InvalidBranchName {
+            name: name.into(),
+            pattern: pattern.into(),
+        }
+    }
+
+    /// Create a split failed error
+    pub fn split_failed(message: impl Into<String>) -> Self {
+        Self::SplitFailed(message.into())
+    }
+
+    /// Create a commit failed error
+    pub fn commit_failed(message: impl Into<String>) -> Self {
+        Self::CommitFailed(message.into())
+    }
+
+    /// Create a reset failed error
+    pub fn reset_failed(message: impl Into<String>) -> Self {
+        Self::ResetFailed(message.into())
+    }
+
+    /// Create a reword failed error
+    pub fn reword_failed(message: impl Into<String>) -> Self {
+        Self::RewordFailed(message.into())
+    }
+
+    /// Create a fetch failed error
+    pub fn fetch_failed(message: impl Into<String>) -> Self {
+        Self::FetchFailed(message.into())
+    }
+
+    /// Create a pull failed error
+    pub fn pull_failed(message: impl Into<String>) -> Self {
+        Self::PullFailed(message.into())
+    }
+
+    /// Create a push failed error
+    pub fn push_failed(message: impl Into<String>) -> Self {
+        Self::PushFailed(message.into())
+    }
+
+    /// Create an open failed error
+    pub fn open_failed(target: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::OpenFailed {
+            target: target.into(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Create an exec failed error
+    pub fn exec_failed(command: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::ExecFailed {
+            command: command.into(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Create a diff tool failed error
+    pub fn diff_tool_failed(tool: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::DiffToolFailed {
+            tool: tool.into(),
+            reason: reason.into(),
+        }
+    }
+
     /// Create a cache load error
     pub fn cache_load_error<E>(source: E) -> Self
     where
@@ -319,6 +613,14 @@ impl GitNavigatorError {
         Self::RollbackFailed(message.into())
     }
 
+    /// Create an uninstall failed error for `path`
+    pub fn uninstall_failed(path: &std::path::Path, reason: impl std::fmt::Display) -> Self {
+        Self::UninstallFailed {
+            path: path.display().to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
     /// Create a version not found error
     pub fn version_not_found(version: impl Into<String>) -> Self {
         Self::VersionNotFound {
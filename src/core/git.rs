@@ -15,21 +15,261 @@
 //! - **Type safety**: All operations return structured data instead of raw strings
 
 use crate::core::{
+    backend::{self, Backend},
+    cache::{CacheStore, CacheTtl},
+    config::Settings,
+    credentials::Credentials,
     error::{GitNavigatorError, Result},
+    git_config::GitDefaults,
     git_status::GitStatus,
-    state::FileEntry,
+    is_interactive,
+    network::NetworkCheck,
+    sparse::SparseCheckout,
+    state::{CacheSnapshot, CommitEntry, Contributor, FileEntry, FileStat, StatusView, TagEntry},
 };
-use git2::{Repository, StatusOptions};
-use std::path::{Path, PathBuf};
+use git2::{DiffFindOptions, DiffOptions, Direction, Repository, StatusOptions};
+use std::collections::{HashMap, HashSet};
+use std::path::{Component, Path, PathBuf};
 
 pub struct GitRepo {
     repo: Repository,
+    /// Alternate index from `GIT_INDEX_FILE`, made absolute
+    index_file: Option<PathBuf>,
+}
+
+/// Context about a detached HEAD
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DetachedHead {
+    /// Nearest branch or tag containing the commit, e.g. `main~2` or `v1.0`
+    pub nearest_ref: Option<String>,
+}
+
+/// The remote branch the current branch tracks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Upstream {
+    /// Remote name, e.g. `origin`
+    pub remote: String,
+    /// Branch name on the remote, e.g. `main`
+    pub branch: String,
+    /// Remote-tracking branch, e.g. `origin/main`
+    pub tracking: String,
+    /// Where the remote branch was at the last fetch, if it was ever fetched
+    pub oid: Option<git2::Oid>,
+}
+
+/// A checkout of this repository, from `git worktree list`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Worktree {
+    pub path: PathBuf,
+    /// Full id of the commit checked out there
+    pub head: Option<String>,
+    /// Branch checked out there, `None` when detached or bare
+    pub branch: Option<String>,
+    pub bare: bool,
+    /// Its directory is gone; `git worktree prune` forgets it
+    pub prunable: bool,
+}
+
+/// The tagger and message of an annotated tag
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagAnnotation {
+    /// "Name <email>", when the tag records one
+    pub tagger: Option<String>,
+    /// Unix time the tag was made
+    pub time: Option<i64>,
+    pub message: String,
+}
+
+/// What a shallow or partial clone left out
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HistoryLimits {
+    /// Commits reachable from HEAD in a shallow clone
+    pub shallow_depth: Option<usize>,
+    /// Object filter of a partial clone, e.g. `blob:none`
+    pub partial_filter: Option<String>,
+}
+
+impl HistoryLimits {
+    /// Header annotation, e.g. `shallow, depth 1`; `None` for a full clone
+    pub fn describe(&self) -> Option<String> {
+        let parts: Vec<String> = [
+            self.shallow_depth
+                .map(|depth| format!("shallow, depth {depth}")),
+            self.partial_filter
+                .as_ref()
+                .map(|filter| format!("partial, {filter}")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        (!parts.is_empty()).then(|| parts.join("; "))
+    }
+}
+
+/// Which commits `log` shows
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogQuery {
+    /// Show at most this many commits
+    pub limit: usize,
+    /// Only commits by this author
+    pub author: Option<AuthorMatch>,
+    /// Only commits touching this file, following it across renames
+    pub path: Option<PathBuf>,
+    /// Only commits whose message matches this regular expression
+    pub grep: Option<String>,
+    /// Only commits changing how many times this string occurs (`git log -S`)
+    pub pickaxe: Option<String>,
+    /// Leave out commits reachable from this one, as in `<since>..HEAD`
+    pub since: Option<git2::Oid>,
+    /// Leave out merge commits
+    pub no_merges: bool,
+}
+
+/// How a commit changed the file `LogQuery::path` follows
+enum PathChange {
+    Untouched,
+    Changed,
+    /// Added by renaming this older path
+    RenamedFrom(PathBuf),
+}
+
+/// How `LogQuery::author` is matched, after applying `.mailmap`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuthorMatch {
+    /// The author email, ignoring case (from a contributor index)
+    Email(String),
+    /// Part of the author name or email, ignoring case
+    Pattern(String),
+}
+
+impl AuthorMatch {
+    fn matches(&self, name: &str, email: &str) -> bool {
+        match self {
+            Self::Email(wanted) => email.eq_ignore_ascii_case(wanted),
+            Self::Pattern(pattern) => {
+                let pattern = pattern.to_lowercase();
+                name.to_lowercase().contains(&pattern) || email.to_lowercase().contains(&pattern)
+            }
+        }
+    }
+}
+
+/// A numbered commit in `log` output
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogEntry {
+    pub commit: CommitEntry,
+    pub author: String,
+    /// Unix time the commit was authored
+    pub time: i64,
+}
+
+/// A file changed by a commit, or since one, numbered for `review` and `since`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitFile {
+    pub index: usize,
+    pub status: GitStatus,
+    pub path: PathBuf,
+    /// Path before a rename or copy
+    pub old_path: Option<PathBuf>,
+    /// First line that changed, to open an editor there
+    pub first_line: Option<u32>,
+}
+
+/// One hunk of a file's unstaged changes, as `ga -p` offers it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatchHunk {
+    /// The `@@ -1,3 +1,4 @@` line
+    pub header: String,
+    /// Each line with its origin: ' ', '+' or '-'
+    pub lines: Vec<(char, String)>,
+}
+
+/// What `grs <commit>` resets besides HEAD, as git's `--soft`, `--mixed`
+/// and `--hard`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ResetMode {
+    /// Move HEAD only
+    Soft,
+    /// Move HEAD and reset the index
+    #[default]
+    Mixed,
+    /// Move HEAD and reset the index and the worktree
+    Hard,
+}
+
+impl ResetMode {
+    pub fn flag(self) -> &'static str {
+        match self {
+            ResetMode::Soft => "--soft",
+            ResetMode::Mixed => "--mixed",
+            ResetMode::Hard => "--hard",
+        }
+    }
+}
+
+/// Object counts and sizes for the whole object database
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ObjectTotals {
+    pub objects: usize,
+    pub blobs: usize,
+    pub blob_bytes: u64,
+}
+
+/// A blob and where it first shows up in history
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BigObject {
+    pub oid: git2::Oid,
+    pub size: u64,
+    /// Path the blob was first added under, `None` if no commit references it
+    pub path: Option<PathBuf>,
+    /// Short id and summary of the commit that introduced it
+    pub introduced_by: Option<(String, String)>,
 }
 
 impl GitRepo {
     pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
         let repo = Repository::discover(path)?;
-        Ok(GitRepo { repo })
+
+        // libgit2 ignores GIT_INDEX_FILE, while the git commands we run
+        // inherit it; point both at the same index so scripted commit flows
+        // (hooks, partial-index tricks) never touch the main one
+        let index_file = std::env::var_os("GIT_INDEX_FILE")
+            .filter(|file| !file.is_empty())
+            .map(|file| {
+                // git resolves a relative path from the worktree root
+                let base = repo.workdir().unwrap_or_else(|| repo.path());
+                base.join(file)
+            });
+        if let Some(file) = &index_file {
+            let mut index = git2::Index::open(file)?;
+            repo.set_index(&mut index)?;
+        }
+
+        Ok(GitRepo { repo, index_file })
+    }
+
+    /// The index file in use: `GIT_INDEX_FILE` if set, else `.git/index`
+    pub fn index_path(&self) -> PathBuf {
+        self.index_file
+            .clone()
+            .unwrap_or_else(|| self.repo.path().join("index"))
+    }
+
+    /// Run a git command in the repository's working directory and capture its output
+    fn git_output(&self, mut cmd: std::process::Command) -> Result<std::process::Output> {
+        let workdir = self
+            .repo
+            .workdir()
+            .ok_or(GitNavigatorError::custom_empty_files_error(
+                "Repository has no working directory",
+            ))?;
+
+        cmd.current_dir(workdir);
+        if !is_interactive() {
+            // Fail instead of waiting for a username nobody will type
+            cmd.env("GIT_TERMINAL_PROMPT", "0");
+        }
+        cmd.output().map_err(GitNavigatorError::Io)
     }
 
     /// Execute a git command in the repository's working directory
@@ -42,6 +282,9 @@ impl GitRepo {
             ))?;
 
         cmd.current_dir(workdir);
+        if !is_interactive() {
+            cmd.env("GIT_TERMINAL_PROMPT", "0");
+        }
 
         let output = cmd.output().map_err(GitNavigatorError::Io)?;
 
@@ -57,13 +300,73 @@ impl GitRepo {
     }
 
     pub fn get_status(&self) -> Result<Vec<FileEntry>> {
+        self.scan_status(false)
+    }
+
+    /// The part of the status `view` shows, numbered from 1
+    ///
+    /// With paths, untracked directories are listed file by file, so a path
+    /// or glob inside one finds its files.
+    pub fn get_status_in(&self, view: &StatusView) -> Result<Vec<FileEntry>> {
+        Ok(view.apply(self.scan_status(!view.pathspecs.is_empty())?))
+    }
+
+    /// `pathspecs` as typed in the current directory, made relative to the
+    /// top of the worktree
+    pub fn repo_pathspecs(&self, pathspecs: &[String]) -> Result<Vec<String>> {
+        match self.repo.workdir() {
+            Some(workdir) if !pathspecs.is_empty() => {
+                let workdir = workdir.canonicalize()?;
+                let current_dir = std::env::current_dir()?.canonicalize()?;
+                let prefix = current_dir.strip_prefix(&workdir).unwrap_or(Path::new(""));
+                pathspecs
+                    .iter()
+                    .map(|pathspec| repo_pathspec(&workdir, prefix, pathspec))
+                    .collect()
+            }
+            _ => Ok(pathspecs.to_vec()),
+        }
+    }
+
+    fn scan_status(&self, recurse_untracked_dirs: bool) -> Result<Vec<FileEntry>> {
         let mut opts = StatusOptions::new();
         opts.include_untracked(true);
+        opts.recurse_untracked_dirs(recurse_untracked_dirs);
         opts.include_ignored(false);
 
         let statuses = self.repo.statuses(Some(&mut opts))?;
         let mut files = Vec::new();
 
+        // libgit2 ignores skip-worktree, so the files a sparse checkout leaves
+        // out would all look deleted
+        let skip_worktree = if self.sparse_checkout().is_some() {
+            self.skip_worktree_paths()?
+        } else {
+            HashSet::new()
+        };
+        // A change in a submodule shows up as a modified directory
+        let submodules: HashSet<PathBuf> = self
+            .repo
+            .submodules()?
+            .iter()
+            .map(|submodule| submodule.path().to_path_buf())
+            .collect();
+        // A staged rename or copy is one entry for the new path rather than
+        // a deletion and an addition
+        let staged_moves = if statuses
+            .iter()
+            .any(|entry| entry.status().contains(git2::Status::INDEX_NEW))
+        {
+            self.staged_moves()?
+        } else {
+            HashMap::new()
+        };
+        let renamed_from: HashSet<&PathBuf> = staged_moves
+            .values()
+            .filter(|(status, _)| *status == GitStatus::Renamed)
+            .map(|(_, old_path)| old_path)
+            .collect();
+
         for entry in statuses.iter() {
             let path = entry.path().ok_or(GitNavigatorError::InvalidUtf8Path)?;
 
@@ -71,22 +374,38 @@ impl GitRepo {
             let path_buf = PathBuf::from(path);
 
             // Handle staged changes
-            if let Some((status, staged)) = GitStatus::from_git2_staged(status_flags) {
-                files.push(FileEntry {
-                    index: 0, // Will be recalculated in display order
-                    status,
-                    path: path_buf.clone(),
-                    staged,
-                });
+            match GitStatus::from_git2_staged(status_flags) {
+                // Listed under its new name
+                Some((GitStatus::Deleted, _)) if renamed_from.contains(&path_buf) => {}
+                Some((status, staged)) => {
+                    let moved = staged_moves.get(&path_buf);
+                    files.push(FileEntry {
+                        index: 0, // Will be recalculated in display order
+                        status: moved.map_or(status, |(moved, _)| *moved),
+                        path: path_buf.clone(),
+                        staged,
+                        old_path: moved.map(|(_, old_path)| old_path.clone()),
+                    });
+                }
+                None => {}
             }
 
             // Handle unstaged changes (can be in addition to staged)
             if let Some((status, staged)) = GitStatus::from_git2_unstaged(status_flags) {
+                if status == GitStatus::Deleted && skip_worktree.contains(&path_buf) {
+                    continue;
+                }
+                let status = if submodules.contains(&path_buf) {
+                    self.submodule_status(path).unwrap_or(status)
+                } else {
+                    status
+                };
                 files.push(FileEntry {
                     index: 0, // Will be recalculated in display order
                     status,
                     path: path_buf,
                     staged,
+                    old_path: None,
                 });
             }
         }
@@ -107,10 +426,70 @@ impl GitRepo {
         Ok(files)
     }
 
+    /// Staged renames and copies, keyed by the new path
+    ///
+    /// Copies are found from files that changed too, as with `git diff -C`;
+    /// looking at every unchanged file would read the whole tree.
+    fn staged_moves(&self) -> Result<HashMap<PathBuf, (GitStatus, PathBuf)>> {
+        let head_tree = self
+            .repo
+            .head()
+            .ok()
+            .and_then(|head| head.peel_to_tree().ok());
+        let mut diff = self
+            .repo
+            .diff_tree_to_index(head_tree.as_ref(), None, None)?;
+        diff.find_similar(Some(DiffFindOptions::new().renames(true).copies(true)))?;
+
+        Ok(diff
+            .deltas()
+            .filter_map(|delta| {
+                let status = match delta.status() {
+                    git2::Delta::Renamed => GitStatus::Renamed,
+                    git2::Delta::Copied => GitStatus::Copied,
+                    _ => return None,
+                };
+                let old_path = delta.old_file().path()?.to_path_buf();
+                let new_path = delta.new_file().path()?.to_path_buf();
+                Some((new_path, (status, old_path)))
+            })
+            .collect())
+    }
+
+    /// What changed in the working directory of the submodule at `path`
+    fn submodule_status(&self, path: &str) -> Option<GitStatus> {
+        match self
+            .repo
+            .submodule_status(path, git2::SubmoduleIgnore::Unspecified)
+        {
+            Ok(flags) => GitStatus::from_git2_submodule(flags),
+            Err(e) => {
+                log::debug!("Could not read the status of submodule {path}: {e}");
+                None
+            }
+        }
+    }
+
+    /// Index entries marked skip-worktree, i.e. left out of a sparse checkout
+    fn skip_worktree_paths(&self) -> Result<HashSet<PathBuf>> {
+        let index = self.repo.index()?;
+        Ok(index
+            .iter()
+            .filter(|entry| {
+                git2::IndexEntryExtendedFlag::from_bits_truncate(entry.flags_extended)
+                    .is_skip_worktree()
+            })
+            .map(|entry| PathBuf::from(String::from_utf8_lossy(&entry.path).into_owned()))
+            .collect())
+    }
+
     pub fn reset_files(&self, paths: &[PathBuf]) -> Result<()> {
         if paths.is_empty() {
             return Ok(());
         }
+        if self.backend() == Backend::Libgit2 {
+            return backend::reset_files(&self.repo, paths);
+        }
 
         let mut cmd = std::process::Command::new("git");
         cmd.arg("reset").arg("HEAD").arg("--");
@@ -122,6 +501,95 @@ impl GitRepo {
         self.execute_git_command(cmd)
     }
 
+    /// `git rm [--cached] [-r] [--force] -- <paths>`
+    ///
+    /// Always runs git, whatever the backend: libgit2 has no counterpart for
+    /// the checks git makes before dropping changes. `force` skips them.
+    pub fn remove_files(
+        &self,
+        paths: &[PathBuf],
+        cached: bool,
+        recursive: bool,
+        force: bool,
+    ) -> Result<()> {
+        if paths.is_empty() {
+            return Ok(());
+        }
+
+        let mut cmd = std::process::Command::new("git");
+        cmd.arg("rm").arg("--quiet");
+        if cached {
+            cmd.arg("--cached");
+        }
+        if recursive {
+            cmd.arg("-r");
+        }
+        if force {
+            cmd.arg("--force");
+        }
+        cmd.arg("--");
+
+        for path in paths {
+            cmd.arg(path);
+        }
+
+        self.execute_git_command(cmd)
+    }
+
+    /// `git reset --soft|--mixed|--hard <target>`: move HEAD, and the
+    /// current branch with it, to the commit `target` names
+    pub fn reset_to(&self, target: &str, mode: ResetMode) -> Result<()> {
+        if self.backend() == Backend::Libgit2 {
+            let reset_type = match mode {
+                ResetMode::Soft => git2::ResetType::Soft,
+                ResetMode::Mixed => git2::ResetType::Mixed,
+                ResetMode::Hard => git2::ResetType::Hard,
+            };
+            return backend::reset_to(&self.repo, target, reset_type);
+        }
+
+        let mut cmd = std::process::Command::new("git");
+        cmd.args(["reset", "--quiet", mode.flag(), target, "--"]);
+        self.execute_git_command(cmd)
+    }
+
+    /// Throw away the staged and unstaged changes to `paths`, as
+    /// `git reset --hard` does for the whole worktree
+    ///
+    /// Files added since HEAD are deleted; untracked files are left alone.
+    pub fn discard_files(&self, paths: &[PathBuf]) -> Result<()> {
+        let head_tree = self
+            .repo
+            .head()
+            .ok()
+            .and_then(|head| head.peel_to_tree().ok());
+        let index = self.repo.index()?;
+        let in_head = |path: &Path| {
+            head_tree
+                .as_ref()
+                .is_some_and(|tree| tree.get_path(path).is_ok())
+        };
+
+        let tracked: Vec<PathBuf> = paths
+            .iter()
+            .filter(|path| in_head(path) || index.get_path(path, 0).is_some())
+            .cloned()
+            .collect();
+        let (restored, added): (Vec<PathBuf>, Vec<PathBuf>) =
+            tracked.iter().cloned().partition(|path| in_head(path));
+
+        self.reset_files(&tracked)?;
+        self.checkout_files(&restored)?;
+        let workdir = self.repo.workdir().unwrap_or(Path::new("."));
+        for path in added {
+            match std::fs::remove_file(workdir.join(&path)) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e.into()),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
     pub fn get_repo_path(&self) -> PathBuf {
         self.repo.path().to_path_buf()
     }
@@ -130,95 +598,1754 @@ impl GitRepo {
         &self.repo
     }
 
-    pub fn get_current_branch(&self) -> Result<String> {
+    /// Cheap fingerprint of the state `gs` output depends on
+    ///
+    /// Combines the HEAD OID, the checksum git stores at the end of the index
+    /// file, and modification times of a worktree sample: the directories
+    /// holding tracked files (capped) plus `dirty`, the files the last status
+    /// reported. Edits to clean files that keep their directory's mtime, or
+    /// that sit in directories past the cap, are not detected: the fast
+    /// status mode can show an outdated status, which is why it is opt-in.
+    pub fn status_fingerprint(&self, dirty: &[FileEntry]) -> Result<String> {
+        const MAX_SAMPLED_DIRS: usize = 256;
+
+        let head = match self.repo.head() {
+            Ok(head) => head.target().map(|oid| oid.to_string()).unwrap_or_default(),
+            Err(_) => "unborn".to_string(),
+        };
+
+        let index_checksum = {
+            use std::io::{Read, Seek, SeekFrom};
+            let mut checksum = Vec::new();
+            if let Ok(mut file) = std::fs::File::open(self.index_path()) {
+                // Long enough for both SHA-1 and SHA-256 trailers
+                if file.seek(SeekFrom::End(-32)).is_ok() {
+                    file.read_to_end(&mut checksum)?;
+                }
+            }
+            checksum
+        };
+
+        let workdir = match self.repo.workdir() {
+            Some(workdir) => workdir,
+            None => return Ok(head),
+        };
+
+        let mut sample = std::collections::BTreeSet::new();
+        sample.insert(PathBuf::new());
+        for entry in self.repo.index()?.iter() {
+            if sample.len() >= MAX_SAMPLED_DIRS {
+                break;
+            }
+            let path = PathBuf::from(String::from_utf8_lossy(&entry.path).as_ref());
+            if let Some(parent) = path.parent() {
+                sample.insert(parent.to_path_buf());
+            }
+        }
+        sample.extend(dirty.iter().map(|file| file.path.clone()));
+
+        let mut context = md5::Context::new();
+        context.consume(head.as_bytes());
+        context.consume(&index_checksum);
+        for path in &sample {
+            let mtime = std::fs::symlink_metadata(workdir.join(path))
+                .and_then(|metadata| metadata.modified())
+                .ok()
+                .and_then(|time| time.duration_since(std::time::UNIX_EPOCH).ok())
+                .map(|duration| duration.as_nanos())
+                .unwrap_or(0);
+            context.consume(path.to_string_lossy().as_bytes());
+            context.consume(mtime.to_le_bytes());
+        }
+
+        Ok(format!("{:x}", context.finalize()))
+    }
+
+    /// Cache of numbered files and branches for this repository, with the
+    /// `[cache]` expiry settings applied
+    pub fn cache_store(&self) -> Result<CacheStore> {
+        let ttl = CacheTtl::from_settings(&self.load_settings());
+        Ok(CacheStore::for_repo(self.repo.path())?.with_ttl(ttl))
+    }
+
+    /// The repository state `files` was computed from
+    pub fn cache_snapshot(&self, files: &[FileEntry]) -> CacheSnapshot {
+        let head = self
+            .repo
+            .head()
+            .ok()
+            .and_then(|head| head.target())
+            .map(|oid| oid.to_string())
+            .unwrap_or_default();
+        let index_mtime = std::fs::metadata(self.index_path())
+            .and_then(|metadata| metadata.modified())
+            .ok();
+        CacheSnapshot {
+            head,
+            index_mtime,
+            status_hash: CacheSnapshot::status_hash(files),
+        }
+    }
+
+    /// Save the numbered file list for index commands, with its snapshot and,
+    /// under `status.fast`, the fingerprint `gs` reuses it by
+    pub fn save_file_list(&self, files: &[FileEntry]) -> Result<()> {
+        self.save_file_list_in_view(files, &StatusView::default())
+    }
+
+    /// Save a file list limited to part of the status (`gs --staged`,
+    /// `gs src/`), so stale checks compare it with the same part of a fresh scan
+    pub fn save_file_list_in_view(&self, files: &[FileEntry], view: &StatusView) -> Result<()> {
+        let fast = self
+            .load_settings()
+            .get_bool("status.fast")
+            .unwrap_or(false);
+        let fingerprint = if fast {
+            Some(self.status_fingerprint(files)?)
+        } else {
+            None
+        };
+        self.cache_store()?
+            .save_file_list(files, self.cache_snapshot(files), view, fingerprint)
+    }
+
+    /// Whether index, worktree and HEAD changes run git or libgit2
+    pub fn backend(&self) -> Backend {
+        Backend::from_settings(&self.load_settings())
+    }
+
+    /// Load git-navigator settings with this repository's overrides applied
+    pub fn load_settings(&self) -> Settings {
+        Settings::load_layered(self.repo.path(), self.repo.workdir())
+    }
+
+    /// Read git settings that git-navigator follows (pager, tools, color.ui)
+    pub fn git_defaults(&self) -> GitDefaults {
+        match self.repo.config() {
+            Ok(config) => GitDefaults::from_config(&config),
+            Err(e) => {
+                log::debug!("Could not read git config: {e}");
+                GitDefaults::default()
+            }
+        }
+    }
+
+    /// Read a string value from the repository's git config
+    pub fn config_string(&self, key: &str) -> Option<String> {
+        self.repo.config().ok()?.get_string(key).ok()
+    }
+
+    /// Name of the checked-out branch, or `detached at <oid>`
+    ///
+    /// An unborn branch (a new repository, or after `checkout --orphan`) has
+    /// no commit for HEAD to resolve to, so its name is read from the
+    /// symbolic HEAD instead.
+    pub fn get_current_branch(&self) -> Result<String> {
+        let head = match self.repo.head() {
+            Ok(head) => head,
+            Err(e) if e.code() == git2::ErrorCode::UnbornBranch => {
+                return Ok(self.unborn_branch().unwrap_or_else(|| "-none-".to_string()));
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        match (head.shorthand(), head.target()) {
+            (Some(branch_name), _) if head.is_branch() => Ok(branch_name.to_string()),
+            // Detached HEAD
+            (_, Some(oid)) => Ok(format!("detached at {}", self.short_oid(oid))),
+            _ => Ok("-none-".to_string()),
+        }
+    }
+
+    /// Branch a symbolic HEAD names before its first commit
+    fn unborn_branch(&self) -> Option<String> {
+        let head = self.repo.find_reference("HEAD").ok()?;
+        let target = head.symbolic_target()?;
+        Some(
+            target
+                .strip_prefix("refs/heads/")
+                .unwrap_or(target)
+                .to_string(),
+        )
+    }
+
+    /// Abbreviated id of `oid` for display
+    ///
+    /// libgit2 makes it `core.abbrev` characters long (7 by default), and
+    /// longer where that would be ambiguous in this repository.
+    pub fn short_oid(&self, oid: git2::Oid) -> String {
+        self.repo
+            .find_object(oid, None)
+            .and_then(|object| object.short_id())
+            .ok()
+            .and_then(|short| short.as_str().map(str::to_string))
+            .unwrap_or_else(|| oid.to_string().chars().take(7).collect())
+    }
+
+    /// Describe a detached HEAD, or `None` when HEAD points at a branch
+    ///
+    /// The nearest ref is the local branch whose tip contains HEAD with the
+    /// fewest commits in between, or a tag pointing right at HEAD; branches
+    /// win ties. Tags further away are not looked at: walking the graph for
+    /// each of them would slow down every `gs` in repositories with many tags.
+    pub fn get_detached_head(&self) -> Result<Option<DetachedHead>> {
+        if !self.repo.head_detached().unwrap_or(false) {
+            return Ok(None);
+        }
+        let head_oid = match self.repo.head()?.target() {
+            Some(oid) => oid,
+            None => return Ok(None),
+        };
+
+        let mut nearest: Option<(usize, bool, String)> = None;
+        let mut consider = |candidate: (usize, bool, String)| {
+            // Sort key: fewest commits first, then branches before tags
+            if nearest.as_ref().is_none_or(|best| candidate < *best) {
+                nearest = Some(candidate);
+            }
+        };
+
+        for branch in self.repo.branches(Some(git2::BranchType::Local))? {
+            let (branch, _) = branch?;
+            let (Ok(Some(name)), Ok(tip)) = (branch.name(), branch.get().peel_to_commit()) else {
+                continue;
+            };
+            let tip = tip.id();
+
+            let distance = if tip == head_oid {
+                0
+            } else if self.repo.graph_descendant_of(tip, head_oid)? {
+                self.repo.graph_ahead_behind(tip, head_oid)?.0
+            } else {
+                continue;
+            };
+            consider((distance, false, name.to_string()));
+        }
+
+        for reference in self.repo.references_glob("refs/tags/*")?.flatten() {
+            let Some(name) = reference.shorthand() else {
+                continue;
+            };
+            if reference
+                .peel_to_commit()
+                .is_ok_and(|tip| tip.id() == head_oid)
+            {
+                consider((0, true, name.to_string()));
+            }
+        }
+
+        Ok(Some(DetachedHead {
+            nearest_ref: nearest.map(|(distance, _, name)| match distance {
+                0 => name,
+                n => format!("{name}~{n}"),
+            }),
+        }))
+    }
+
+    /// Commits reachable from a detached HEAD but from no branch or tag
+    ///
+    /// These are the commits that switching away would leave behind. Returns
+    /// `(short hash, subject)` pairs, newest first; empty when HEAD is attached.
+    pub fn get_orphaned_commits(&self) -> Result<Vec<(String, String)>> {
+        if !self.repo.head_detached().unwrap_or(false) {
+            return Ok(Vec::new());
+        }
+
+        let mut revwalk = self.repo.revwalk()?;
+        revwalk.push_head()?;
+        for reference in self.repo.references()?.flatten() {
+            if !reference.is_branch() && !reference.is_tag() {
+                continue;
+            }
+            if let Ok(commit) = reference.peel_to_commit() {
+                revwalk.hide(commit.id())?;
+            }
+        }
+
+        let mut commits = Vec::new();
+        for oid in revwalk {
+            let oid = oid?;
+            let commit = self.repo.find_commit(oid)?;
+            let subject = commit.summary().unwrap_or("").to_string();
+            commits.push((self.short_oid(oid), subject));
+        }
+        Ok(commits)
+    }
+
+    /// Short id and subject line of the HEAD commit
+    ///
+    /// Before the first commit (or on an orphan branch) the id is empty and
+    /// the subject reads `- no commits yet -`.
+    pub fn get_parent_commit_info(&self) -> Result<(String, String)> {
+        match self.repo.head().and_then(|head| head.peel_to_commit()) {
+            Ok(commit) => {
+                let message = commit
+                    .message()
+                    .unwrap_or("")
+                    .lines()
+                    .next()
+                    .unwrap_or("")
+                    .to_string();
+                Ok((self.short_oid(commit.id()), message))
+            }
+            Err(_) => Ok(("".to_string(), "- no commits yet -".to_string())),
+        }
+    }
+
+    /// The parent commit's message body, without its subject line
+    ///
+    /// `None` before the first commit or when the message is a subject only.
+    pub fn parent_commit_body(&self) -> Result<Option<String>> {
+        let Ok(commit) = self.repo.head().and_then(|head| head.peel_to_commit()) else {
+            return Ok(None);
+        };
+        Ok(commit
+            .body()
+            .map(str::trim)
+            .filter(|body| !body.is_empty())
+            .map(str::to_string))
+    }
+
+    /// Get ahead/behind information for the current branch relative to its upstream
+    /// Returns (ahead, behind) counts, or None if no upstream is set
+    pub fn get_ahead_behind(&self) -> Result<Option<(usize, usize)>> {
+        // Get the current branch reference
+        let head = match self.repo.head() {
+            Ok(head) => head,
+            Err(_) => return Ok(None),
+        };
+
+        // Get local commit OID
+        let local_oid = match head.target() {
+            Some(oid) => oid,
+            None => return Ok(None),
+        };
+
+        // Get branch name from HEAD
+        let branch_name = match head.shorthand() {
+            Some(name) => name,
+            None => return Ok(None),
+        };
+
+        // Find the local branch object
+        let local_branch = match self.repo.find_branch(branch_name, git2::BranchType::Local) {
+            Ok(branch) => branch,
+            Err(_) => return Ok(None),
+        };
+
+        // Get the upstream branch
+        let upstream_branch = match local_branch.upstream() {
+            Ok(upstream) => upstream,
+            Err(_) => return Ok(None), // No upstream configured
+        };
+
+        // Get upstream commit OID
+        let upstream_ref = upstream_branch.get();
+        let upstream_oid = match upstream_ref.target() {
+            Some(oid) => oid,
+            None => return Ok(None),
+        };
+
+        // A shallow clone may have cut the history before the fork point, and
+        // counting up to the cut gives numbers that look real but are not
+        if self.repo.is_shallow() && self.repo.merge_base(local_oid, upstream_oid).is_err() {
+            return Err(GitNavigatorError::ShallowHistory);
+        }
+
+        // Calculate ahead/behind using git2's graph functionality
+        match self.repo.graph_ahead_behind(local_oid, upstream_oid) {
+            Ok((ahead, behind)) => Ok(Some((ahead, behind))),
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// The last `limit` commits on the first-parent line of HEAD, numbered from 1
+    pub fn recent_commits(&self, limit: usize) -> Result<Vec<CommitEntry>> {
+        if self.repo.head().is_err() {
+            return Ok(Vec::new());
+        }
+
+        let mut revwalk = self.repo.revwalk()?;
+        revwalk.push_head()?;
+        revwalk.simplify_first_parent()?;
+
+        let mut commits = Vec::new();
+        for (i, oid) in revwalk.take(limit).enumerate() {
+            let commit = self.repo.find_commit(oid?)?;
+            commits.push(CommitEntry {
+                index: i + 1,
+                oid: commit.id().to_string(),
+                short_oid: self.short_oid(commit.id()),
+                summary: commit.summary().unwrap_or_default().to_string(),
+            });
+        }
+
+        Ok(commits)
+    }
+
+    /// Commits reachable from HEAD matching `query`, newest first and numbered
+    pub fn log(&self, query: &LogQuery) -> Result<Vec<LogEntry>> {
+        if self.repo.head().is_err() {
+            return Ok(Vec::new());
+        }
+
+        let grep = query
+            .grep
+            .as_deref()
+            .map(|pattern| {
+                regex::Regex::new(pattern).map_err(|e| GitNavigatorError::InvalidSearchPattern {
+                    pattern: pattern.to_string(),
+                    reason: e.to_string(),
+                })
+            })
+            .transpose()?;
+
+        let mailmap = self.repo.mailmap().ok();
+        let mut revwalk = self.repo.revwalk()?;
+        revwalk.push_head()?;
+        if let Some(since) = query.since {
+            revwalk.hide(since)?;
+        }
+        revwalk.set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::TIME)?;
+
+        let mut followed = query.path.clone();
+        let mut entries = Vec::new();
+        for oid in revwalk {
+            if entries.len() >= query.limit {
+                break;
+            }
+            let commit = self.repo.find_commit(oid?)?;
+            if query.no_merges && commit.parent_count() > 1 {
+                continue;
+            }
+            // Track renames even through commits the author filter drops
+            if let Some(path) = &mut followed {
+                match self.path_change(&commit, path)? {
+                    PathChange::Untouched => continue,
+                    PathChange::Changed => {}
+                    PathChange::RenamedFrom(old_path) => *path = old_path,
+                }
+            }
+            let author = self.mapped_author(&commit, mailmap.as_ref())?;
+            let name = author.name().unwrap_or_default();
+            let email = author.email().unwrap_or_default();
+            if let Some(wanted) = &query.author {
+                if !wanted.matches(name, email) {
+                    continue;
+                }
+            }
+            if let Some(grep) = &grep {
+                if !grep.is_match(commit.message().unwrap_or_default()) {
+                    continue;
+                }
+            }
+            if let Some(needle) = &query.pickaxe {
+                if !self.changes_occurrences(&commit, needle.as_bytes())? {
+                    continue;
+                }
+            }
+
+            entries.push(LogEntry {
+                commit: CommitEntry {
+                    index: entries.len() + 1,
+                    oid: commit.id().to_string(),
+                    short_oid: self.short_oid(commit.id()),
+                    summary: commit.summary().unwrap_or_default().to_string(),
+                },
+                author: name.to_string(),
+                time: author.when().seconds(),
+            });
+        }
+
+        Ok(entries)
+    }
+
+    /// Whether `commit` changed `path`, and the old name if it was renamed to it
+    fn path_change(&self, commit: &git2::Commit, path: &Path) -> Result<PathChange> {
+        let entry_id = |tree: &git2::Tree| tree.get_path(path).ok().map(|entry| entry.id());
+        let tree = commit.tree()?;
+        let current = entry_id(&tree);
+
+        let mut parent_ids = Vec::new();
+        for parent in commit.parents() {
+            parent_ids.push(entry_id(&parent.tree()?));
+        }
+        if parent_ids.is_empty() {
+            return Ok(if current.is_some() {
+                PathChange::Changed
+            } else {
+                PathChange::Untouched
+            });
+        }
+        // Like git log, a merge only counts if the file differs from every parent
+        if parent_ids.contains(&current) {
+            return Ok(PathChange::Untouched);
+        }
+
+        if current.is_some() && parent_ids == [None] {
+            let parent_tree = commit.parent(0)?.tree()?;
+            let mut diff = self
+                .repo
+                .diff_tree_to_tree(Some(&parent_tree), Some(&tree), None)?;
+            diff.find_similar(Some(git2::DiffFindOptions::new().renames(true)))?;
+            let renamed_from = diff.deltas().find_map(|delta| {
+                (delta.status() == git2::Delta::Renamed && delta.new_file().path() == Some(path))
+                    .then(|| delta.old_file().path().map(Path::to_path_buf))
+                    .flatten()
+            });
+            if let Some(old_path) = renamed_from {
+                return Ok(PathChange::RenamedFrom(old_path));
+            }
+        }
+        Ok(PathChange::Changed)
+    }
+
+    /// Whether `commit` changes the number of times `needle` occurs in some file
+    ///
+    /// Merges are skipped like `git log -S` does. Binary files are compared
+    /// byte for byte like text.
+    fn changes_occurrences(&self, commit: &git2::Commit, needle: &[u8]) -> Result<bool> {
+        if commit.parent_count() > 1 || needle.is_empty() {
+            return Ok(false);
+        }
+        let parent_tree = match commit.parent(0) {
+            Ok(parent) => Some(parent.tree()?),
+            Err(_) => None,
+        };
+        let diff =
+            self.repo
+                .diff_tree_to_tree(parent_tree.as_ref(), Some(&commit.tree()?), None)?;
+
+        let count_in = |oid: git2::Oid| -> Result<usize> {
+            if oid.is_zero() {
+                return Ok(0);
+            }
+            let blob = self
+                .repo
+                .find_blob(oid)
+                .map_err(|e| self.missing_object(oid, e))?;
+            Ok(blob
+                .content()
+                .windows(needle.len())
+                .filter(|window| *window == needle)
+                .count())
+        };
+        for delta in diff.deltas() {
+            if count_in(delta.old_file().id())? != count_in(delta.new_file().id())? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Authors of the commits reachable from HEAD, most commits first and numbered
+    ///
+    /// Authors are merged by email after applying `.mailmap`, and shown with
+    /// the name from their latest commit. With `since`, only commits authored
+    /// at or after that Unix time count.
+    pub fn contributors(&self, since: Option<i64>) -> Result<Vec<Contributor>> {
+        if self.repo.head().is_err() {
+            return Ok(Vec::new());
+        }
+
+        let mailmap = self.repo.mailmap().ok();
+        let mut revwalk = self.repo.revwalk()?;
+        revwalk.push_head()?;
+
+        let mut by_email: std::collections::HashMap<String, Contributor> =
+            std::collections::HashMap::new();
+        for oid in revwalk {
+            let commit = self.repo.find_commit(oid?)?;
+            let author = self.mapped_author(&commit, mailmap.as_ref())?;
+            let time = author.when().seconds();
+            if since.is_some_and(|since| time < since) {
+                continue;
+            }
+
+            let email = author.email().unwrap_or_default();
+            let entry = by_email
+                .entry(email.to_lowercase())
+                .or_insert_with(|| Contributor {
+                    index: 0,
+                    name: String::new(),
+                    email: email.to_string(),
+                    commits: 0,
+                    last_commit_time: i64::MIN,
+                });
+            entry.commits += 1;
+            if time > entry.last_commit_time {
+                entry.last_commit_time = time;
+                entry.name = author.name().unwrap_or_default().to_string();
+            }
+        }
+
+        let mut contributors: Vec<Contributor> = by_email.into_values().collect();
+        contributors.sort_by(|a, b| {
+            b.commits
+                .cmp(&a.commits)
+                .then(b.last_commit_time.cmp(&a.last_commit_time))
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        for (i, contributor) in contributors.iter_mut().enumerate() {
+            contributor.index = i + 1;
+        }
+        Ok(contributors)
+    }
+
+    fn mapped_author(
+        &self,
+        commit: &git2::Commit,
+        mailmap: Option<&git2::Mailmap>,
+    ) -> Result<git2::Signature<'static>> {
+        Ok(match mailmap {
+            Some(mailmap) => commit.author_with_mailmap(mailmap)?,
+            None => commit.author().to_owned(),
+        })
+    }
+
+    /// When the repository was last fetched, from the mtime of `FETCH_HEAD`
+    ///
+    /// `None` if it was never fetched (a fresh clone has no `FETCH_HEAD`).
+    pub fn last_fetch_time(&self) -> Option<std::time::SystemTime> {
+        std::fs::metadata(self.repo.path().join("FETCH_HEAD"))
+            .and_then(|metadata| metadata.modified())
+            .ok()
+    }
+
+    /// The upstream of the current branch, if one is configured
+    pub fn get_upstream(&self) -> Result<Option<Upstream>> {
+        let Ok(head) = self.repo.head() else {
+            return Ok(None);
+        };
+        let Some(refname) = head.name().filter(|_| head.is_branch()) else {
+            return Ok(None);
+        };
+
+        let (Ok(remote), Ok(merge), Ok(tracking)) = (
+            self.repo.branch_upstream_remote(refname),
+            self.repo.branch_upstream_merge(refname),
+            self.repo.branch_upstream_name(refname),
+        ) else {
+            return Ok(None);
+        };
+        let (Some(remote), Some(merge), Some(tracking)) =
+            (remote.as_str(), merge.as_str(), tracking.as_str())
+        else {
+            return Err(GitNavigatorError::InvalidUtf8Path);
+        };
+
+        Ok(Some(Upstream {
+            remote: remote.to_string(),
+            branch: merge.trim_start_matches("refs/heads/").to_string(),
+            tracking: tracking.trim_start_matches("refs/remotes/").to_string(),
+            oid: self.repo.refname_to_id(tracking).ok(),
+        }))
+    }
+
+    /// Whether local branch `branch` tracks a remote branch that no longer exists
+    ///
+    /// `Branch::upstream` fails with `NotFound` both when no upstream is
+    /// configured and when the remote-tracking ref was pruned after the remote
+    /// branch was deleted; only in the second case does the branch config
+    /// still name an upstream.
+    pub fn upstream_gone(&self, branch: &str) -> bool {
+        let Ok(local) = self.repo.find_branch(branch, git2::BranchType::Local) else {
+            return false;
+        };
+        match local.upstream() {
+            Err(e) if e.code() == git2::ErrorCode::NotFound => self
+                .repo
+                .branch_upstream_name(&format!("refs/heads/{branch}"))
+                .is_ok(),
+            _ => false,
+        }
+    }
+
+    /// How much history a shallow clone has and what a partial clone filtered out
+    pub fn history_limits(&self) -> HistoryLimits {
+        let shallow_depth = self.repo.is_shallow().then(|| {
+            let mut revwalk = match self.repo.revwalk() {
+                Ok(revwalk) => revwalk,
+                Err(_) => return 0,
+            };
+            if revwalk.push_head().is_err() {
+                return 0;
+            }
+            revwalk.count()
+        });
+
+        // `git clone --filter` marks the remote as a promisor (older git sets
+        // extensions.partialclone instead) and records its filter
+        let partial_filter = self.repo.config().ok().and_then(|config| {
+            let remote = config
+                .get_string("extensions.partialclone")
+                .ok()
+                .or_else(|| {
+                    let remotes = self.repo.remotes().ok()?;
+                    remotes
+                        .iter()
+                        .flatten()
+                        .find(|remote| {
+                            config
+                                .get_bool(&format!("remote.{remote}.promisor"))
+                                .unwrap_or(false)
+                        })
+                        .map(str::to_string)
+                })?;
+            Some(
+                config
+                    .get_string(&format!("remote.{remote}.partialclonefilter"))
+                    .unwrap_or_else(|_| "filtered".to_string()),
+            )
+        });
+
+        HistoryLimits {
+            shallow_depth,
+            partial_filter,
+        }
+    }
+
+    /// Say a missing object was left out by a partial clone, rather than a bare
+    /// "object not found"
+    fn missing_object(&self, oid: git2::Oid, e: git2::Error) -> GitNavigatorError {
+        if e.code() == git2::ErrorCode::NotFound && self.history_limits().partial_filter.is_some() {
+            GitNavigatorError::ObjectNotFetched(self.short_oid(oid))
+        } else {
+            e.into()
+        }
+    }
+
+    /// Commits reachable from `oid` but not from HEAD, newest first and numbered
+    ///
+    /// For a remote branch these are the commits a force push would discard.
+    pub fn commits_missing_from_head(&self, oid: git2::Oid) -> Result<Vec<CommitEntry>> {
+        let mut revwalk = self.repo.revwalk()?;
+        revwalk.push(oid)?;
+        if self.repo.head().is_ok() {
+            revwalk.hide_head()?;
+        }
+        self.numbered_commits(revwalk)
+    }
+
+    /// Commits reachable from HEAD but not from `oid`, newest first and numbered
+    ///
+    /// These are the commits `grs <commit>` takes off the current branch.
+    pub fn commits_missing_from(&self, oid: git2::Oid) -> Result<Vec<CommitEntry>> {
+        let mut revwalk = self.repo.revwalk()?;
+        revwalk.push_head()?;
+        revwalk.hide(oid)?;
+        self.numbered_commits(revwalk)
+    }
+
+    fn numbered_commits(&self, revwalk: git2::Revwalk) -> Result<Vec<CommitEntry>> {
+        let mut commits = Vec::new();
+        for (i, oid) in revwalk.enumerate() {
+            let commit = self.repo.find_commit(oid?)?;
+            commits.push(CommitEntry {
+                index: i + 1,
+                oid: commit.id().to_string(),
+                short_oid: self.short_oid(commit.id()),
+                summary: commit.summary().unwrap_or_default().to_string(),
+            });
+        }
+
+        Ok(commits)
+    }
+
+    /// Paths changed between the merge base of `base` and HEAD, and HEAD
+    ///
+    /// These are the changes a branch made since it left `base`, such as
+    /// the commits not pushed to the upstream yet.
+    pub fn paths_changed_since(&self, base: git2::Oid) -> Result<Vec<PathBuf>> {
+        let head = self.repo.head()?.peel_to_commit()?;
+        let merge_base = self.repo.merge_base(base, head.id())?;
+        let old_tree = self.repo.find_commit(merge_base)?.tree()?;
+        let diff = self
+            .repo
+            .diff_tree_to_tree(Some(&old_tree), Some(&head.tree()?), None)?;
+
+        let mut paths = Vec::new();
+        for delta in diff.deltas() {
+            paths.extend(delta.old_file().path().map(Path::to_path_buf));
+            paths.extend(delta.new_file().path().map(Path::to_path_buf));
+        }
+        paths.sort();
+        paths.dedup();
+        Ok(paths)
+    }
+
+    /// URL git uses to fetch from (or push to) `remote`
+    pub fn remote_url(&self, remote: &str, push: bool) -> Option<String> {
+        let remote = self.repo.find_remote(remote).ok()?;
+        let url = if push {
+            remote.pushurl().or_else(|| remote.url())
+        } else {
+            remote.url()
+        };
+        url.map(str::to_string)
+    }
+
+    /// Count every object in the object database and add up the blob sizes
+    pub fn object_totals(&self) -> Result<ObjectTotals> {
+        let mut totals = ObjectTotals::default();
+        self.for_each_object(|_, size, kind| {
+            totals.objects += 1;
+            if kind == git2::ObjectType::Blob {
+                totals.blobs += 1;
+                totals.blob_bytes += size;
+            }
+        })?;
+        Ok(totals)
+    }
+
+    /// The `limit` largest blobs, biggest first, with the commit that introduced each
+    ///
+    /// Every object in the database is considered, so blobs that only
+    /// unreachable or stashed commits refer to are listed too (without a path).
+    pub fn largest_blobs(&self, limit: usize) -> Result<Vec<BigObject>> {
+        let mut blobs = Vec::new();
+        self.for_each_object(|oid, size, kind| {
+            if kind == git2::ObjectType::Blob {
+                blobs.push((size, oid));
+            }
+        })?;
+        blobs.sort_unstable_by(|a, b| b.cmp(a));
+        blobs.truncate(limit);
+
+        let mut big: Vec<BigObject> = blobs
+            .into_iter()
+            .map(|(size, oid)| BigObject {
+                oid,
+                size,
+                path: None,
+                introduced_by: None,
+            })
+            .collect();
+        self.find_introducing_commits(&mut big)?;
+        Ok(big)
+    }
+
+    /// Call `f` once per object with its id, size and type
+    fn for_each_object(&self, mut f: impl FnMut(git2::Oid, u64, git2::ObjectType)) -> Result<()> {
+        let odb = self.repo.odb()?;
+        // Objects can be both loose and packed, so remember what was seen
+        let mut seen = std::collections::HashSet::new();
+        let mut failure = None;
+        let walked = odb.foreach(|oid| {
+            if !seen.insert(*oid) {
+                return true;
+            }
+            match odb.read_header(*oid) {
+                Ok((size, kind)) => {
+                    f(*oid, size as u64, kind);
+                    true
+                }
+                Err(e) => {
+                    failure = Some(e);
+                    false
+                }
+            }
+        });
+        // A failed header read stops the walk; report that error, not the abort
+        if let Some(e) = failure {
+            return Err(e.into());
+        }
+        walked?;
+        Ok(())
+    }
+
+    /// Walk all refs oldest first and note where each blob was first added
+    fn find_introducing_commits(&self, blobs: &mut [BigObject]) -> Result<()> {
+        let mut revwalk = self.repo.revwalk()?;
+        revwalk.push_glob("*")?;
+        revwalk.set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::REVERSE)?;
+
+        let mut missing = blobs.len();
+        for oid in revwalk {
+            if missing == 0 {
+                break;
+            }
+            let commit = self.repo.find_commit(oid?)?;
+            let parent_tree = match commit.parent(0) {
+                Ok(parent) => Some(parent.tree()?),
+                Err(_) => None,
+            };
+            let diff =
+                self.repo
+                    .diff_tree_to_tree(parent_tree.as_ref(), Some(&commit.tree()?), None)?;
+
+            for delta in diff.deltas() {
+                let new_file = delta.new_file();
+                let Some(blob) = blobs
+                    .iter_mut()
+                    .find(|blob| blob.oid == new_file.id() && blob.path.is_none())
+                else {
+                    continue;
+                };
+                blob.path = new_file.path().map(Path::to_path_buf);
+                blob.introduced_by = Some((
+                    self.short_oid(commit.id()),
+                    commit.summary().unwrap_or_default().to_string(),
+                ));
+                missing -= 1;
+            }
+        }
+        Ok(())
+    }
+
+    /// `git show` output for a commit, colored when the terminal is
+    pub fn show_commit(&self, oid: &str) -> Result<String> {
+        self.git_show(oid, &[])
+    }
+
+    /// The diff a commit made to one file, without the commit header
+    pub fn show_commit_file(&self, oid: &str, file: &CommitFile) -> Result<String> {
+        let mut paths = vec![file.path.as_path()];
+        paths.extend(file.old_path.as_deref());
+        self.git_show(oid, &paths)
+    }
+
+    fn git_show(&self, oid: &str, paths: &[&Path]) -> Result<String> {
+        let color_flag = if colored::control::SHOULD_COLORIZE.should_colorize() {
+            "--color"
+        } else {
+            "--no-color"
+        };
+        let mut cmd = std::process::Command::new("git");
+        cmd.arg("show").arg(color_flag);
+        if !paths.is_empty() {
+            cmd.args(["--format=", "--find-renames"])
+                .arg(oid)
+                .arg("--")
+                .args(paths);
+        } else {
+            cmd.arg(oid);
+        }
+
+        let output = self.git_output(cmd)?;
+        if !output.status.success() {
+            return Err(GitNavigatorError::custom_empty_files_error(format!(
+                "git show failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    /// Files a commit changed against its first parent, numbered in path order
+    pub fn commit_files(&self, oid: &str) -> Result<Vec<CommitFile>> {
+        let commit = self.repo.revparse_single(oid)?.peel_to_commit()?;
+        let parent_tree = match commit.parents().next() {
+            Some(parent) => Some(parent.tree()?),
+            None => None,
+        };
+
+        let mut opts = DiffOptions::new();
+        opts.context_lines(0);
+        let mut diff = self.repo.diff_tree_to_tree(
+            parent_tree.as_ref(),
+            Some(&commit.tree()?),
+            Some(&mut opts),
+        )?;
+        diff.find_similar(None)?;
+        numbered_files(&diff)
+    }
+
+    /// The newest commit reachable from HEAD committed at or before `time`,
+    /// walking history by committer date
+    pub fn last_commit_before(&self, time: i64) -> Result<Option<CommitEntry>> {
+        if self.repo.head().is_err() {
+            return Ok(None);
+        }
+
+        let mut revwalk = self.repo.revwalk()?;
+        revwalk.push_head()?;
+        revwalk.set_sorting(git2::Sort::TIME)?;
+        for oid in revwalk {
+            let commit = self.repo.find_commit(oid?)?;
+            if commit.time().seconds() <= time {
+                return Ok(Some(CommitEntry {
+                    index: 1,
+                    oid: commit.id().to_string(),
+                    short_oid: self.short_oid(commit.id()),
+                    summary: commit.summary().unwrap_or_default().to_string(),
+                }));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Files in the worktree that differ from `base`, untracked ones included,
+    /// numbered in path order. Without a base everything counts as new
+    pub fn files_changed_since(&self, base: Option<&str>) -> Result<Vec<CommitFile>> {
+        let base_tree = match base {
+            Some(oid) => Some(self.repo.revparse_single(oid)?.peel_to_tree()?),
+            None => None,
+        };
+
+        let mut opts = DiffOptions::new();
+        opts.context_lines(0)
+            .include_untracked(true)
+            .recurse_untracked_dirs(true)
+            .show_untracked_content(true);
+        let mut diff = self
+            .repo
+            .diff_tree_to_workdir_with_index(base_tree.as_ref(), Some(&mut opts))?;
+        diff.find_similar(None)?;
+        numbered_files(&diff)
+    }
+
+    /// What `file` looks like in the worktree against `base`, as `git diff` shows it
+    pub fn diff_since_file(&self, base: Option<&str>, file: &CommitFile) -> Result<String> {
+        let color_flag = if colored::control::SHOULD_COLORIZE.should_colorize() {
+            "--color"
+        } else {
+            "--no-color"
+        };
+        let mut cmd = std::process::Command::new("git");
+        cmd.arg("diff").arg(color_flag);
+        if file.status == GitStatus::Untracked {
+            // Shown as added in full; --no-index exits 1 when the files differ
+            cmd.args(["--no-index", "--", "/dev/null"]).arg(&file.path);
+        } else {
+            let base = match base {
+                Some(oid) => oid.to_string(),
+                None => git2::Oid::hash_object(git2::ObjectType::Tree, &[])?.to_string(),
+            };
+            cmd.args(["--find-renames", &base, "--"])
+                .arg(&file.path)
+                .args(file.old_path.as_deref());
+        }
+
+        let output = self.git_output(cmd)?;
+        if output.status.code().is_none_or(|code| code > 1) {
+            return Err(GitNavigatorError::custom_empty_files_error(format!(
+                "git diff failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    /// Changes to `path` that are not staged yet, a new file's included
+    fn unstaged_diff(&self, path: &Path) -> Result<git2::Diff<'_>> {
+        let mut opts = DiffOptions::new();
+        opts.pathspec(path)
+            .disable_pathspec_match(true)
+            .include_untracked(true)
+            .show_untracked_content(true);
+        Ok(self.repo.diff_index_to_workdir(None, Some(&mut opts))?)
+    }
+
+    /// The unstaged hunks of `path`, in file order
+    pub fn unstaged_hunks(&self, path: &Path) -> Result<Vec<PatchHunk>> {
+        let diff = self.unstaged_diff(path)?;
+        if diff.deltas().len() == 0 {
+            return Ok(Vec::new());
+        }
+        let Some(patch) = git2::Patch::from_diff(&diff, 0)? else {
+            return Ok(Vec::new());
+        };
+
+        let mut hunks = Vec::new();
+        for hunk_index in 0..patch.num_hunks() {
+            let (hunk, line_count) = patch.hunk(hunk_index)?;
+            let mut lines = Vec::with_capacity(line_count);
+            for line_index in 0..line_count {
+                let line = patch.line_in_hunk(hunk_index, line_index)?;
+                let content = String::from_utf8_lossy(line.content());
+                lines.push((line.origin(), content.trim_end_matches('\n').to_string()));
+            }
+            hunks.push(PatchHunk {
+                header: String::from_utf8_lossy(hunk.header())
+                    .trim_end()
+                    .to_string(),
+                lines,
+            });
+        }
+        Ok(hunks)
+    }
+
+    /// Stage the hunks of `path` picked in `accepted`, one entry per hunk of
+    /// [`GitRepo::unstaged_hunks`]
+    pub fn stage_hunks(&self, path: &Path, accepted: &[bool]) -> Result<()> {
+        if !accepted.contains(&true) {
+            return Ok(());
+        }
+        let diff = self.unstaged_diff(path)?;
+        // A new file is a single hunk, so picking it adds the whole file
+        let untracked = diff
+            .deltas()
+            .any(|delta| delta.status() == git2::Delta::Untracked);
+        if untracked {
+            return self.add_files(&[path.to_path_buf()]);
+        }
+
+        let mut next = 0;
+        let mut opts = git2::ApplyOptions::new();
+        opts.hunk_callback(|_| {
+            let keep = accepted.get(next).copied().unwrap_or(false);
+            next += 1;
+            keep
+        });
+        self.repo
+            .apply(&diff, git2::ApplyLocation::Index, Some(&mut opts))?;
+        Ok(())
+    }
+
+    /// Cherry-pick `oids` onto HEAD, in the order given
+    pub fn cherry_pick(&self, oids: &[&str]) -> Result<()> {
+        let mut cmd = std::process::Command::new("git");
+        cmd.arg("cherry-pick").args(oids);
+
+        let output = self.git_output(cmd)?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let reason = stderr
+                .lines()
+                .find(|line| line.starts_with("error:"))
+                .unwrap_or_else(|| stderr.trim());
+            return Err(GitNavigatorError::CherryPickFailed(
+                reason.trim_start_matches("error:").trim().to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Tags pointing at commits, newest commit first and numbered
+    pub fn list_tags(&self) -> Result<Vec<TagEntry>> {
+        let mut tags = Vec::new();
+        for name in self.repo.tag_names(None)?.iter().flatten() {
+            let reference = self.repo.find_reference(&format!("refs/tags/{name}"))?;
+            let annotated = reference
+                .target()
+                .and_then(|oid| self.repo.find_tag(oid).ok())
+                .is_some();
+            // Tags of trees or blobs have no place in a commit-oriented list
+            let Ok(commit) = reference.peel_to_commit() else {
+                continue;
+            };
+            tags.push(TagEntry {
+                index: 0,
+                name: name.to_string(),
+                oid: commit.id().to_string(),
+                short_oid: self.short_oid(commit.id()),
+                annotated,
+                time: commit.time().seconds(),
+            });
+        }
+
+        // Same commit time: higher names (usually later versions) first
+        tags.sort_by(|a, b| b.time.cmp(&a.time).then_with(|| b.name.cmp(&a.name)));
+        for (i, tag) in tags.iter_mut().enumerate() {
+            tag.index = i + 1;
+        }
+        Ok(tags)
+    }
+
+    /// Who made annotated tag `name`, when, and its message; `None` for a
+    /// lightweight tag
+    pub fn tag_annotation(&self, name: &str) -> Result<Option<TagAnnotation>> {
+        let reference = self.repo.find_reference(&format!("refs/tags/{name}"))?;
+        let Some(tag) = reference
+            .target()
+            .and_then(|oid| self.repo.find_tag(oid).ok())
+        else {
+            return Ok(None);
+        };
+        Ok(Some(TagAnnotation {
+            tagger: tag.tagger().map(|tagger| {
+                format!(
+                    "{} <{}>",
+                    tagger.name().unwrap_or("unknown"),
+                    tagger.email().unwrap_or("")
+                )
+            }),
+            time: tag.tagger().map(|tagger| tagger.when().seconds()),
+            message: tag.message().unwrap_or("").trim_end().to_string(),
+        }))
+    }
+
+    /// Tag commit `oid` as `name`, annotated when a message is given
+    pub fn create_tag(&self, name: &str, oid: git2::Oid, message: Option<&str>) -> Result<()> {
+        if !git2::Reference::is_valid_name(&format!("refs/tags/{name}")) {
+            return Err(GitNavigatorError::InvalidTagName(name.to_string()));
+        }
+        if self
+            .repo
+            .find_reference(&format!("refs/tags/{name}"))
+            .is_ok()
+        {
+            return Err(GitNavigatorError::TagExists(name.to_string()));
+        }
+
+        let target = self.repo.find_object(oid, Some(git2::ObjectType::Commit))?;
+        match message {
+            Some(message) => {
+                let tagger = self.repo.signature()?;
+                self.repo.tag(name, &target, &tagger, message, false)?;
+            }
+            None => {
+                self.repo.tag_lightweight(name, &target, false)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Commits with a note in `notes_ref` (default `refs/notes/commits`),
+    /// newest first and numbered, each with its note
+    pub fn commit_notes(&self, notes_ref: Option<&str>) -> Result<Vec<(CommitEntry, String)>> {
+        let notes = match self.repo.notes(notes_ref) {
+            Ok(notes) => notes,
+            // No note was ever added to this ref
+            Err(e) if e.code() == git2::ErrorCode::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut noted = Vec::new();
+        for note in notes {
+            let (_, annotated) = note?;
+            // Notes on blobs or trees are left to git itself
+            let Ok(commit) = self.repo.find_commit(annotated) else {
+                continue;
+            };
+            let message = self
+                .repo
+                .find_note(notes_ref, annotated)?
+                .message()
+                .unwrap_or_default()
+                .to_string();
+            noted.push((commit.time().seconds(), commit.id(), message));
+        }
+        noted.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+
+        noted
+            .into_iter()
+            .enumerate()
+            .map(|(i, (_, oid, message))| {
+                let commit = self.repo.find_commit(oid)?;
+                let entry = CommitEntry {
+                    index: i + 1,
+                    oid: oid.to_string(),
+                    short_oid: self.short_oid(oid),
+                    summary: commit.summary().unwrap_or_default().to_string(),
+                };
+                Ok((entry, message))
+            })
+            .collect()
+    }
+
+    /// The note on commit `oid` in `notes_ref`, if it has one
+    pub fn commit_note(&self, notes_ref: Option<&str>, oid: git2::Oid) -> Result<Option<String>> {
+        match self.repo.find_note(notes_ref, oid) {
+            Ok(note) => Ok(Some(note.message().unwrap_or_default().to_string())),
+            Err(e) if e.code() == git2::ErrorCode::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Attach `message` to commit `oid`, replacing its note only with `force`
+    pub fn set_commit_note(
+        &self,
+        notes_ref: Option<&str>,
+        oid: git2::Oid,
+        message: &str,
+        force: bool,
+    ) -> Result<()> {
+        let signature = self.repo.signature()?;
+        match self
+            .repo
+            .note(&signature, &signature, notes_ref, oid, message, force)
+        {
+            Ok(_) => Ok(()),
+            Err(e) if e.code() == git2::ErrorCode::Exists => {
+                Err(GitNavigatorError::NoteExists(self.short_oid(oid)))
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn remove_commit_note(&self, notes_ref: Option<&str>, oid: git2::Oid) -> Result<()> {
+        let signature = self.repo.signature()?;
+        self.repo
+            .note_delete(oid, notes_ref, &signature, &signature)?;
+        Ok(())
+    }
+
+    /// Delete the local tag `name`
+    pub fn delete_tag(&self, name: &str) -> Result<()> {
+        self.repo.tag_delete(name)?;
+        Ok(())
+    }
+
+    /// Remotes that have tag `name`, and remotes that could not be asked
+    ///
+    /// Remote tags are not tracked locally, so each remote is contacted.
+    pub fn remotes_with_tag(&self, name: &str) -> Result<(Vec<String>, Vec<String>)> {
+        let refname = format!("refs/tags/{name}");
+        let network = NetworkCheck::for_repo(self);
+        let mut found = Vec::new();
+        let mut unknown = Vec::new();
+
+        for remote_name in self.remote_names()? {
+            let reachable = self
+                .remote_url(&remote_name, false)
+                .map(|url| network.ensure_remote_reachable(&url));
+            if !matches!(reachable, Some(Ok(()))) {
+                unknown.push(remote_name);
+                continue;
+            }
+
+            let mut remote = self.repo.find_remote(&remote_name)?;
+            let credentials = Credentials::new(&self.repo);
+            let has_tag = remote
+                .connect_auth(Direction::Fetch, Some(credentials.callbacks()), None)
+                .and_then(|connection| {
+                    Ok(connection.list()?.iter().any(|head| head.name() == refname))
+                });
+            match has_tag {
+                Ok(true) => found.push(remote_name),
+                Ok(false) => {}
+                Err(e) => {
+                    log::debug!("Could not list tags on {remote_name}: {e}");
+                    unknown.push(remote_name);
+                }
+            }
+        }
+        Ok((found, unknown))
+    }
+
+    /// Names of the configured remotes
+    pub fn remote_names(&self) -> Result<Vec<String>> {
+        Ok(self
+            .repo
+            .remotes()?
+            .iter()
+            .flatten()
+            .map(str::to_string)
+            .collect())
+    }
+
+    /// Connect to `remote` with the credential callbacks and count the refs it advertises
+    pub fn check_remote_access(&self, remote: &str) -> Result<usize> {
+        let mut handle = self
+            .repo
+            .find_remote(remote)
+            .map_err(|_| GitNavigatorError::RemoteNotFound(remote.to_string()))?;
+        let credentials = Credentials::new(&self.repo);
+        let connection = handle
+            .connect_auth(Direction::Fetch, Some(credentials.callbacks()), None)
+            .map_err(|e| credentials.classify(remote, e))?;
+        Ok(connection.list()?.len())
+    }
+
+    /// Fetch from `remote`
+    pub fn fetch(&self, remote: &str) -> Result<()> {
+        let mut cmd = std::process::Command::new("git");
+        cmd.arg("fetch").arg("--quiet").arg(remote);
+
+        let output = self.git_output(cmd)?;
+        if !output.status.success() {
+            return Err(GitNavigatorError::fetch_failed(
+                String::from_utf8_lossy(&output.stderr).trim(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Pull the current branch from its upstream, following `pull.rebase`
+    pub fn pull(&self) -> Result<()> {
+        let mut cmd = std::process::Command::new("git");
+        cmd.arg("pull").arg("--quiet");
+
+        let output = self.git_output(cmd)?;
+        if !output.status.success() {
+            return Err(GitNavigatorError::pull_failed(
+                String::from_utf8_lossy(&output.stderr).trim(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Commit the index with `message`, running the usual hooks
+    ///
+    /// Returns the short id of the new commit.
+    pub fn commit(&self, message: &str) -> Result<String> {
+        let mut cmd = std::process::Command::new("git");
+        cmd.arg("commit")
+            .arg("--quiet")
+            .arg("--message")
+            .arg(message);
+        self.run_commit(cmd)
+    }
+
+    /// Commit the working-tree content of `paths` on top of HEAD
+    ///
+    /// As with [`Self::commit_staged_paths`] the commit is built in a
+    /// temporary index, so files staged outside `paths` stay staged. The real
+    /// index entries of `paths` are then reset to the new commit. A rename
+    /// needs both of its paths (see [`FileEntry::affected_paths`]).
+    pub fn commit_paths(&self, paths: &[PathBuf], message: &str) -> Result<String> {
+        let index_file = self.temporary_index_file("commit");
+        let git = |args: &[&str]| {
+            let mut cmd = std::process::Command::new("git");
+            cmd.args(args).env("GIT_INDEX_FILE", &index_file);
+            cmd
+        };
+
+        let result = (|| {
+            if self.repo.head().is_ok() {
+                self.execute_git_command(git(&["read-tree", "HEAD"]))?;
+            } else {
+                self.execute_git_command(git(&["read-tree", "--empty"]))?;
+            }
+            let mut add = git(&["add", "--all", "--"]);
+            add.args(paths);
+            self.execute_git_command(add)?;
+            self.run_commit(git(&["commit", "--quiet", "--message", message]))
+        })();
+        let _ = std::fs::remove_file(&index_file);
+        let short_oid = result?;
+
+        let mut reset = std::process::Command::new("git");
+        reset.args(["reset", "--quiet", "--"]).args(paths);
+        self.execute_git_command(reset)?;
+        Ok(short_oid)
+    }
+
+    /// Run a `git commit` command and return the new HEAD's short id
+    fn run_commit(&self, cmd: std::process::Command) -> Result<String> {
+        let output = self.git_output(cmd)?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let reason = if stderr.trim().is_empty() {
+                String::from_utf8_lossy(&output.stdout)
+            } else {
+                stderr
+            };
+            return Err(GitNavigatorError::commit_failed(reason.trim()));
+        }
+
+        let head = self.repo.head()?.peel_to_commit()?;
+        Ok(self.short_oid(head.id()))
+    }
+
+    /// Push the current branch to `branch` on `remote`
+    ///
+    /// With `lease`, the push is forced but only goes through while the
+    /// remote branch still points at that commit (`--force-with-lease`).
+    pub fn push_branch(
+        &self,
+        remote: &str,
+        branch: &str,
+        lease: Option<git2::Oid>,
+        set_upstream: bool,
+    ) -> Result<()> {
+        let mut cmd = std::process::Command::new("git");
+        cmd.arg("push").arg("--porcelain");
+        if set_upstream {
+            cmd.arg("--set-upstream");
+        }
+        if let Some(expected) = lease {
+            cmd.arg(format!("--force-with-lease=refs/heads/{branch}:{expected}"));
+        }
+        cmd.arg(remote).arg(format!("HEAD:refs/heads/{branch}"));
+
+        let output = self.git_output(cmd)?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            // --porcelain reports rejections on stdout as "!\t<ref>\t[reason]"
+            let rejection = stdout
+                .lines()
+                .find(|line| line.starts_with('!'))
+                .and_then(|line| line.rsplit('\t').next())
+                .map(str::to_string);
+            return Err(GitNavigatorError::push_failed(
+                rejection.unwrap_or_else(|| stderr.trim().to_string()),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Name of the upstream branch already containing `oid`, if any
+    pub fn upstream_containing(&self, oid: git2::Oid) -> Result<Option<String>> {
+        let Ok(head) = self.repo.head() else {
+            return Ok(None);
+        };
+        let Some(branch_name) = head.shorthand().filter(|_| head.is_branch()) else {
+            return Ok(None);
+        };
+        let Ok(upstream) = self
+            .repo
+            .find_branch(branch_name, git2::BranchType::Local)
+            .and_then(|branch| branch.upstream())
+        else {
+            return Ok(None);
+        };
+        let Some(upstream_oid) = upstream.get().target() else {
+            return Ok(None);
+        };
+
+        if upstream_oid == oid || self.repo.graph_descendant_of(upstream_oid, oid)? {
+            Ok(upstream.name()?.map(str::to_string))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Replace the message of `oid`, an ancestor of HEAD (or HEAD itself)
+    ///
+    /// The commit and every commit after it are recreated with the same trees
+    /// and authors, as `git rebase` would, and the branch is moved to the new
+    /// tip. Since no tree changes, the index and worktree are left alone.
+    /// History containing merges after `oid` is refused. Later commits keep
+    /// their message bytes and encoding; signatures cannot survive a rewrite,
+    /// so the number of signed commits that lost theirs is returned.
+    pub fn reword_commit(&self, oid: git2::Oid, message: &str) -> Result<usize> {
         let head = self.repo.head()?;
+        let head_oid = head
+            .target()
+            .ok_or_else(|| GitNavigatorError::reword_failed("HEAD does not point to a commit"))?;
+
+        // Commits from HEAD back to `oid`, which must be on the first-parent line
+        let mut chain = Vec::new();
+        let mut current = self.repo.find_commit(head_oid)?;
+        loop {
+            if current.parent_count() > 1 {
+                return Err(GitNavigatorError::reword_failed(
+                    "history after it contains merges",
+                ));
+            }
+            let reached = current.id() == oid;
+            let parent = current.parent(0).ok();
+            chain.push(current);
+            if reached {
+                break;
+            }
+            current = parent
+                .ok_or_else(|| GitNavigatorError::reword_failed("it is not an ancestor of HEAD"))?;
+        }
 
-        if let Some(branch_name) = head.shorthand() {
-            if head.is_branch() {
-                Ok(branch_name.to_string())
-            } else {
-                // Detached HEAD
-                let oid = head.target().unwrap();
-                Ok(format!("detached at {}", &oid.to_string()[..7]))
+        let committer = self.repo.signature()?;
+        let mut parent = chain.last().and_then(|target| target.parent_id(0).ok());
+        let mut new_oid = head_oid;
+        let mut unsigned = 0;
+        for (i, commit) in chain.iter().rev().enumerate() {
+            if commit.header_field_bytes("gpgsig").is_ok()
+                || commit.header_field_bytes("gpgsig-sha256").is_ok()
+            {
+                unsigned += 1;
             }
-        } else {
-            Ok("-none-".to_string())
+            // The new message is the user's UTF-8 text, so it drops the
+            // target's encoding header
+            let (message, encoding) = if i == 0 {
+                (message.as_bytes(), None)
+            } else {
+                (commit.message_raw_bytes(), commit.message_encoding())
+            };
+            new_oid = self.write_rewritten_commit(commit, parent, &committer, encoding, message)?;
+            parent = Some(new_oid);
         }
+
+        let mut cmd = std::process::Command::new("git");
+        cmd.arg("update-ref")
+            .arg("-m")
+            .arg("git-navigator: reword")
+            .arg("HEAD")
+            .arg(new_oid.to_string())
+            .arg(head_oid.to_string());
+        self.execute_git_command(cmd)?;
+        Ok(unsigned)
     }
 
-    pub fn get_parent_commit_info(&self) -> Result<(String, String)> {
-        match self.repo.head() {
-            Ok(head) => {
-                if let Some(oid) = head.target() {
-                    let commit = self.repo.find_commit(oid)?;
-                    let short_hash = oid.to_string()[..7].to_string();
-                    let message = commit
-                        .message()
-                        .unwrap_or("")
-                        .lines()
-                        .next()
-                        .unwrap_or("")
-                        .to_string();
-                    Ok((short_hash, message))
-                } else {
-                    Ok(("".to_string(), "- no commits yet -".to_string()))
-                }
-            }
-            Err(_) => Ok(("".to_string(), "- no commits yet -".to_string())),
+    /// Write a copy of `commit` on top of `parent` with a new committer
+    ///
+    /// `Repository::commit` only takes UTF-8 messages, so the object is built
+    /// by hand to keep `message` byte for byte along with its encoding.
+    fn write_rewritten_commit(
+        &self,
+        commit: &git2::Commit,
+        parent: Option<git2::Oid>,
+        committer: &git2::Signature,
+        encoding: Option<&str>,
+        message: &[u8],
+    ) -> Result<git2::Oid> {
+        let mut buffer = format!("tree {}\n", commit.tree_id()).into_bytes();
+        if let Some(parent) = parent {
+            buffer.extend_from_slice(format!("parent {parent}\n").as_bytes());
+        }
+        push_signature(&mut buffer, "author", &commit.author());
+        push_signature(&mut buffer, "committer", committer);
+        if let Some(encoding) = encoding {
+            buffer.extend_from_slice(format!("encoding {encoding}\n").as_bytes());
         }
+        buffer.push(b'\n');
+        buffer.extend_from_slice(message);
+        Ok(self.repo.odb()?.write(git2::ObjectType::Commit, &buffer)?)
     }
 
-    /// Get ahead/behind information for the current branch relative to its upstream
-    /// Returns (ahead, behind) counts, or None if no upstream is set
-    pub fn get_ahead_behind(&self) -> Result<Option<(usize, usize)>> {
-        // Get the current branch reference
-        let head = match self.repo.head() {
-            Ok(head) => head,
-            Err(_) => return Ok(None),
+    /// First line of `path` touched by its staged or unstaged changes
+    ///
+    /// Returns the start of the first hunk on the new side of the diff, or
+    /// `None` when the file has no textual changes (e.g. untracked files).
+    pub fn first_changed_line(&self, path: &Path, staged: bool) -> Result<Option<u32>> {
+        let mut opts = DiffOptions::new();
+        opts.pathspec(path).context_lines(0);
+
+        let diff = if staged {
+            let head_tree = self
+                .repo
+                .head()
+                .ok()
+                .and_then(|head| head.peel_to_tree().ok());
+            self.repo
+                .diff_tree_to_index(head_tree.as_ref(), None, Some(&mut opts))?
+        } else {
+            self.repo.diff_index_to_workdir(None, Some(&mut opts))?
         };
 
-        // Get local commit OID
-        let local_oid = match head.target() {
-            Some(oid) => oid,
-            None => return Ok(None),
-        };
+        let mut first_line = None;
+        diff.foreach(
+            &mut |_, _| true,
+            None,
+            Some(&mut |_, hunk| {
+                if first_line.is_none() {
+                    // A pure deletion starts at the line before the removed block
+                    first_line = Some(hunk.new_start().max(1));
+                }
+                true
+            }),
+            None,
+        )?;
 
-        // Get branch name from HEAD
-        let branch_name = match head.shorthand() {
-            Some(name) => name,
-            None => return Ok(None),
-        };
+        Ok(first_line)
+    }
 
-        // Find the local branch object
-        let local_branch = match self.repo.find_branch(branch_name, git2::BranchType::Local) {
-            Ok(branch) => branch,
-            Err(_) => return Ok(None),
-        };
+    /// Lines added and removed per file, for everything not yet committed
+    ///
+    /// Covers staged, unstaged and untracked changes against HEAD. Binary
+    /// files and files without textual changes are left out.
+    pub fn line_stats(&self) -> Result<Vec<FileStat>> {
+        let mut opts = DiffOptions::new();
+        opts.include_untracked(true)
+            .recurse_untracked_dirs(true)
+            .show_untracked_content(true)
+            .context_lines(0);
+
+        let head_tree = self
+            .repo
+            .head()
+            .ok()
+            .and_then(|head| head.peel_to_tree().ok());
+        let diff = self
+            .repo
+            .diff_tree_to_workdir_with_index(head_tree.as_ref(), Some(&mut opts))?;
+
+        let mut stats = Vec::new();
+        for idx in 0..diff.deltas().len() {
+            let Some(patch) = git2::Patch::from_diff(&diff, idx)? else {
+                continue;
+            };
+            let (_, added, removed) = patch.line_stats()?;
+            if added + removed == 0 {
+                continue;
+            }
+            let delta = patch.delta();
+            let Some(path) = delta.new_file().path().or_else(|| delta.old_file().path()) else {
+                continue;
+            };
+            stats.push(FileStat {
+                path: path.to_path_buf(),
+                added,
+                removed,
+            });
+        }
 
-        // Get the upstream branch
-        let upstream_branch = match local_branch.upstream() {
-            Ok(upstream) => upstream,
-            Err(_) => return Ok(None), // No upstream configured
-        };
+        Ok(stats)
+    }
 
-        // Get upstream commit OID
-        let upstream_ref = upstream_branch.get();
-        let upstream_oid = match upstream_ref.target() {
-            Some(oid) => oid,
-            None => return Ok(None),
+    /// Commit the staged content of `paths` on top of HEAD
+    ///
+    /// The commit is built in a temporary index holding HEAD plus the staged
+    /// entries of `paths`, so a partially staged file commits exactly what
+    /// was staged and other staged files stay staged. The real index is not
+    /// touched; once every staged path has been committed it matches HEAD.
+    /// As with [`Self::commit_paths`], a rename needs both of its paths.
+    pub fn commit_staged_paths(&self, paths: &[PathBuf], message: &str) -> Result<()> {
+        let index_file = self.temporary_index_file("split");
+        let result = self.commit_with_index_file(&index_file, paths, message);
+        let _ = std::fs::remove_file(&index_file);
+        result
+    }
+
+    /// Path of a temporary index named after `purpose`, inside `.git`
+    ///
+    /// The name carries the process id, so two commands running at once never
+    /// share one.
+    fn temporary_index_file(&self, purpose: &str) -> PathBuf {
+        self.repo.path().join(format!(
+            "git-navigator-{purpose}.{}.index",
+            std::process::id()
+        ))
+    }
+
+    fn commit_with_index_file(
+        &self,
+        index_file: &Path,
+        paths: &[PathBuf],
+        message: &str,
+    ) -> Result<()> {
+        let git = |args: &[&str]| {
+            let mut cmd = std::process::Command::new("git");
+            cmd.args(args).env("GIT_INDEX_FILE", index_file);
+            cmd
         };
 
-        // Calculate ahead/behind using git2's graph functionality
-        match self.repo.graph_ahead_behind(local_oid, upstream_oid) {
-            Ok((ahead, behind)) => Ok(Some((ahead, behind))),
-            Err(_) => Ok(None),
+        if self.repo.head().is_ok() {
+            self.execute_git_command(git(&["read-tree", "HEAD"]))?;
+        } else {
+            self.execute_git_command(git(&["read-tree", "--empty"]))?;
+        }
+
+        let index = self.repo.index()?;
+        let mut update = git(&["update-index", "--add"]);
+        let mut remove = git(&["update-index", "--force-remove", "--"]);
+        let mut removals = 0;
+        for path in paths {
+            match index.get_path(path, 0) {
+                Some(entry) => {
+                    update.arg("--cacheinfo").arg(format!(
+                        "{:o},{},{}",
+                        entry.mode,
+                        entry.id,
+                        path.display()
+                    ));
+                }
+                None => {
+                    remove.arg(path);
+                    removals += 1;
+                }
+            }
+        }
+        if removals < paths.len() {
+            self.execute_git_command(update)?;
         }
+        if removals > 0 {
+            self.execute_git_command(remove)?;
+        }
+
+        self.execute_git_command(git(&["commit", "--quiet", "--message", message]))
     }
 
     pub fn add_files(&self, paths: &[PathBuf]) -> Result<()> {
         if paths.is_empty() {
             return Ok(());
         }
+        if self.backend() == Backend::Libgit2 {
+            return backend::add_files(&self.repo, paths);
+        }
 
         let mut cmd = std::process::Command::new("git");
         cmd.arg("add").arg("--");
@@ -234,6 +2361,9 @@ impl GitRepo {
         if paths.is_empty() {
             return Ok(());
         }
+        if self.backend() == Backend::Libgit2 {
+            return backend::checkout_files(&self.repo, paths);
+        }
 
         let mut cmd = std::process::Command::new("git");
         cmd.arg("checkout").arg("--");
@@ -245,52 +2375,426 @@ impl GitRepo {
         self.execute_git_command(cmd)
     }
 
+    /// `git checkout <revision> -- <paths>`: put the content of `paths` at
+    /// `revision` in the index and the worktree
+    ///
+    /// Every path must exist in `revision`; nothing is changed otherwise.
+    pub fn checkout_files_from(&self, revision: &str, paths: &[PathBuf]) -> Result<()> {
+        if paths.is_empty() {
+            return Ok(());
+        }
+        let tree = self.repo.revparse_single(revision)?.peel_to_tree()?;
+        if let Some(missing) = paths.iter().find(|path| tree.get_path(path).is_err()) {
+            return Err(GitNavigatorError::FileNotInRevision {
+                path: missing.display().to_string(),
+                revision: revision.to_string(),
+            });
+        }
+        if self.backend() == Backend::Libgit2 {
+            return backend::checkout_files_from(&self.repo, &tree, paths);
+        }
+
+        let mut cmd = std::process::Command::new("git");
+        cmd.arg("checkout").arg(revision).arg("--");
+
+        for path in paths {
+            cmd.arg(path);
+        }
+
+        self.execute_git_command(cmd)
+    }
+
     pub fn create_branch(&self, branch_name: &str) -> Result<()> {
+        if self.backend() == Backend::Libgit2 {
+            return backend::create_branch(&self.repo, branch_name, None);
+        }
         let mut cmd = std::process::Command::new("git");
         cmd.args(["checkout", "-b", branch_name]);
         self.execute_git_command(cmd)
     }
 
+    /// Create a new branch at `start_point` and switch to it
+    pub fn create_branch_from(&self, branch_name: &str, start_point: &str) -> Result<()> {
+        if self.backend() == Backend::Libgit2 {
+            return backend::create_branch(&self.repo, branch_name, Some(start_point));
+        }
+        let mut cmd = std::process::Command::new("git");
+        cmd.args(["checkout", "-b", branch_name, start_point]);
+        self.execute_git_command(cmd)
+    }
+
+    /// Create local branch `branch_name` tracking `remote_branch` ("origin/topic")
+    /// without switching to it
+    pub fn track_branch(&self, branch_name: &str, remote_branch: &str) -> Result<()> {
+        if self.backend() == Backend::Libgit2 {
+            return backend::track_branch(&self.repo, branch_name, remote_branch);
+        }
+        let mut cmd = std::process::Command::new("git");
+        cmd.args(["branch", "--track", branch_name, remote_branch]);
+        self.execute_git_command(cmd)
+    }
+
+    /// Delete local branch `branch_name`; git refuses if it has unmerged commits
+    pub fn delete_branch(&self, branch_name: &str) -> Result<()> {
+        let mut cmd = std::process::Command::new("git");
+        cmd.args(["branch", "--delete", branch_name]);
+        self.execute_git_command(cmd)
+    }
+
+    /// Every worktree of the repository, the main one first
+    pub fn worktrees(&self) -> Result<Vec<Worktree>> {
+        let mut cmd = std::process::Command::new("git");
+        cmd.args(["worktree", "list", "--porcelain"]);
+        let output = self.git_output(cmd)?;
+        if !output.status.success() {
+            return Err(GitNavigatorError::custom_empty_files_error(format!(
+                "git command failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        let listing = String::from_utf8_lossy(&output.stdout);
+        Ok(parse_worktree_list(&listing))
+    }
+
+    /// Check `branch` out in a new worktree at `path`
+    pub fn add_worktree(&self, path: &Path, branch: &str) -> Result<()> {
+        let mut cmd = std::process::Command::new("git");
+        cmd.args(["worktree", "add"]).arg(path).arg(branch);
+        self.execute_git_command(cmd)
+    }
+
+    /// Remove the worktree at `path`; git refuses when it has local changes
+    /// unless `force` is set
+    pub fn remove_worktree(&self, path: &Path, force: bool) -> Result<()> {
+        let mut cmd = std::process::Command::new("git");
+        cmd.args(["worktree", "remove"]);
+        if force {
+            cmd.arg("--force");
+        }
+        cmd.arg(path);
+        self.execute_git_command(cmd)
+    }
+
+    /// The sparse checkout definition of this worktree, if it is sparse
+    pub fn sparse_checkout(&self) -> Option<SparseCheckout> {
+        SparseCheckout::load(&self.repo)
+    }
+
+    /// Add directories to the sparse checkout cone, checking their files out
+    pub fn sparse_add(&self, dirs: &[PathBuf]) -> Result<()> {
+        let mut cmd = std::process::Command::new("git");
+        cmd.args(["sparse-checkout", "add", "--"]).args(dirs);
+        self.execute_git_command(cmd)
+    }
+
+    /// Check whether `revision` resolves to a commit
+    pub fn commit_exists(&self, revision: &str) -> bool {
+        self.repo
+            .revparse_single(revision)
+            .and_then(|object| object.peel_to_commit())
+            .is_ok()
+    }
+
+    /// Find files with local changes that a checkout of `target` would overwrite
+    ///
+    /// A file blocks the switch when it has staged, unstaged or untracked
+    /// changes and its content differs between HEAD and the target tree.
+    /// Unknown targets yield no conflicts so git can report them itself.
+    pub fn find_checkout_conflicts(&self, target: &str) -> Result<Vec<PathBuf>> {
+        let head_tree = match self.repo.head().and_then(|head| head.peel_to_tree()) {
+            Ok(tree) => tree,
+            Err(_) => return Ok(Vec::new()),
+        };
+        let target_tree = match self
+            .repo
+            .revparse_single(target)
+            .and_then(|object| object.peel_to_tree())
+        {
+            Ok(tree) => tree,
+            Err(_) => return Ok(Vec::new()),
+        };
+
+        let diff = self
+            .repo
+            .diff_tree_to_tree(Some(&head_tree), Some(&target_tree), None)?;
+        let changed: std::collections::HashSet<&Path> = diff
+            .deltas()
+            .flat_map(|delta| [delta.old_file().path(), delta.new_file().path()])
+            .flatten()
+            .collect();
+
+        let mut conflicts: Vec<PathBuf> = self
+            .get_status()?
+            .into_iter()
+            .filter(|file| changed.contains(file.path.as_path()))
+            .map(|file| file.path)
+            .collect();
+        conflicts.dedup();
+
+        Ok(conflicts)
+    }
+
+    /// Stash local changes, returning the new stash commit if anything was stashed
+    pub fn stash_push(&self, message: &str, include_untracked: bool) -> Result<Option<git2::Oid>> {
+        let before = self.repo.refname_to_id("refs/stash").ok();
+
+        let mut cmd = std::process::Command::new("git");
+        cmd.args(["stash", "push", "-m", message]);
+        if include_untracked {
+            cmd.arg("--include-untracked");
+        }
+        self.execute_git_command(cmd)?;
+
+        let after = self.repo.refname_to_id("refs/stash").ok();
+        Ok(after.filter(|oid| Some(*oid) != before))
+    }
+
+    /// Re-apply and drop the most recent stash entry
+    pub fn stash_pop(&self) -> Result<()> {
+        let mut cmd = std::process::Command::new("git");
+        cmd.args(["stash", "pop"]);
+        self.execute_git_command(cmd)
+    }
+
+    /// Check whether a local branch with the given name exists
+    pub fn branch_exists(&self, branch_name: &str) -> bool {
+        self.repo
+            .find_branch(branch_name, git2::BranchType::Local)
+            .is_ok()
+    }
+
     pub fn checkout_branch(&self, branch_name: &str) -> Result<()> {
+        if self.backend() == Backend::Libgit2 {
+            return backend::checkout_branch(&self.repo, branch_name);
+        }
         let mut cmd = std::process::Command::new("git");
         cmd.args(["checkout", branch_name]);
         self.execute_git_command(cmd)
     }
 }
 
+/// `pathspec` as typed in `prefix` (the current directory below the top of
+/// the worktree), made relative to the top with `/` separators
+fn repo_pathspec(workdir: &Path, prefix: &Path, pathspec: &str) -> Result<String> {
+    let outside = || {
+        GitNavigatorError::custom_empty_files_error(format!(
+            "'{pathspec}' is outside the repository"
+        ))
+    };
+    let path = Path::new(pathspec);
+    let joined = if path.is_absolute() {
+        path.strip_prefix(workdir)
+            .map_err(|_| outside())?
+            .to_path_buf()
+    } else {
+        prefix.join(path)
+    };
+
+    let mut parts: Vec<String> = Vec::new();
+    for component in joined.components() {
+        match component {
+            Component::Normal(part) => parts.push(part.to_string_lossy().into_owned()),
+            Component::ParentDir => {
+                parts.pop().ok_or_else(outside)?;
+            }
+            _ => {}
+        }
+    }
+    Ok(parts.join("/"))
+}
+
+/// Append a `field name <email> time offset` commit header line, keeping the
+/// name and email bytes as they are
+fn push_signature(buffer: &mut Vec<u8>, field: &str, signature: &git2::Signature) {
+    let when = signature.when();
+    let offset = when.offset_minutes().abs();
+    buffer.extend_from_slice(field.as_bytes());
+    buffer.push(b' ');
+    buffer.extend_from_slice(signature.name_bytes());
+    buffer.extend_from_slice(b" <");
+    buffer.extend_from_slice(signature.email_bytes());
+    buffer.extend_from_slice(
+        format!(
+            "> {} {}{:02}{:02}\n",
+            when.seconds(),
+            when.sign(),
+            offset / 60,
+            offset % 60
+        )
+        .as_bytes(),
+    );
+}
+
+/// Parse `git worktree list --porcelain`: one block of `key value` lines
+/// per worktree, separated by blank lines
+fn parse_worktree_list(output: &str) -> Vec<Worktree> {
+    let mut worktrees = Vec::new();
+    for block in output.split("\n\n") {
+        let mut worktree: Option<Worktree> = None;
+        for line in block.lines() {
+            let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+            if key == "worktree" {
+                worktree = Some(Worktree {
+                    path: PathBuf::from(value),
+                    head: None,
+                    branch: None,
+                    bare: false,
+                    prunable: false,
+                });
+                continue;
+            }
+            let Some(worktree) = worktree.as_mut() else {
+                continue;
+            };
+            match key {
+                "HEAD" => worktree.head = Some(value.to_string()),
+                "branch" => {
+                    let branch = value.strip_prefix("refs/heads/").unwrap_or(value);
+                    worktree.branch = Some(branch.to_string());
+                }
+                "bare" => worktree.bare = true,
+                "prunable" => worktree.prunable = true,
+                _ => {}
+            }
+        }
+        worktrees.extend(worktree);
+    }
+    worktrees
+}
+
+/// Number the files of `diff` in path order, with the first changed line of each
+fn numbered_files(diff: &git2::Diff) -> Result<Vec<CommitFile>> {
+    let mut files = Vec::new();
+    for (position, delta) in diff.deltas().enumerate() {
+        let status = match delta.status() {
+            git2::Delta::Added => GitStatus::Added,
+            git2::Delta::Deleted => GitStatus::Deleted,
+            git2::Delta::Renamed => GitStatus::Renamed,
+            git2::Delta::Copied => GitStatus::Copied,
+            git2::Delta::Typechange => GitStatus::TypeChanged,
+            git2::Delta::Untracked => GitStatus::Untracked,
+            _ => GitStatus::Modified,
+        };
+        let Some(path) = delta.new_file().path().or(delta.old_file().path()) else {
+            continue;
+        };
+        let old_path = delta
+            .old_file()
+            .path()
+            .filter(|old| matches!(status, GitStatus::Renamed | GitStatus::Copied) && *old != path)
+            .map(Path::to_path_buf);
+        // A pure deletion starts at the line before the removed block
+        let first_line = git2::Patch::from_diff(diff, position)?
+            .and_then(|patch| patch.hunk(0).ok().map(|(hunk, _)| hunk.new_start().max(1)));
+
+        files.push(CommitFile {
+            index: files.len() + 1,
+            status,
+            path: path.to_path_buf(),
+            old_path,
+            first_line,
+        });
+    }
+    Ok(files)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use tempfile::TempDir;
 
+    #[test]
+    fn test_repo_pathspec() -> Result<()> {
+        let workdir = Path::new("/work/repo");
+        let in_src = Path::new("src");
+        assert_eq!(repo_pathspec(workdir, in_src, "core/")?, "src/core");
+        assert_eq!(repo_pathspec(workdir, in_src, "*.rs")?, "src/*.rs");
+        assert_eq!(repo_pathspec(workdir, in_src, "../docs")?, "docs");
+        assert_eq!(repo_pathspec(workdir, in_src, ".")?, "src");
+        assert_eq!(repo_pathspec(workdir, Path::new(""), ".")?, "");
+        assert_eq!(repo_pathspec(workdir, in_src, "/work/repo/tests")?, "tests");
+        assert!(repo_pathspec(workdir, in_src, "../..").is_err());
+        assert!(repo_pathspec(workdir, in_src, "/elsewhere").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_worktree_list() {
+        let output = "worktree /work/app\nHEAD 1111111111111111111111111111111111111111\nbranch refs/heads/main\n\nworktree /work/app-fix\nHEAD 2222222222222222222222222222222222222222\ndetached\n\nworktree /tmp/gone\nHEAD 3333333333333333333333333333333333333333\nbranch refs/heads/feature/x\nprunable gitdir file points to non-existent location\n\n";
+        let worktrees = parse_worktree_list(output);
+        assert_eq!(worktrees.len(), 3);
+        assert_eq!(worktrees[0].path, PathBuf::from("/work/app"));
+        assert_eq!(worktrees[0].branch.as_deref(), Some("main"));
+        assert_eq!(worktrees[1].branch, None);
+        assert_eq!(
+            worktrees[1].head.as_deref(),
+            Some("2222222222222222222222222222222222222222")
+        );
+        assert_eq!(worktrees[2].branch.as_deref(), Some("feature/x"));
+        assert!(worktrees[2].prunable);
+        assert!(!worktrees[0].prunable);
+    }
+
     fn setup_test_repo() -> Result<(TempDir, crate::core::git::GitRepo)> {
-        let temp_dir = TempDir::new().map_err(|e| GitNavigatorError::Io(e))?;
+        let temp_dir = TempDir::new().map_err(GitNavigatorError::Io)?;
         let repo_path = temp_dir.path();
 
         // Initialize git repo
         std::process::Command::new("git")
             .args(["init"])
-            .current_dir(&repo_path)
+            .current_dir(repo_path)
             .output()
-            .map_err(|e| GitNavigatorError::Io(e))?;
+            .map_err(GitNavigatorError::Io)?;
 
         // Set git config
         std::process::Command::new("git")
             .args(["config", "user.name", "Test User"])
-            .current_dir(&repo_path)
+            .current_dir(repo_path)
             .output()
-            .map_err(|e| GitNavigatorError::Io(e))?;
+            .map_err(GitNavigatorError::Io)?;
 
         std::process::Command::new("git")
             .args(["config", "user.email", "test@example.com"])
-            .current_dir(&repo_path)
+            .current_dir(repo_path)
             .output()
-            .map_err(|e| GitNavigatorError::Io(e))?;
+            .map_err(GitNavigatorError::Io)?;
 
-        let git_repo = GitRepo::open(&repo_path)?;
+        let git_repo = GitRepo::open(repo_path)?;
         Ok((temp_dir, git_repo))
     }
 
+    #[test]
+    fn test_stage_picked_hunks() -> Result<()> {
+        let (temp_dir, git_repo) = setup_test_repo()?;
+        let path = Path::new("notes.txt");
+        let original: String = (1..=20).map(|n| format!("line {n}\n")).collect();
+        std::fs::write(temp_dir.path().join(path), &original)?;
+        git_repo.add_files(&[path.to_path_buf()])?;
+
+        // Two changes far enough apart to make two hunks
+        let changed = original
+            .replace("line 2\n", "line two\n")
+            .replace("line 19\n", "line nineteen\n");
+        std::fs::write(temp_dir.path().join(path), &changed)?;
+
+        let hunks = git_repo.unstaged_hunks(path)?;
+        assert_eq!(hunks.len(), 2);
+        assert!(hunks[0].header.starts_with("@@ -1,5 +1,5 @@"));
+        assert!(hunks[0].lines.contains(&('+', "line two".to_string())));
+
+        git_repo.stage_hunks(path, &[false, true])?;
+        let left = git_repo.unstaged_hunks(path)?;
+        assert_eq!(left.len(), 1);
+        assert!(left[0].lines.contains(&('-', "line 2".to_string())));
+
+        // A new file is one hunk, staged whole
+        std::fs::write(temp_dir.path().join("new.txt"), "a\nb\n")?;
+        assert_eq!(git_repo.unstaged_hunks(Path::new("new.txt"))?.len(), 1);
+        git_repo.stage_hunks(Path::new("new.txt"), &[true])?;
+        assert!(git_repo.unstaged_hunks(Path::new("new.txt"))?.is_empty());
+        Ok(())
+    }
+
     #[test]
     fn test_open_git_repo() -> Result<()> {
         let (_temp_dir, _git_repo) = setup_test_repo()
@@ -320,7 +2824,7 @@ mod tests {
                 .join("test.txt"),
             "test content",
         )
-        .map_err(|e| GitNavigatorError::Io(e))?;
+        .map_err(GitNavigatorError::Io)?;
 
         let files = git_repo.get_status()?;
         assert_eq!(files.len(), 1);
@@ -347,19 +2851,17 @@ mod tests {
 
         // Create a directory structure with files
         let test_dir = workdir.join("test_dir");
-        std::fs::create_dir_all(&test_dir).map_err(|e| GitNavigatorError::Io(e))?;
+        std::fs::create_dir_all(&test_dir).map_err(GitNavigatorError::Io)?;
 
         // Create files in the directory
-        std::fs::write(test_dir.join("file1.txt"), "content1")
-            .map_err(|e| GitNavigatorError::Io(e))?;
-        std::fs::write(test_dir.join("file2.rs"), "content2")
-            .map_err(|e| GitNavigatorError::Io(e))?;
+        std::fs::write(test_dir.join("file1.txt"), "content1").map_err(GitNavigatorError::Io)?;
+        std::fs::write(test_dir.join("file2.rs"), "content2").map_err(GitNavigatorError::Io)?;
 
         // Create a subdirectory with a file
         let sub_dir = test_dir.join("subdir");
-        std::fs::create_dir_all(&sub_dir).map_err(|e| GitNavigatorError::Io(e))?;
+        std::fs::create_dir_all(&sub_dir).map_err(GitNavigatorError::Io)?;
         std::fs::write(sub_dir.join("nested.md"), "nested content")
-            .map_err(|e| GitNavigatorError::Io(e))?;
+            .map_err(GitNavigatorError::Io)?;
 
         // Add the directory (should add all files recursively)
         let dir_path = workdir.join("test_dir");
@@ -388,7 +2890,7 @@ mod tests {
 
         // Create an empty directory
         let empty_dir = workdir.join("empty_dir");
-        std::fs::create_dir_all(&empty_dir).map_err(|e| GitNavigatorError::Io(e))?;
+        std::fs::create_dir_all(&empty_dir).map_err(GitNavigatorError::Io)?;
 
         // Adding an empty directory should succeed but not stage anything
         let dir_path = workdir.join("empty_dir");
@@ -410,13 +2912,13 @@ mod tests {
 
         // Create individual file
         std::fs::write(workdir.join("single.txt"), "single file content")
-            .map_err(|e| GitNavigatorError::Io(e))?;
+            .map_err(GitNavigatorError::Io)?;
 
         // Create directory with files
         let test_dir = workdir.join("dir_with_files");
-        std::fs::create_dir_all(&test_dir).map_err(|e| GitNavigatorError::Io(e))?;
+        std::fs::create_dir_all(&test_dir).map_err(GitNavigatorError::Io)?;
         std::fs::write(test_dir.join("dir_file.rs"), "directory file content")
-            .map_err(|e| GitNavigatorError::Io(e))?;
+            .map_err(GitNavigatorError::Io)?;
 
         // Add both file and directory in one operation
         let paths = vec![workdir.join("single.txt"), workdir.join("dir_with_files")];
@@ -444,18 +2946,18 @@ mod tests {
 
         // Create and commit a file first
         let test_file = workdir.join("test_file.txt");
-        std::fs::write(&test_file, "initial content").map_err(|e| GitNavigatorError::Io(e))?;
+        std::fs::write(&test_file, "initial content").map_err(GitNavigatorError::Io)?;
 
         // Add and commit the file
-        git_repo.add_files(&[test_file.clone()])?;
+        git_repo.add_files(std::slice::from_ref(&test_file))?;
         std::process::Command::new("git")
             .args(["commit", "-m", "Add test file"])
             .current_dir(workdir)
             .output()
-            .map_err(|e| GitNavigatorError::Io(e))?;
+            .map_err(GitNavigatorError::Io)?;
 
         // Now delete the file from filesystem
-        std::fs::remove_file(&test_file).map_err(|e| GitNavigatorError::Io(e))?;
+        std::fs::remove_file(&test_file).map_err(GitNavigatorError::Io)?;
 
         // Verify file shows as deleted in status
         let status_before_add = git_repo.get_status()?;
@@ -495,10 +2997,8 @@ mod tests {
         let workdir = git_repo.get_repository().workdir().unwrap();
 
         // Create test files
-        std::fs::write(workdir.join("file1.txt"), "content 1")
-            .map_err(|e| GitNavigatorError::Io(e))?;
-        std::fs::write(workdir.join("file2.rs"), "content 2")
-            .map_err(|e| GitNavigatorError::Io(e))?;
+        std::fs::write(workdir.join("file1.txt"), "content 1").map_err(GitNavigatorError::Io)?;
+        std::fs::write(workdir.join("file2.rs"), "content 2").map_err(GitNavigatorError::Io)?;
 
         // Add multiple files at once using our new git command approach
         let paths = vec![PathBuf::from("file1.txt"), PathBuf::from("file2.rs")];
@@ -540,18 +3040,18 @@ mod tests {
 
         // Create and commit a file first
         let test_file = workdir.join("test_reset.txt");
-        std::fs::write(&test_file, "initial content").map_err(|e| GitNavigatorError::Io(e))?;
+        std::fs::write(&test_file, "initial content").map_err(GitNavigatorError::Io)?;
 
-        git_repo.add_files(&[test_file.clone()])?;
+        git_repo.add_files(std::slice::from_ref(&test_file))?;
         std::process::Command::new("git")
             .args(["commit", "-m", "Add test file"])
             .current_dir(workdir)
             .output()
-            .map_err(|e| GitNavigatorError::Io(e))?;
+            .map_err(GitNavigatorError::Io)?;
 
         // Modify the file and stage the changes
-        std::fs::write(&test_file, "modified content").map_err(|e| GitNavigatorError::Io(e))?;
-        git_repo.add_files(&[test_file.clone()])?;
+        std::fs::write(&test_file, "modified content").map_err(GitNavigatorError::Io)?;
+        git_repo.add_files(std::slice::from_ref(&test_file))?;
 
         // Verify file is staged
         let status_before_reset = git_repo.get_status()?;
@@ -582,19 +3082,19 @@ mod tests {
         let file1 = workdir.join("file1.txt");
         let file2 = workdir.join("file2.txt");
 
-        std::fs::write(&file1, "content 1").map_err(|e| GitNavigatorError::Io(e))?;
-        std::fs::write(&file2, "content 2").map_err(|e| GitNavigatorError::Io(e))?;
+        std::fs::write(&file1, "content 1").map_err(GitNavigatorError::Io)?;
+        std::fs::write(&file2, "content 2").map_err(GitNavigatorError::Io)?;
 
         git_repo.add_files(&[file1.clone(), file2.clone()])?;
         std::process::Command::new("git")
             .args(["commit", "-m", "Add test files"])
             .current_dir(workdir)
             .output()
-            .map_err(|e| GitNavigatorError::Io(e))?;
+            .map_err(GitNavigatorError::Io)?;
 
         // Modify both files and stage them
-        std::fs::write(&file1, "modified content 1").map_err(|e| GitNavigatorError::Io(e))?;
-        std::fs::write(&file2, "modified content 2").map_err(|e| GitNavigatorError::Io(e))?;
+        std::fs::write(&file1, "modified content 1").map_err(GitNavigatorError::Io)?;
+        std::fs::write(&file2, "modified content 2").map_err(GitNavigatorError::Io)?;
 
         git_repo.add_files(&[file1, file2])?;
 
@@ -615,6 +3115,294 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_find_checkout_conflicts() -> Result<()> {
+        let (_temp_dir, git_repo) = setup_test_repo()?;
+        let workdir = git_repo.get_repository().workdir().unwrap().to_path_buf();
+        let run_git = |args: &[&str]| {
+            std::process::Command::new("git")
+                .args(args)
+                .current_dir(&workdir)
+                .output()
+                .map_err(GitNavigatorError::Io)
+        };
+
+        std::fs::write(workdir.join("shared.txt"), "base")?;
+        std::fs::write(workdir.join("local.txt"), "base")?;
+        run_git(&["add", "."])?;
+        run_git(&["commit", "-m", "base"])?;
+        run_git(&["branch", "-M", "main"])?;
+
+        run_git(&["checkout", "-b", "other"])?;
+        std::fs::write(workdir.join("shared.txt"), "other")?;
+        run_git(&["commit", "-am", "other"])?;
+        run_git(&["checkout", "main"])?;
+
+        // Only the file that differs on the target branch blocks the switch
+        std::fs::write(workdir.join("shared.txt"), "dirty")?;
+        std::fs::write(workdir.join("local.txt"), "dirty")?;
+
+        let conflicts = git_repo.find_checkout_conflicts("other")?;
+        assert_eq!(conflicts, vec![PathBuf::from("shared.txt")]);
+        assert!(git_repo.find_checkout_conflicts("missing")?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_first_changed_line() -> Result<()> {
+        let (_temp_dir, git_repo) = setup_test_repo()?;
+        let workdir = git_repo.get_repository().workdir().unwrap().to_path_buf();
+        let run_git = |args: &[&str]| {
+            std::process::Command::new("git")
+                .args(args)
+                .current_dir(&workdir)
+                .output()
+                .map_err(GitNavigatorError::Io)
+        };
+
+        std::fs::write(workdir.join("notes.txt"), "one\ntwo\nthree\nfour\n")?;
+        run_git(&["add", "."])?;
+        run_git(&["commit", "-m", "base"])?;
+
+        std::fs::write(workdir.join("notes.txt"), "one\ntwo\nTHREE\nfour\n")?;
+        let path = Path::new("notes.txt");
+        assert_eq!(git_repo.first_changed_line(path, false)?, Some(3));
+        assert_eq!(git_repo.first_changed_line(path, true)?, None);
+
+        run_git(&["add", "notes.txt"])?;
+        assert_eq!(git_repo.first_changed_line(path, true)?, Some(3));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_line_stats_covers_staged_unstaged_and_untracked() -> Result<()> {
+        let (_temp_dir, git_repo) = setup_test_repo()?;
+        let workdir = git_repo.get_repository().workdir().unwrap().to_path_buf();
+        let run_git = |args: &[&str]| {
+            std::process::Command::new("git")
+                .args(args)
+                .current_dir(&workdir)
+                .output()
+                .map_err(GitNavigatorError::Io)
+        };
+
+        std::fs::write(workdir.join("a.txt"), "one\ntwo\nthree\n")?;
+        std::fs::write(workdir.join("b.txt"), "b\n")?;
+        run_git(&["add", "."])?;
+        run_git(&["commit", "-m", "base"])?;
+
+        std::fs::write(workdir.join("a.txt"), "one\nTWO\nthree\nfour\n")?;
+        run_git(&["add", "a.txt"])?;
+        std::fs::remove_file(workdir.join("b.txt"))?;
+        std::fs::write(workdir.join("new.txt"), "x\ny\n")?;
+
+        let mut stats = git_repo.line_stats()?;
+        stats.sort_by(|a, b| a.path.cmp(&b.path));
+        let summary: Vec<_> = stats
+            .iter()
+            .map(|stat| {
+                (
+                    stat.path.to_string_lossy().to_string(),
+                    stat.added,
+                    stat.removed,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("a.txt".to_string(), 2, 1),
+                ("b.txt".to_string(), 0, 1),
+                ("new.txt".to_string(), 2, 0),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_detached_head_context() -> Result<()> {
+        let (_temp_dir, git_repo) = setup_test_repo()?;
+        let workdir = git_repo.get_repository().workdir().unwrap().to_path_buf();
+        let run_git = |args: &[&str]| {
+            std::process::Command::new("git")
+                .args(args)
+                .current_dir(&workdir)
+                .output()
+                .map_err(GitNavigatorError::Io)
+        };
+
+        std::fs::write(workdir.join("a.txt"), "a")?;
+        run_git(&["add", "."])?;
+        run_git(&["commit", "-m", "first"])?;
+        std::fs::write(workdir.join("a.txt"), "b")?;
+        run_git(&["commit", "-am", "second"])?;
+        run_git(&["branch", "-M", "main"])?;
+        run_git(&["tag", "v1.0"])?;
+
+        // Attached HEAD has no detached context
+        assert!(git_repo.get_detached_head()?.is_none());
+        assert!(git_repo.get_orphaned_commits()?.is_empty());
+
+        // The branch wins the tie with the tag at distance zero
+        run_git(&["checkout", "--detach", "HEAD"])?;
+        let detached = git_repo.get_detached_head()?.unwrap();
+        assert_eq!(detached.nearest_ref.as_deref(), Some("main"));
+
+        run_git(&["checkout", "--detach", "HEAD~1"])?;
+        let detached = git_repo.get_detached_head()?.unwrap();
+        assert_eq!(detached.nearest_ref.as_deref(), Some("main~1"));
+
+        // A commit made while detached is only reachable from HEAD
+        std::fs::write(workdir.join("a.txt"), "c")?;
+        run_git(&["commit", "-am", "detached work"])?;
+        let orphaned = git_repo.get_orphaned_commits()?;
+        assert_eq!(orphaned.len(), 1);
+        assert_eq!(orphaned[0].1, "detached work");
+        assert!(git_repo.get_detached_head()?.unwrap().nearest_ref.is_none());
+
+        // Tags count when they point right at HEAD
+        run_git(&["tag", "wip"])?;
+        let detached = git_repo.get_detached_head()?.unwrap();
+        assert_eq!(detached.nearest_ref.as_deref(), Some("wip"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_history_limits_describe() {
+        assert_eq!(HistoryLimits::default().describe(), None);
+        let limits = HistoryLimits {
+            shallow_depth: Some(1),
+            partial_filter: Some("blob:none".to_string()),
+        };
+        assert_eq!(
+            limits.describe().as_deref(),
+            Some("shallow, depth 1; partial, blob:none")
+        );
+    }
+
+    #[test]
+    fn test_short_oid_follows_core_abbrev() -> Result<()> {
+        let (_temp_dir, git_repo) = setup_test_repo()?;
+        let workdir = git_repo.get_repository().workdir().unwrap().to_path_buf();
+        let run_git = |args: &[&str]| {
+            std::process::Command::new("git")
+                .args(args)
+                .current_dir(&workdir)
+                .output()
+                .map_err(GitNavigatorError::Io)
+        };
+        std::fs::write(workdir.join("a.txt"), "a")?;
+        run_git(&["add", "."])?;
+        run_git(&["commit", "-m", "first"])?;
+        let head = git_repo.get_repository().head()?.target().unwrap();
+
+        assert_eq!(git_repo.short_oid(head).len(), 7);
+        assert_eq!(git_repo.get_parent_commit_info()?.0.len(), 7);
+
+        run_git(&["config", "core.abbrev", "12"])?;
+        let git_repo = GitRepo::open(&workdir)?;
+        let short = git_repo.short_oid(head);
+        assert_eq!(short.len(), 12);
+        assert!(head.to_string().starts_with(&short));
+        assert_eq!(git_repo.get_parent_commit_info()?.0, short);
+        assert_eq!(git_repo.recent_commits(1)?[0].short_oid, short);
+        Ok(())
+    }
+
+    #[test]
+    fn test_unborn_and_orphan_branches() -> Result<()> {
+        let (_temp_dir, git_repo) = setup_test_repo()?;
+        let workdir = git_repo.get_repository().workdir().unwrap().to_path_buf();
+        let run_git = |args: &[&str]| {
+            std::process::Command::new("git")
+                .args(args)
+                .current_dir(&workdir)
+                .output()
+                .map_err(GitNavigatorError::Io)
+        };
+
+        // A new repository: HEAD names a branch with no commit yet
+        run_git(&["symbolic-ref", "HEAD", "refs/heads/trunk"])?;
+        assert_eq!(git_repo.get_current_branch()?, "trunk");
+        assert_eq!(
+            git_repo.get_parent_commit_info()?,
+            ("".to_string(), "- no commits yet -".to_string())
+        );
+        assert_eq!(git_repo.get_ahead_behind()?, None);
+        assert!(git_repo.get_detached_head()?.is_none());
+        assert!(git_repo.recent_commits(5)?.is_empty());
+
+        std::fs::write(workdir.join("a.txt"), "a")?;
+        run_git(&["add", "."])?;
+        run_git(&["commit", "-m", "first"])?;
+        assert_eq!(git_repo.get_parent_commit_info()?.1, "first");
+
+        // An orphan branch is unborn again, with the index still full
+        run_git(&["checkout", "--orphan", "pages"])?;
+        assert_eq!(git_repo.get_current_branch()?, "pages");
+        assert_eq!(git_repo.get_parent_commit_info()?.0, "");
+        assert_eq!(git_repo.get_status()?.len(), 1);
+
+        // Detached at a commit
+        run_git(&["checkout", "--quiet", "trunk"])?;
+        run_git(&["checkout", "--quiet", "--detach"])?;
+        let short = git_repo.get_parent_commit_info()?.0;
+        assert_eq!(
+            git_repo.get_current_branch()?,
+            format!("detached at {short}")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_commit_files_numbers_changes() -> Result<()> {
+        let (_temp_dir, git_repo) = setup_test_repo()?;
+        let workdir = git_repo.get_repository().workdir().unwrap().to_path_buf();
+        let run_git = |args: &[&str]| {
+            std::process::Command::new("git")
+                .args(args)
+                .current_dir(&workdir)
+                .output()
+                .map_err(GitNavigatorError::Io)
+        };
+
+        let body = "shared line\n".repeat(20);
+        std::fs::write(workdir.join("old.txt"), &body)?;
+        std::fs::write(workdir.join("gone.txt"), "bye\n")?;
+        std::fs::write(workdir.join("edit.txt"), "one\ntwo\nthree\n")?;
+        run_git(&["add", "."])?;
+        run_git(&["commit", "-m", "first"])?;
+
+        run_git(&["mv", "old.txt", "new.txt"])?;
+        run_git(&["rm", "--quiet", "gone.txt"])?;
+        std::fs::write(workdir.join("edit.txt"), "one\ntwo\nTHREE\n")?;
+        run_git(&["commit", "-am", "second"])?;
+
+        let files = git_repo.commit_files("HEAD")?;
+        let summary: Vec<(usize, &str, GitStatus)> = files
+            .iter()
+            .map(|file| (file.index, file.path.to_str().unwrap(), file.status))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (1, "edit.txt", GitStatus::Modified),
+                (2, "gone.txt", GitStatus::Deleted),
+                (3, "new.txt", GitStatus::Renamed),
+            ]
+        );
+        assert_eq!(files[0].first_line, Some(3));
+        assert_eq!(files[2].old_path, Some(PathBuf::from("old.txt")));
+
+        let diff = git_repo.show_commit_file("HEAD", &files[0])?;
+        assert!(diff.contains("+THREE"));
+        assert!(!diff.contains("gone.txt"));
+        Ok(())
+    }
+
     #[test]
     fn test_reset_files_empty_list() -> Result<()> {
         let (_temp_dir, git_repo) = setup_test_repo()?;
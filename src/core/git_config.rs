@@ -0,0 +1,230 @@
+//! Defaults taken from the user's git configuration.
+//!
+//! git-navigator sits on top of git, so wherever it has an equivalent of a
+//! git setting it follows what the user already configured instead of
+//! inventing its own default.
+//!
+//! # Keys
+//! - `color.ui`: whether git-navigator output is colored (`--color` wins)
+//! - `core.pager`: pager for `gd` output (`GIT_PAGER` still wins, as in git)
+//! - `diff.tool`: viewer for `gd` when it is difftastic and `[diff] tool`
+//!   is not set
+//! - `merge.tool`: merge tool mentioned in conflict hints
+//! - `core.editor`: editor for `edit` (after `GIT_EDITOR`, as in git)
+
+use clap::ValueEnum;
+use std::env;
+use std::io::IsTerminal;
+
+/// How `--color` or `color.ui` asks for colored output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ColorChoice {
+    /// Color when writing to a terminal, unless `NO_COLOR` is set
+    Auto,
+    /// Always color, even when not writing to a terminal
+    Always,
+    /// Never color
+    Never,
+}
+
+impl ColorChoice {
+    /// Parse a `color.ui` value the way git does
+    pub fn parse(value: &str) -> Self {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "always" => Self::Always,
+            "never" | "false" | "no" | "off" | "0" => Self::Never,
+            _ => Self::Auto,
+        }
+    }
+
+    /// Whether output gets colored, with `var` reading the environment
+    ///
+    /// `Auto` colors a terminal, except with `NO_COLOR` set to anything but
+    /// the empty string, `CLICOLOR=0` or `TERM=dumb`; `CLICOLOR_FORCE`
+    /// colors even piped output.
+    pub fn should_colorize(self, var: impl Fn(&str) -> Option<String>, terminal: bool) -> bool {
+        let set = |name: &str| var(name).filter(|value| !value.is_empty());
+        match self {
+            Self::Always => true,
+            Self::Never => false,
+            Self::Auto if set("CLICOLOR_FORCE").is_some_and(|value| value != "0") => true,
+            Self::Auto => {
+                terminal
+                    && set("NO_COLOR").is_none()
+                    && set("CLICOLOR").as_deref() != Some("0")
+                    && set("TERM").as_deref() != Some("dumb")
+            }
+        }
+    }
+
+    /// Apply the choice to the process-wide color switch, which the
+    /// templates, messages and the git commands run for diffs all follow
+    pub fn apply(self) {
+        let colorize =
+            self.should_colorize(|name| env::var(name).ok(), std::io::stdout().is_terminal());
+        colored::control::set_override(colorize);
+    }
+}
+
+/// Git settings git-navigator uses as defaults
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitDefaults {
+    pub diff_tool: Option<String>,
+    pub merge_tool: Option<String>,
+    pub pager: Option<String>,
+    pub editor: Option<String>,
+    pub color: ColorChoice,
+}
+
+impl GitDefaults {
+    /// Read the defaults from a git configuration snapshot
+    pub fn from_config(config: &git2::Config) -> Self {
+        let get = |key: &str| {
+            config
+                .get_string(key)
+                .ok()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+
+        Self {
+            diff_tool: get("diff.tool"),
+            merge_tool: get("merge.tool"),
+            pager: get("core.pager"),
+            editor: get("core.editor"),
+            color: get("color.ui")
+                .map(|value| ColorChoice::parse(&value))
+                .unwrap_or(ColorChoice::Auto),
+        }
+    }
+
+    /// Read the defaults for the repository containing the current directory
+    ///
+    /// Outside a repository only the global and system configuration apply.
+    pub fn discover() -> Self {
+        let config = git2::Repository::discover(".")
+            .and_then(|repo| repo.config())
+            .or_else(|_| git2::Config::open_default());
+
+        match config {
+            Ok(config) => Self::from_config(&config),
+            Err(e) => {
+                log::debug!("Could not read git config: {e}");
+                Self::default()
+            }
+        }
+    }
+
+    /// Pager command to use, if any
+    ///
+    /// `GIT_PAGER` overrides `core.pager`, and `cat` or an empty value
+    /// disables paging, matching git's own rules.
+    pub fn pager_command(&self) -> Option<String> {
+        env::var("GIT_PAGER")
+            .ok()
+            .or_else(|| self.pager.clone())
+            .map(|pager| pager.trim().to_string())
+            .filter(|pager| !pager.is_empty() && pager != "cat")
+    }
+
+    /// Editor command to use
+    ///
+    /// Follows git's order: `GIT_EDITOR`, `core.editor`, `VISUAL`, `EDITOR`,
+    /// and finally `vi`.
+    pub fn editor_command(&self) -> String {
+        env::var("GIT_EDITOR")
+            .ok()
+            .or_else(|| self.editor.clone())
+            .or_else(|| env::var("VISUAL").ok())
+            .or_else(|| env::var("EDITOR").ok())
+            .map(|editor| editor.trim().to_string())
+            .filter(|editor| !editor.is_empty())
+            .unwrap_or_else(|| "vi".to_string())
+    }
+}
+
+impl Default for GitDefaults {
+    fn default() -> Self {
+        Self {
+            diff_tool: None,
+            merge_tool: None,
+            pager: None,
+            editor: None,
+            color: ColorChoice::Auto,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn config_with(entries: &[(&str, &str)]) -> (TempDir, git2::Config) {
+        let dir = TempDir::new().unwrap();
+        let mut config = git2::Config::open(&dir.path().join("config")).unwrap();
+        for (key, value) in entries {
+            config.set_str(key, value).unwrap();
+        }
+        let snapshot = config.snapshot().unwrap();
+        (dir, snapshot)
+    }
+
+    #[test]
+    fn test_color_choice_parse() {
+        assert_eq!(ColorChoice::parse("always"), ColorChoice::Always);
+        assert_eq!(ColorChoice::parse("never"), ColorChoice::Never);
+        assert_eq!(ColorChoice::parse("false"), ColorChoice::Never);
+        assert_eq!(ColorChoice::parse("auto"), ColorChoice::Auto);
+        // `true` means "auto" for color.ui
+        assert_eq!(ColorChoice::parse("true"), ColorChoice::Auto);
+    }
+
+    #[test]
+    fn test_color_choice_should_colorize() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+
+        assert!(ColorChoice::Auto.should_colorize(env(&[]), true));
+        assert!(!ColorChoice::Auto.should_colorize(env(&[]), false));
+        assert!(!ColorChoice::Auto.should_colorize(env(&[("NO_COLOR", "1")]), true));
+        // An empty NO_COLOR doesn't count
+        assert!(ColorChoice::Auto.should_colorize(env(&[("NO_COLOR", "")]), true));
+        assert!(!ColorChoice::Auto.should_colorize(env(&[("TERM", "dumb")]), true));
+        assert!(ColorChoice::Auto.should_colorize(env(&[("CLICOLOR_FORCE", "1")]), false));
+
+        // An explicit choice beats the environment
+        assert!(ColorChoice::Always.should_colorize(env(&[("NO_COLOR", "1")]), false));
+        assert!(!ColorChoice::Never.should_colorize(env(&[]), true));
+    }
+
+    #[test]
+    fn test_from_config_reads_keys() {
+        let (_dir, config) = config_with(&[
+            ("diff.tool", "meld"),
+            ("merge.tool", "vimdiff"),
+            ("core.pager", "less -R"),
+            ("core.editor", "nvim"),
+            ("color.ui", "never"),
+        ]);
+
+        let defaults = GitDefaults::from_config(&config);
+        assert_eq!(defaults.diff_tool.as_deref(), Some("meld"));
+        assert_eq!(defaults.merge_tool.as_deref(), Some("vimdiff"));
+        assert_eq!(defaults.pager.as_deref(), Some("less -R"));
+        assert_eq!(defaults.editor.as_deref(), Some("nvim"));
+        assert_eq!(defaults.color, ColorChoice::Never);
+    }
+
+    #[test]
+    fn test_from_empty_config() {
+        let (_dir, config) = config_with(&[]);
+        let defaults = GitDefaults::from_config(&config);
+        assert_eq!(defaults, GitDefaults::default());
+    }
+}
@@ -39,6 +39,18 @@ pub enum GitStatus {
     Untracked,
     /// Unmerged/conflicted file (UU)
     Unmerged,
+    /// Submodule with new commits or local changes (M, m or ?)
+    Submodule(SubmoduleChanges),
+}
+
+/// What changed in a submodule's working directory, as `git status` tells it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SubmoduleChanges {
+    /// Its HEAD moved away from the commit the superproject records
+    pub new_commits: bool,
+    /// Its tracked files have staged or unstaged changes
+    pub modified_content: bool,
+    pub untracked_content: bool,
 }
 
 impl GitStatus {
@@ -93,6 +105,23 @@ impl GitStatus {
         None
     }
 
+    /// Convert from the working directory flags of a submodule
+    /// Returns None when nothing changed in it
+    pub fn from_git2_submodule(flags: git2::SubmoduleStatus) -> Option<GitStatus> {
+        let changes = SubmoduleChanges {
+            new_commits: flags.contains(git2::SubmoduleStatus::WD_MODIFIED),
+            modified_content: flags.intersects(
+                git2::SubmoduleStatus::WD_INDEX_MODIFIED | git2::SubmoduleStatus::WD_WD_MODIFIED,
+            ),
+            untracked_content: flags.contains(git2::SubmoduleStatus::WD_UNTRACKED),
+        };
+        if changes.new_commits || changes.modified_content || changes.untracked_content {
+            Some(GitStatus::Submodule(changes))
+        } else {
+            None
+        }
+    }
+
     /// Get the string representation for display (legacy compatibility)
     pub fn as_str(&self) -> &'static str {
         match self {
@@ -104,6 +133,10 @@ impl GitStatus {
             GitStatus::TypeChanged => "T",
             GitStatus::Untracked => "??",
             GitStatus::Unmerged => "UU",
+            // The letters of `git status --short`
+            GitStatus::Submodule(changes) if changes.new_commits => "M",
+            GitStatus::Submodule(changes) if changes.modified_content => "m",
+            GitStatus::Submodule(_) => "?",
         }
     }
 
@@ -126,10 +159,12 @@ impl GitStatus {
             (GitStatus::Renamed, false) => 9,
             (GitStatus::Copied, false) => 10,
             (GitStatus::TypeChanged, false) => 11,
-            // Group 4: Untracked
-            (GitStatus::Untracked, _) => 12,
+            // Group 4: Submodules
+            (GitStatus::Submodule(_), _) => 12,
+            // Group 5: Untracked
+            (GitStatus::Untracked, _) => 13,
             // Default
-            _ => 13,
+            _ => 14,
         }
     }
 
@@ -144,6 +179,19 @@ impl GitStatus {
             GitStatus::TypeChanged => "type changed",
             GitStatus::Untracked => "untracked",
             GitStatus::Unmerged => "both modified",
+            GitStatus::Submodule(changes) => match (
+                changes.new_commits,
+                changes.modified_content,
+                changes.untracked_content,
+            ) {
+                (true, false, false) => "new commits",
+                (true, true, false) => "new commits, modified content",
+                (true, false, true) => "new commits, untracked content",
+                (true, true, true) => "new commits, modified content, untracked content",
+                (false, true, false) => "modified content",
+                (false, true, true) => "modified content, untracked content",
+                (false, false, _) => "untracked content",
+            },
         }
     }
 
@@ -284,4 +332,28 @@ mod tests {
             Some((GitStatus::Unmerged, false))
         );
     }
+
+    #[test]
+    fn test_from_git2_submodule() {
+        let in_place = git2::SubmoduleStatus::IN_HEAD
+            | git2::SubmoduleStatus::IN_INDEX
+            | git2::SubmoduleStatus::IN_CONFIG
+            | git2::SubmoduleStatus::IN_WD;
+        assert_eq!(GitStatus::from_git2_submodule(in_place), None);
+
+        let moved = GitStatus::from_git2_submodule(
+            in_place | git2::SubmoduleStatus::WD_MODIFIED | git2::SubmoduleStatus::WD_WD_MODIFIED,
+        )
+        .unwrap();
+        assert_eq!(moved.as_str(), "M");
+        assert_eq!(moved.description(), "new commits, modified content");
+
+        let untracked =
+            GitStatus::from_git2_submodule(in_place | git2::SubmoduleStatus::WD_UNTRACKED).unwrap();
+        assert_eq!(untracked.as_str(), "?");
+        assert_eq!(untracked.description(), "untracked content");
+        // Listed between the unstaged and the untracked files
+        assert!(untracked.sort_priority(false) > GitStatus::Modified.sort_priority(false));
+        assert!(untracked.sort_priority(false) < GitStatus::Untracked.sort_priority(false));
+    }
 }
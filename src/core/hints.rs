@@ -0,0 +1,228 @@
+//! Next-step hints after `gs`.
+//!
+//! With `ui.hints = true`, `gs` ends with a line such as
+//! "Tip: `ga 1-3` to stage modified files, `gd 4` to inspect the conflict".
+//! Each rule looks at the grouped [`StatusReport`] and may suggest one
+//! command; the first [`MAX_HINTS`] rules that apply make up the line, so
+//! the more pressing suggestions (conflicts) come first.
+
+use crate::core::git_status::GitStatus;
+use crate::core::state::FileEntry;
+
+/// Suggestions shown on the hint line at most
+pub const MAX_HINTS: usize = 2;
+
+/// The state `gs` showed: file indices by section, and the upstream counts
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StatusReport {
+    pub unmerged: Vec<usize>,
+    pub staged: Vec<usize>,
+    pub unstaged: Vec<usize>,
+    pub untracked: Vec<usize>,
+    /// Commits the branch is ahead of its upstream
+    pub ahead: usize,
+    /// Commits the upstream has that the branch doesn't
+    pub behind: usize,
+}
+
+impl StatusReport {
+    /// Group `files` the way `gs` lists them
+    pub fn new(files: &[FileEntry]) -> Self {
+        let mut report = Self::default();
+        for file in files {
+            let section = match file.status {
+                GitStatus::Unmerged => &mut report.unmerged,
+                GitStatus::Untracked => &mut report.untracked,
+                _ if file.staged => &mut report.staged,
+                _ => &mut report.unstaged,
+            };
+            section.push(file.index);
+        }
+        report
+    }
+
+    pub fn with_ahead_behind(mut self, ahead: usize, behind: usize) -> Self {
+        self.ahead = ahead;
+        self.behind = behind;
+        self
+    }
+
+    fn is_clean(&self) -> bool {
+        self.unmerged.is_empty()
+            && self.staged.is_empty()
+            && self.unstaged.is_empty()
+            && self.untracked.is_empty()
+    }
+}
+
+/// A rule suggests a command when the report calls for it
+type Rule = fn(&StatusReport) -> Option<String>;
+
+/// In order of precedence
+const RULES: &[Rule] = &[
+    resolve_conflicts,
+    stage_modified,
+    commit_staged,
+    track_untracked,
+    pull_behind,
+    push_ahead,
+];
+
+fn resolve_conflicts(report: &StatusReport) -> Option<String> {
+    match report.unmerged.as_slice() {
+        [] => None,
+        [index] => Some(format!("`gd {index}` to inspect the conflict")),
+        indices => Some(format!(
+            "`gd {}` to inspect the conflicts",
+            format_indices(indices)
+        )),
+    }
+}
+
+fn stage_modified(report: &StatusReport) -> Option<String> {
+    if report.unstaged.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "`ga {}` to stage modified files",
+        format_indices(&report.unstaged)
+    ))
+}
+
+fn commit_staged(report: &StatusReport) -> Option<String> {
+    // Committing mid-conflict or with edits left out is rarely the next step
+    if report.staged.is_empty() || !report.unmerged.is_empty() || !report.unstaged.is_empty() {
+        return None;
+    }
+    Some("`gc` to commit the staged files".to_string())
+}
+
+fn track_untracked(report: &StatusReport) -> Option<String> {
+    if report.untracked.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "`ga {}` to track new files",
+        format_indices(&report.untracked)
+    ))
+}
+
+fn pull_behind(report: &StatusReport) -> Option<String> {
+    (report.behind > 0).then(|| "`git-navigator pull` to catch up with upstream".to_string())
+}
+
+fn push_ahead(report: &StatusReport) -> Option<String> {
+    if report.ahead == 0 || report.behind > 0 || !report.is_clean() {
+        return None;
+    }
+    Some(format!(
+        "`git-navigator push` to publish {} commit(s)",
+        report.ahead
+    ))
+}
+
+/// The suggestions for `report`, most pressing first
+pub fn hints(report: &StatusReport) -> Vec<String> {
+    RULES
+        .iter()
+        .filter_map(|rule| rule(report))
+        .take(MAX_HINTS)
+        .collect()
+}
+
+/// "Tip: ..." with the suggestions for `report`, if any apply
+pub fn hint_line(report: &StatusReport) -> Option<String> {
+    let hints = hints(report);
+    (!hints.is_empty()).then(|| format!("Tip: {}", hints.join(", ")))
+}
+
+/// Sorted indices as the index parser reads them: "1-3,5"
+fn format_indices(indices: &[usize]) -> String {
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    for &index in indices {
+        match ranges.last_mut() {
+            Some((_, end)) if *end + 1 == index => *end = index,
+            _ => ranges.push((index, index)),
+        }
+    }
+    ranges
+        .iter()
+        .map(|&(start, end)| {
+            if start == end {
+                start.to_string()
+            } else {
+                format!("{start}-{end}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn entry(index: usize, status: GitStatus, staged: bool) -> FileEntry {
+        FileEntry {
+            index,
+            status,
+            path: PathBuf::from(format!("file{index}.txt")),
+            staged,
+            old_path: None,
+        }
+    }
+
+    #[test]
+    fn test_format_indices() {
+        assert_eq!(format_indices(&[1, 2, 3, 5, 7, 8]), "1-3,5,7-8");
+        assert_eq!(format_indices(&[4]), "4");
+        assert_eq!(format_indices(&[]), "");
+    }
+
+    #[test]
+    fn test_conflict_and_modified_files() {
+        let report = StatusReport::new(&[
+            entry(1, GitStatus::Unmerged, false),
+            entry(2, GitStatus::Modified, false),
+            entry(3, GitStatus::Deleted, false),
+            entry(4, GitStatus::Untracked, false),
+        ]);
+        assert_eq!(
+            hint_line(&report).as_deref(),
+            Some("Tip: `gd 1` to inspect the conflict, `ga 2-3` to stage modified files")
+        );
+    }
+
+    #[test]
+    fn test_staged_only_suggests_commit() {
+        let report = StatusReport::new(&[
+            entry(1, GitStatus::Added, true),
+            entry(2, GitStatus::Untracked, false),
+        ]);
+        assert_eq!(
+            hints(&report),
+            vec![
+                "`gc` to commit the staged files".to_string(),
+                "`ga 2` to track new files".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_clean_tree_and_upstream() {
+        assert_eq!(hint_line(&StatusReport::default()), None);
+
+        let ahead = StatusReport::default().with_ahead_behind(2, 0);
+        assert_eq!(
+            hints(&ahead),
+            vec!["`git-navigator push` to publish 2 commit(s)".to_string()]
+        );
+
+        let diverged = StatusReport::default().with_ahead_behind(2, 1);
+        assert_eq!(
+            hints(&diverged),
+            vec!["`git-navigator pull` to catch up with upstream".to_string()]
+        );
+    }
+}
@@ -0,0 +1,338 @@
+//! Clickable file names using OSC 8 terminal hyperlinks.
+//!
+//! Terminals that understand OSC 8 (iTerm2, WezTerm, kitty, VS Code, GNOME
+//! Terminal and other VTE terminals, Windows Terminal, recent tmux) show the
+//! file names in `status` and `diff` output as links. Other terminals would
+//! print the escape codes literally, so links are only emitted when one of
+//! those terminals is detected and stdout is a terminal.
+//!
+//! ```toml
+//! [ui]
+//! hyperlinks = false                        # off switch; true forces them on
+//! hyperlink_url = "vscode://file{file}:{line}"  # default: file://{file}
+//! ```
+//!
+//! `{file}` is the absolute, URL-encoded path and `{line}` is always 1.
+//!
+//! Issue keys in the branch name and commit subjects shown by `gs` and `gl`
+//! are linked to an issue tracker once its URL is configured:
+//!
+//! ```toml
+//! [issues]
+//! url = "https://example.atlassian.net/browse/{issue}"
+//! pattern = "[A-Z][A-Z0-9]+-[0-9]+"  # the default, Jira-style keys
+//! ```
+//!
+//! `{issue}` is the pattern's first capture group, or the whole match when it
+//! has none, so `pattern = "#([0-9]+)"` with
+//! `url = "https://github.com/org/repo/issues/{issue}"` links GitHub issues.
+
+use crate::core::config::{SettingValue, Settings};
+use crate::core::editor::encode_url_path;
+use crate::core::is_plain_mode;
+use regex::Regex;
+use std::env;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// URL template used when `ui.hyperlink_url` is not set
+pub const DEFAULT_URL_TEMPLATE: &str = "file://{file}";
+
+/// Whether `ui.hyperlinks` asks for links
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HyperlinkMode {
+    /// Emit links when the terminal supports them (the default)
+    Auto,
+    /// Always emit links
+    Always,
+    /// Never emit links
+    Never,
+}
+
+impl HyperlinkMode {
+    /// Read `ui.hyperlinks`, accepting a bool or `auto`/`always`/`never`
+    pub fn from_settings(settings: &Settings) -> Self {
+        match settings.get("ui.hyperlinks") {
+            Some(SettingValue::Bool(true)) => Self::Always,
+            Some(SettingValue::Bool(false)) => Self::Never,
+            Some(SettingValue::String(value)) => match value.as_str() {
+                "always" => Self::Always,
+                "never" => Self::Never,
+                _ => Self::Auto,
+            },
+            _ => Self::Auto,
+        }
+    }
+}
+
+/// Link target for files under a repository root
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hyperlinks {
+    root: PathBuf,
+    url_template: String,
+}
+
+impl Hyperlinks {
+    pub fn new(root: impl Into<PathBuf>, url_template: impl Into<String>) -> Self {
+        Self {
+            root: root.into(),
+            url_template: url_template.into(),
+        }
+    }
+
+    /// URL for a repository-relative path
+    pub fn url(&self, path: &Path) -> String {
+        let full_path = self.root.join(path);
+        self.url_template
+            .replace("{file}", &encode_url_path(&full_path.to_string_lossy()))
+            .replace("{line}", "1")
+    }
+
+    /// Wrap already formatted `text` in a link to `path`
+    pub fn wrap(&self, text: &str, path: &Path) -> String {
+        format!("\x1b]8;;{}\x1b\\{text}\x1b]8;;\x1b\\", self.url(path))
+    }
+}
+
+/// Pattern used when `issues.pattern` is not set
+pub const DEFAULT_ISSUE_PATTERN: &str = r"\b[A-Z][A-Z0-9]+-[0-9]+\b";
+
+/// Links from issue keys to the issue tracker
+#[derive(Debug, Clone)]
+pub struct IssueLinks {
+    pattern: Regex,
+    url_template: String,
+}
+
+impl IssueLinks {
+    pub fn new(pattern: Regex, url_template: impl Into<String>) -> Self {
+        Self {
+            pattern,
+            url_template: url_template.into(),
+        }
+    }
+
+    /// Read `issues.url` and `issues.pattern`; `None` without a tracker URL
+    pub fn from_settings(settings: &Settings) -> Option<Self> {
+        let url_template = settings.get_str("issues.url")?;
+        let pattern = settings
+            .get_str("issues.pattern")
+            .unwrap_or(DEFAULT_ISSUE_PATTERN);
+        match Regex::new(pattern) {
+            Ok(pattern) => Some(Self::new(pattern, url_template)),
+            Err(e) => {
+                log::warn!("Ignoring invalid issues.pattern '{pattern}': {e}");
+                None
+            }
+        }
+    }
+
+    /// Wrap every issue key in `text` in a link to its tracker page
+    pub fn wrap(&self, text: &str) -> String {
+        self.pattern
+            .replace_all(text, |captures: &regex::Captures| {
+                let key = &captures[0];
+                let issue = captures.get(1).map_or(key, |group| group.as_str());
+                let url = self.url_template.replace("{issue}", issue);
+                format!("\x1b]8;;{url}\x1b\\{key}\x1b]8;;\x1b\\")
+            })
+            .into_owned()
+    }
+}
+
+static HYPERLINKS: OnceLock<Option<Hyperlinks>> = OnceLock::new();
+static ISSUE_LINKS: OnceLock<Option<IssueLinks>> = OnceLock::new();
+
+/// Whether the terminal described by `var` is known to support OSC 8
+///
+/// `var` looks up an environment variable; it is a parameter so detection
+/// can be tested without touching the process environment.
+pub fn terminal_supports_hyperlinks(var: impl Fn(&str) -> Option<String>) -> bool {
+    if var("TERM").as_deref() == Some("dumb") {
+        return false;
+    }
+
+    if let Some(program) = var("TERM_PROGRAM") {
+        match program.as_str() {
+            "iTerm.app" | "WezTerm" | "vscode" | "ghostty" | "Hyper" | "rio" => return true,
+            // tmux passes links through since 3.4
+            "tmux" => {
+                let version = var("TERM_PROGRAM_VERSION").unwrap_or_default();
+                let mut parts = version.split('.').map(|part| {
+                    part.trim_end_matches(|c: char| !c.is_ascii_digit())
+                        .parse::<u32>()
+                        .unwrap_or(0)
+                });
+                let major = parts.next().unwrap_or(0);
+                let minor = parts.next().unwrap_or(0);
+                return (major, minor) >= (3, 4);
+            }
+            _ => {}
+        }
+    }
+
+    if ["KITTY_WINDOW_ID", "WT_SESSION", "KONSOLE_VERSION"]
+        .iter()
+        .any(|name| var(name).is_some())
+    {
+        return true;
+    }
+
+    // VTE-based terminals (GNOME Terminal, Tilix, ...) since 0.50
+    var("VTE_VERSION")
+        .and_then(|version| version.parse::<u32>().ok())
+        .is_some_and(|version| version >= 5000)
+}
+
+/// Set up links from the settings and the repository in the current directory
+///
+/// Call after plain mode has been decided; plain output never carries links.
+pub fn configure_hyperlinks(settings: &Settings) {
+    let enabled = match HyperlinkMode::from_settings(settings) {
+        HyperlinkMode::Never => false,
+        HyperlinkMode::Always => true,
+        // Output that isn't colored (piped, NO_COLOR, --color=never) stays plain text
+        HyperlinkMode::Auto => {
+            std::io::stdout().is_terminal()
+                && colored::control::SHOULD_COLORIZE.should_colorize()
+                && terminal_supports_hyperlinks(|name| env::var(name).ok())
+        }
+    };
+
+    let root = git2::Repository::discover(".")
+        .ok()
+        .and_then(|repo| repo.workdir().map(Path::to_path_buf));
+
+    let enabled = enabled && !is_plain_mode();
+    let _ = ISSUE_LINKS.set(
+        enabled
+            .then(|| IssueLinks::from_settings(settings))
+            .flatten(),
+    );
+
+    let hyperlinks = match root {
+        Some(root) if enabled => Some(Hyperlinks::new(
+            root,
+            settings
+                .get_str("ui.hyperlink_url")
+                .unwrap_or(DEFAULT_URL_TEMPLATE),
+        )),
+        _ => None,
+    };
+    let _ = HYPERLINKS.set(hyperlinks);
+}
+
+/// Wrap `text` in a link to the repository-relative `path`, if links are on
+pub fn link(text: &str, path: &Path) -> String {
+    match HYPERLINKS.get() {
+        Some(Some(hyperlinks)) => hyperlinks.wrap(text, path),
+        _ => text.to_string(),
+    }
+}
+
+/// Link the issue keys in `text`, if links are on and a tracker is configured
+pub fn link_issues(text: &str) -> String {
+    match ISSUE_LINKS.get() {
+        Some(Some(issue_links)) => issue_links.wrap(text),
+        _ => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn env_of(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn test_terminal_detection() {
+        assert!(terminal_supports_hyperlinks(env_of(&[(
+            "TERM_PROGRAM",
+            "iTerm.app"
+        )])));
+        assert!(terminal_supports_hyperlinks(env_of(&[(
+            "VTE_VERSION",
+            "7600"
+        )])));
+        assert!(terminal_supports_hyperlinks(env_of(&[(
+            "WT_SESSION",
+            "abc"
+        )])));
+        assert!(terminal_supports_hyperlinks(env_of(&[
+            ("TERM_PROGRAM", "tmux"),
+            ("TERM_PROGRAM_VERSION", "3.4a"),
+        ])));
+
+        assert!(!terminal_supports_hyperlinks(env_of(&[])));
+        assert!(!terminal_supports_hyperlinks(env_of(&[(
+            "VTE_VERSION",
+            "4800"
+        )])));
+        assert!(!terminal_supports_hyperlinks(env_of(&[
+            ("TERM_PROGRAM", "tmux"),
+            ("TERM_PROGRAM_VERSION", "3.3a"),
+        ])));
+        assert!(!terminal_supports_hyperlinks(env_of(&[
+            ("TERM", "dumb"),
+            ("TERM_PROGRAM", "WezTerm"),
+        ])));
+    }
+
+    #[test]
+    fn test_mode_from_settings() {
+        let parse = |toml: &str| HyperlinkMode::from_settings(&Settings::parse(toml).unwrap());
+
+        assert_eq!(parse(""), HyperlinkMode::Auto);
+        assert_eq!(parse("[ui]\nhyperlinks = false"), HyperlinkMode::Never);
+        assert_eq!(parse("[ui]\nhyperlinks = true"), HyperlinkMode::Always);
+        assert_eq!(parse("[ui]\nhyperlinks = \"never\""), HyperlinkMode::Never);
+        assert_eq!(parse("[ui]\nhyperlinks = \"auto\""), HyperlinkMode::Auto);
+    }
+
+    #[test]
+    fn test_wrap_and_url() {
+        let hyperlinks = Hyperlinks::new("/repo dir", DEFAULT_URL_TEMPLATE);
+        assert_eq!(
+            hyperlinks.url(Path::new("src/main.rs")),
+            "file:///repo%20dir/src/main.rs"
+        );
+        assert_eq!(
+            hyperlinks.wrap("main.rs", Path::new("src/main.rs")),
+            "\x1b]8;;file:///repo%20dir/src/main.rs\x1b\\main.rs\x1b]8;;\x1b\\"
+        );
+
+        let vscode = Hyperlinks::new("/repo", "vscode://file{file}:{line}");
+        assert_eq!(vscode.url(Path::new("a.rs")), "vscode://file/repo/a.rs:1");
+    }
+
+    #[test]
+    fn test_issue_links() {
+        let parse = |toml: &str| IssueLinks::from_settings(&Settings::parse(toml).unwrap());
+        assert!(parse("").is_none());
+        assert!(parse("[issues]\nurl = \"x/{issue}\"\npattern = \"(\"").is_none());
+
+        let jira = parse("[issues]\nurl = \"https://jira/browse/{issue}\"").unwrap();
+        assert_eq!(
+            jira.wrap("feature/ABC-12-login"),
+            "feature/\x1b]8;;https://jira/browse/ABC-12\x1b\\ABC-12\x1b]8;;\x1b\\-login"
+        );
+        assert_eq!(jira.wrap("no keys here, utf-8"), "no keys here, utf-8");
+
+        let github = parse(
+            "[issues]\nurl = \"https://github.com/o/r/issues/{issue}\"\npattern = \"#([0-9]+)\"",
+        )
+        .unwrap();
+        assert_eq!(
+            github.wrap("Fix #7"),
+            "Fix \x1b]8;;https://github.com/o/r/issues/7\x1b\\#7\x1b]8;;\x1b\\"
+        );
+    }
+}
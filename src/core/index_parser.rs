@@ -14,6 +14,12 @@
 //! - **Comma-separated**: `1,3,5`  
 //! - **Ranges**: `3-6` (expands to 3,4,5,6)
 //! - **Mixed combinations**: `1 3-5,8` (expands to 1,3,4,5,8)
+//! - **Everything**: `all` or `a` (expands to every listed index, given the
+//!   count through [`IndexParser::parse_with_max`])
+//! - **Exclusions**: `^3`, `^7-9` or `-3` leave indices out: `all ^3 ^7-9`,
+//!   `1-10 -3`; on their own they leave them out of everything listed
+//! - **As listed**: `[01]`, `(1)` and `1.`, however `templates.index` draws
+//!   them, so a number copied from the output works as typed
 //!
 //! # Features
 //! - **Deduplication**: Automatically removes duplicate indices
@@ -31,13 +37,26 @@ pub struct IndexRange {
 
 pub struct IndexParser;
 
+/// Words that select every listed index
+const ALL_KEYWORDS: [&str; 2] = ["all", "a"];
+
 impl IndexParser {
     pub fn parse(input: &str) -> Result<Vec<usize>> {
+        Self::parse_tokens(input, None)
+    }
+
+    /// Like [`Self::parse`], with `all` (or `a`) expanding to `1..=max_index`
+    pub fn parse_with_max(input: &str, max_index: usize) -> Result<Vec<usize>> {
+        Self::parse_tokens(input, Some(max_index))
+    }
+
+    fn parse_tokens(input: &str, max_index: Option<usize>) -> Result<Vec<usize>> {
         if input.trim().is_empty() {
             return Ok(Vec::new());
         }
 
         let mut indices = HashSet::new();
+        let mut excluded = HashSet::new();
 
         // Split by spaces and commas
         let parts: Vec<&str> = input
@@ -47,41 +66,93 @@ impl IndexParser {
 
         for part in parts {
             let part = part.trim();
-            if part.contains('-') {
-                // Handle range like "3-6"
-                let range_parts: Vec<&str> = part.split('-').collect();
-                if range_parts.len() != 2 {
-                    return Err(GitNavigatorError::invalid_range_format(part));
-                }
-
-                let start: usize = range_parts[0]
-                    .parse()
-                    .map_err(|_| GitNavigatorError::invalid_range_number(range_parts[0]))?;
-                let end: usize = range_parts[1]
-                    .parse()
-                    .map_err(|_| GitNavigatorError::invalid_range_number(range_parts[1]))?;
-
-                if start > end {
-                    return Err(GitNavigatorError::invalid_range_order(start, end));
-                }
-
-                for i in start..=end {
-                    indices.insert(i);
-                }
-            } else {
-                // Handle single number
-                let num: usize = part
-                    .parse()
-                    .map_err(|_| GitNavigatorError::invalid_number(part))?;
-                indices.insert(num);
+            // "^3", "^7-9" and "-3" leave indices out of the selection
+            match part.strip_prefix(['^', '-']) {
+                Some(rest) if !rest.is_empty() => Self::parse_part(rest, max_index, &mut excluded)?,
+                _ => Self::parse_part(part, max_index, &mut indices)?,
             }
         }
 
-        let mut result: Vec<usize> = indices.into_iter().collect();
+        if let Some(max_index) = max_index {
+            // Exclusions alone leave indices out of everything listed
+            if indices.is_empty() && !excluded.is_empty() {
+                indices.extend(1..=max_index);
+            }
+            let mut excluded: Vec<usize> = excluded.iter().copied().collect();
+            excluded.sort();
+            Self::validate(&excluded, max_index)?;
+        }
+
+        let mut result: Vec<usize> = indices.difference(&excluded).copied().collect();
         result.sort();
         Ok(result)
     }
 
+    /// Add the indices of one part ("3", "3-6" or "all") to `indices`
+    fn parse_part(
+        part: &str,
+        max_index: Option<usize>,
+        indices: &mut HashSet<usize>,
+    ) -> Result<()> {
+        if let (true, Some(max_index)) = (Self::is_all(part), max_index) {
+            indices.extend(1..=max_index);
+        } else if part.contains('-') {
+            // Handle range like "3-6"
+            let range_parts: Vec<&str> = part.split('-').map(Self::undecorate).collect();
+            if range_parts.len() != 2 {
+                return Err(GitNavigatorError::invalid_range_format(part));
+            }
+
+            let start: usize = range_parts[0]
+                .parse()
+                .map_err(|_| GitNavigatorError::invalid_range_number(range_parts[0]))?;
+            let end: usize = range_parts[1]
+                .parse()
+                .map_err(|_| GitNavigatorError::invalid_range_number(range_parts[1]))?;
+
+            if start > end {
+                return Err(GitNavigatorError::invalid_range_order(start, end));
+            }
+
+            indices.extend(start..=end);
+        } else {
+            // Handle single number
+            let num: usize = Self::undecorate(part)
+                .parse()
+                .map_err(|_| GitNavigatorError::invalid_number(part))?;
+            indices.insert(num);
+        }
+        Ok(())
+    }
+
+    /// Whether `arg` reads as indices rather than a name, e.g. "1 3-5,8", "[02]"
+    /// or "all"
+    pub fn is_index_list(arg: &str) -> bool {
+        if Self::is_all(arg) {
+            return true;
+        }
+        let mut parts = arg
+            .split([' ', ',', '-'])
+            .filter(|s| !s.is_empty())
+            .peekable();
+        parts.peek().is_some()
+            && parts.all(|part| {
+                let number = Self::undecorate(part.trim_start_matches('^'));
+                !number.is_empty() && number.chars().all(|c| c.is_ascii_digit())
+            })
+    }
+
+    fn is_all(part: &str) -> bool {
+        ALL_KEYWORDS.contains(&part.trim().to_ascii_lowercase().as_str())
+    }
+
+    /// The number inside an index as listings draw it: "[01]", "(1)" or "1."
+    fn undecorate(part: &str) -> &str {
+        part.trim()
+            .trim_start_matches(['[', '('])
+            .trim_end_matches([']', ')', '.'])
+    }
+
     pub fn validate(indices: &[usize], max_index: usize) -> Result<()> {
         if max_index == 0 {
             return Err(GitNavigatorError::NoFilesAvailable);
@@ -169,6 +240,60 @@ mod tests {
             .contains("Invalid number: 'abc'"));
     }
 
+    #[test]
+    fn test_parse_decorated_indices() -> Result<()> {
+        assert_eq!(IndexParser::parse("[01] (3) 5.")?, vec![1, 3, 5]);
+        assert_eq!(IndexParser::parse("[07]-[09],(12)")?, vec![7, 8, 9, 12]);
+        assert!(IndexParser::parse("[]").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_all_keyword() -> Result<()> {
+        assert_eq!(IndexParser::parse_with_max("all", 4)?, vec![1, 2, 3, 4]);
+        assert_eq!(IndexParser::parse_with_max("a", 3)?, vec![1, 2, 3]);
+        assert_eq!(IndexParser::parse_with_max("2 ALL,3", 3)?, vec![1, 2, 3]);
+        // Without a count there is nothing for it to expand to
+        assert!(IndexParser::parse("all").is_err());
+        assert!(IndexParser::parse_with_max("all-3", 5).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_exclusions() -> Result<()> {
+        assert_eq!(
+            IndexParser::parse_with_max("all ^3 ^7-9", 10)?,
+            vec![1, 2, 4, 5, 6, 10]
+        );
+        assert_eq!(IndexParser::parse_with_max("1-5 -3", 10)?, vec![1, 2, 4, 5]);
+        assert_eq!(IndexParser::parse("1-5,^2,^2,^[04]")?, vec![1, 3, 5]);
+        // On their own they leave indices out of everything listed
+        assert_eq!(IndexParser::parse_with_max("^1", 3)?, vec![2, 3]);
+        assert_eq!(IndexParser::parse_with_max("^all", 3)?, Vec::<usize>::new());
+        assert!(IndexParser::parse_with_max("all ^12", 10)
+            .unwrap_err()
+            .to_string()
+            .contains("Index 12 is out of range"));
+        assert!(IndexParser::parse_with_max("^0", 10).is_err());
+        assert!(IndexParser::parse("^").is_err());
+        assert!(IndexParser::parse("^5-3").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_index_list() {
+        assert!(IndexParser::is_index_list("1 3-5,8"));
+        assert!(IndexParser::is_index_list("all"));
+        assert!(IndexParser::is_index_list("a"));
+        assert!(IndexParser::is_index_list("^3 ^7-9"));
+        assert!(IndexParser::is_index_list("[02]"));
+        assert!(IndexParser::is_index_list("4."));
+        assert!(!IndexParser::is_index_list("main"));
+        assert!(!IndexParser::is_index_list("v1.2"));
+        assert!(!IndexParser::is_index_list("1.2"));
+        assert!(!IndexParser::is_index_list("-"));
+    }
+
     #[test]
     fn test_parse_invalid_range() {
         let result = IndexParser::parse("5-3");
@@ -0,0 +1,116 @@
+//! How the user reaches each subcommand, for usage text.
+//!
+//! The functions from `init` (and the installer's aliases) run
+//! `GIT_NAVIGATOR_ALIAS=ga git-navigator add ...`, so an error from `add` can
+//! say `ga <index>...` when typed as `ga` and `git-navigator add <index>...`
+//! when the binary was run directly. The short names themselves come from the
+//! `[aliases]` settings table, keyed by subcommand: `add = "a"` renames `ga`,
+//! and `add = ""` leaves it out.
+
+use crate::core::config::Settings;
+use std::env;
+use std::path::Path;
+
+/// Set by the shell functions to the short name that was typed
+pub const ALIAS_ENV: &str = "GIT_NAVIGATOR_ALIAS";
+
+/// The short names and the subcommands they run, as the installer sets them up
+pub const DEFAULT_ALIASES: [(&str, &str); 9] = [
+    ("gs", "status"),
+    ("ga", "add"),
+    ("gd", "diff"),
+    ("grs", "reset"),
+    ("gco", "checkout"),
+    ("gb", "branches"),
+    ("gl", "log"),
+    ("gc", "commit"),
+    ("ge", "edit"),
+];
+
+/// The short names `init` defines, after `[aliases]` overrides
+pub fn configured_aliases(settings: &Settings) -> Vec<(String, &'static str)> {
+    DEFAULT_ALIASES
+        .iter()
+        .filter_map(|&(name, command)| {
+            let name = match settings
+                .get_str(&format!("aliases.{command}"))
+                .map(str::trim)
+            {
+                Some(custom) if is_valid_name(custom) => custom,
+                Some(custom) => {
+                    if !custom.is_empty() {
+                        log::warn!(
+                            "Ignoring aliases.{command} = '{custom}': not a valid function name"
+                        );
+                    }
+                    return None;
+                }
+                None => name,
+            };
+            Some((name.to_string(), command))
+        })
+        .collect()
+}
+
+/// Names every shell can define a function for
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with('-')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// What the user types to run `command`: the alias it came through, else the
+/// binary and the subcommand
+pub fn command_name(command: &str) -> String {
+    let alias = env::var(ALIAS_ENV).ok();
+    let program = env::args_os().next();
+    invoked_as(command, alias.as_deref(), program.as_deref().map(Path::new))
+}
+
+fn invoked_as(command: &str, alias: Option<&str>, program: Option<&Path>) -> String {
+    if let Some(alias) = alias.map(str::trim).filter(|alias| !alias.is_empty()) {
+        return alias.to_string();
+    }
+    let program = program
+        .and_then(Path::file_stem)
+        .map(|name| name.to_string_lossy().into_owned())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "git-navigator".to_string());
+    format!("{program} {command}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invoked_as() {
+        assert_eq!(invoked_as("add", Some("ga"), None), "ga");
+        assert_eq!(
+            invoked_as("add", None, Some(Path::new("/usr/local/bin/git-navigator"))),
+            "git-navigator add"
+        );
+        assert_eq!(
+            invoked_as("diff", Some(" "), Some(Path::new("gn.exe"))),
+            "gn diff"
+        );
+        assert_eq!(invoked_as("diff", None, None), "git-navigator diff");
+    }
+
+    #[test]
+    fn test_configured_aliases() {
+        let settings =
+            Settings::parse("[aliases]\nadd = \"a\"\ndiff = \"\"\nlog = \"l; rm\"\n").unwrap();
+        let aliases = configured_aliases(&settings);
+        assert!(aliases.contains(&("a".to_string(), "add")));
+        assert!(aliases.contains(&("gs".to_string(), "status")));
+        assert!(!aliases.iter().any(|(_, command)| *command == "diff"));
+        assert!(!aliases.iter().any(|(_, command)| *command == "log"));
+        assert_eq!(
+            configured_aliases(&Settings::default()).len(),
+            DEFAULT_ALIASES.len()
+        );
+    }
+}
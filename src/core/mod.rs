@@ -4,17 +4,36 @@
 //! file indexing, error handling, and UI components.
 
 pub mod args_parser;
+pub mod backend;
+pub mod branch_name;
+pub mod cache;
+pub mod codeowners;
 pub mod colors;
 pub mod command_init;
 pub mod config;
+pub mod conventional;
+pub mod credentials;
+pub mod dates;
+pub mod diff_tool;
 pub mod dirs;
+pub mod editor;
 pub mod error;
 pub mod git;
+pub mod git_config;
 pub mod git_status;
+pub mod hints;
+pub mod hyperlink;
 pub mod index_parser;
+pub mod invocation;
+pub mod network;
+pub mod opener;
 pub mod output;
+pub mod packages;
+pub mod sparse;
 pub mod state;
+pub mod style;
 pub mod templates;
+pub mod usage;
 
 // === Error handling ===
 // Core error types and result type used throughout the application
@@ -30,6 +49,7 @@ pub use git_status::GitStatus;
 
 // === State management ===
 // Data structures for caching file and branch information
+pub use cache::CacheStore;
 pub use state::{BranchEntry, FileEntry, StateCache};
 
 // === Index parsing ===
@@ -68,5 +88,9 @@ pub use colors::{
 // === Output formatting ===
 // Unified output formatting for consistent CLI presentation
 pub use output::{
-    print_error, print_error_with_structured_usage, print_info, print_section_header, print_success,
+    configure_output, enable_non_interactive, enable_plain_mode, format_age, format_ahead_behind,
+    format_ahead_behind_plain, format_ahead_behind_unknown, format_error, format_header_note,
+    format_size, format_upstream_gone, is_interactive, is_plain_mode, print_error,
+    print_error_with_structured_usage, print_info, print_paged, print_section_header,
+    print_success, print_warning, OutputOptions, Stream,
 };
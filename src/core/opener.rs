@@ -0,0 +1,89 @@
+//! Opening paths and URLs with the desktop's default application.
+//!
+//! Uses `open` on macOS, `explorer` on Windows and `xdg-open` elsewhere.
+//! `GIT_NAVIGATOR_OPENER` replaces the platform opener, e.g. for a file
+//! manager that isn't the desktop default.
+
+use crate::core::error::{GitNavigatorError, Result};
+use std::env;
+use std::ffi::OsStr;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// The platform's default opener
+pub fn default_opener() -> &'static str {
+    if cfg!(target_os = "macos") {
+        "open"
+    } else if cfg!(windows) {
+        "explorer"
+    } else {
+        "xdg-open"
+    }
+}
+
+/// Opener to use, honoring `GIT_NAVIGATOR_OPENER`
+pub fn opener() -> String {
+    env::var("GIT_NAVIGATOR_OPENER")
+        .ok()
+        .map(|opener| opener.trim().to_string())
+        .filter(|opener| !opener.is_empty())
+        .unwrap_or_else(|| default_opener().to_string())
+}
+
+/// Open `path` (a file or directory) with the opener
+pub fn open_path(path: &Path) -> Result<()> {
+    open_with(&opener(), path)
+}
+
+/// Open a URL (e.g. an editor's `vscode://` link) with the opener
+pub fn open_url(url: &str) -> Result<()> {
+    open_with(&opener(), url)
+}
+
+/// Open a path or URL with a specific opener program
+pub fn open_with(opener: &str, target: impl AsRef<OsStr>) -> Result<()> {
+    let arg = target.as_ref();
+    let target = arg.to_string_lossy().into_owned();
+
+    let status = Command::new(opener)
+        .arg(arg)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map_err(|e| {
+            GitNavigatorError::open_failed(&target, format!("cannot run '{opener}': {e}"))
+        })?;
+
+    // explorer.exe reports failure even when the window opened fine
+    if !status.success() && !cfg!(windows) {
+        return Err(GitNavigatorError::open_failed(
+            target,
+            format!("'{opener}' exited with {status}"),
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_opener_is_known() {
+        assert!(["open", "explorer", "xdg-open"].contains(&default_opener()));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_open_with_reports_failures() {
+        assert!(open_with("true", Path::new(".")).is_ok());
+
+        let err = open_with("false", Path::new(".")).unwrap_err();
+        assert!(err.to_string().contains("'false' exited with"));
+
+        let err = open_with("git-navigator-no-such-opener", Path::new(".")).unwrap_err();
+        assert!(err.to_string().contains("cannot run"));
+    }
+}
@@ -8,10 +8,237 @@
 //! - **Standardized spacing**: Newline before and after all command outputs
 //! - **Context-aware messaging**: Command-specific usage examples and error messages
 //! - **User-friendly formatting**: Clear visual hierarchy and readable output
+//!
+//! # Plain mode
+//! [`enable_plain_mode`] (the global `--plain` flag) turns off colors and
+//! decorative glyphs and spells out labels in words, for screen readers and
+//! dumb terminals. Renderers check [`is_plain_mode`] to pick their layout.
+//!
+//! # Compact output
+//! The blank lines around messages and their glyph prefixes can be turned
+//! off with `ui.compact = true` and `ui.decorations = false` (see
+//! [`OutputOptions`]), which keeps scripted use quiet.
+//!
+//! # Streams
+//! Errors, warnings and usage help go to stderr and everything else to
+//! stdout (see [`Stream`]), so piping a command's output never captures its
+//! diagnostics.
 
+use crate::core::{config::Settings, invocation::command_name, templates::glyphs};
 use colored::*;
+use std::io::{IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+
+static PLAIN_MODE: AtomicBool = AtomicBool::new(false);
+static NON_INTERACTIVE: AtomicBool = AtomicBool::new(false);
+static OUTPUT_OPTIONS: OnceLock<OutputOptions> = OnceLock::new();
+
+/// Spacing and decoration of messages printed by this module
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutputOptions {
+    /// Drop the blank lines around messages (`ui.compact`)
+    pub compact: bool,
+    /// Prefix messages with glyphs such as ✓ and ✕ (`ui.decorations`)
+    pub decorations: bool,
+}
+
+impl Default for OutputOptions {
+    fn default() -> Self {
+        Self {
+            compact: false,
+            decorations: true,
+        }
+    }
+}
+
+impl OutputOptions {
+    /// Read the options from the `[ui]` settings
+    pub fn from_settings(settings: &Settings) -> Self {
+        let defaults = Self::default();
+        Self {
+            compact: settings.get_bool("ui.compact").unwrap_or(defaults.compact),
+            decorations: settings
+                .get_bool("ui.decorations")
+                .unwrap_or(defaults.decorations),
+        }
+    }
+
+    /// Options in effect for this process
+    pub fn current() -> Self {
+        OUTPUT_OPTIONS.get().copied().unwrap_or_default()
+    }
+
+    /// Blank line placed around messages, empty in compact mode
+    fn gap(self) -> &'static str {
+        if self.compact {
+            ""
+        } else {
+            "\n"
+        }
+    }
+
+    /// Message prefix such as "✕ Error:"
+    ///
+    /// Without decorations (or in plain mode) only the word is kept; a
+    /// prefix that is nothing but a glyph disappears entirely.
+    fn label(self, glyph: &str, word: &str) -> Option<String> {
+        if is_plain_mode() || !self.decorations {
+            (!word.is_empty()).then(|| word.to_string())
+        } else if word.is_empty() {
+            Some(glyph.to_string())
+        } else {
+            Some(format!("{glyph} {word}"))
+        }
+    }
+}
+
+/// Install the output options for the rest of the process
+///
+/// Only the first call takes effect.
+pub fn configure_output(settings: &Settings) {
+    let _ = OUTPUT_OPTIONS.set(OutputOptions::from_settings(settings));
+}
+
+/// Where a message is written
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stream {
+    /// Command output: lists, diffs, paths and confirmations
+    Stdout,
+    /// Diagnostics: errors, warnings and usage help
+    Stderr,
+}
+
+impl Stream {
+    /// Write `text` as is; a closed pipe is not worth failing over
+    pub fn write(self, text: &str) {
+        let _ = match self {
+            Stream::Stdout => std::io::stdout().lock().write_all(text.as_bytes()),
+            Stream::Stderr => std::io::stderr().lock().write_all(text.as_bytes()),
+        };
+    }
+
+    /// [`Stream::write`] with a newline
+    pub fn write_line(self, text: &str) {
+        self.write(&format!("{text}\n"));
+    }
+}
+
+/// Join an optional colored prefix and a message
+fn prefixed(prefix: Option<ColoredString>, message: &str) -> String {
+    match prefix {
+        Some(prefix) => format!("{} {}", prefix, message.white()),
+        None => message.white().to_string(),
+    }
+}
+
+/// Switch this process to plain output: no colors, glyphs or padding
+pub fn enable_plain_mode() {
+    PLAIN_MODE.store(true, Ordering::Relaxed);
+    colored::control::set_override(false);
+}
+
+/// Whether output should use the plain, screen-reader friendly layout
+pub fn is_plain_mode() -> bool {
+    PLAIN_MODE.load(Ordering::Relaxed)
+}
+
+/// Never prompt, open a pager or let git ask for credentials (`--non-interactive`)
+pub fn enable_non_interactive() {
+    NON_INTERACTIVE.store(true, Ordering::Relaxed);
+}
+
+/// Whether there is someone to ask: not `--non-interactive`, and stdin is a
+/// terminal (hooks and CI jobs usually have none)
+pub fn is_interactive() -> bool {
+    !NON_INTERACTIVE.load(Ordering::Relaxed) && std::io::stdin().is_terminal()
+}
+
+/// Spells out ahead/behind counts for plain mode, e.g. ` (ahead 2, behind 1)`
+pub fn format_ahead_behind_plain(ahead: usize, behind: usize) -> String {
+    match (ahead, behind) {
+        (0, 0) => String::new(),
+        (ahead, 0) => format!(" (ahead {ahead})"),
+        (0, behind) => format!(" (behind {behind})"),
+        (ahead, behind) => format!(" (ahead {ahead}, behind {behind})"),
+    }
+}
+
+/// Ahead/behind counts after a branch name, e.g. ` (+2/−1)`
+///
+/// Empty when the branch is in sync; spelled out in plain mode.
+pub fn format_ahead_behind(ahead: usize, behind: usize) -> String {
+    if is_plain_mode() {
+        return format_ahead_behind_plain(ahead, behind);
+    }
+    let minus = glyphs().minus;
+    let counts = match (ahead, behind) {
+        (0, 0) => return String::new(),
+        (ahead, 0) => format!("+{}", ahead.to_string().white()),
+        (0, behind) => format!("{minus}{}", behind.to_string().white()),
+        (ahead, behind) => format!(
+            "+{}/{minus}{}",
+            ahead.to_string().white(),
+            behind.to_string().white()
+        ),
+    };
+    format!(" {}{counts}{}", "(".bright_black(), ")".bright_black())
+}
+
+/// Marker after a branch whose upstream was deleted on the remote
+pub fn format_upstream_gone() -> String {
+    if is_plain_mode() {
+        " (upstream gone)".to_string()
+    } else {
+        format!(" {}", "(upstream gone)".yellow())
+    }
+}
+
+/// Marker after a branch whose fork point a shallow clone cut off
+pub fn format_ahead_behind_unknown() -> String {
+    if is_plain_mode() {
+        " (ahead/behind unknown)".to_string()
+    } else {
+        format!(" {}", "(ahead/behind unknown)".yellow())
+    }
+}
+
+/// Note after the branch in the header, e.g. ` (shallow, depth 1)`
+pub fn format_header_note(note: &str) -> String {
+    if is_plain_mode() {
+        format!(" ({note})")
+    } else {
+        format!(" {}", format!("({note})").bright_black())
+    }
+}
+
+/// Compact age of something, e.g. `45s`, `12m`, `3h` or `5d`
+pub fn format_age(age: std::time::Duration) -> String {
+    let secs = age.as_secs();
+    match secs {
+        0..60 => format!("{secs}s"),
+        60..3600 => format!("{}m", secs / 60),
+        3600..86400 => format!("{}h", secs / 3600),
+        _ => format!("{}d", secs / 86400),
+    }
+}
 
-/// Formats and prints an error message with consistent styling
+/// Human-readable size in binary units, e.g. `512 B`, `3.4 KiB` or `12.0 MiB`
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+/// Formats and prints an error message to stderr with consistent styling
 ///
 /// # Format
 /// ```text
@@ -25,18 +252,29 @@ use colored::*;
 /// - Message in white
 /// - Newlines before and after for spacing
 pub fn print_error(message: &str) {
-    println!("\n{} {}\n", "✕ Error:".red(), message.white());
+    Stream::Stderr.write_line(&format_error(message));
+}
+
+/// Formats an error message like [`print_error`] without printing it
+pub fn format_error(message: &str) -> String {
+    format_error_with(OutputOptions::current(), message)
+}
+
+fn format_error_with(options: OutputOptions, message: &str) -> String {
+    let prefix = options.label(glyphs().error, "Error:").map(|p| p.red());
+    let gap = options.gap();
+    format!("{gap}{}{gap}", prefixed(prefix, message))
 }
 
-/// Formats and prints an error with structured usage information
+/// Formats and prints an error with structured usage information to stderr
 ///
 /// # Format
 /// ```text
 ///
 /// ✕ Error: <message>.
 /// Usage:
-///   <usage_pattern1>
-///   <usage_pattern2>
+///   <command> <usage_pattern1>
+///   <command> <usage_pattern2>
 ///   ...
 ///
 /// Options:
@@ -46,6 +284,9 @@ pub fn print_error(message: &str) {
 ///
 /// ```
 ///
+/// `<command>` is what the user typed to run the subcommand `command`, see
+/// [`command_name`].
+///
 /// # Colors
 /// - Error prefix in red
 /// - Message in white
@@ -53,24 +294,37 @@ pub fn print_error(message: &str) {
 /// - Options in bright_black (muted)
 pub fn print_error_with_structured_usage(
     message: &str,
+    command: &str,
     usage_patterns: &[&str],
     options: &[(&str, &str)],
 ) {
-    println!("\n{} {}.\n", "✕ Error:".red(), message.white());
-    println!("{}", "Usage:".blue());
+    let name = command_name(command);
+    let output = OutputOptions::current();
+    let gap = output.gap();
+    let prefix = output.label(glyphs().error, "Error:").map(|p| p.red());
+    let mut text = format!(
+        "{gap}{}.{gap}\n{}\n",
+        prefixed(prefix, message),
+        "Usage:".blue()
+    );
 
     for pattern in usage_patterns {
-        println!("  {}", pattern.white());
+        text.push_str(&format!("  {}\n", format!("{name} {pattern}").white()));
     }
 
     if !options.is_empty() {
-        println!("\n{}", "Options:".blue());
+        text.push_str(&format!("{gap}{}\n", "Options:".blue()));
         for (flag, description) in options {
-            println!("  {}  {}", flag.bright_black(), description.bright_black());
+            text.push_str(&format!(
+                "  {}  {}\n",
+                flag.bright_black(),
+                description.bright_black()
+            ));
         }
     }
 
-    println!();
+    text.push_str(gap);
+    Stream::Stderr.write(&text);
 }
 
 /// Formats and prints a success message with consistent styling
@@ -86,7 +340,33 @@ pub fn print_error_with_structured_usage(
 /// - Checkmark in green, message in white
 /// - Newlines before and after for spacing
 pub fn print_success(message: &str) {
-    println!("\n{} {}", "✓".green(), message.white());
+    println!("{}", format_success_with(OutputOptions::current(), message));
+}
+
+fn format_success_with(options: OutputOptions, message: &str) -> String {
+    let word = if is_plain_mode() { "Success:" } else { "" };
+    let prefix = options.label(glyphs().success, word).map(|p| p.green());
+    format!("{}{}", options.gap(), prefixed(prefix, message))
+}
+
+/// Formats and prints a warning message to stderr with consistent styling
+///
+/// # Format
+/// ```text
+///
+/// ⚠ Warning: <message>
+/// ```
+///
+/// # Colors
+/// - "⚠ Warning:" in yellow
+/// - Message in white
+/// - Newline before for spacing
+pub fn print_warning(message: &str) {
+    let options = OutputOptions::current();
+    let prefix = options
+        .label(glyphs().warning, "Warning:")
+        .map(|p| p.yellow());
+    Stream::Stderr.write_line(&format!("{}{}", options.gap(), prefixed(prefix, message)));
 }
 
 /// Formats and prints an informational message with consistent styling
@@ -102,7 +382,7 @@ pub fn print_success(message: &str) {
 /// - Message in white
 /// - Newlines before and after for spacing
 pub fn print_info(message: &str) {
-    println!("\n{}", message.white());
+    println!("{}{}", OutputOptions::current().gap(), message.white());
 }
 
 /// Formats and prints a section header with consistent styling
@@ -118,23 +398,168 @@ pub fn print_info(message: &str) {
 /// - Header in white
 /// - Newlines before and after for spacing
 pub fn print_section_header(header: &str) {
-    println!("\n{}:\n", header.white());
+    let gap = OutputOptions::current().gap();
+    if is_plain_mode() {
+        println!("{gap}Section: {header}{gap}");
+        return;
+    }
+    println!("{gap}{}:{gap}", header.white());
+}
+
+/// Prints long output through a pager when writing to a terminal
+///
+/// Falls back to plain printing when there is no pager, stdout is not a
+/// terminal, or the pager cannot be started. Like git, `LESS=FRX` is set
+/// unless the user already has a `LESS` preference.
+pub fn print_paged(text: &str, pager: Option<&str>) {
+    let pager = match pager {
+        Some(pager) if std::io::stdout().is_terminal() && is_interactive() => pager,
+        _ => {
+            print!("{text}");
+            return;
+        }
+    };
+
+    let mut cmd = std::process::Command::new("sh");
+    cmd.args(["-c", pager]).stdin(std::process::Stdio::piped());
+    if std::env::var_os("LESS").is_none() {
+        cmd.env("LESS", "FRX");
+    }
+
+    match cmd.spawn() {
+        Ok(mut child) => {
+            if let Some(mut stdin) = child.stdin.take() {
+                // The pager may exit early (e.g. `q` in less); that's not an error
+                let _ = stdin.write_all(text.as_bytes());
+            }
+            let _ = child.wait();
+        }
+        Err(e) => {
+            log::warn!("Could not start pager '{pager}': {e}");
+            print!("{text}");
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::templates::strip_ansi_codes;
 
     #[test]
     fn test_print_error_does_not_panic() {
         print_error("Test error message");
     }
 
+    #[test]
+    fn test_format_error_contains_message() {
+        let formatted = strip_ansi_codes(&format_error("Boom"));
+        assert_eq!(formatted, "\n✕ Error: Boom\n");
+    }
+
+    #[test]
+    fn test_output_options_from_settings() {
+        let settings = Settings::parse("[ui]\ncompact = true\ndecorations = false").unwrap();
+        assert_eq!(
+            OutputOptions::from_settings(&settings),
+            OutputOptions {
+                compact: true,
+                decorations: false,
+            }
+        );
+        assert_eq!(
+            OutputOptions::from_settings(&Settings::default()),
+            OutputOptions::default()
+        );
+    }
+
+    #[test]
+    fn test_compact_messages_have_no_blank_lines() {
+        let compact = OutputOptions {
+            compact: true,
+            decorations: true,
+        };
+        assert_eq!(
+            strip_ansi_codes(&format_error_with(compact, "Boom")),
+            format!("{} Error: Boom", glyphs().error)
+        );
+        assert_eq!(
+            strip_ansi_codes(&format_success_with(compact, "Done")),
+            format!("{} Done", glyphs().success)
+        );
+    }
+
+    #[test]
+    fn test_undecorated_messages_keep_words_only() {
+        let bare = OutputOptions {
+            compact: false,
+            decorations: false,
+        };
+        assert_eq!(
+            strip_ansi_codes(&format_error_with(bare, "Boom")),
+            "\nError: Boom\n"
+        );
+        assert_eq!(
+            strip_ansi_codes(&format_success_with(bare, "Done")),
+            "\nDone"
+        );
+    }
+
+    #[test]
+    fn test_format_ahead_behind_plain() {
+        assert_eq!(format_ahead_behind_plain(0, 0), "");
+        assert_eq!(format_ahead_behind_plain(2, 0), " (ahead 2)");
+        assert_eq!(format_ahead_behind_plain(0, 3), " (behind 3)");
+        assert_eq!(format_ahead_behind_plain(2, 3), " (ahead 2, behind 3)");
+    }
+
+    #[test]
+    fn test_format_ahead_behind() {
+        let minus = glyphs().minus;
+        assert_eq!(format_ahead_behind(0, 0), "");
+        assert_eq!(strip_ansi_codes(&format_ahead_behind(2, 0)), " (+2)");
+        assert_eq!(
+            strip_ansi_codes(&format_ahead_behind(0, 3)),
+            format!(" ({minus}3)")
+        );
+        assert_eq!(
+            strip_ansi_codes(&format_ahead_behind(2, 3)),
+            format!(" (+2/{minus}3)")
+        );
+    }
+
+    #[test]
+    fn test_format_age() {
+        use std::time::Duration;
+        assert_eq!(format_age(Duration::from_secs(45)), "45s");
+        assert_eq!(format_age(Duration::from_secs(12 * 60 + 5)), "12m");
+        assert_eq!(format_age(Duration::from_secs(3 * 3600 + 59)), "3h");
+        assert_eq!(format_age(Duration::from_secs(5 * 86400)), "5d");
+    }
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(3 * 1024 + 400), "3.4 KiB");
+        assert_eq!(format_size(12 * 1024 * 1024), "12.0 MiB");
+        assert_eq!(format_size(5 * 1024 * 1024 * 1024), "5.0 GiB");
+    }
+
+    #[test]
+    fn test_print_paged_without_pager_does_not_panic() {
+        print_paged("plain output\n", None);
+    }
+
     #[test]
     fn test_print_success_does_not_panic() {
         print_success("Operation completed");
     }
 
+    #[test]
+    fn test_print_warning_does_not_panic() {
+        print_warning("Something needs attention");
+    }
+
     #[test]
     fn test_print_info_does_not_panic() {
         print_info("Information message");
@@ -0,0 +1,425 @@
+//! Workspace packages of a monorepo, for `packages`.
+//!
+//! Packages come from, in order of precedence:
+//!
+//! 1. a Cargo workspace: `[workspace] members` (and `exclude`) in the root
+//!    `Cargo.toml`, plus the root crate itself when it has a `[package]`
+//! 2. npm, yarn or pnpm workspaces: `workspaces` in the root `package.json`
+//! 3. directory globs from the settings, named after their path:
+//!
+//! ```toml
+//! [packages]
+//! globs = ["services/*", "libs/**"]
+//! ```
+//!
+//! Globs understand `*` and `?` within a directory name and `**` for any
+//! number of directories.
+
+use crate::core::config::Settings;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Directories never searched by `**`
+const SKIPPED_DIRS: [&str; 3] = [".git", "node_modules", "target"];
+
+/// A package and the directory it lives in
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Package {
+    pub name: String,
+    /// Directory relative to the repository root (`.` for the root itself)
+    pub path: PathBuf,
+}
+
+/// All packages under `workdir`, sorted by path
+pub fn discover(workdir: &Path, settings: &Settings) -> Vec<Package> {
+    let mut packages: BTreeMap<PathBuf, String> = BTreeMap::new();
+    let globs = settings.get_list("packages.globs").unwrap_or_default();
+    let found = cargo_packages(workdir)
+        .into_iter()
+        .chain(npm_packages(workdir))
+        .chain(globs.iter().flat_map(|glob| {
+            expand_glob(workdir, glob).into_iter().map(|path| Package {
+                name: path.display().to_string(),
+                path,
+            })
+        }));
+    for package in found {
+        packages.entry(package.path).or_insert(package.name);
+    }
+
+    packages
+        .into_iter()
+        .map(|(path, name)| Package { name, path })
+        .collect()
+}
+
+/// The innermost package containing a repository-relative path
+pub fn package_for<'a>(packages: &'a [Package], path: &Path) -> Option<&'a Package> {
+    packages
+        .iter()
+        .filter(|package| package.path == Path::new(".") || path.starts_with(&package.path))
+        .max_by_key(|package| {
+            if package.path == Path::new(".") {
+                0
+            } else {
+                package.path.components().count()
+            }
+        })
+}
+
+fn cargo_packages(workdir: &Path) -> Vec<Package> {
+    let Ok(manifest) = std::fs::read_to_string(workdir.join("Cargo.toml")) else {
+        return Vec::new();
+    };
+
+    let mut packages = Vec::new();
+    if let Some(name) = toml_string(&manifest, "package", "name") {
+        packages.push(Package {
+            name,
+            path: PathBuf::from("."),
+        });
+    }
+
+    let excluded: Vec<PathBuf> = toml_array(&manifest, "workspace", "exclude")
+        .iter()
+        .flat_map(|pattern| expand_glob(workdir, pattern))
+        .collect();
+    for pattern in toml_array(&manifest, "workspace", "members") {
+        for path in expand_glob(workdir, &pattern) {
+            if excluded.contains(&path) {
+                continue;
+            }
+            let Ok(member) = std::fs::read_to_string(workdir.join(&path).join("Cargo.toml")) else {
+                continue;
+            };
+            packages.push(Package {
+                name: toml_string(&member, "package", "name").unwrap_or_else(|| dir_name(&path)),
+                path,
+            });
+        }
+    }
+    packages
+}
+
+fn npm_packages(workdir: &Path) -> Vec<Package> {
+    let Some(manifest) = read_json(&workdir.join("package.json")) else {
+        return Vec::new();
+    };
+    // Either an array, or yarn's { "packages": [...] }
+    let workspaces = match &manifest["workspaces"] {
+        serde_json::Value::Array(patterns) => patterns.clone(),
+        other => other["packages"].as_array().cloned().unwrap_or_default(),
+    };
+    let patterns: Vec<&str> = workspaces.iter().filter_map(|p| p.as_str()).collect();
+
+    let excluded: Vec<PathBuf> = patterns
+        .iter()
+        .filter_map(|pattern| pattern.strip_prefix('!'))
+        .flat_map(|pattern| expand_glob(workdir, pattern))
+        .collect();
+    patterns
+        .iter()
+        .filter(|pattern| !pattern.starts_with('!'))
+        .flat_map(|pattern| expand_glob(workdir, pattern))
+        .filter(|path| !excluded.contains(path))
+        .filter_map(|path| {
+            let member = read_json(&workdir.join(&path).join("package.json"))?;
+            let name = member["name"]
+                .as_str()
+                .map_or_else(|| dir_name(&path), str::to_string);
+            Some(Package { name, path })
+        })
+        .collect()
+}
+
+fn read_json(path: &Path) -> Option<serde_json::Value> {
+    let content = std::fs::read_to_string(path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn dir_name(path: &Path) -> String {
+    path.file_name().map_or_else(
+        || path.display().to_string(),
+        |name| name.to_string_lossy().into_owned(),
+    )
+}
+
+/// Directories under `workdir` matching a glob, relative and sorted
+pub fn expand_glob(workdir: &Path, pattern: &str) -> Vec<PathBuf> {
+    let pattern = pattern
+        .trim()
+        .trim_start_matches("./")
+        .trim_end_matches('/');
+    if pattern.is_empty() {
+        return Vec::new();
+    }
+
+    let mut matches = vec![PathBuf::new()];
+    for segment in pattern.split('/') {
+        let mut next = Vec::new();
+        for dir in &matches {
+            if segment == "**" {
+                collect_dirs(workdir, dir, &mut next);
+            } else {
+                next.extend(subdirs(workdir, dir).into_iter().filter(|path| {
+                    path.file_name()
+                        .is_some_and(|name| wildcard_match(segment, &name.to_string_lossy()))
+                }));
+            }
+        }
+        next.sort();
+        next.dedup();
+        matches = next;
+    }
+    matches.retain(|path| !path.as_os_str().is_empty());
+    matches
+}
+
+fn subdirs(workdir: &Path, dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(workdir.join(dir)) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .filter(|entry| entry.file_type().is_ok_and(|kind| kind.is_dir()))
+        .map(|entry| dir.join(entry.file_name()))
+        .collect()
+}
+
+/// `dir` and every directory below it
+fn collect_dirs(workdir: &Path, dir: &Path, out: &mut Vec<PathBuf>) {
+    out.push(dir.to_path_buf());
+    for subdir in subdirs(workdir, dir) {
+        let skipped = subdir
+            .file_name()
+            .is_some_and(|name| SKIPPED_DIRS.iter().any(|skipped| name == *skipped));
+        if !skipped {
+            collect_dirs(workdir, &subdir, out);
+        }
+    }
+}
+
+/// Match a name against `*` and `?` wildcards
+pub(crate) fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack = None;
+
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some('?') => {
+                p += 1;
+                n += 1;
+            }
+            Some(c) if *c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    n = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+/// Lines of a TOML table, without comments
+fn toml_table(content: &str, table: &str) -> Vec<String> {
+    let header = format!("[{table}]");
+    content
+        .lines()
+        .map(strip_toml_comment)
+        .skip_while(|line| line.trim() != header)
+        .skip(1)
+        .take_while(|line| {
+            let line = line.trim();
+            !(line.starts_with('[') && !line.starts_with("[\"") && line.ends_with(']'))
+        })
+        .collect()
+}
+
+fn strip_toml_comment(line: &str) -> String {
+    let mut quote = None;
+    for (position, c) in line.char_indices() {
+        match (c, quote) {
+            ('"' | '\'', None) => quote = Some(c),
+            (c, Some(open)) if c == open => quote = None,
+            ('#', None) => return line[..position].to_string(),
+            _ => {}
+        }
+    }
+    line.to_string()
+}
+
+/// The value after `key =`, up to the end of the table
+fn toml_value(content: &str, table: &str, key: &str) -> Option<String> {
+    let lines = toml_table(content, table);
+    let start = lines.iter().position(|line| {
+        line.split_once('=')
+            .is_some_and(|(name, _)| name.trim().trim_matches('"') == key)
+    })?;
+    let mut value = lines[start].split_once('=')?.1.to_string();
+    for line in &lines[start + 1..] {
+        value.push('\n');
+        value.push_str(line);
+    }
+    Some(value)
+}
+
+/// A string value from a TOML table
+fn toml_string(content: &str, table: &str, key: &str) -> Option<String> {
+    let value = toml_value(content, table, key)?;
+    quoted_strings(value.lines().next()?).into_iter().next()
+}
+
+/// A (possibly multi-line) array of strings from a TOML table
+fn toml_array(content: &str, table: &str, key: &str) -> Vec<String> {
+    let Some(value) = toml_value(content, table, key) else {
+        return Vec::new();
+    };
+    let Some(array) = value.trim_start().strip_prefix('[') else {
+        return Vec::new();
+    };
+    let array = array.split(']').next().unwrap_or_default();
+    quoted_strings(array)
+}
+
+fn quoted_strings(text: &str) -> Vec<String> {
+    let mut strings = Vec::new();
+    let mut current: Option<(char, String)> = None;
+    for c in text.chars() {
+        match &mut current {
+            Some((quote, value)) if c == *quote => {
+                strings.push(std::mem::take(value));
+                current = None;
+            }
+            Some((_, value)) => value.push(c),
+            None if c == '"' || c == '\'' => current = Some((c, String::new())),
+            None => {}
+        }
+    }
+    strings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(root: &Path, path: &str, content: &str) {
+        let path = root.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    fn package(name: &str, path: &str) -> Package {
+        Package {
+            name: name.to_string(),
+            path: PathBuf::from(path),
+        }
+    }
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("*", "core"));
+        assert!(wildcard_match("app-*", "app-web"));
+        assert!(wildcard_match("a?c", "abc"));
+        assert!(wildcard_match("*-*-x", "a-b-c-x"));
+        assert!(!wildcard_match("app-*", "lib-web"));
+        assert!(!wildcard_match("a?c", "ac"));
+    }
+
+    #[test]
+    fn test_toml_arrays_and_strings() {
+        let manifest = r#"
+[package]
+name = "root-crate" # the binary
+version = "0.1.0"
+
+[workspace]
+members = [
+    "crates/*", # every crate
+    'tools/gen',
+]
+exclude = ["crates/old"]
+
+[dependencies]
+members = ["not-these"]
+"#;
+        assert_eq!(
+            toml_string(manifest, "package", "name"),
+            Some("root-crate".to_string())
+        );
+        assert_eq!(
+            toml_array(manifest, "workspace", "members"),
+            vec!["crates/*", "tools/gen"]
+        );
+        assert_eq!(
+            toml_array(manifest, "workspace", "exclude"),
+            vec!["crates/old"]
+        );
+        assert!(toml_array(manifest, "workspace", "default-members").is_empty());
+    }
+
+    #[test]
+    fn test_discover_cargo_npm_and_globs() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        write(
+            root,
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"crates/*\"]\nexclude = [\"crates/old\"]\n",
+        );
+        write(
+            root,
+            "crates/core/Cargo.toml",
+            "[package]\nname = \"nav-core\"\n",
+        );
+        write(root, "crates/old/Cargo.toml", "[package]\nname = \"old\"\n");
+        write(root, "crates/notes.txt/README", "not a crate");
+        write(
+            root,
+            "package.json",
+            r#"{"workspaces": {"packages": ["web/*", "!web/legacy"]}}"#,
+        );
+        write(root, "web/app/package.json", r#"{"name": "@org/app"}"#);
+        write(root, "web/legacy/package.json", r#"{"name": "legacy"}"#);
+        write(root, "services/api/main.go", "package main");
+
+        let settings = Settings::parse("[packages]\nglobs = [\"services/*\"]").unwrap();
+        assert_eq!(
+            discover(root, &settings),
+            vec![
+                package("nav-core", "crates/core"),
+                package("services/api", "services/api"),
+                package("@org/app", "web/app"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_package_for_innermost() {
+        let packages = vec![
+            package("root", "."),
+            package("web", "web"),
+            package("widgets", "web/widgets"),
+        ];
+        let name = |path: &str| package_for(&packages, Path::new(path)).map(|p| p.name.as_str());
+
+        assert_eq!(name("web/widgets/button.ts"), Some("widgets"));
+        assert_eq!(name("web/index.ts"), Some("web"));
+        assert_eq!(name("webby/index.ts"), Some("root"));
+        assert_eq!(name("README.md"), Some("root"));
+        assert_eq!(package_for(&packages[1..], Path::new("README.md")), None);
+    }
+}
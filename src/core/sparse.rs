@@ -0,0 +1,134 @@
+//! Sparse checkout definitions from `info/sparse-checkout`.
+//!
+//! In cone mode (`core.sparseCheckoutCone`) the file names directories:
+//! everything below a listed directory is checked out, along with the files
+//! at the root and directly inside each parent of a listed directory. Files
+//! written in the older pattern mode are kept as they are; telling which
+//! paths they include would need git's full matcher, so every path counts
+//! as included there.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+/// The sparse checkout of a worktree
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SparseCheckout {
+    /// Whether the definition uses cone mode
+    pub cone: bool,
+    /// Directories checked out in full (cone mode) or the raw patterns
+    entries: Vec<String>,
+    /// Directories whose own files are checked out, but not their subdirectories
+    parents: BTreeSet<PathBuf>,
+}
+
+impl SparseCheckout {
+    /// The sparse checkout of `repo`'s worktree, if one is enabled
+    pub fn load(repo: &git2::Repository) -> Option<Self> {
+        if !config_bool(repo, "core.sparseCheckout").unwrap_or(false) {
+            return None;
+        }
+        let content = std::fs::read_to_string(repo.path().join("info/sparse-checkout")).ok()?;
+        let cone = config_bool(repo, "core.sparseCheckoutCone").unwrap_or(false);
+        Some(Self::parse(&content, cone))
+    }
+
+    pub fn parse(content: &str, cone: bool) -> Self {
+        let lines: Vec<&str> = content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .collect();
+        if !cone {
+            return Self {
+                cone,
+                entries: lines.iter().map(|line| line.to_string()).collect(),
+                parents: BTreeSet::new(),
+            };
+        }
+
+        // `/a/` followed by `!/a/*/` only keeps the files directly in `a`
+        let mut entries = Vec::new();
+        let mut parents = BTreeSet::new();
+        for line in &lines {
+            let Some(dir) = line
+                .strip_prefix('/')
+                .and_then(|line| line.strip_suffix('/'))
+                .filter(|dir| !dir.is_empty() && *dir != "*")
+            else {
+                continue;
+            };
+            if lines.contains(&format!("!/{dir}/*/").as_str()) {
+                parents.insert(PathBuf::from(dir));
+            } else {
+                entries.push(dir.to_string());
+            }
+        }
+        Self {
+            cone,
+            entries,
+            parents,
+        }
+    }
+
+    /// What `git sparse-checkout list` shows
+    pub fn entries(&self) -> &[String] {
+        &self.entries
+    }
+
+    /// Whether `path` (relative to the worktree root) is inside the definition
+    pub fn includes(&self, path: &Path) -> bool {
+        if !self.cone {
+            return true;
+        }
+        let parent = path.parent().unwrap_or(Path::new(""));
+        parent.as_os_str().is_empty()
+            || self.parents.contains(parent)
+            || self.entries.iter().any(|dir| path.starts_with(dir))
+    }
+}
+
+/// A boolean setting, looking in `config.worktree` too, where
+/// `git sparse-checkout` writes its settings when worktree config is on
+fn config_bool(repo: &git2::Repository, key: &str) -> Option<bool> {
+    repo.config()
+        .ok()
+        .and_then(|config| config.get_bool(key).ok())
+        .or_else(|| {
+            git2::Config::open(&repo.path().join("config.worktree"))
+                .ok()?
+                .get_bool(key)
+                .ok()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONE: &str = "/*\n!/*/\n/a/\n!/a/*/\n/a/b/\n/d/\n";
+
+    #[test]
+    fn test_parse_cone() {
+        let sparse = SparseCheckout::parse(CONE, true);
+        assert_eq!(sparse.entries(), ["a/b", "d"]);
+    }
+
+    #[test]
+    fn test_includes_cone() {
+        let sparse = SparseCheckout::parse(CONE, true);
+        assert!(sparse.includes(Path::new("README.md")));
+        assert!(sparse.includes(Path::new("a/x.txt")));
+        assert!(sparse.includes(Path::new("a/b/deep/y.txt")));
+        assert!(sparse.includes(Path::new("d/w.txt")));
+        assert!(!sparse.includes(Path::new("a/c/z.txt")));
+        assert!(!sparse.includes(Path::new("e/v.txt")));
+    }
+
+    #[test]
+    fn test_pattern_mode_includes_everything() {
+        let sparse = SparseCheckout::parse("/docs/\n!/docs/drafts/\n", false);
+        assert!(!sparse.cone);
+        assert_eq!(sparse.entries(), ["/docs/", "!/docs/drafts/"]);
+        assert!(sparse.includes(Path::new("src/main.rs")));
+    }
+}
@@ -7,16 +7,26 @@
 //! # Public API
 //! - [`FileEntry`]: Represents a single file with its git status and metadata
 //! - [`BranchEntry`]: Represents a git branch with selection index
+//! - [`DirEntry`]: Represents a directory containing changes, for `dirs`
+//! - [`FileStat`]: Lines added and removed in a changed file
+//! - [`CommitEntry`]: Represents a recent commit with selection index
+//! - [`Contributor`]: An author with commit count and last activity, for `contributors`
+//! - [`TagEntry`]: Represents a tag with selection index
+//! - [`PackageEntry`]: A workspace package with changes, for `packages`
 //! - [`StateCache`]: Complete repository state cache with timing information
+//! - [`CacheSnapshot`]: Repository state a cached file list was computed from
 //!
 //! # Cache Strategy
 //! - **JSON serialization**: Human-readable cache files for debugging
 //! - **Timestamping**: Track when cache was last updated
 //! - **Repository isolation**: Separate cache per repository path
+//! - **Staleness**: File lists carry a [`CacheSnapshot`] so index commands can
+//!   tell when the worktree moved on since `gs`
 
 use crate::core::git_status::GitStatus;
+use crate::core::packages::wildcard_match;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -25,6 +35,122 @@ pub struct FileEntry {
     pub status: GitStatus,
     pub path: PathBuf,
     pub staged: bool,
+    /// Where a renamed or copied file came from
+    #[serde(default)]
+    pub old_path: Option<PathBuf>,
+}
+
+impl FileEntry {
+    /// The paths resetting or discarding this entry has to touch: a rename
+    /// is undone at both ends, a copy only where it was copied to
+    pub fn affected_paths(&self) -> Vec<PathBuf> {
+        match (&self.old_path, self.status) {
+            (Some(old_path), GitStatus::Renamed) => vec![old_path.clone(), self.path.clone()],
+            _ => vec![self.path.clone()],
+        }
+    }
+}
+
+/// A section of the `gs` list, which `gs --staged` and friends limit it to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StatusGroup {
+    Unmerged,
+    Staged,
+    Unstaged,
+    Untracked,
+}
+
+impl StatusGroup {
+    /// The group `file` is listed in; changed submodules count as unstaged
+    pub fn of(file: &FileEntry) -> Self {
+        match file.status {
+            GitStatus::Unmerged => Self::Unmerged,
+            GitStatus::Untracked => Self::Untracked,
+            _ if file.staged => Self::Staged,
+            _ => Self::Unstaged,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Unmerged => "unmerged",
+            Self::Staged => "staged",
+            Self::Unstaged => "unstaged",
+            Self::Untracked => "untracked",
+        }
+    }
+
+    /// The group called `name`, as in `ga unstaged`
+    pub fn from_name(name: &str) -> Option<Self> {
+        [
+            Self::Unmerged,
+            Self::Staged,
+            Self::Unstaged,
+            Self::Untracked,
+        ]
+        .into_iter()
+        .find(|group| group.name().eq_ignore_ascii_case(name))
+    }
+}
+
+/// The part of the status a numbered file list shows: some sections
+/// (`gs --staged`) and paths (`gs src/`), or all of it by default
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusView {
+    #[serde(default)]
+    pub groups: Vec<StatusGroup>,
+    /// Relative to the top of the worktree: a file, a directory, or a glob
+    /// whose `*` also matches slashes, as in git pathspecs
+    #[serde(default)]
+    pub pathspecs: Vec<String>,
+}
+
+impl StatusView {
+    pub fn is_all(&self) -> bool {
+        self.groups.is_empty() && self.pathspecs.is_empty()
+    }
+
+    pub fn includes(&self, file: &FileEntry) -> bool {
+        let in_groups = self.groups.is_empty() || self.groups.contains(&StatusGroup::of(file));
+        let on_paths = self.pathspecs.is_empty()
+            || self.pathspecs.iter().any(|pathspec| {
+                std::iter::once(&file.path)
+                    .chain(&file.old_path)
+                    .any(|path| pathspec_matches(pathspec, path))
+            });
+        in_groups && on_paths
+    }
+
+    /// The files in view, numbered from 1 again
+    pub fn apply(&self, files: Vec<FileEntry>) -> Vec<FileEntry> {
+        if self.is_all() {
+            return files;
+        }
+        files
+            .into_iter()
+            .filter(|file| self.includes(file))
+            .enumerate()
+            .map(|(i, file)| FileEntry {
+                index: i + 1,
+                ..file
+            })
+            .collect()
+    }
+}
+
+/// Whether `path` is `pathspec`, lies below it, or matches it as a glob
+pub(crate) fn pathspec_matches(pathspec: &str, path: &Path) -> bool {
+    let path = path.to_string_lossy();
+    if pathspec.contains(['*', '?']) {
+        return wildcard_match(pathspec, &path);
+    }
+    let pathspec = pathspec.trim_end_matches('/');
+    pathspec.is_empty()
+        || path == pathspec
+        || path
+            .strip_prefix(pathspec)
+            .is_some_and(|rest| rest.starts_with('/'))
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -34,12 +160,157 @@ pub struct BranchEntry {
     pub is_current: bool,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DirEntry {
+    pub index: usize,
+    /// Directory relative to the repository root (`.` for the root itself)
+    pub path: PathBuf,
+    /// Number of changed files directly inside it
+    pub files: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorktreeEntry {
+    pub index: usize,
+    pub path: PathBuf,
+    /// Branch checked out there, `None` when detached
+    pub branch: Option<String>,
+    /// The repository's main worktree, which cannot be removed
+    pub is_main: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RepoEntry {
+    pub index: usize,
+    /// Working directory of the repository
+    pub path: PathBuf,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommitEntry {
+    pub index: usize,
+    /// Full object id, so the entry stays unambiguous
+    pub oid: String,
+    pub short_oid: String,
+    pub summary: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Contributor {
+    pub index: usize,
+    pub name: String,
+    pub email: String,
+    pub commits: usize,
+    /// Unix time of their most recent commit
+    pub last_commit_time: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TagEntry {
+    pub index: usize,
+    pub name: String,
+    /// Full id of the commit the tag points at
+    pub oid: String,
+    pub short_oid: String,
+    /// Annotated tags carry their own message and tagger
+    pub annotated: bool,
+    /// Unix time of the tagged commit
+    pub time: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PackageEntry {
+    pub index: usize,
+    pub name: String,
+    /// Directory relative to the repository root (`.` for the root itself)
+    pub path: PathBuf,
+    /// Number of changed files inside it
+    pub files: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileStat {
+    pub path: PathBuf,
+    pub added: usize,
+    pub removed: usize,
+}
+
+impl FileStat {
+    /// Changed lines counted by reviewers: additions plus deletions
+    pub fn lines(&self) -> usize {
+        self.added + self.removed
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StateCache {
     pub files: Vec<FileEntry>,
     pub branches: Vec<BranchEntry>,
+    #[serde(default)]
+    pub dirs: Vec<DirEntry>,
+    #[serde(default)]
+    pub line_stats: Vec<FileStat>,
+    #[serde(default)]
+    pub commits: Vec<CommitEntry>,
+    #[serde(default)]
+    pub contributors: Vec<Contributor>,
+    #[serde(default)]
+    pub tags: Vec<TagEntry>,
+    #[serde(default)]
+    pub packages: Vec<PackageEntry>,
+    #[serde(default)]
+    pub repos: Vec<RepoEntry>,
+    #[serde(default)]
+    pub worktrees: Vec<WorktreeEntry>,
     pub last_updated: SystemTime,
     pub repo_path: PathBuf,
+    /// Repository state the file list was computed from, for `status.fast`
+    #[serde(default)]
+    pub fingerprint: Option<String>,
+    /// HEAD, index and status the file list was computed from
+    #[serde(default)]
+    pub snapshot: Option<CacheSnapshot>,
+    /// Sections and paths the file list was limited to, kept in `view.json`
+    #[serde(default)]
+    pub view: StatusView,
+}
+
+/// What the repository looked like when a file list was saved
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheSnapshot {
+    /// HEAD commit id, empty on an unborn branch
+    pub head: String,
+    /// Modification time of the index file
+    pub index_mtime: Option<SystemTime>,
+    /// [`CacheSnapshot::status_hash`] of the saved list
+    pub status_hash: String,
+}
+
+impl CacheSnapshot {
+    /// Hash of a numbered list: the same files with the same statuses in the
+    /// same order hash the same
+    pub fn status_hash(files: &[FileEntry]) -> String {
+        let mut context = md5::Context::new();
+        for file in files {
+            context.consume(file.index.to_le_bytes());
+            context.consume(file.path.to_string_lossy().as_bytes());
+            context.consume([0]);
+            context.consume(file.status.description().as_bytes());
+            context.consume([u8::from(file.staged)]);
+        }
+        format!("{:x}", context.finalize())
+    }
+
+    /// What differs in `current`, for telling the user why a list went stale
+    pub fn describe_change(&self, current: &CacheSnapshot) -> &'static str {
+        if self.head != current.head {
+            "HEAD moved"
+        } else if self.index_mtime != current.index_mtime {
+            "the index changed"
+        } else {
+            "the worktree changed"
+        }
+    }
 }
 
 impl StateCache {
@@ -47,8 +318,112 @@ impl StateCache {
         Self {
             files: Vec::new(),
             branches: Vec::new(),
+            dirs: Vec::new(),
+            line_stats: Vec::new(),
+            commits: Vec::new(),
+            contributors: Vec::new(),
+            tags: Vec::new(),
+            packages: Vec::new(),
+            repos: Vec::new(),
+            worktrees: Vec::new(),
             last_updated: SystemTime::now(),
             repo_path,
+            fingerprint: None,
+            snapshot: None,
+            view: StatusView::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(index: usize, path: &str, staged: bool) -> FileEntry {
+        FileEntry {
+            index,
+            status: GitStatus::Modified,
+            path: PathBuf::from(path),
+            staged,
+            old_path: None,
         }
     }
+
+    #[test]
+    fn test_status_hash_tracks_numbering_and_staging() {
+        let files = vec![entry(1, "a.txt", false), entry(2, "b.txt", false)];
+        let hash = CacheSnapshot::status_hash(&files);
+        assert_eq!(hash, CacheSnapshot::status_hash(&files.clone()));
+
+        let reordered = vec![entry(1, "b.txt", false), entry(2, "a.txt", false)];
+        assert_ne!(hash, CacheSnapshot::status_hash(&reordered));
+
+        let staged = vec![entry(1, "a.txt", true), entry(2, "b.txt", false)];
+        assert_ne!(hash, CacheSnapshot::status_hash(&staged));
+    }
+
+    #[test]
+    fn test_describe_change() {
+        let before = CacheSnapshot {
+            head: "abc".to_string(),
+            index_mtime: Some(SystemTime::UNIX_EPOCH),
+            status_hash: String::new(),
+        };
+        let moved = CacheSnapshot {
+            head: "def".to_string(),
+            ..before.clone()
+        };
+        assert_eq!(before.describe_change(&moved), "HEAD moved");
+        assert_eq!(before.describe_change(&before), "the worktree changed");
+    }
+
+    #[test]
+    fn test_status_view_renumbers_files_in_view() {
+        let untracked = FileEntry {
+            status: GitStatus::Untracked,
+            ..entry(3, "new.txt", false)
+        };
+        let files = vec![entry(1, "a.txt", true), entry(2, "b.txt", false), untracked];
+        assert_eq!(StatusView::default().apply(files.clone()), files);
+
+        let view = StatusView {
+            groups: vec![StatusGroup::Unstaged, StatusGroup::Untracked],
+            ..Default::default()
+        };
+        let numbered: Vec<(usize, PathBuf)> = view
+            .apply(files)
+            .into_iter()
+            .map(|file| (file.index, file.path))
+            .collect();
+        assert_eq!(
+            numbered,
+            vec![(1, PathBuf::from("b.txt")), (2, PathBuf::from("new.txt"))]
+        );
+    }
+
+    #[test]
+    fn test_pathspec_matches() {
+        let path = Path::new("src/core/state.rs");
+        assert!(pathspec_matches("src", path));
+        assert!(pathspec_matches("src/core/", path));
+        assert!(pathspec_matches("src/core/state.rs", path));
+        assert!(pathspec_matches("", path));
+        assert!(!pathspec_matches("sr", path));
+        assert!(!pathspec_matches("src/core/state", path));
+        // Globs match across directories, as git pathspecs do
+        assert!(pathspec_matches("*.rs", path));
+        assert!(pathspec_matches("src/*.rs", path));
+        assert!(!pathspec_matches("*.md", path));
+
+        let renamed = FileEntry {
+            status: GitStatus::Renamed,
+            old_path: Some(PathBuf::from("lib/old.rs")),
+            ..entry(1, "src/new.rs", true)
+        };
+        let view = StatusView {
+            pathspecs: vec!["lib".to_string()],
+            ..Default::default()
+        };
+        assert!(view.includes(&renamed));
+    }
 }
@@ -0,0 +1,445 @@
+//! Text styles parsed from git-style color specifications.
+//!
+//! A spec is a space-separated list of words, as in `git config color.*`:
+//! the first color is the foreground, the second the background, and any
+//! attributes apply on top.
+//!
+//! ```text
+//! bold yellow
+//! red ul
+//! 208 #1e1e1e
+//! brightcyan reverse
+//! ```
+//!
+//! # Colors
+//! - Names: `black`, `red`, `green`, `yellow`, `blue`, `magenta`, `cyan`, `white`
+//! - Bright names: `brightred`, ... (git) or `bright_red` / `bright-red`
+//! - `normal` / `default`: keep the terminal's color (placeholder for position)
+//! - `0`-`255`: 256-color palette
+//! - `#rrggbb`: truecolor
+//!
+//! # Attributes
+//! `bold`, `dim`, `italic`, `ul` / `underline`, `blink`, `reverse`, `strike`;
+//! `no`-prefixed forms (`nobold`) switch an attribute off.
+//!
+//! # Color depth
+//! Styles are rendered at the depth the terminal advertises (see
+//! [`ColorDepth::detect`]): 24-bit colors are kept on truecolor terminals,
+//! mapped to the nearest 256-color entry on 256-color terminals, and to the
+//! nearest of the 16 basic colors otherwise.
+
+use crate::core::error::{GitNavigatorError, Result};
+use colored::{Color, ColoredString, Colorize};
+use std::env;
+use std::sync::OnceLock;
+
+/// How many colors the terminal can display
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColorDepth {
+    /// The 16 basic ANSI colors
+    Basic,
+    /// The xterm 256-color palette
+    Ansi256,
+    /// 24-bit RGB
+    TrueColor,
+}
+
+impl ColorDepth {
+    /// Detect the depth from `COLORTERM` and `TERM`
+    pub fn detect() -> Self {
+        Self::from_env(
+            env::var("COLORTERM").ok().as_deref(),
+            env::var("TERM").ok().as_deref(),
+        )
+    }
+
+    /// Depth advertised by the given `COLORTERM` and `TERM` values
+    pub fn from_env(colorterm: Option<&str>, term: Option<&str>) -> Self {
+        match colorterm {
+            Some("truecolor" | "24bit") => Self::TrueColor,
+            _ if term.is_some_and(|term| term.contains("256color")) => Self::Ansi256,
+            _ => Self::Basic,
+        }
+    }
+
+    /// Depth of the current terminal, detected once per process
+    pub fn current() -> Self {
+        static DEPTH: OnceLock<ColorDepth> = OnceLock::new();
+        *DEPTH.get_or_init(Self::detect)
+    }
+
+    /// Map `color` to the closest color this depth can display
+    pub fn fit(self, color: Color) -> Color {
+        match (self, color) {
+            (Self::TrueColor, _) => color,
+            (Self::Ansi256, Color::TrueColor { r, g, b }) => {
+                Color::AnsiColor(rgb_to_ansi256(r, g, b))
+            }
+            (Self::Ansi256, _) => color,
+            (Self::Basic, Color::TrueColor { r, g, b }) => nearest_basic(r, g, b),
+            (Self::Basic, Color::AnsiColor(code)) => {
+                let (r, g, b) = ansi256_to_rgb(code);
+                nearest_basic(r, g, b)
+            }
+            (Self::Basic, _) => color,
+        }
+    }
+}
+
+/// A parsed color specification
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Style {
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub bold: bool,
+    pub dim: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub blink: bool,
+    pub reverse: bool,
+    pub strikethrough: bool,
+}
+
+impl Style {
+    /// Style with only a foreground color
+    pub const fn fg(color: Color) -> Self {
+        Self {
+            fg: Some(color),
+            bg: None,
+            bold: false,
+            dim: false,
+            italic: false,
+            underline: false,
+            blink: false,
+            reverse: false,
+            strikethrough: false,
+        }
+    }
+
+    /// The same style in bold
+    pub const fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// Parse a git-style color spec such as `"bold yellow"`
+    pub fn parse(spec: &str) -> Result<Self> {
+        let mut style = Self::default();
+        let mut colors_seen = 0;
+
+        for word in spec.split_whitespace() {
+            let lower = word.to_ascii_lowercase();
+
+            if let Some(color) = parse_color(&lower) {
+                match colors_seen {
+                    0 => style.fg = color,
+                    1 => style.bg = color,
+                    _ => {
+                        return Err(GitNavigatorError::config_error(format!(
+                            "too many colors in '{spec}'"
+                        )))
+                    }
+                }
+                colors_seen += 1;
+                continue;
+            }
+
+            let (enabled, attribute) = match lower.strip_prefix("no") {
+                Some(rest) => (false, rest.trim_start_matches('-')),
+                None => (true, lower.as_str()),
+            };
+            match attribute {
+                "bold" => style.bold = enabled,
+                "dim" => style.dim = enabled,
+                "italic" => style.italic = enabled,
+                "ul" | "underline" => style.underline = enabled,
+                "blink" => style.blink = enabled,
+                "reverse" => style.reverse = enabled,
+                "strike" => style.strikethrough = enabled,
+                _ => {
+                    return Err(GitNavigatorError::config_error(format!(
+                        "unknown color or attribute '{word}' in '{spec}'"
+                    )))
+                }
+            }
+        }
+
+        Ok(style)
+    }
+
+    /// Apply the style to `text` at the terminal's color depth
+    pub fn apply(&self, text: &str) -> ColoredString {
+        self.apply_with_depth(text, ColorDepth::current())
+    }
+
+    /// Apply the style to `text`, fitting colors to `depth`
+    pub fn apply_with_depth(&self, text: &str, depth: ColorDepth) -> ColoredString {
+        let mut colored = ColoredString::from(text);
+        if let Some(fg) = self.fg {
+            colored = colored.color(depth.fit(fg));
+        }
+        if let Some(bg) = self.bg {
+            colored = colored.on_color(depth.fit(bg));
+        }
+        if self.bold {
+            colored = colored.bold();
+        }
+        if self.dim {
+            colored = colored.dimmed();
+        }
+        if self.italic {
+            colored = colored.italic();
+        }
+        if self.underline {
+            colored = colored.underline();
+        }
+        if self.blink {
+            colored = colored.blink();
+        }
+        if self.reverse {
+            colored = colored.reversed();
+        }
+        if self.strikethrough {
+            colored = colored.strikethrough();
+        }
+        colored
+    }
+}
+
+/// Standard xterm values of the 16 basic colors
+const BASIC_PALETTE: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::White, (229, 229, 229)),
+    (Color::BrightBlack, (127, 127, 127)),
+    (Color::BrightRed, (255, 0, 0)),
+    (Color::BrightGreen, (0, 255, 0)),
+    (Color::BrightYellow, (255, 255, 0)),
+    (Color::BrightBlue, (92, 92, 255)),
+    (Color::BrightMagenta, (255, 0, 255)),
+    (Color::BrightCyan, (0, 255, 255)),
+    (Color::BrightWhite, (255, 255, 255)),
+];
+
+/// Channel levels of the 6x6x6 color cube (entries 16-231)
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let d = |a: u8, b: u8| (i32::from(a) - i32::from(b)).unsigned_abs();
+    d(r1, r2).pow(2) + d(g1, g2).pow(2) + d(b1, b2).pow(2)
+}
+
+fn nearest_basic(r: u8, g: u8, b: u8) -> Color {
+    BASIC_PALETTE
+        .iter()
+        .min_by_key(|(_, rgb)| distance(*rgb, (r, g, b)))
+        .map(|(color, _)| *color)
+        .unwrap_or(Color::White)
+}
+
+/// Nearest entry of the 256-color palette, from the cube or the gray ramp
+fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    let level = |v: u8| {
+        (0..CUBE_LEVELS.len())
+            .min_by_key(|&i| (i32::from(CUBE_LEVELS[i]) - i32::from(v)).unsigned_abs())
+            .unwrap_or(0) as u8
+    };
+    let (ri, gi, bi) = (level(r), level(g), level(b));
+    let cube = 16 + 36 * ri + 6 * gi + bi;
+
+    let average = (u16::from(r) + u16::from(g) + u16::from(b)) / 3;
+    let gray_step = (average.saturating_sub(3) / 10).min(23) as u8;
+    let gray = 232 + gray_step;
+
+    if distance(ansi256_to_rgb(gray), (r, g, b)) < distance(ansi256_to_rgb(cube), (r, g, b)) {
+        gray
+    } else {
+        cube
+    }
+}
+
+fn ansi256_to_rgb(code: u8) -> (u8, u8, u8) {
+    match code {
+        0..=15 => BASIC_PALETTE[usize::from(code)].1,
+        16..=231 => {
+            let index = code - 16;
+            (
+                CUBE_LEVELS[usize::from(index / 36)],
+                CUBE_LEVELS[usize::from(index / 6 % 6)],
+                CUBE_LEVELS[usize::from(index % 6)],
+            )
+        }
+        _ => {
+            let level = 8 + 10 * (code - 232);
+            (level, level, level)
+        }
+    }
+}
+
+/// Parse a single color word
+///
+/// Returns `Some(None)` for `normal`/`default`, which take a color slot
+/// without setting a color.
+fn parse_color(word: &str) -> Option<Option<Color>> {
+    if matches!(word, "normal" | "default") {
+        return Some(None);
+    }
+
+    if let Some(hex) = word.strip_prefix('#') {
+        if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        return Some(Some(Color::TrueColor {
+            r: channel(0)?,
+            g: channel(2)?,
+            b: channel(4)?,
+        }));
+    }
+
+    if let Ok(code) = word.parse::<u8>() {
+        return Some(Some(Color::AnsiColor(code)));
+    }
+
+    let (bright, name) = match word
+        .strip_prefix("bright")
+        .map(|rest| rest.trim_start_matches(['_', '-']))
+    {
+        Some(rest) => (true, rest),
+        None => (false, word),
+    };
+
+    let color = match (bright, name) {
+        (false, "black") => Color::Black,
+        (false, "red") => Color::Red,
+        (false, "green") => Color::Green,
+        (false, "yellow") => Color::Yellow,
+        (false, "blue") => Color::Blue,
+        (false, "magenta") => Color::Magenta,
+        (false, "cyan") => Color::Cyan,
+        (false, "white") => Color::White,
+        (true, "black") => Color::BrightBlack,
+        (true, "red") => Color::BrightRed,
+        (true, "green") => Color::BrightGreen,
+        (true, "yellow") => Color::BrightYellow,
+        (true, "blue") => Color::BrightBlue,
+        (true, "magenta") => Color::BrightMagenta,
+        (true, "cyan") => Color::BrightCyan,
+        (true, "white") => Color::BrightWhite,
+        _ => return None,
+    };
+    Some(Some(color))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_named_color_and_attribute() {
+        let style = Style::parse("bold yellow").unwrap();
+        assert_eq!(style, Style::fg(Color::Yellow).bold());
+    }
+
+    #[test]
+    fn test_parse_background_and_bright() {
+        let style = Style::parse("brightred blue ul").unwrap();
+        assert_eq!(style.fg, Some(Color::BrightRed));
+        assert_eq!(style.bg, Some(Color::Blue));
+        assert!(style.underline);
+
+        assert_eq!(
+            Style::parse("bright_cyan").unwrap().fg,
+            Some(Color::BrightCyan)
+        );
+    }
+
+    #[test]
+    fn test_parse_256_and_truecolor() {
+        let style = Style::parse("208 #1E1e2f").unwrap();
+        assert_eq!(style.fg, Some(Color::AnsiColor(208)));
+        assert_eq!(
+            style.bg,
+            Some(Color::TrueColor {
+                r: 0x1e,
+                g: 0x1e,
+                b: 0x2f
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_normal_placeholder_and_negation() {
+        let style = Style::parse("normal red nobold").unwrap();
+        assert_eq!(style.fg, None);
+        assert_eq!(style.bg, Some(Color::Red));
+        assert!(!style.bold);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Style::parse("sparkly").is_err());
+        assert!(Style::parse("red green blue").is_err());
+        assert!(Style::parse("#12345").is_err());
+    }
+
+    #[test]
+    fn test_color_depth_detection() {
+        assert_eq!(
+            ColorDepth::from_env(Some("truecolor"), Some("xterm")),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(
+            ColorDepth::from_env(None, Some("xterm-256color")),
+            ColorDepth::Ansi256
+        );
+        assert_eq!(ColorDepth::from_env(None, Some("xterm")), ColorDepth::Basic);
+        assert_eq!(ColorDepth::from_env(None, None), ColorDepth::Basic);
+    }
+
+    #[test]
+    fn test_fit_truecolor() {
+        let orange = Color::TrueColor {
+            r: 255,
+            g: 135,
+            b: 0,
+        };
+        assert_eq!(ColorDepth::TrueColor.fit(orange), orange);
+        assert_eq!(ColorDepth::Ansi256.fit(orange), Color::AnsiColor(208));
+        assert_eq!(ColorDepth::Basic.fit(orange), Color::Yellow);
+
+        let gray = Color::TrueColor {
+            r: 128,
+            g: 128,
+            b: 128,
+        };
+        assert_eq!(ColorDepth::Ansi256.fit(gray), Color::AnsiColor(244));
+    }
+
+    #[test]
+    fn test_fit_256_to_basic() {
+        assert_eq!(
+            ColorDepth::Basic.fit(Color::AnsiColor(196)),
+            Color::BrightRed
+        );
+        assert_eq!(ColorDepth::Basic.fit(Color::AnsiColor(4)), Color::Blue);
+        assert_eq!(
+            ColorDepth::Ansi256.fit(Color::AnsiColor(196)),
+            Color::AnsiColor(196)
+        );
+        assert_eq!(ColorDepth::Basic.fit(Color::Yellow), Color::Yellow);
+    }
+
+    #[test]
+    fn test_apply_matches_colorize() {
+        assert_eq!(
+            Style::fg(Color::Red).bold().apply("x").to_string(),
+            "x".red().bold().to_string()
+        );
+    }
+}
@@ -8,9 +8,15 @@
 //! - [`Templates`]: Template definitions for all output sections
 //! - [`TemplateContext`]: Context data for template rendering
 //! - [`TEMPLATES`]: Global template instance with default formatting
+//! - [`templates`]: Templates in effect, with the `[templates]` overrides
 //! - [`render_template`]: Main rendering function with colors
+//! - [`render_section_header`]: A gs section header from its kind and count
 //! - [`render_template_plain`]: Plain text rendering for testing
 //! - [`strip_ansi_codes`]: Utility for removing color codes
+//! - [`wrap_text`]: Word wrapping to [`terminal_width`] for long messages
+//! - [`Glyphs`]: Decorative glyphs, unicode or ASCII (`ui.unicode = false`)
+//! - [`colored_index`]: An index as listings draw it, `[3]` unless
+//!   `templates.index` and `templates.index_width` say otherwise
 //!
 //! # Template Categories
 //! - **Headers**: Branch names, commit information
@@ -22,26 +28,108 @@
 //! - **Capacity estimation**: Pre-allocate buffers based on content size
 //! - **Color optimization**: Direct color application without string manipulation
 
-use crate::core::{colors::get_colored_path, git_status::GitStatus};
+use crate::core::{
+    colors::get_colored_path,
+    config::Settings,
+    error::{GitNavigatorError, Result},
+    git_status::GitStatus,
+    hyperlink::{link, link_issues},
+    output::is_plain_mode,
+};
 use colored::*;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Decorative glyphs drawn around the output
+#[derive(Debug, PartialEq, Eq)]
+pub struct Glyphs {
+    pub section: &'static str,
+    pub success: &'static str,
+    pub error: &'static str,
+    pub warning: &'static str,
+    pub rule: &'static str,
+    pub minus: &'static str,
+    pub ellipsis: &'static str,
+}
+
+/// Default glyph set
+pub static UNICODE_GLYPHS: Glyphs = Glyphs {
+    section: "➤",
+    success: "✓",
+    error: "✕",
+    warning: "⚠",
+    rule: "═══",
+    minus: "−",
+    ellipsis: "…",
+};
+
+/// Glyph set for terminals and fonts without unicode support
+pub static ASCII_GLYPHS: Glyphs = Glyphs {
+    section: ">",
+    success: "OK",
+    error: "x",
+    warning: "!",
+    rule: "===",
+    minus: "-",
+    ellipsis: "...",
+};
+
+static GLYPHS: OnceLock<&'static Glyphs> = OnceLock::new();
+
+impl Glyphs {
+    /// Glyph set selected by `ui.unicode` (unicode unless set to `false`)
+    pub fn from_settings(settings: &Settings) -> &'static Glyphs {
+        match settings.get_bool("ui.unicode") {
+            Some(false) => &ASCII_GLYPHS,
+            _ => &UNICODE_GLYPHS,
+        }
+    }
+}
+
+/// Select the glyph set for the rest of the process
+///
+/// Only the first call takes effect.
+pub fn configure_glyphs(settings: &Settings) {
+    let _ = GLYPHS.set(Glyphs::from_settings(settings));
+}
+
+/// Glyph set in effect
+pub fn glyphs() -> &'static Glyphs {
+    GLYPHS.get().copied().unwrap_or(&UNICODE_GLYPHS)
+}
 
 /// Template definitions for all output formatting
 pub struct Templates {
     // Header templates
     pub header_empty_line: &'static str,
     pub header_branch: &'static str,
+    pub header_detached_hint: &'static str,
+    pub header_upstream_gone_hint: &'static str,
+    pub header_last_fetch: &'static str,
+    pub footer_cached: &'static str,
     pub header_parent_no_commits: &'static str,
     pub header_parent_with_commits: &'static str,
+    pub header_parent_body: &'static str,
 
-    // Section templates
+    // Section labels; the glyph, colon and color come from the section kind
     pub section_unmerged: &'static str,
     pub section_staged: &'static str,
     pub section_unstaged: &'static str,
     pub section_untracked: &'static str,
+    pub section_submodules: &'static str,
 
     // File line template
     pub file_line: &'static str,
     pub section_spacing: &'static str,
+
+    // Commit line of `notes`, with the note's lines under it
+    pub note_line: &'static str,
+    pub note_body: &'static str,
+
+    // How every listing draws an index: "[{n}]", "({n})", "{n}." ...
+    pub index: &'static str,
+    /// Digits indices are zero-padded to (0 for none)
+    pub index_width: usize,
 }
 
 impl Default for Templates {
@@ -49,14 +137,24 @@ impl Default for Templates {
         Self {
             header_empty_line: "",
             header_branch: "Branch: {branch_name}{ahead_behind}",
+            header_detached_hint: "        gco -b <name> to keep work",
+            header_upstream_gone_hint: "        gb --prune to delete gone branches",
+            header_last_fetch: "Last fetch: {fetch_age} ago",
+            footer_cached: "(cached, gs --refresh to rescan)",
             header_parent_no_commits: "Parent: {commit_message}",
             header_parent_with_commits: "Parent: {short_hash} {commit_message}",
-            section_unmerged: "➤ Unmerged:",
-            section_staged: "➤ Staged:",
-            section_unstaged: "➤ Not staged:",
-            section_untracked: "➤ Untracked:",
+            header_parent_body: "        {commit_message}",
+            section_unmerged: "Unmerged ({count})",
+            section_staged: "Staged ({count})",
+            section_unstaged: "Not staged ({count})",
+            section_untracked: "Untracked ({count})",
+            section_submodules: "Submodules ({count})",
             file_line: "   ({file_status}) [{n}] {filename}",
             section_spacing: "",
+            note_line: "{n} {short_hash} {commit_message}",
+            note_body: "    {commit_message}",
+            index: "[{n}]",
+            index_width: 0,
         }
     }
 }
@@ -65,32 +163,198 @@ impl Default for Templates {
 pub static TEMPLATES: Templates = Templates {
     header_empty_line: "",
     header_branch: "Branch: {branch_name}{ahead_behind}",
+    header_detached_hint: "        gco -b <name> to keep work",
+    header_upstream_gone_hint: "        gb --prune to delete gone branches",
+    header_last_fetch: "Last fetch: {fetch_age} ago",
+    footer_cached: "(cached, gs --refresh to rescan)",
     header_parent_no_commits: "Parent: {commit_message}",
     header_parent_with_commits: "Parent: {short_hash} {commit_message}",
-    section_unmerged: "➤ Unmerged:",
-    section_staged: "➤ Staged:",
-    section_unstaged: "➤ Not staged:",
-    section_untracked: "➤ Untracked:",
+    header_parent_body: "        {commit_message}",
+    section_unmerged: "Unmerged ({count})",
+    section_staged: "Staged ({count})",
+    section_unstaged: "Not staged ({count})",
+    section_untracked: "Untracked ({count})",
+    section_submodules: "Submodules ({count})",
     file_line: "   ({file_status}) [{n}] {filename}",
     section_spacing: "",
+    note_line: "{n} {short_hash} {commit_message}",
+    note_body: "    {commit_message}",
+    index: "[{n}]",
+    index_width: 0,
 };
 
+static CONFIGURED_TEMPLATES: OnceLock<Templates> = OnceLock::new();
+
+impl Templates {
+    /// The built-in templates with the `templates.*` overrides applied
+    ///
+    /// Section labels, the file line layout and the index format can be
+    /// changed; the header templates drive its coloring and stay built in.
+    pub fn from_settings(settings: &Settings) -> Result<Self> {
+        let mut templates = Templates::default();
+        let overrides: [(&str, &mut &'static str); 8] = [
+            ("section_unmerged", &mut templates.section_unmerged),
+            ("section_staged", &mut templates.section_staged),
+            ("section_unstaged", &mut templates.section_unstaged),
+            ("section_untracked", &mut templates.section_untracked),
+            ("section_submodules", &mut templates.section_submodules),
+            ("file_line", &mut templates.file_line),
+            ("note_line", &mut templates.note_line),
+            ("index", &mut templates.index),
+        ];
+        for (name, field) in overrides {
+            let key = format!("templates.{name}");
+            let Some(template) = settings.get_str(&key) else {
+                continue;
+            };
+            if name == "file_line" && !(template.contains("{n}") && template.contains("{filename}"))
+            {
+                return Err(GitNavigatorError::config_error(format!(
+                    "{key}: needs {{n}} and {{filename}} so files can be picked by number"
+                )));
+            }
+            if name == "note_line" && !template.contains("{n}") {
+                return Err(GitNavigatorError::config_error(format!(
+                    "{key}: needs {{n}} so commits can be picked by number"
+                )));
+            }
+            if name == "index" && template.matches("{n}").count() != 1 {
+                return Err(GitNavigatorError::config_error(format!(
+                    "{key}: needs {{n}} exactly once, e.g. \"({{n}})\" or \"{{n}}.\""
+                )));
+            }
+            // Read once per process, so the few bytes are never freed
+            *field = Box::leak(template.to_string().into_boxed_str());
+        }
+
+        if let Some(width) = settings.get_int("templates.index_width") {
+            templates.index_width = usize::try_from(width)
+                .ok()
+                .filter(|width| *width <= 9)
+                .ok_or_else(|| {
+                    GitNavigatorError::config_error(format!(
+                        "templates.index_width: {width} is not between 0 and 9"
+                    ))
+                })?;
+        }
+
+        Ok(templates)
+    }
+
+    /// Label template of `section`
+    pub fn section(&self, section: StatusSection) -> &'static str {
+        match section {
+            StatusSection::Unmerged => self.section_unmerged,
+            StatusSection::Staged => self.section_staged,
+            StatusSection::Unstaged => self.section_unstaged,
+            StatusSection::Untracked => self.section_untracked,
+            StatusSection::Submodules => self.section_submodules,
+        }
+    }
+
+    /// `n` zero-padded to `index_width`
+    pub fn index_number(&self, n: usize) -> String {
+        format!("{n:0width$}", width = self.index_width)
+    }
+
+    /// `number` drawn with the index template, dimming all but the number
+    fn colored_index_label(&self, number: &str) -> String {
+        let (before, after) = self.index.split_once("{n}").unwrap_or(("[", "]"));
+        format!(
+            "{}{}{}",
+            before.bright_black(),
+            number.white(),
+            after.bright_black()
+        )
+    }
+}
+
+/// The groups gs lists files in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusSection {
+    Unmerged,
+    Staged,
+    Unstaged,
+    Untracked,
+    Submodules,
+}
+
+impl StatusSection {
+    /// Color of the section's header
+    fn color(self) -> Color {
+        match self {
+            StatusSection::Unmerged => Color::Red,
+            StatusSection::Staged => Color::Green,
+            StatusSection::Unstaged => Color::Yellow,
+            StatusSection::Untracked => Color::Cyan,
+            StatusSection::Submodules => Color::Magenta,
+        }
+    }
+}
+
+/// Install the `[templates]` overrides for the rest of the process
+///
+/// Only the first call takes effect. An invalid template is reported and the
+/// built-in ones are kept.
+pub fn configure_templates(settings: &Settings) {
+    match Templates::from_settings(settings) {
+        Ok(templates) => {
+            let _ = CONFIGURED_TEMPLATES.set(templates);
+        }
+        Err(e) => log::warn!("Ignoring templates: {e}"),
+    }
+}
+
+/// Templates in effect: the built-in ones unless `[templates]` overrides them
+pub fn templates() -> &'static Templates {
+    CONFIGURED_TEMPLATES.get().unwrap_or(&TEMPLATES)
+}
+
+/// Index `n` as listings draw it: `[3]` by default, or `(03)`, `3.` ... as
+/// `templates.index` and `templates.index_width` set it
+pub fn colored_index(n: usize) -> String {
+    colored_index_label(&templates().index_number(n))
+}
+
+/// Any label in the index format, such as the current branch's `[3*]` or `[*]`
+pub fn colored_index_label(label: &str) -> String {
+    templates().colored_index_label(label)
+}
+
 /// Context for template rendering
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct TemplateContext<'a> {
     pub branch_name: Option<&'a str>,
     pub ahead_behind: Option<&'a str>,
+    pub fetch_age: Option<&'a str>, // e.g. "3h", for the last fetch line
     pub short_hash: Option<&'a str>,
     pub commit_message: Option<&'a str>,
     pub section_type: Option<&'a str>, // "staged", "unstaged", etc.
+    pub common_prefix: Option<&'a str>, // Directory shared by a section's files
+    pub count: Option<usize>,          // Number of files in a section
     pub file_status: Option<&'a str>,
     pub filename: Option<&'a str>,
+    pub file_path: Option<&'a Path>, // Full repository path, for hyperlinks
     pub n: Option<usize>,
     pub git_status: Option<GitStatus>, // GitStatus enum for coloring
+    pub width: Option<usize>,          // Columns the line may take; cuts commit_message
 }
 
 /// Render a template with context and apply colors
+///
+/// In plain mode the template is rendered by [`render_plain_mode`] instead.
 pub fn render_template(template: &str, context: &TemplateContext) -> String {
+    let truncated = truncated_message(template, context);
+    let fitted = truncated.as_deref().map(|message| TemplateContext {
+        commit_message: Some(message),
+        ..context.clone()
+    });
+    let context = fitted.as_ref().unwrap_or(context);
+
+    if is_plain_mode() {
+        return render_plain_mode(template, context);
+    }
+
     // Pre-allocate buffer with estimated capacity
     let estimated_capacity = template.len() +
         context.branch_name.map_or(0, |s| s.len()) +
@@ -111,6 +375,85 @@ pub fn render_template(template: &str, context: &TemplateContext) -> String {
     apply_colors_optimized(&result, template, context)
 }
 
+/// `{commit_message}` cut short when the line would not fit in `context.width`
+fn truncated_message(template: &str, context: &TemplateContext) -> Option<String> {
+    let (width, message) = (context.width?, context.commit_message?);
+    let mut rest = String::new();
+    let without_message = TemplateContext {
+        commit_message: Some(""),
+        ..context.clone()
+    };
+    render_template_single_pass(template, &without_message, &mut rest);
+
+    let available = width.saturating_sub(rest.chars().count());
+    (message.chars().count() > available).then(|| truncate_to_width(message, available))
+}
+
+/// Cut `text` to at most `width` characters, ending it with an ellipsis
+pub fn truncate_to_width(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        return text.to_string();
+    }
+    let ellipsis = glyphs().ellipsis;
+    let keep = width.saturating_sub(ellipsis.chars().count());
+    let mut cut: String = text.chars().take(keep).collect();
+    cut.truncate(cut.trim_end().len());
+    cut.push_str(ellipsis);
+    cut
+}
+
+/// Render a template for plain mode
+///
+/// Glyphs and alignment padding are dropped and labels are spelled out, so
+/// a file line reads `Index 3: src/main.rs (modified)`.
+fn render_plain_mode(template: &str, context: &TemplateContext) -> String {
+    if template.contains("({file_status}) [{n}] {filename}") || template == templates().file_line {
+        let mut line = String::new();
+        if let Some(n) = context.n {
+            line.push_str(&format!("Index {n}: "));
+        }
+        line.push_str(context.filename.unwrap_or_default());
+        if let Some(file_status) = context.file_status {
+            line.push_str(&format!(" ({file_status})"));
+        }
+        return line;
+    }
+
+    if template == templates().note_line {
+        return format!(
+            "Index {}: {} {}",
+            context.n.unwrap_or_default(),
+            context.short_hash.unwrap_or_default(),
+            context.commit_message.unwrap_or_default()
+        );
+    }
+
+    let mut text = String::with_capacity(template.len());
+    render_template_single_pass(template, context, &mut text);
+    text.trim_start().to_string()
+}
+
+/// Header of a gs section, such as `➤ Not staged (4) (in src/core/):`
+///
+/// The section's template gives the label; the glyph, colon and color come
+/// from `section` itself. Plain mode reads `Section: Not staged (4)`.
+pub fn render_section_header(
+    section: StatusSection,
+    count: usize,
+    common_prefix: Option<&str>,
+) -> String {
+    let label = section_label(section, count, common_prefix);
+    if is_plain_mode() {
+        return format!("Section: {label}");
+    }
+    let color = section.color();
+    format!(
+        "{} {}",
+        glyphs().section.color(color),
+        format!("{label}:").color(color)
+    )
+}
+
 /// Optimized single-pass template renderer
 fn render_template_single_pass(template: &str, context: &TemplateContext, output: &mut String) {
     let mut chars = template.chars().peekable();
@@ -143,6 +486,11 @@ fn render_template_single_pass(template: &str, context: &TemplateContext, output
                             output.push_str(value);
                         }
                     }
+                    "fetch_age" => {
+                        if let Some(value) = context.fetch_age {
+                            output.push_str(value);
+                        }
+                    }
                     "short_hash" => {
                         if let Some(value) = context.short_hash {
                             output.push_str(value);
@@ -169,6 +517,12 @@ fn render_template_single_pass(template: &str, context: &TemplateContext, output
                             let _ = write!(output, "{value}");
                         }
                     }
+                    "count" => {
+                        if let Some(value) = context.count {
+                            use std::fmt::Write;
+                            let _ = write!(output, "{value}");
+                        }
+                    }
                     _ => {
                         // Unknown placeholder, keep as-is
                         output.push('{');
@@ -194,11 +548,20 @@ fn apply_colors_optimized(text: &str, template: &str, context: &TemplateContext)
     // Pre-allocate with extra space for color codes
     let mut result = String::with_capacity(text.len() + 128);
 
+    // File lines are found by template, so custom ones color too
+    let active = templates();
+    if template == active.file_line && template != TEMPLATES.file_line {
+        return render_custom_file_line(template, context);
+    }
+    if template == active.note_line {
+        return render_commit_line(template, context);
+    }
+
     match template {
         // Header templates
         t if t.contains("Branch:") => {
             if let Some(branch_name) = context.branch_name {
-                let _ = write!(result, "Branch: {}", branch_name.blue());
+                let _ = write!(result, "Branch: {}", link_issues(branch_name).blue());
                 // Add ahead/behind info if present
                 if let Some(ahead_behind) = context.ahead_behind {
                     result.push_str(ahead_behind);
@@ -208,6 +571,19 @@ fn apply_colors_optimized(text: &str, template: &str, context: &TemplateContext)
             }
         }
 
+        t if t.contains("to keep work")
+            || t.contains("to delete gone branches")
+            || t.contains("--refresh to rescan")
+            || t.contains("Last fetch:") =>
+        {
+            let _ = write!(result, "{}", text.bright_black());
+        }
+
+        // A line of the parent's message body, under the Parent: line
+        t if t.trim_start() == "{commit_message}" => {
+            let _ = write!(result, "{}", link_issues(text).bright_black());
+        }
+
         t if t.contains("Parent:") && t.contains("{short_hash}") => {
             if let (Some(short_hash), Some(commit_message)) =
                 (context.short_hash, context.commit_message)
@@ -216,7 +592,7 @@ fn apply_colors_optimized(text: &str, template: &str, context: &TemplateContext)
                     result,
                     "Parent: {} {}",
                     short_hash.blue(),
-                    commit_message.bright_black()
+                    link_issues(commit_message).bright_black()
                 );
             } else {
                 result.push_str(text);
@@ -225,33 +601,20 @@ fn apply_colors_optimized(text: &str, template: &str, context: &TemplateContext)
 
         t if t.contains("Parent:") && !t.contains("{short_hash}") => {
             if let Some(commit_message) = context.commit_message {
-                let _ = write!(result, "Parent: {}", commit_message.white());
+                let _ = write!(result, "Parent: {}", link_issues(commit_message).white());
             } else {
                 result.push_str(text);
             }
         }
 
-        // Section templates - use write! to avoid format! allocation
-        t if t.contains("➤ Unmerged:") => {
-            let _ = write!(result, "{} {}", "➤".red(), "Unmerged:".red());
-        }
-        t if t.contains("➤ Staged:") => {
-            let _ = write!(result, "{} {}", "➤".green(), "Staged:".green());
-        }
-        t if t.contains("➤ Not staged:") => {
-            let _ = write!(result, "{} {}", "➤".yellow(), "Not staged:".yellow());
-        }
-        t if t.contains("➤ Untracked:") => {
-            let _ = write!(result, "{} {}", "➤".cyan(), "Untracked:".cyan());
-        }
-
         // File line template - optimized single-pass formatting
         t if t.contains("({file_status}) [{n}] {filename}") => {
             result.push_str("   "); // Leading spaces
 
             if let Some(file_status) = context.file_status {
-                // Format status with padding for alignment
-                let padding_needed = 13 - file_status.len();
+                // Format status with padding for alignment; the longer
+                // submodule descriptions just push the index along
+                let padding_needed = 13usize.saturating_sub(file_status.len());
                 let _ = write!(
                     result,
                     "{}{}{}",
@@ -267,20 +630,13 @@ fn apply_colors_optimized(text: &str, template: &str, context: &TemplateContext)
             result.push(' '); // Space before index
 
             if let Some(n) = context.n {
-                let _ = write!(
-                    result,
-                    "{}{}{}",
-                    "[".bright_black(),
-                    n.to_string().white(),
-                    "]".bright_black()
-                );
+                result.push_str(&colored_index(n));
             }
 
             result.push(' '); // Space before filename
 
-            if let (Some(filename), Some(git_status)) = (context.filename, context.git_status) {
-                let colored_filename = get_colored_path(git_status, filename);
-                let _ = write!(result, "{colored_filename}");
+            if let Some(filename) = colored_filename(context) {
+                result.push_str(&filename);
             }
         }
 
@@ -293,6 +649,152 @@ fn apply_colors_optimized(text: &str, template: &str, context: &TemplateContext)
     result
 }
 
+/// A `templates.file_line` layout, with the status, index and file name
+/// colored wherever it puts them
+fn render_custom_file_line(template: &str, context: &TemplateContext) -> String {
+    use std::fmt::Write;
+
+    let mut result = String::with_capacity(template.len() + 128);
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find('}').map(|end| start + end + 1) else {
+            break;
+        };
+        match &rest[start..end] {
+            "{file_status}" => {
+                if let Some(file_status) = context.file_status {
+                    let _ = write!(result, "{}", file_status.bright_black());
+                }
+            }
+            "{n}" => {
+                if let Some(n) = context.n {
+                    let _ = write!(result, "{}", templates().index_number(n).white());
+                }
+            }
+            "{filename}" => {
+                if let Some(filename) = colored_filename(context) {
+                    result.push_str(&filename);
+                }
+            }
+            placeholder => render_template_single_pass(placeholder, context, &mut result),
+        }
+        rest = &rest[end..];
+    }
+    // Whatever follows the last placeholder, including an unclosed brace
+    if let Some(start) = rest.find('{') {
+        result.push_str(&rest[start..]);
+    } else {
+        result.push_str(rest);
+    }
+    result
+}
+
+/// A commit line such as `notes`' `[2] a1b2c3d Fix parser`, with the index,
+/// hash and subject colored wherever the template puts them
+fn render_commit_line(template: &str, context: &TemplateContext) -> String {
+    use std::fmt::Write;
+
+    let mut result = String::with_capacity(template.len() + 128);
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find('}').map(|end| start + end + 1) else {
+            break;
+        };
+        match &rest[start..end] {
+            "{n}" => {
+                if let Some(n) = context.n {
+                    result.push_str(&colored_index(n));
+                }
+            }
+            "{short_hash}" => {
+                if let Some(short_hash) = context.short_hash {
+                    let _ = write!(result, "{}", short_hash.blue());
+                }
+            }
+            "{commit_message}" => {
+                if let Some(commit_message) = context.commit_message {
+                    result.push_str(&link_issues(commit_message));
+                }
+            }
+            placeholder => render_template_single_pass(placeholder, context, &mut result),
+        }
+        rest = &rest[end..];
+    }
+    if let Some(start) = rest.find('{') {
+        result.push_str(&rest[start..]);
+    } else {
+        result.push_str(rest);
+    }
+    result
+}
+
+/// The file name in its status color, linked when hyperlinks are on
+fn colored_filename(context: &TemplateContext) -> Option<String> {
+    let (filename, git_status) = (context.filename?, context.git_status?);
+    let colored_filename = get_colored_path(git_status, filename).to_string();
+    Some(match context.file_path {
+        Some(path) => link(&colored_filename, path),
+        None => colored_filename,
+    })
+}
+
+/// Section label from its template and count, e.g. `Not staged (4) (in src/core/)`
+fn section_label(section: StatusSection, count: usize, common_prefix: Option<&str>) -> String {
+    let context = TemplateContext {
+        count: Some(count),
+        common_prefix,
+        ..Default::default()
+    };
+    let mut label = String::new();
+    render_template_single_pass(templates().section(section), &context, &mut label);
+    label.push_str(&prefix_note(&context));
+    label
+}
+
+/// " (in src/core/)" after a section name when its files share a directory
+fn prefix_note(context: &TemplateContext) -> String {
+    context
+        .common_prefix
+        .map(|prefix| format!(" (in {prefix})"))
+        .unwrap_or_default()
+}
+
+/// Width available for output: `COLUMNS` when the shell exports it, else 80
+pub fn terminal_width() -> usize {
+    std::env::var("COLUMNS")
+        .ok()
+        .and_then(|columns| columns.trim().parse().ok())
+        .filter(|&columns| columns > 0)
+        .unwrap_or(80)
+}
+
+/// Word-wrap `text` into lines of at most `width` characters
+///
+/// Existing line breaks are kept and blank lines dropped; a word longer than
+/// `width` gets a line of its own rather than being split.
+pub fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let fits = line.chars().count() + 1 + word.chars().count() <= width;
+            if !line.is_empty() && !fits {
+                lines.push(std::mem::take(&mut line));
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(word);
+        }
+        if !line.is_empty() {
+            lines.push(line);
+        }
+    }
+    lines
+}
+
 /// Strip ANSI color codes for testing
 pub fn strip_ansi_codes(text: &str) -> String {
     // Simple state machine to remove ANSI escape sequences
@@ -300,7 +802,15 @@ pub fn strip_ansi_codes(text: &str) -> String {
     let mut chars = text.chars().peekable();
 
     while let Some(ch) = chars.next() {
-        if ch == '\x1b' && chars.peek() == Some(&'[') {
+        if ch == '\x1b' && chars.peek() == Some(&']') {
+            // Skip an OSC sequence such as a hyperlink, ended by ESC \ or BEL
+            chars.next(); // consume ']'
+            while let Some(ch) = chars.next() {
+                if ch == '\x07' || (ch == '\x1b' && chars.next_if_eq(&'\\').is_some()) {
+                    break;
+                }
+            }
+        } else if ch == '\x1b' && chars.peek() == Some(&'[') {
             // Skip the escape sequence
             chars.next(); // consume '['
             for ch in chars.by_ref() {
@@ -326,6 +836,129 @@ pub fn render_template_plain(template: &str, context: &TemplateContext) -> Strin
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_glyphs_from_settings() {
+        let ascii = Settings::parse("[ui]\nunicode = false").unwrap();
+        assert_eq!(Glyphs::from_settings(&ascii), &ASCII_GLYPHS);
+
+        let unicode = Settings::parse("[ui]\nunicode = true").unwrap();
+        assert_eq!(Glyphs::from_settings(&unicode), &UNICODE_GLYPHS);
+        assert_eq!(Glyphs::from_settings(&Settings::default()), &UNICODE_GLYPHS);
+    }
+
+    #[test]
+    fn test_templates_from_settings() -> Result<()> {
+        let settings = Settings::parse(
+            "[templates]\nsection_staged = \"Ready ({count})\"\nfile_line = \"{n}. {filename} - {file_status}\"\n",
+        )?;
+        let templates = Templates::from_settings(&settings)?;
+        assert_eq!(templates.section_staged, "Ready ({count})");
+        assert_eq!(templates.section_unstaged, TEMPLATES.section_unstaged);
+        assert_eq!(templates.file_line, "{n}. {filename} - {file_status}");
+        assert_eq!(templates.section(StatusSection::Staged), "Ready ({count})");
+
+        let defaults = Templates::from_settings(&Settings::default())?;
+        assert_eq!(defaults.file_line, TEMPLATES.file_line);
+        Ok(())
+    }
+
+    #[test]
+    fn test_templates_from_settings_rejects_unusable_templates() {
+        let no_index = Settings::parse("[templates]\nfile_line = \"{filename}\"\n").unwrap();
+        assert!(Templates::from_settings(&no_index).is_err());
+
+        let no_number = Settings::parse("[templates]\nindex = \"#\"\n").unwrap();
+        assert!(Templates::from_settings(&no_number).is_err());
+
+        let too_wide = Settings::parse("[templates]\nindex_width = 12\n").unwrap();
+        assert!(Templates::from_settings(&too_wide).is_err());
+
+        let unnumbered_note =
+            Settings::parse("[templates]\nnote_line = \"{short_hash}\"\n").unwrap();
+        assert!(Templates::from_settings(&unnumbered_note).is_err());
+    }
+
+    #[test]
+    fn test_index_format() -> Result<()> {
+        let settings = Settings::parse("[templates]\nindex = \"({n})\"\nindex_width = 2\n")?;
+        let templates = Templates::from_settings(&settings)?;
+        assert_eq!(templates.index_number(3), "03");
+        assert_eq!(templates.index_number(120), "120");
+        assert_eq!(
+            strip_ansi_codes(&templates.colored_index_label("03*")),
+            "(03*)"
+        );
+
+        assert_eq!(TEMPLATES.index_number(3), "3");
+        assert_eq!(strip_ansi_codes(&TEMPLATES.colored_index_label("3")), "[3]");
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_custom_file_line() {
+        let context = TemplateContext {
+            file_status: Some("modified"),
+            n: Some(4),
+            filename: Some("src/lib.rs"),
+            git_status: Some(GitStatus::Modified),
+            ..Default::default()
+        };
+        let line = render_custom_file_line("{n}. {filename} - {file_status} {x", &context);
+        assert_eq!(strip_ansi_codes(&line), "4. src/lib.rs - modified {x");
+    }
+
+    #[test]
+    fn test_ascii_glyphs_are_ascii() {
+        let Glyphs {
+            section,
+            success,
+            error,
+            warning,
+            rule,
+            minus,
+            ellipsis,
+        } = ASCII_GLYPHS;
+        for glyph in [section, success, error, warning, rule, minus, ellipsis] {
+            assert!(glyph.is_ascii(), "{glyph:?} is not ASCII");
+        }
+    }
+
+    #[test]
+    fn test_render_plain_mode_file_line() {
+        let context = TemplateContext {
+            file_status: Some("modified"),
+            n: Some(3),
+            filename: Some("src/main.rs"),
+            git_status: Some(GitStatus::Modified),
+            ..Default::default()
+        };
+        assert_eq!(
+            render_plain_mode(TEMPLATES.file_line, &context),
+            "Index 3: src/main.rs (modified)"
+        );
+    }
+
+    #[test]
+    fn test_section_label_with_count_and_common_prefix() {
+        assert_eq!(
+            section_label(StatusSection::Unstaged, 4, None),
+            "Not staged (4)"
+        );
+        assert_eq!(
+            section_label(StatusSection::Untracked, 2, Some("src/core/")),
+            "Untracked (2) (in src/core/)"
+        );
+    }
+
+    #[test]
+    fn test_render_plain_mode_drops_padding() {
+        let context = TemplateContext::default();
+        assert_eq!(
+            render_plain_mode(TEMPLATES.header_detached_hint, &context),
+            "gco -b <name> to keep work"
+        );
+    }
+
     #[test]
     fn test_render_branch_template() {
         let branch_name = "main";
@@ -337,6 +970,53 @@ mod tests {
         assert_eq!(result, "Branch: main");
     }
 
+    #[test]
+    fn test_render_detached_hint() {
+        let result =
+            render_template_plain(TEMPLATES.header_detached_hint, &TemplateContext::default());
+        assert_eq!(result, "        gco -b <name> to keep work");
+    }
+
+    #[test]
+    fn test_render_upstream_gone_hint() {
+        let result = render_template_plain(
+            TEMPLATES.header_upstream_gone_hint,
+            &TemplateContext::default(),
+        );
+        assert_eq!(result, "        gb --prune to delete gone branches");
+    }
+
+    #[test]
+    fn test_render_last_fetch() {
+        let context = TemplateContext {
+            fetch_age: Some("3h"),
+            ..Default::default()
+        };
+        let result = render_template_plain(TEMPLATES.header_last_fetch, &context);
+        assert_eq!(result, "Last fetch: 3h ago");
+    }
+
+    #[test]
+    fn test_render_cached_footer() {
+        let result = render_template_plain(TEMPLATES.footer_cached, &TemplateContext::default());
+        assert_eq!(result, "(cached, gs --refresh to rescan)");
+    }
+
+    #[test]
+    fn test_render_commit_line() {
+        let context = TemplateContext {
+            n: Some(2),
+            short_hash: Some("a1b2c3d"),
+            commit_message: Some("Fix parser"),
+            ..Default::default()
+        };
+        let line = render_commit_line(TEMPLATES.note_line, &context);
+        assert_eq!(strip_ansi_codes(&line), "[2] a1b2c3d Fix parser");
+
+        let line = render_commit_line("{short_hash} ({n}) {commit_message} {", &context);
+        assert_eq!(strip_ansi_codes(&line), "a1b2c3d ([2]) Fix parser {");
+    }
+
     #[test]
     fn test_render_parent_with_commits() {
         let short_hash = "a1b2c3d";
@@ -350,6 +1030,56 @@ mod tests {
         assert_eq!(result, "Parent: a1b2c3d Initial commit");
     }
 
+    #[test]
+    fn test_render_parent_body() {
+        let context = TemplateContext {
+            commit_message: Some("Explains why"),
+            ..Default::default()
+        };
+        let result = render_template_plain(TEMPLATES.header_parent_body, &context);
+        assert_eq!(result, "        Explains why");
+    }
+
+    #[test]
+    fn test_render_parent_fits_width() {
+        let context = TemplateContext {
+            short_hash: Some("abc1234"),
+            commit_message: Some("Rework the cache layer for speed"),
+            width: Some(30),
+            ..Default::default()
+        };
+        let result = render_template_plain(TEMPLATES.header_parent_with_commits, &context);
+        assert_eq!(result, "Parent: abc1234 Rework the ca…");
+        assert_eq!(result.chars().count(), 30);
+
+        let roomy = TemplateContext {
+            width: Some(80),
+            ..context
+        };
+        let result = render_template_plain(TEMPLATES.header_parent_with_commits, &roomy);
+        assert_eq!(result, "Parent: abc1234 Rework the cache layer for speed");
+    }
+
+    #[test]
+    fn test_truncate_to_width() {
+        assert_eq!(truncate_to_width("short", 10), "short");
+        assert_eq!(truncate_to_width("a longer subject", 10), "a longer…");
+        assert_eq!(truncate_to_width("subject", 0), "…");
+    }
+
+    #[test]
+    fn test_wrap_text() {
+        assert_eq!(
+            wrap_text("one two three four\n\n- five six", 9),
+            vec!["one two", "three", "four", "- five", "six"]
+        );
+        assert_eq!(
+            wrap_text("a extraordinarily long", 5),
+            vec!["a", "extraordinarily", "long"]
+        );
+        assert!(wrap_text("\n  \n", 10).is_empty());
+    }
+
     #[test]
     fn test_render_file_line() {
         let file_status = "modified";
@@ -366,38 +1096,33 @@ mod tests {
     }
 
     #[test]
-    fn test_render_section_templates() {
-        assert_eq!(
-            strip_ansi_codes(&apply_colors_optimized(
-                "➤ Staged:",
-                TEMPLATES.section_staged,
-                &TemplateContext::default()
-            )),
-            "➤ Staged:"
-        );
-        assert_eq!(
-            strip_ansi_codes(&apply_colors_optimized(
-                "➤ Not staged:",
-                TEMPLATES.section_unstaged,
-                &TemplateContext::default()
-            )),
-            "➤ Not staged:"
-        );
-        assert_eq!(
-            strip_ansi_codes(&apply_colors_optimized(
-                "➤ Untracked:",
-                TEMPLATES.section_untracked,
-                &TemplateContext::default()
-            )),
-            "➤ Untracked:"
-        );
+    fn test_strip_ansi_codes_removes_hyperlinks() {
+        let linked = "\x1b]8;;file:///repo/a.rs\x1b\\\x1b[34ma.rs\x1b[0m\x1b]8;;\x1b\\";
+        assert_eq!(strip_ansi_codes(linked), "a.rs");
+        assert_eq!(strip_ansi_codes("\x1b]8;;file:///b\x07b\x1b]8;;\x07"), "b");
+    }
+
+    #[test]
+    fn test_render_section_header() {
+        for (section, label) in [
+            (StatusSection::Unmerged, "Unmerged"),
+            (StatusSection::Staged, "Staged"),
+            (StatusSection::Unstaged, "Not staged"),
+            (StatusSection::Untracked, "Untracked"),
+            (StatusSection::Submodules, "Submodules"),
+        ] {
+            assert_eq!(
+                strip_ansi_codes(&render_section_header(section, 1, None)),
+                format!("{} {label} (1):", glyphs().section)
+            );
+        }
         assert_eq!(
-            strip_ansi_codes(&apply_colors_optimized(
-                "➤ Unmerged:",
-                TEMPLATES.section_unmerged,
-                &TemplateContext::default()
+            strip_ansi_codes(&render_section_header(
+                StatusSection::Unstaged,
+                3,
+                Some("src/core/")
             )),
-            "➤ Unmerged:"
+            format!("{} Not staged (3) (in src/core/):", glyphs().section)
         );
     }
 
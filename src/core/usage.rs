@@ -0,0 +1,139 @@
+//! Opt-in counts of how often each subcommand runs, kept on this machine.
+//!
+//! With `collect = true` under `[usage]` in the settings, every run that
+//! finishes adds one to its subcommand in `usage.json` in the config
+//! directory, together with how long it took and the name it was typed as
+//! (`gs`, or `git-navigator status`). Nothing is sent anywhere; `stats --self`
+//! reads the file back.
+
+use crate::core::config::Settings;
+use crate::core::dirs::get_config_directory;
+use crate::core::error::Result;
+use crate::core::invocation::command_name;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// File in the config directory the counts are kept in
+pub const USAGE_FILE: &str = "usage.json";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UsageStats {
+    /// When the first run was recorded
+    pub since: chrono::DateTime<chrono::Utc>,
+    /// Keyed by subcommand ("status", "add", ...)
+    pub commands: BTreeMap<String, CommandUsage>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommandUsage {
+    pub runs: u64,
+    /// Wall-clock time of all runs together
+    pub total_ms: u64,
+    /// Runs per typed name: an alias such as "gs", or "git-navigator status"
+    pub typed: BTreeMap<String, u64>,
+}
+
+impl CommandUsage {
+    pub fn average_ms(&self) -> u64 {
+        self.total_ms.checked_div(self.runs).unwrap_or(0)
+    }
+}
+
+impl Default for UsageStats {
+    fn default() -> Self {
+        Self {
+            since: chrono::Utc::now(),
+            commands: BTreeMap::new(),
+        }
+    }
+}
+
+impl UsageStats {
+    /// The recorded counts, `None` when nothing was recorded yet
+    pub fn load(path: &Path) -> Result<Option<Self>> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => Ok(Some(serde_json::from_str(&content)?)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn add_run(&mut self, command: &str, typed: &str, elapsed: Duration) {
+        let usage = self.commands.entry(command.to_string()).or_default();
+        usage.runs += 1;
+        usage.total_ms += elapsed.as_millis() as u64;
+        *usage.typed.entry(typed.to_string()).or_default() += 1;
+    }
+
+    /// Write through a temporary file, so a run cut short never leaves half
+    /// a file behind
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let temp = path.with_extension("json.tmp");
+        std::fs::write(&temp, serde_json::to_string_pretty(self)?)?;
+        std::fs::rename(&temp, path)?;
+        Ok(())
+    }
+}
+
+/// Whether `usage.collect` is switched on
+pub fn is_enabled(settings: &Settings) -> bool {
+    settings.get_bool("usage.collect").unwrap_or(false)
+}
+
+pub fn usage_file() -> Result<PathBuf> {
+    Ok(get_config_directory()?.join(USAGE_FILE))
+}
+
+/// Count a finished run of `command` when collecting is switched on
+///
+/// Failures are only logged: statistics never get in the way of a command.
+pub fn record_run(settings: &Settings, command: &str, elapsed: Duration) {
+    if !is_enabled(settings) {
+        return;
+    }
+    if let Err(e) = try_record_run(command, elapsed) {
+        log::debug!("Cannot record usage of '{command}': {e}");
+    }
+}
+
+fn try_record_run(command: &str, elapsed: Duration) -> Result<()> {
+    let path = usage_file()?;
+    // An unreadable file starts the counts over instead of failing every run
+    let mut stats = UsageStats::load(&path).ok().flatten().unwrap_or_default();
+    stats.add_run(command, &command_name(command), elapsed);
+    stats.save(&path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_add_run_and_save() -> Result<()> {
+        let temp_dir = TempDir::new()?;
+        let path = temp_dir.path().join("nested").join(USAGE_FILE);
+        assert_eq!(UsageStats::load(&path)?, None);
+
+        let mut stats = UsageStats::default();
+        stats.add_run("status", "gs", Duration::from_millis(40));
+        stats.add_run("status", "gs", Duration::from_millis(60));
+        stats.add_run("status", "git-navigator status", Duration::from_millis(20));
+        stats.save(&path)?;
+
+        let loaded = UsageStats::load(&path)?.unwrap();
+        assert_eq!(loaded, stats);
+        let status = &loaded.commands["status"];
+        assert_eq!(status.runs, 3);
+        assert_eq!(status.average_ms(), 40);
+        assert_eq!(status.typed["gs"], 2);
+        assert_eq!(CommandUsage::default().average_ms(), 0);
+        Ok(())
+    }
+}
@@ -11,10 +11,17 @@
 //! - Index parsing and validation
 //! - Error handling and result types
 //! - UI templates and color system
+//!
+//! Programs that want to run commands without spawning the binary can use
+//! [`run_command`] from the [`api`] module.
 
+pub mod api;
 pub mod commands;
 pub mod core;
 
+// In-process commands
+pub use api::{run_command, AppContext, Command, CommandReport};
+
 // Re-export the core public API for external users
 pub use core::{
     format_file_status,
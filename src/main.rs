@@ -1,10 +1,16 @@
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand};
 use git_navigator::commands::*;
 use git_navigator::core::{
+    cache::CacheStore,
+    colors,
+    config::Settings,
+    configure_output, enable_non_interactive, enable_plain_mode,
     error::{GitNavigatorError, Result},
-    print_error, print_success,
+    git_config::{ColorChoice, GitDefaults},
+    hyperlink, print_error, print_success, templates, usage,
 };
 use std::env;
+use std::time::Instant;
 
 #[derive(Parser)]
 #[command(name = "git-navigator")]
@@ -15,6 +21,22 @@ struct Cli {
     #[arg(long, global = true)]
     debug: bool,
 
+    /// Don't write the index cache (read-only checkouts, CI)
+    #[arg(long, global = true)]
+    no_cache_write: bool,
+
+    /// Plain output for screen readers and dumb terminals: no colors, glyphs or padding
+    #[arg(long, global = true)]
+    plain: bool,
+
+    /// Never prompt or page: take the safe default or fail (implied when stdin is not a terminal)
+    #[arg(long, global = true)]
+    non_interactive: bool,
+
+    /// When to color output (default: git's color.ui, else auto: terminals only, unless NO_COLOR is set)
+    #[arg(long, global = true, value_enum, value_name = "WHEN")]
+    color: Option<ColorChoice>,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -22,35 +44,211 @@ struct Cli {
 #[derive(Subcommand)]
 enum Commands {
     /// Show numbered git status (gs alias)
-    Status,
+    Status {
+        #[command(flatten)]
+        args: status::StatusArgs,
+    },
     /// Add files by index (ga alias)
     Add {
         /// File indices to add (e.g., "1 3-5,8")
+        #[arg(allow_negative_numbers = true)]
         indices: Vec<String>,
+
+        /// Pick the hunks of the files to stage, one by one
+        #[arg(short, long)]
+        patch: bool,
     },
     /// Show diff for files by index (gd alias)
     Diff {
         /// File indices to diff (e.g., "1 3-5,8")
+        #[arg(allow_negative_numbers = true)]
         indices: Vec<String>,
+
+        /// Show the diff with CMD (delta, difft, ...) instead of `diff.tool`;
+        /// "none" for git's own
+        #[arg(long, value_name = "CMD")]
+        tool: Option<String>,
+
+        /// Show a diffstat (lines added and removed per file) instead of the patches
+        #[arg(long)]
+        stat: bool,
     },
-    /// Reset files by index (grs alias)
+    /// Unstage or discard files by index, or reset HEAD to a commit (grs alias)
     Reset {
-        /// File indices to reset (e.g., "1 3-5,8")
-        indices: Vec<String>,
+        #[command(flatten)]
+        args: reset::ResetArgs,
+    },
+    /// Remove files by index from the index and the worktree (--cached keeps them on disk)
+    Rm {
+        #[command(flatten)]
+        args: rm::RmArgs,
     },
     /// Checkout files by index or switch to branch (gco alias)
     Checkout {
-        /// Create and switch to a new branch
-        #[arg(short = 'b', long = "create")]
-        create_branch: bool,
-        /// File indices (e.g., "1 3-5,8") OR branch name (e.g., "main") OR branch name to create
-        indices: Vec<String>,
+        #[command(flatten)]
+        args: checkout::CheckoutArgs,
     },
     /// Show numbered branches or switch to a branch (gb alias)
     Branches {
-        /// Branch index to checkout (if provided)
+        #[command(flatten)]
+        args: branches::BranchesArgs,
+    },
+    /// Cherry-pick commits by index from the last gl listing
+    CherryPick {
+        #[command(flatten)]
+        args: cherry_pick::CherryPickArgs,
+    },
+    /// Commit staged changes or files by index (gc alias); --type or -i for conventional commits
+    Commit {
+        #[command(flatten)]
+        args: commit::CommitArgs,
+    },
+    /// List authors with commit counts and last activity, numbered for `log --author`
+    Contributors {
+        #[command(flatten)]
+        args: contributors::ContributorsArgs,
+    },
+    /// List directories with changes, or print one by index for the shell to cd into (gcd alias)
+    Dirs {
+        /// Directory index to print (if provided)
         index: Option<usize>,
     },
+    /// Check that each remote is reachable and accepts your credentials
+    Doctor {
+        #[command(flatten)]
+        args: doctor::DoctorArgs,
+    },
+    /// Open files in your editor by index (ge alias)
+    Edit {
+        #[command(flatten)]
+        args: edit::EditArgs,
+    },
+    /// Run a command on files by index: `exec -- cp {1} {3} /tmp/`, or
+    /// `exec 1-3 -- wc -l` to put the paths last
+    Exec {
+        #[command(flatten)]
+        args: exec::ExecArgs,
+    },
+    /// Fetch from the upstream remote (or the one given)
+    Fetch {
+        #[command(flatten)]
+        args: fetch::FetchArgs,
+    },
+    /// Print the current indices of files and branches as TSV or JSON, for editor plugins and TUIs
+    Keys {
+        #[command(flatten)]
+        args: keys::KeysArgs,
+    },
+    /// Show numbered recent commits (gl alias)
+    Log {
+        #[command(flatten)]
+        args: log::LogArgs,
+    },
+    /// Draft release notes since a tag, or list, show, add and edit commit notes
+    Notes {
+        #[command(flatten)]
+        args: notes::NotesArgs,
+    },
+    /// Open a file in a GUI editor (VS Code, JetBrains, ...) at its first change
+    Open {
+        #[command(flatten)]
+        args: open::OpenArgs,
+    },
+    /// List workspace packages with changes, or print one's directory by index
+    Packages {
+        #[command(flatten)]
+        args: packages::PackagesArgs,
+    },
+    /// Pick files from the numbered list with the keyboard to stage, unstage or diff
+    Pick {
+        #[command(flatten)]
+        args: pick::PickArgs,
+    },
+    /// Print the branch and change counts for a shell prompt
+    Prompt {
+        #[command(flatten)]
+        args: prompt::PromptArgs,
+    },
+    /// Pull the current branch from its upstream
+    Pull {
+        #[command(flatten)]
+        args: pull::PullArgs,
+    },
+    /// Push the current branch; force pushes list the remote commits they discard
+    Push {
+        #[command(flatten)]
+        args: push::PushArgs,
+    },
+    /// Rebuild the numbered file, branch, directory and tag lists without printing them
+    Refresh {
+        #[command(flatten)]
+        args: refresh::RefreshArgs,
+    },
+    /// List the repositories git-navigator has seen, or print one's path by index (grepo function)
+    Repos {
+        #[command(flatten)]
+        args: repos::ReposArgs,
+    },
+    /// Open a file's directory in the file manager by index
+    Reveal {
+        #[command(flatten)]
+        args: reveal::RevealArgs,
+    },
+    /// Step through the files of a commit by gl index: diff, accept, next or edit
+    Review {
+        #[command(flatten)]
+        args: review::ReviewArgs,
+    },
+    /// List recent commits, or edit the message of one by index
+    Reword {
+        #[command(flatten)]
+        args: reword::RewordArgs,
+    },
+    /// Show a commit by index from the last gl listing
+    ShowCommit {
+        #[command(flatten)]
+        args: show_commit::ShowCommitArgs,
+    },
+    /// List the files changed since a time, committed or not, or show one's diff by index
+    Since {
+        #[command(flatten)]
+        args: since::SinceArgs,
+    },
+    /// Show the sparse checkout cone, or add directories to it by file index or path
+    Sparse {
+        #[command(flatten)]
+        args: sparse::SparseArgs,
+    },
+    /// Split the staged changes into several commits
+    Split {
+        #[command(flatten)]
+        args: split::SplitArgs,
+    },
+    /// Repository object statistics; --big-objects lists the largest files in history
+    Stats {
+        #[command(flatten)]
+        args: stats::StatsArgs,
+    },
+    /// List numbered tags, or create and delete them
+    Tags {
+        #[command(flatten)]
+        args: tags::TagsArgs,
+    },
+    /// List the files changed since midnight, for standup notes (since today)
+    Today {
+        #[command(flatten)]
+        args: since::TodayArgs,
+    },
+    /// Run the configured pre-push checks (fmt, lint, tests) and summarize them
+    Verify {
+        #[command(flatten)]
+        args: verify::VerifyArgs,
+    },
+    /// List worktrees, print one's path by index (gwt function), or add/remove them
+    Worktrees {
+        #[command(flatten)]
+        args: worktrees::WorktreesArgs,
+    },
     /// Update git-navigator to the latest version
     Update {
         #[command(flatten)]
@@ -61,10 +259,23 @@ enum Commands {
         #[command(flatten)]
         args: rollback::RollbackArgs,
     },
+    /// Remove the binary, settings, caches and backups
+    Uninstall {
+        #[command(flatten)]
+        args: uninstall::UninstallArgs,
+    },
+    /// Print shell functions for the short aliases (eval "$(git-navigator init zsh)")
+    Init {
+        #[command(flatten)]
+        args: init::InitArgs,
+    },
 }
 
 fn main() -> Result<()> {
-    let cli = Cli::parse();
+    let started = Instant::now();
+    let matches = Cli::command().get_matches();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    let subcommand = matches.subcommand_name().unwrap_or_default().to_string();
 
     // Configure logging based on --debug flag
     if cli.debug {
@@ -74,9 +285,31 @@ fn main() -> Result<()> {
     }
     env_logger::init();
 
+    if cli.no_cache_write {
+        CacheStore::disable_writes();
+    }
+
+    // --color, else the user's color.ui preference from git config
+    cli.color
+        .unwrap_or_else(|| GitDefaults::discover().color)
+        .apply();
+    let settings = Settings::discover();
+    colors::configure_status_colors(&settings);
+    templates::configure_glyphs(&settings);
+    templates::configure_templates(&settings);
+    configure_output(&settings);
+    status::configure_path_display(&settings);
+    if cli.plain {
+        enable_plain_mode();
+    }
+    if cli.non_interactive {
+        enable_non_interactive();
+    }
+    hyperlink::configure_hyperlinks(&settings);
+
     match cli.command {
-        Commands::Status => {
-            if let Err(e) = execute_status() {
+        Commands::Status { args } => {
+            if let Err(e) = execute_status_with_args(args) {
                 if let GitNavigatorError::NotInGitRepo = e {
                     print_error("Not in a git repository");
                 } else {
@@ -85,8 +318,8 @@ fn main() -> Result<()> {
                 std::process::exit(1);
             }
         }
-        Commands::Add { indices } => {
-            if let Err(e) = execute_add(indices) {
+        Commands::Add { indices, patch } => {
+            if let Err(e) = execute_add(indices, patch) {
                 if let GitNavigatorError::NotInGitRepo = e {
                     print_error("Not in a git repository");
                 } else {
@@ -95,8 +328,12 @@ fn main() -> Result<()> {
                 std::process::exit(1);
             }
         }
-        Commands::Diff { indices } => {
-            if let Err(e) = execute_diff(indices) {
+        Commands::Diff {
+            indices,
+            tool,
+            stat,
+        } => {
+            if let Err(e) = execute_diff(indices, tool, stat) {
                 if let GitNavigatorError::NotInGitRepo = e {
                     print_error("Not in a git repository");
                 } else {
@@ -105,8 +342,8 @@ fn main() -> Result<()> {
                 std::process::exit(1);
             }
         }
-        Commands::Reset { indices } => {
-            if let Err(e) = execute_reset(indices) {
+        Commands::Reset { args } => {
+            if let Err(e) = execute_reset_with_args(args) {
                 if let GitNavigatorError::NotInGitRepo = e {
                     print_error("Not in a git repository");
                 } else {
@@ -115,11 +352,217 @@ fn main() -> Result<()> {
                 std::process::exit(1);
             }
         }
-        Commands::Checkout {
-            create_branch,
-            indices,
-        } => {
-            if let Err(e) = execute_checkout_with_flags(create_branch, indices) {
+        Commands::Rm { args } => {
+            if let Err(e) = execute_rm(args) {
+                if let GitNavigatorError::NotInGitRepo = e {
+                    print_error("Not in a git repository");
+                } else {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Checkout { args } => {
+            if let Err(e) = checkout::execute_checkout_with_args(args) {
+                if let GitNavigatorError::NotInGitRepo = e {
+                    print_error("Not in a git repository");
+                } else {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Branches { args } => {
+            if let Err(e) = execute_branches(args) {
+                if let GitNavigatorError::NotInGitRepo = e {
+                    print_error("Not in a git repository");
+                } else {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::CherryPick { args } => {
+            if let Err(e) = execute_cherry_pick(args) {
+                if let GitNavigatorError::NotInGitRepo = e {
+                    print_error("Not in a git repository");
+                } else {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Commit { args } => {
+            if let Err(e) = execute_commit(args) {
+                if let GitNavigatorError::NotInGitRepo = e {
+                    print_error("Not in a git repository");
+                } else {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Contributors { args } => {
+            if let Err(e) = execute_contributors(args) {
+                if let GitNavigatorError::NotInGitRepo = e {
+                    print_error("Not in a git repository");
+                } else {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Dirs { index } => {
+            if let Err(e) = execute_dirs(index) {
+                if let GitNavigatorError::NotInGitRepo = e {
+                    print_error("Not in a git repository");
+                } else {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Doctor { args } => {
+            if let Err(e) = execute_doctor(args) {
+                if let GitNavigatorError::NotInGitRepo = e {
+                    print_error("Not in a git repository");
+                } else {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Edit { args } => {
+            if let Err(e) = execute_edit(args) {
+                if let GitNavigatorError::NotInGitRepo = e {
+                    print_error("Not in a git repository");
+                } else {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Exec { args } => {
+            if let Err(e) = execute_exec(args) {
+                if let GitNavigatorError::NotInGitRepo = e {
+                    print_error("Not in a git repository");
+                } else {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Fetch { args } => {
+            if let Err(e) = execute_fetch(args) {
+                if let GitNavigatorError::NotInGitRepo = e {
+                    print_error("Not in a git repository");
+                } else {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Keys { args } => {
+            if let Err(e) = execute_keys(args) {
+                if let GitNavigatorError::NotInGitRepo = e {
+                    print_error("Not in a git repository");
+                } else {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Log { args } => {
+            if let Err(e) = execute_log(args) {
+                if let GitNavigatorError::NotInGitRepo = e {
+                    print_error("Not in a git repository");
+                } else {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Notes { args } => {
+            if let Err(e) = execute_notes(args) {
+                if let GitNavigatorError::NotInGitRepo = e {
+                    print_error("Not in a git repository");
+                } else {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Open { args } => {
+            if let Err(e) = execute_open(args) {
+                if let GitNavigatorError::NotInGitRepo = e {
+                    print_error("Not in a git repository");
+                } else {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Packages { args } => {
+            if let Err(e) = execute_packages(args) {
+                if let GitNavigatorError::NotInGitRepo = e {
+                    print_error("Not in a git repository");
+                } else {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Pick { args } => {
+            if let Err(e) = execute_pick(args) {
+                if let GitNavigatorError::NotInGitRepo = e {
+                    print_error("Not in a git repository");
+                } else {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Prompt { args } => {
+            if let Err(e) = execute_prompt(args) {
+                // Outside a repository the prompt segment is simply empty
+                if !matches!(e, GitNavigatorError::NotInGitRepo) {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Pull { args } => {
+            if let Err(e) = execute_pull(args) {
+                if let GitNavigatorError::NotInGitRepo = e {
+                    print_error("Not in a git repository");
+                } else {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Push { args } => {
+            if let Err(e) = execute_push(args) {
+                if let GitNavigatorError::NotInGitRepo = e {
+                    print_error("Not in a git repository");
+                } else {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Reveal { args } => {
+            if let Err(e) = execute_reveal(args) {
+                if let GitNavigatorError::NotInGitRepo = e {
+                    print_error("Not in a git repository");
+                } else {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Refresh { args } => {
+            if let Err(e) = execute_refresh(args) {
                 if let GitNavigatorError::NotInGitRepo = e {
                     print_error("Not in a git repository");
                 } else {
@@ -128,8 +571,114 @@ fn main() -> Result<()> {
                 std::process::exit(1);
             }
         }
-        Commands::Branches { index } => {
-            if let Err(e) = execute_branches(index) {
+        Commands::Repos { args } => {
+            if let Err(e) = execute_repos(args) {
+                print_error(&e.to_string());
+                std::process::exit(1);
+            }
+        }
+        Commands::Review { args } => {
+            if let Err(e) = execute_review(args) {
+                if let GitNavigatorError::NotInGitRepo = e {
+                    print_error("Not in a git repository");
+                } else {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Reword { args } => {
+            if let Err(e) = execute_reword(args) {
+                if let GitNavigatorError::NotInGitRepo = e {
+                    print_error("Not in a git repository");
+                } else {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::ShowCommit { args } => {
+            if let Err(e) = execute_show_commit(args) {
+                if let GitNavigatorError::NotInGitRepo = e {
+                    print_error("Not in a git repository");
+                } else {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Since { args } => {
+            if let Err(e) = execute_since(args) {
+                if let GitNavigatorError::NotInGitRepo = e {
+                    print_error("Not in a git repository");
+                } else {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Sparse { args } => {
+            if let Err(e) = execute_sparse(args) {
+                if let GitNavigatorError::NotInGitRepo = e {
+                    print_error("Not in a git repository");
+                } else {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Split { args } => {
+            if let Err(e) = execute_split(args) {
+                if let GitNavigatorError::NotInGitRepo = e {
+                    print_error("Not in a git repository");
+                } else {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Stats { args } => {
+            if let Err(e) = execute_stats(args) {
+                if let GitNavigatorError::NotInGitRepo = e {
+                    print_error("Not in a git repository");
+                } else {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Tags { args } => {
+            if let Err(e) = execute_tags(args) {
+                if let GitNavigatorError::NotInGitRepo = e {
+                    print_error("Not in a git repository");
+                } else {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Today { args } => {
+            if let Err(e) = execute_today(args) {
+                if let GitNavigatorError::NotInGitRepo = e {
+                    print_error("Not in a git repository");
+                } else {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Verify { args } => {
+            if let Err(e) = execute_verify(args) {
+                if let GitNavigatorError::NotInGitRepo = e {
+                    print_error("Not in a git repository");
+                } else {
+                    print_error(&e.to_string());
+                }
+                std::process::exit(1);
+            }
+        }
+        Commands::Worktrees { args } => {
+            if let Err(e) = execute_worktrees(args) {
                 if let GitNavigatorError::NotInGitRepo = e {
                     print_error("Not in a git repository");
                 } else {
@@ -160,7 +709,21 @@ fn main() -> Result<()> {
                 std::process::exit(1);
             }
         }
+        Commands::Uninstall { args } => {
+            if let Err(e) = execute_uninstall(args) {
+                print_error(&e.to_string());
+                std::process::exit(1);
+            }
+        }
+        Commands::Init { args } => {
+            if let Err(e) = execute_init(args) {
+                print_error(&e.to_string());
+                std::process::exit(1);
+            }
+        }
     }
 
+    // Only runs that get this far are counted; failures exit above
+    usage::record_run(&settings, &subcommand, started.elapsed());
     Ok(())
 }
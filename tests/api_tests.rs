@@ -0,0 +1,211 @@
+use git_navigator::api::{run_command, AppContext, Command};
+use git_navigator::{GitNavigatorError, GitStatus};
+use std::sync::OnceLock;
+use tempfile::TempDir;
+
+mod common;
+use common::repository::*;
+
+/// One cache home for the whole test binary, so parallel tests agree on it
+fn use_test_cache_home() {
+    static CACHE_HOME: OnceLock<TempDir> = OnceLock::new();
+    CACHE_HOME.get_or_init(|| {
+        let dir = TempDir::new().expect("cache dir");
+        std::env::set_var("XDG_CACHE_HOME", dir.path());
+        dir
+    });
+}
+
+fn indices(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+}
+
+#[cfg(test)]
+mod api_tests {
+    use super::*;
+
+    #[test]
+    fn test_status_add_and_reset() -> anyhow::Result<()> {
+        use_test_cache_home();
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "notes.txt", "draft\n")?;
+        create_file(&repo.path, "initial.txt", "changed\n")?;
+        let context = AppContext::new(&repo.path);
+
+        let status = run_command(Command::Status, &context)?;
+        assert_eq!(status.status.len(), 2);
+        let notes = status
+            .status
+            .iter()
+            .find(|file| file.path.ends_with("notes.txt"))
+            .expect("notes.txt listed");
+
+        let added = run_command(
+            Command::Add {
+                indices: indices(&[&notes.index.to_string()]),
+            },
+            &context,
+        )?;
+        assert_eq!(added.files.len(), 1);
+        assert!(added.files[0].path.ends_with("notes.txt"));
+        let staged_notes = added
+            .status
+            .iter()
+            .find(|file| file.path.ends_with("notes.txt") && file.staged)
+            .expect("notes.txt staged");
+
+        // The report's numbering is the one the next command resolves
+        let reset = run_command(
+            Command::Reset {
+                indices: indices(&[&staged_notes.index.to_string()]),
+            },
+            &context,
+        )?;
+        assert!(reset.files[0].path.ends_with("notes.txt"));
+        assert!(reset
+            .status
+            .iter()
+            .any(|file| file.path.ends_with("notes.txt") && file.status == GitStatus::Untracked));
+        Ok(())
+    }
+
+    #[test]
+    fn test_reset_unstages_both_ends_of_a_rename() -> anyhow::Result<()> {
+        use_test_cache_home();
+        let repo = setup_test_repo_with_initial_commit()?;
+        run_git(&repo.path, &["mv", "initial.txt", "renamed.txt"])?;
+        let context = AppContext::new(&repo.path);
+        let status = run_command(Command::Status, &context)?;
+        assert_eq!(status.status.len(), 1);
+        assert_eq!(status.status[0].status, GitStatus::Renamed);
+
+        let reset = run_command(
+            Command::Reset {
+                indices: indices(&["1"]),
+            },
+            &context,
+        )?;
+        // Nothing is left staged: the deletion of the old path is undone too
+        assert!(reset.status.iter().all(|file| !file.staged));
+        assert!(reset
+            .status
+            .iter()
+            .any(|file| file.path.ends_with("initial.txt") && file.status == GitStatus::Deleted));
+        assert!(reset
+            .status
+            .iter()
+            .any(|file| file.path.ends_with("renamed.txt") && file.status == GitStatus::Untracked));
+        Ok(())
+    }
+
+    #[test]
+    fn test_diff_and_checkout() -> anyhow::Result<()> {
+        use_test_cache_home();
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "initial.txt", "changed\n")?;
+        let context = AppContext::new(&repo.path);
+        run_command(Command::Status, &context)?;
+
+        let diff = run_command(
+            Command::Diff {
+                indices: indices(&["1"]),
+            },
+            &context,
+        )?;
+        assert!(diff.diff.expect("diff text").contains("+changed"));
+
+        let checkout = run_command(
+            Command::Checkout {
+                indices: indices(&["1"]),
+            },
+            &context,
+        )?;
+        assert!(checkout.status.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_validation_errors() -> anyhow::Result<()> {
+        use_test_cache_home();
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "notes.txt", "draft\n")?;
+        let context = AppContext::new(&repo.path);
+        run_command(Command::Status, &context)?;
+
+        let result = run_command(
+            Command::Add {
+                indices: indices(&["5"]),
+            },
+            &context,
+        );
+        assert!(matches!(
+            result,
+            Err(GitNavigatorError::IndexOutOfRange { index: 5, max: 1 })
+        ));
+
+        let result = run_command(Command::Add { indices: vec![] }, &context);
+        assert!(matches!(result, Err(GitNavigatorError::NoIndicesProvided)));
+
+        let outside = TempDir::new()?;
+        let result = run_command(Command::Status, &AppContext::new(outside.path()));
+        assert!(matches!(result, Err(GitNavigatorError::NotInGitRepo)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_stale_list_is_refreshed() -> anyhow::Result<()> {
+        use_test_cache_home();
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "b.txt", "b\n")?;
+        create_file(&repo.path, "c.txt", "c\n")?;
+        let context = AppContext::new(&repo.path);
+        run_command(Command::Status, &context)?;
+
+        // a.txt sorts first, so [1] would now be a.txt instead of b.txt
+        create_file(&repo.path, "a.txt", "a\n")?;
+        let result = run_command(
+            Command::Add {
+                indices: indices(&["1"]),
+            },
+            &context,
+        );
+        match result {
+            Err(GitNavigatorError::StaleFileList {
+                index, was, now, ..
+            }) => {
+                assert_eq!((index, was.as_str(), now.as_str()), (1, "b.txt", "a.txt"));
+            }
+            other => panic!("expected a stale list error, got {other:?}"),
+        }
+
+        // The refreshed list is what indices resolve against now
+        let added = run_command(
+            Command::Add {
+                indices: indices(&["1"]),
+            },
+            &context,
+        )?;
+        assert!(added.files[0].path.ends_with("a.txt"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_stale_list_keeps_unmoved_indices() -> anyhow::Result<()> {
+        use_test_cache_home();
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "a.txt", "a\n")?;
+        let context = AppContext::new(&repo.path);
+        run_command(Command::Status, &context)?;
+
+        // A file sorting after a.txt leaves [1] where it was
+        create_file(&repo.path, "z.txt", "z\n")?;
+        let added = run_command(
+            Command::Add {
+                indices: indices(&["1"]),
+            },
+            &context,
+        )?;
+        assert!(added.files[0].path.ends_with("a.txt"));
+        Ok(())
+    }
+}
@@ -0,0 +1,134 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+mod common;
+use common::repository::*;
+
+/// Run git-navigator with the libgit2 backend and no git on PATH
+fn navigator(repo_path: &Path, cache_home: &Path, args: &[&str]) -> Command {
+    let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+    cmd.args(args)
+        .current_dir(repo_path)
+        .env("XDG_CACHE_HOME", cache_home)
+        .env("PATH", "");
+    cmd
+}
+
+fn use_libgit2(repo_path: &Path) -> anyhow::Result<()> {
+    let dir = repo_path.join(".git").join("git-navigator");
+    fs::create_dir_all(&dir)?;
+    fs::write(dir.join("config.toml"), "[git]\nbackend = \"libgit2\"\n")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod backend_tests {
+    use super::*;
+
+    #[test]
+    fn test_libgit2_backend_stages_and_restores_without_git() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache_home = tempfile::TempDir::new()?;
+        use_libgit2(&repo.path)?;
+        create_file(&repo.path, "initial.txt", "changed\n")?;
+        create_file(&repo.path, "notes.txt", "draft\n")?;
+        fs::create_dir(repo.path.join("docs"))?;
+        create_file(&repo.path, "docs/guide.md", "guide\n")?;
+
+        navigator(&repo.path, cache_home.path(), &["status", "--plain"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Index 3: notes.txt (untracked)"));
+
+        // initial.txt and the untracked docs/ directory
+        navigator(&repo.path, cache_home.path(), &["add", "1-2"])
+            .assert()
+            .success();
+        navigator(&repo.path, cache_home.path(), &["status", "--plain"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Index 1: docs/guide.md (new)"))
+            .stdout(predicate::str::contains("Index 2: initial.txt (modified)"));
+
+        navigator(&repo.path, cache_home.path(), &["reset", "2"])
+            .assert()
+            .success();
+        navigator(&repo.path, cache_home.path(), &["status", "--plain"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Index 2: initial.txt (modified)"));
+        navigator(&repo.path, cache_home.path(), &["checkout", "2"])
+            .assert()
+            .success();
+        assert_eq!(
+            fs::read_to_string(repo.path.join("initial.txt"))?,
+            "initial content\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_libgit2_backend_checks_out_files_from_a_ref() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache_home = tempfile::TempDir::new()?;
+        create_file(&repo.path, "initial.txt", "second version\n")?;
+        git_add(&repo.path, "initial.txt")?;
+        git_commit(&repo.path, "Second version")?;
+        use_libgit2(&repo.path)?;
+        create_file(&repo.path, "initial.txt", "work in progress\n")?;
+
+        navigator(&repo.path, cache_home.path(), &["status"])
+            .assert()
+            .success();
+        navigator(&repo.path, cache_home.path(), &["checkout", "1", "HEAD~1"])
+            .assert()
+            .success();
+        assert_eq!(
+            fs::read_to_string(repo.path.join("initial.txt"))?,
+            "initial content\n"
+        );
+        // Staged too, as git checkout <ref> -- <path> does
+        navigator(&repo.path, cache_home.path(), &["status", "--plain"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Index 1: initial.txt (modified)"))
+            .stdout(predicate::str::contains("Index 2:").not());
+        Ok(())
+    }
+
+    #[test]
+    fn test_libgit2_backend_creates_and_switches_branches() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache_home = tempfile::TempDir::new()?;
+        use_libgit2(&repo.path)?;
+
+        navigator(
+            &repo.path,
+            cache_home.path(),
+            &["checkout", "-b", "feature"],
+        )
+        .assert()
+        .success();
+        create_file(&repo.path, "feature.txt", "feature\n")?;
+        git_add(&repo.path, "feature.txt")?;
+        git_commit(&repo.path, "Add feature")?;
+
+        navigator(&repo.path, cache_home.path(), &["checkout", "main"])
+            .assert()
+            .success();
+        assert!(!repo.path.join("feature.txt").exists());
+        navigator(&repo.path, cache_home.path(), &["status"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("main"));
+
+        navigator(&repo.path, cache_home.path(), &["checkout", "feature"])
+            .assert()
+            .success();
+        assert!(repo.path.join("feature.txt").exists());
+        Ok(())
+    }
+}
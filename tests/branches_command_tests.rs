@@ -88,7 +88,7 @@ mod branches_command_tests {
             .current_dir(&repo.path)
             .assert()
             .failure()
-            .stdout(predicate::str::contains(
+            .stderr(predicate::str::contains(
                 "Cannot switch to current branch. Run 'gs' first to see available files.",
             ));
 
@@ -117,7 +117,7 @@ mod branches_command_tests {
             .current_dir(&repo.path)
             .assert()
             .failure()
-            .stdout(predicate::str::contains("Branch index 5 not found"));
+            .stderr(predicate::str::contains("Branch index 5 not found"));
 
         Ok(())
     }
@@ -135,7 +135,7 @@ mod branches_command_tests {
             .current_dir(non_repo_path)
             .assert()
             .failure()
-            .stdout(assertions::not_in_git_repo());
+            .stderr(assertions::not_in_git_repo());
 
         Ok(())
     }
@@ -143,15 +143,285 @@ mod branches_command_tests {
     #[test]
     fn test_gb_no_cached_branches() -> anyhow::Result<()> {
         let repo = setup_test_repo_with_initial_commit()?;
+        let cache_home = tempfile::TempDir::new()?;
+
+        GitRepo::open(&repo.path)?.create_branch("feature-branch")?;
+        run_git(&repo.path, &["checkout", "--quiet", "main"])?;
 
-        // Try to checkout without running gb first (no cache)
+        // Without a previous gb, branches are numbered the way gb would
         let mut cmd = Command::cargo_bin("git-navigator")?;
         cmd.arg("branches")
             .arg("1")
+            .env("XDG_CACHE_HOME", cache_home.path())
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "Switched to branch 'feature-branch'",
+            ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_switching_renumbers_branch_cache() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache_home = tempfile::TempDir::new()?;
+        let git_repo = GitRepo::open(&repo.path)?;
+        git_repo.create_branch("feature-branch")?;
+        git_repo.checkout_branch("main")?;
+
+        let navigator = |args: &[&str]| {
+            let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+            cmd.args(args)
+                .env("XDG_CACHE_HOME", cache_home.path())
+                .current_dir(&repo.path);
+            cmd
+        };
+
+        // gb lists main as current and feature-branch as [1]
+        navigator(&["branches"]).assert().success();
+
+        // After gco switches, main is the one numbered [1]
+        navigator(&["checkout", "feature-branch"])
+            .assert()
+            .success();
+        navigator(&["branches", "1"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Switched to branch 'main'"));
+
+        // A new branch is numbered right away too
+        navigator(&["checkout", "-b", "topic"]).assert().success();
+        navigator(&["branches", "2"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Switched to branch 'main'"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_gb_recovers_from_corrupt_cache() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache_home = tempfile::TempDir::new()?;
+        let git_repo = GitRepo::open(&repo.path)?;
+        git_repo.create_branch("feature-branch")?;
+        git_repo.checkout_branch("main")?;
+
+        Command::cargo_bin("git-navigator")?
+            .arg("branches")
+            .env("XDG_CACHE_HOME", cache_home.path())
+            .current_dir(&repo.path)
+            .assert()
+            .success();
+        let cache_dir = std::fs::read_dir(cache_home.path().join("git-navigator"))?
+            .next()
+            .unwrap()?
+            .path();
+        std::fs::write(cache_dir.join("branches.json"), "{\"branches\": [")?;
+
+        // The broken file is set aside and the list rebuilt from the
+        // repository; the index is only taken once the list is readable
+        let checkout = || -> anyhow::Result<Command> {
+            let mut cmd = Command::cargo_bin("git-navigator")?;
+            cmd.args(["branches", "1"])
+                .env("XDG_CACHE_HOME", cache_home.path())
+                .current_dir(&repo.path);
+            Ok(cmd)
+        };
+        checkout()?
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("was rebuilt"));
+        checkout()?
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "Switched to branch 'feature-branch'",
+            ));
+
+        let names: Vec<String> = std::fs::read_dir(&cache_dir)?
+            .map(|entry| Ok(entry?.file_name().to_string_lossy().into_owned()))
+            .collect::<std::io::Result<_>>()?;
+        assert!(names.contains(&"branches.json".to_string()));
+        assert!(names
+            .iter()
+            .any(|name| name.starts_with("branches.json.corrupt-")));
+        Ok(())
+    }
+
+    #[test]
+    fn test_gb_numbers_current_branch_when_configured() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache_home = tempfile::TempDir::new()?;
+        let git_repo = GitRepo::open(&repo.path)?;
+        git_repo.create_branch("feature-branch")?;
+        git_repo.checkout_branch("main")?;
+        std::fs::create_dir_all(repo.path.join(".git/git-navigator"))?;
+        std::fs::write(
+            repo.path.join(".git/git-navigator/config.toml"),
+            "[ui]\nnumber_current_branch = true\n",
+        )?;
+
+        let navigator = |args: &[&str]| {
+            let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+            cmd.args(args)
+                .env("XDG_CACHE_HOME", cache_home.path())
+                .current_dir(&repo.path);
+            cmd
+        };
+
+        navigator(&["branches"])
+            .env("NO_COLOR", "1")
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("[1] feature-branch"))
+            .stdout(predicate::str::contains("[2*] main"));
+        navigator(&["--plain", "branches"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Index 2: main (current branch)"));
+
+        // Numbers stay put across switches
+        navigator(&["branches", "1"]).assert().success();
+        navigator(&["branches", "2"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Switched to branch 'main'"));
+        navigator(&["branches", "2"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Cannot switch to current branch"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_gb_sorts_by_committer_date() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache_home = tempfile::TempDir::new()?;
+        run_git(&repo.path, &["branch", "aa-old"])?;
+        run_git(&repo.path, &["checkout", "--quiet", "-b", "zz-recent"])?;
+        create_file(&repo.path, "recent.txt", "recent\n")?;
+        git_add(&repo.path, "recent.txt")?;
+        Command::new("git")
+            .args(["commit", "--quiet", "-m", "Recent work"])
+            .env("GIT_COMMITTER_DATE", "@4000000000 +0000")
+            .current_dir(&repo.path)
+            .assert()
+            .success();
+        run_git(&repo.path, &["checkout", "--quiet", "main"])?;
+
+        let navigator = |args: &[&str]| {
+            let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+            cmd.args(args)
+                .env("XDG_CACHE_HOME", cache_home.path())
+                .current_dir(&repo.path);
+            cmd
+        };
+
+        navigator(&["--plain", "branches"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Index 1: aa-old"));
+        navigator(&["--plain", "branches", "--sort", "committerdate"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Index 1: zz-recent"))
+            .stdout(predicate::str::contains("Index 2: aa-old"));
+        // gb <index> follows the order of the last listing
+        navigator(&["branches", "1"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Switched to branch 'zz-recent'"));
+
+        std::fs::create_dir_all(repo.path.join(".git/git-navigator"))?;
+        std::fs::write(
+            repo.path.join(".git/git-navigator/config.toml"),
+            "[branch]\nsort = \"committerdate\"\n",
+        )?;
+        run_git(&repo.path, &["checkout", "--quiet", "main"])?;
+        navigator(&["--plain", "branches", "-a"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Index 1: zz-recent"))
+            .stdout(predicate::str::contains("Index 2: aa-old"));
+        navigator(&["--plain", "branches", "--sort", "name"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Index 1: aa-old"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_gb_filters_by_pattern() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        for branch in ["feature/login", "feature/search", "fix/typo", "release-fix"] {
+            run_git(&repo.path, &["branch", branch])?;
+        }
+
+        Command::cargo_bin("git-navigator")?
+            .args(["--plain", "branches", "feature/"])
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Index 1: feature/login"))
+            .stdout(predicate::str::contains("Index 2: feature/search"))
+            .stdout(predicate::str::contains("fix/typo").not())
+            .stdout(predicate::str::contains("Current branch").not());
+
+        Command::cargo_bin("git-navigator")?
+            .args(["--plain", "branches", "*-fix"])
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Index 1: release-fix"))
+            .stdout(predicate::str::contains("fix/typo").not());
+
+        // Numbers follow the filtered list
+        Command::cargo_bin("git-navigator")?
+            .args(["branches", "1"])
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Switched to branch 'release-fix'"));
+
+        Command::cargo_bin("git-navigator")?
+            .args(["branches", "nothing-like-this"])
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "No local branches match 'nothing-like-this'",
+            ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gb_compact_output() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let config_home = tempfile::TempDir::new()?;
+        std::fs::create_dir_all(config_home.path().join("git-navigator"))?;
+        std::fs::write(
+            config_home.path().join("git-navigator/config.toml"),
+            "[ui]\ncompact = true\ndecorations = false\n",
+        )?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.arg("branches")
+            .env("XDG_CONFIG_HOME", config_home.path())
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::starts_with("Local Branches:\n[*] main"));
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["branches", "5"])
+            .env("XDG_CONFIG_HOME", config_home.path())
             .current_dir(&repo.path)
             .assert()
             .failure()
-            .stdout(predicate::str::contains("Cannot load branch cache"));
+            .stderr(predicate::str::starts_with("Error: "));
 
         Ok(())
     }
@@ -181,4 +451,131 @@ mod branch_utilities {
 
         Ok(())
     }
+
+    #[test]
+    fn test_gone_upstream_shown_and_pruned() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let remote = tempfile::TempDir::new()?;
+        run_git(remote.path(), &["init", "--bare", "--quiet"])?;
+        run_git(
+            &repo.path,
+            &["remote", "add", "origin", &remote.path().to_string_lossy()],
+        )?;
+        run_git(&repo.path, &["push", "--quiet", "-u", "origin", "main"])?;
+
+        // "done" was merged, "wip" has a commit nobody merged
+        run_git(&repo.path, &["branch", "done"])?;
+        run_git(&repo.path, &["checkout", "--quiet", "-b", "wip"])?;
+        create_file(&repo.path, "wip.txt", "wip")?;
+        git_add(&repo.path, "wip.txt")?;
+        git_commit(&repo.path, "Work in progress")?;
+        for branch in ["done", "wip"] {
+            run_git(&repo.path, &["push", "--quiet", "-u", "origin", branch])?;
+            run_git(
+                &repo.path,
+                &["push", "--quiet", "origin", "--delete", branch],
+            )?;
+        }
+
+        Command::cargo_bin("git-navigator")?
+            .args(["--plain", "status"])
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Branch: wip (upstream gone)"))
+            .stdout(predicate::str::contains(
+                "gb --prune to delete gone branches",
+            ));
+
+        run_git(&repo.path, &["checkout", "--quiet", "main"])?;
+        Command::cargo_bin("git-navigator")?
+            .args(["--plain", "branches"])
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Current branch: main\n"))
+            .stdout(predicate::str::contains("Index 1: done (upstream gone)"))
+            .stdout(predicate::str::contains("Index 2: wip (upstream gone)"))
+            .stdout(predicate::str::contains("'gb --prune' deletes them"));
+
+        Command::cargo_bin("git-navigator")?
+            .args(["branches", "--prune"])
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Deleted branch 'done'"))
+            .stderr(predicate::str::contains(
+                "Kept 'wip': it has unmerged commits",
+            ));
+
+        let git_repo = GitRepo::open(&repo.path)?;
+        assert!(!git_repo.branch_exists("done"));
+        assert!(git_repo.branch_exists("wip"));
+        assert!(!git_repo.upstream_gone("main"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_remote_branches_numbered_and_checked_out() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let remote = tempfile::TempDir::new()?;
+        run_git(remote.path(), &["init", "--bare", "--quiet"])?;
+        run_git(
+            &repo.path,
+            &["remote", "add", "origin", &remote.path().to_string_lossy()],
+        )?;
+        run_git(&repo.path, &["branch", "feature"])?;
+        run_git(
+            &repo.path,
+            &["push", "--quiet", "origin", "main", "feature"],
+        )?;
+        run_git(&repo.path, &["branch", "--quiet", "-D", "feature"])?;
+        run_git(&repo.path, &["branch", "local-only"])?;
+
+        Command::cargo_bin("git-navigator")?
+            .args(["--plain", "branches", "-a"])
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Index 1: local-only"))
+            .stdout(predicate::str::contains("Index 1: origin/feature"))
+            .stdout(predicate::str::contains("Index 2: origin/main"));
+
+        // Remote indices have their own namespace: 1 is origin/feature here
+        Command::cargo_bin("git-navigator")?
+            .args(["branches", "-r", "1"])
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "Switched to a new branch 'feature' tracking 'origin/feature'",
+            ));
+        let git_repo = GitRepo::open(&repo.path)?;
+        assert_eq!(git_repo.get_current_branch()?, "feature");
+        let upstream = Command::new("git")
+            .args(["rev-parse", "--abbrev-ref", "feature@{upstream}"])
+            .current_dir(&repo.path)
+            .output()?;
+        assert_eq!(String::from_utf8(upstream.stdout)?, "origin/feature\n");
+
+        // A local branch of that name already exists: just switch to it
+        Command::cargo_bin("git-navigator")?
+            .args(["branches", "-r", "2"])
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "Switched to existing branch 'main'",
+            ));
+
+        Command::cargo_bin("git-navigator")?
+            .args(["branches", "-r", "2"])
+            .current_dir(&repo.path)
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Already on 'main'"));
+
+        Ok(())
+    }
 }
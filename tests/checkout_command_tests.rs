@@ -94,10 +94,10 @@ mod checkout_command_tests {
             .current_dir(&repo.path)
             .assert()
             .success()
-            .stdout(predicate::str::contains(
+            .stderr(predicate::str::contains(
                 "No file indices or branch name provided",
             ))
-            .stdout(predicate::str::contains("Usage:"));
+            .stderr(predicate::str::contains("Usage:"));
 
         Ok(())
     }
@@ -112,10 +112,10 @@ mod checkout_command_tests {
             .current_dir(&repo.path)
             .assert()
             .success()
-            .stdout(predicate::str::contains(
+            .stderr(predicate::str::contains(
                 "Branch name required with -b flag",
             ))
-            .stdout(predicate::str::contains("Usage:"));
+            .stderr(predicate::str::contains("Usage:"));
 
         Ok(())
     }
@@ -130,7 +130,7 @@ mod checkout_command_tests {
             .current_dir(&repo.path)
             .assert()
             .failure()
-            .stdout(predicate::str::contains(
+            .stderr(predicate::str::contains(
                 "Failed to checkout branch 'nonexistent-branch'",
             ));
 
@@ -173,6 +173,477 @@ mod checkout_command_tests {
         Ok(())
     }
 
+    #[test]
+    fn test_gco_numeric_branch_name_is_ambiguous() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+
+        let git_repo = GitRepo::open(&repo.path)?;
+        git_repo.create_branch("123")?;
+        git_repo.checkout_branch("main")?;
+
+        // Without a terminal to prompt on, the command must refuse to guess
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.arg("checkout")
+            .arg("123")
+            .current_dir(&repo.path)
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(
+                "'123' matches both a file index and a branch name",
+            ));
+
+        // Hooks pass --non-interactive; piped input is never read as an answer
+        let mut cmd = assert_cmd::Command::cargo_bin("git-navigator")?;
+        cmd.args(["--non-interactive", "checkout", "123"])
+            .current_dir(&repo.path)
+            .write_stdin("b\n")
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(
+                "'123' matches both a file index and a branch name",
+            ));
+        assert_eq!(git_repo.get_current_branch()?, "main");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gco_branch_flag_resolves_ambiguity() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+
+        let git_repo = GitRepo::open(&repo.path)?;
+        git_repo.create_branch("123")?;
+        git_repo.checkout_branch("main")?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.arg("checkout")
+            .arg("--branch")
+            .arg("123")
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "Successfully switched to branch '123'",
+            ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gco_files_flag_resolves_ambiguity() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+
+        let git_repo = GitRepo::open(&repo.path)?;
+        git_repo.create_branch("1")?;
+        git_repo.checkout_branch("main")?;
+
+        std::fs::write(repo.path.join("initial.txt"), "changed content\n")?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.arg("status").current_dir(&repo.path).assert().success();
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.arg("checkout")
+            .arg("--files")
+            .arg("1")
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Successfully checked out"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gco_mixed_branch_and_indices_rejected() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.arg("checkout")
+            .arg("dev")
+            .arg("2")
+            .current_dir(&repo.path)
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(
+                "Cannot mix branch names and file indices",
+            ));
+
+        Ok(())
+    }
+
+    /// Creates `feature` with a different `initial.txt` and leaves `main` checked
+    /// out with an uncommitted edit to the same file
+    fn setup_conflicting_branch() -> anyhow::Result<TestRepo> {
+        let repo = setup_test_repo_with_initial_commit()?;
+
+        let git_repo = GitRepo::open(&repo.path)?;
+        git_repo.create_branch("feature")?;
+        create_file(&repo.path, "initial.txt", "feature content\n")?;
+        git_add(&repo.path, "initial.txt")?;
+        git_commit(&repo.path, "Change on feature")?;
+        git_repo.checkout_branch("main")?;
+
+        create_file(&repo.path, "initial.txt", "local edit\n")?;
+        Ok(repo)
+    }
+
+    #[test]
+    fn test_gco_branch_blocked_by_dirty_worktree() -> anyhow::Result<()> {
+        let repo = setup_conflicting_branch()?;
+        let config_home = tempfile::TempDir::new()?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.arg("checkout")
+            .arg("feature")
+            .env("XDG_CONFIG_HOME", config_home.path())
+            .current_dir(&repo.path)
+            .assert()
+            .failure()
+            .stdout(predicate::str::contains(
+                "These local changes would be overwritten",
+            ))
+            .stdout(predicate::str::contains("[1] initial.txt"))
+            .stderr(predicate::str::contains("Cannot switch to 'feature'"));
+
+        assert_eq!(GitRepo::open(&repo.path)?.get_current_branch()?, "main");
+        Ok(())
+    }
+
+    #[test]
+    fn test_gco_branch_autostash_from_config() -> anyhow::Result<()> {
+        let repo = setup_conflicting_branch()?;
+        let config_home = tempfile::TempDir::new()?;
+        std::fs::create_dir_all(config_home.path().join("git-navigator"))?;
+        std::fs::write(
+            config_home.path().join("git-navigator/config.toml"),
+            "[checkout]\nautostash = true\n",
+        )?;
+
+        // The stash re-applies on top of the feature version and conflicts,
+        // but the switch itself must go through
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.arg("checkout")
+            .arg("feature")
+            .env("XDG_CONFIG_HOME", config_home.path())
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Created autostash"))
+            .stderr(predicate::str::contains(
+                "Applying autostash resulted in conflicts",
+            ))
+            .stdout(predicate::str::contains("➤ Unmerged (1):"))
+            .stdout(predicate::str::contains("[1] initial.txt"));
+
+        assert_eq!(GitRepo::open(&repo.path)?.get_current_branch()?, "feature");
+        Ok(())
+    }
+
+    #[test]
+    fn test_gco_autostash_flag_carries_changes() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+
+        let git_repo = GitRepo::open(&repo.path)?;
+        git_repo.create_branch("feature")?;
+        git_repo.checkout_branch("main")?;
+        create_file(&repo.path, "initial.txt", "local edit\n")?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.arg("checkout")
+            .arg("--autostash")
+            .arg("feature")
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Created autostash"))
+            .stdout(predicate::str::contains("Applied autostash."))
+            .stdout(predicate::str::contains(
+                "Successfully switched to branch 'feature'",
+            ));
+
+        assert_eq!(git_repo.get_current_branch()?, "feature");
+        assert_eq!(
+            std::fs::read_to_string(repo.path.join("initial.txt"))?,
+            "local edit\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_gco_warns_before_orphaning_detached_commits() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        run_git(&repo.path, &["checkout", "--detach"])?;
+        create_file(&repo.path, "detached.txt", "work\n")?;
+        git_add(&repo.path, "detached.txt")?;
+        git_commit(&repo.path, "Work on detached HEAD")?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.arg("checkout")
+            .arg("main")
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stderr(predicate::str::contains("will leave 1 commit(s) behind"))
+            .stdout(predicate::str::contains("Work on detached HEAD"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gco_create_branch_from_branch_index() -> anyhow::Result<()> {
+        let repo = setup_conflicting_branch()?;
+        run_git(&repo.path, &["checkout", "--", "initial.txt"])?;
+
+        // Run gb first to cache branches; feature is index 1
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.arg("branches")
+            .current_dir(&repo.path)
+            .assert()
+            .success();
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["checkout", "-b", "topic", "--from", "1"])
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "Successfully created and switched to branch 'topic' from 'feature'",
+            ));
+
+        let content = std::fs::read_to_string(repo.path.join("initial.txt"))?;
+        assert_eq!(content, "feature content\n");
+        assert_eq!(GitRepo::open(&repo.path)?.get_current_branch()?, "topic");
+        Ok(())
+    }
+
+    #[test]
+    fn test_gco_create_branch_from_commit_index() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "initial.txt", "second\n")?;
+        git_add(&repo.path, "initial.txt")?;
+        git_commit(&repo.path, "Second commit")?;
+
+        // Commit [2] in gl is the initial commit
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.arg("log").current_dir(&repo.path).assert().success();
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["checkout", "-b", "topic", "--from-commit", "2"])
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "Successfully created and switched to branch 'topic' from '",
+            ));
+
+        let content = std::fs::read_to_string(repo.path.join("initial.txt"))?;
+        assert_eq!(content, "initial content\n");
+        assert_eq!(GitRepo::open(&repo.path)?.get_current_branch()?, "topic");
+        Ok(())
+    }
+
+    #[test]
+    fn test_gco_create_branch_from_missing_start_point() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["checkout", "-b", "topic", "--from", "no-such-ref"])
+            .current_dir(&repo.path)
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(
+                "Start point 'no-such-ref' does not exist",
+            ));
+
+        assert!(!GitRepo::open(&repo.path)?.branch_exists("topic"));
+        Ok(())
+    }
+
+    fn write_global_config(content: &str) -> anyhow::Result<tempfile::TempDir> {
+        let config_home = tempfile::TempDir::new()?;
+        std::fs::create_dir_all(config_home.path().join("git-navigator"))?;
+        std::fs::write(
+            config_home.path().join("git-navigator/config.toml"),
+            content,
+        )?;
+        Ok(config_home)
+    }
+
+    #[test]
+    fn test_gco_create_branch_expands_template() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let config_home = write_global_config(
+            "[branch]\ntemplate = \"feature/{user}/{slug}\"\npattern = \"^feature/\"\n",
+        )?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["checkout", "-b", "Login Form"])
+            .env("XDG_CONFIG_HOME", config_home.path())
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "Successfully created and switched to branch 'feature/test-user/login-form'",
+            ));
+
+        assert_eq!(
+            GitRepo::open(&repo.path)?.get_current_branch()?,
+            "feature/test-user/login-form"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_gco_create_branch_rejects_pattern_violation() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let config_home = write_global_config("[branch]\npattern = \"^(feature|fix)/\"\n")?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["checkout", "-b", "misc/cleanup"])
+            .env("XDG_CONFIG_HOME", config_home.path())
+            .current_dir(&repo.path)
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(
+                "Branch name 'misc/cleanup' does not match the required pattern",
+            ));
+
+        assert!(!GitRepo::open(&repo.path)?.branch_exists("misc/cleanup"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_gco_create_branch_uses_repo_base_branch() -> anyhow::Result<()> {
+        let repo = setup_conflicting_branch()?;
+        run_git(&repo.path, &["checkout", "--", "initial.txt"])?;
+        let config_home = write_global_config("[branch]\npattern = \"^feature/\"\n")?;
+
+        // Repo settings override the global pattern and set a base branch
+        create_file(
+            &repo.path,
+            ".git-navigator.toml",
+            "[branch]\nbase = \"feature\"\npattern = \"^topic/\"\n",
+        )?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["checkout", "-b", "topic/x"])
+            .env("XDG_CONFIG_HOME", config_home.path())
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("from 'feature'"));
+
+        let content = std::fs::read_to_string(repo.path.join("initial.txt"))?;
+        assert_eq!(content, "feature content\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_gco_commit_detaches_at_log_index() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "second.txt", "second")?;
+        git_add(&repo.path, "second.txt")?;
+        git_commit(&repo.path, "Second commit")?;
+
+        let navigator = |args: &[&str]| -> anyhow::Result<Command> {
+            let mut cmd = Command::cargo_bin("git-navigator")?;
+            cmd.args(args)
+                .env("XDG_CACHE_HOME", cache.path())
+                .current_dir(&repo.path);
+            Ok(cmd)
+        };
+
+        navigator(&["checkout", "--commit", "2"])?
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Commit index 2 not found"));
+
+        navigator(&["log"])?.assert().success();
+        navigator(&["checkout", "--commit", "2"])?
+            .assert()
+            .success()
+            .stdout(predicate::str::is_match(
+                r"HEAD is now at [0-9a-f]{7} Initial commit \(detached\)",
+            )?);
+
+        let git_repo = GitRepo::open(&repo.path)?;
+        assert!(git_repo.get_detached_head()?.is_some());
+        assert_eq!(git_repo.get_parent_commit_info()?.1, "Initial commit");
+        assert!(!repo.path.join("second.txt").exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_gco_index_from_ref() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "initial.txt", "second version\n")?;
+        git_add(&repo.path, "initial.txt")?;
+        git_commit(&repo.path, "Second version")?;
+        create_file(&repo.path, "initial.txt", "work in progress\n")?;
+        create_file(&repo.path, "scratch.txt", "notes\n")?;
+        let cache = tempfile::TempDir::new()?;
+        let navigator = |args: &[&str]| -> anyhow::Result<Command> {
+            let mut cmd = Command::cargo_bin("git-navigator")?;
+            cmd.args(args)
+                .env("XDG_CACHE_HOME", cache.path())
+                .current_dir(&repo.path);
+            Ok(cmd)
+        };
+        navigator(&["status"])?.assert().success();
+
+        navigator(&["checkout", "1", "HEAD~1"])?
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "Checked out 1 file(s) as of HEAD~1; the content is staged.",
+            ));
+        assert_eq!(
+            std::fs::read_to_string(repo.path.join("initial.txt"))?,
+            "initial content\n"
+        );
+
+        navigator(&["status"])?.assert().success();
+        navigator(&["checkout", "2", "HEAD~1"])?
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(
+                "'scratch.txt' does not exist in HEAD~1",
+            ));
+
+        navigator(&["checkout", "1", "no-such-ref"])?
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(
+                "Cannot mix branch names and file indices",
+            ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_gco_branch_named_like_a_directory() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        std::fs::create_dir(repo.path.join("docs"))?;
+        create_file(&repo.path, "docs/guide.md", "guide\n")?;
+        git_add(&repo.path, "docs/guide.md")?;
+        git_commit(&repo.path, "Add docs")?;
+        let git_repo = GitRepo::open(&repo.path)?;
+        git_repo.create_branch("docs")?;
+        git_repo.checkout_branch("main")?;
+
+        // The branch wins over the directory of the same name
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["checkout", "docs"])
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "Successfully switched to branch 'docs'",
+            ));
+        Ok(())
+    }
+
     // Note: is_numeric_index is a private function, so we test it through the public API
     // by testing the behavior differences between numeric and branch arguments
 }
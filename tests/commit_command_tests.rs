@@ -0,0 +1,293 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::path::Path;
+use std::process::Command;
+
+mod common;
+use common::repository::*;
+
+fn navigator(repo_path: &Path, cache_home: &Path, args: &[&str]) -> Command {
+    let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+    cmd.args(args)
+        .current_dir(repo_path)
+        .env("XDG_CACHE_HOME", cache_home);
+    cmd
+}
+
+fn head_message(repo_path: &Path) -> anyhow::Result<String> {
+    let output = Command::new("git")
+        .args(["log", "-1", "--format=%s"])
+        .current_dir(repo_path)
+        .output()?;
+    Ok(String::from_utf8(output.stdout)?.trim().to_string())
+}
+
+fn staged_names(repo_path: &Path, index_file: Option<&str>) -> anyhow::Result<String> {
+    let mut cmd = Command::new("git");
+    cmd.args(["diff", "--cached", "--name-only"])
+        .current_dir(repo_path);
+    if let Some(index_file) = index_file {
+        cmd.env("GIT_INDEX_FILE", index_file);
+    }
+    Ok(String::from_utf8(cmd.output()?.stdout)?.trim().to_string())
+}
+
+#[cfg(test)]
+mod commit_command_tests {
+    use super::*;
+
+    #[test]
+    fn test_commit_with_type_and_scope() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "a.txt", "a\n")?;
+        git_add(&repo.path, "a.txt")?;
+
+        navigator(
+            &repo.path,
+            cache.path(),
+            &[
+                "commit",
+                "--type",
+                "feat",
+                "--scope",
+                "core",
+                "--breaking",
+                "-m",
+                "drop the old cache format",
+            ],
+        )
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(
+            r"Committed [0-9a-f]+: feat\(core\)!: drop the old cache format",
+        )?);
+
+        assert_eq!(
+            head_message(&repo.path)?,
+            "feat(core)!: drop the old cache format"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_commit_rejects_unconfigured_type_and_scope() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+        let config_dir = repo.path.join(".git").join("git-navigator");
+        std::fs::create_dir_all(&config_dir)?;
+        std::fs::write(
+            config_dir.join("config.toml"),
+            "[commit]\ntypes = [\"feat\", \"fix\"]\nscopes = [\"core\", \"cli\"]\n",
+        )?;
+        create_file(&repo.path, "a.txt", "a\n")?;
+        git_add(&repo.path, "a.txt")?;
+
+        navigator(
+            &repo.path,
+            cache.path(),
+            &["commit", "--type", "chore", "--scope", "docs", "-m", "x"],
+        )
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "unknown type 'chore' (allowed: feat, fix)",
+        ))
+        .stderr(predicate::str::contains(
+            "unknown scope 'docs' (allowed: core, cli)",
+        ));
+        assert_eq!(head_message(&repo.path)?, "Initial commit");
+
+        // --no-lint commits anyway
+        navigator(
+            &repo.path,
+            cache.path(),
+            &[
+                "commit",
+                "--type",
+                "chore",
+                "--scope",
+                "docs",
+                "-m",
+                "x",
+                "--no-lint",
+            ],
+        )
+        .assert()
+        .success();
+        assert_eq!(head_message(&repo.path)?, "chore(docs): x");
+        Ok(())
+    }
+
+    #[test]
+    fn test_commit_plain_message_is_linted() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "a.txt", "a\n")?;
+        git_add(&repo.path, "a.txt")?;
+
+        let long = "x".repeat(80);
+        navigator(&repo.path, cache.path(), &["commit", "-m", &long])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(
+                "the subject line is 80 characters long (limit 72)",
+            ));
+
+        // Plain messages need not be conventional unless commit.conventional is set
+        navigator(&repo.path, cache.path(), &["commit", "-m", "Add a.txt"])
+            .assert()
+            .success();
+        assert_eq!(head_message(&repo.path)?, "Add a.txt");
+        Ok(())
+    }
+
+    #[test]
+    fn test_commit_errors() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+
+        navigator(&repo.path, cache.path(), &["commit", "-m", "Nothing"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("nothing is staged"));
+
+        create_file(&repo.path, "a.txt", "a\n")?;
+        git_add(&repo.path, "a.txt")?;
+        navigator(&repo.path, cache.path(), &["commit", "--type", "feat"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("--type needs a description"));
+        navigator(&repo.path, cache.path(), &["commit", "-i"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("no terminal to ask"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_commit_selected_indices_only() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "staged.txt", "staged\n")?;
+        git_add(&repo.path, "staged.txt")?;
+        create_file(&repo.path, "a.txt", "a\n")?;
+        create_file(&repo.path, "b.txt", "b\n")?;
+
+        // [1] staged.txt, [2] a.txt, [3] b.txt
+        navigator(&repo.path, cache.path(), &["status"])
+            .assert()
+            .success();
+        navigator(&repo.path, cache.path(), &["commit", "2", "-m", "Add a"])
+            .assert()
+            .success()
+            .stdout(predicate::str::is_match(
+                r"Committed 1 file\(s\) as [0-9a-f]+: Add a",
+            )?);
+
+        let git = |args: &[&str]| -> anyhow::Result<String> {
+            let output = Command::new("git")
+                .args(args)
+                .current_dir(&repo.path)
+                .output()?;
+            Ok(String::from_utf8(output.stdout)?)
+        };
+        assert_eq!(head_message(&repo.path)?, "Add a");
+        assert_eq!(
+            git(&["show", "--format=", "--name-only", "HEAD"])?,
+            "a.txt\n"
+        );
+        assert_eq!(git(&["diff", "--cached", "--name-only"])?, "staged.txt\n");
+        assert_eq!(
+            git(&["status", "--porcelain"])?,
+            "A  staged.txt\n?? b.txt\n"
+        );
+
+        navigator(&repo.path, cache.path(), &["commit", "9", "-m", "Nope"])
+            .assert()
+            .failure();
+        Ok(())
+    }
+
+    #[test]
+    fn test_commit_rename_by_index() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+        run_git(&repo.path, &["mv", "initial.txt", "renamed.txt"])?;
+
+        navigator(&repo.path, cache.path(), &["status"])
+            .assert()
+            .success();
+        navigator(&repo.path, cache.path(), &["commit", "1", "-m", "Rename"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Committed 1 file(s)"));
+
+        // Both ends of the rename went into the commit
+        let git = |args: &[&str]| -> anyhow::Result<String> {
+            let output = Command::new("git")
+                .args(args)
+                .current_dir(&repo.path)
+                .output()?;
+            Ok(String::from_utf8(output.stdout)?)
+        };
+        assert_eq!(
+            git(&["ls-tree", "-r", "--name-only", "HEAD"])?,
+            "renamed.txt\n"
+        );
+        assert_eq!(git(&["status", "--porcelain"])?, "");
+        Ok(())
+    }
+
+    #[test]
+    fn test_git_index_file_keeps_main_index_untouched() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+        std::fs::copy(
+            repo.path.join(".git/index"),
+            repo.path.join(".git/alt-index"),
+        )?;
+        create_file(&repo.path, "initial.txt", "changed\n")?;
+
+        // A relative path is taken from the worktree root, as git does
+        let alternate = |args: &[&str]| {
+            let mut cmd = navigator(&repo.path, cache.path(), args);
+            cmd.env("GIT_INDEX_FILE", ".git/alt-index");
+            cmd
+        };
+        alternate(&["status"]).assert().success();
+        alternate(&["add", "1"]).assert().success();
+
+        assert_eq!(
+            staged_names(&repo.path, Some(".git/alt-index"))?,
+            "initial.txt"
+        );
+        assert_eq!(staged_names(&repo.path, None)?, "");
+
+        alternate(&["--plain", "status"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "Section: Staged (1)\nIndex 1: initial.txt (modified)",
+            ));
+        navigator(&repo.path, cache.path(), &["--plain", "status"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "Section: Not staged (1)\nIndex 1: initial.txt (modified)",
+            ));
+
+        // Committing from the alternate index, as a hook-driven flow would
+        alternate(&[
+            "commit",
+            "--no-lint",
+            "-m",
+            "Change from the alternate index",
+        ])
+        .assert()
+        .success();
+        assert_eq!(head_message(&repo.path)?, "Change from the alternate index");
+        assert_eq!(staged_names(&repo.path, Some(".git/alt-index"))?, "");
+        Ok(())
+    }
+}
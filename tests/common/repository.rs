@@ -40,7 +40,6 @@ impl TestRepo {
 /// ```rust
 /// use git_navigator_tests::common::setup_test_repo;
 ///
-/// #[test]
 /// fn my_test() -> anyhow::Result<()> {
 ///     let repo = setup_test_repo()?;
 ///     // Use repo.path() for git operations
@@ -48,28 +47,28 @@ impl TestRepo {
 /// }
 /// ```
 pub fn setup_test_repo() -> Result<TestRepo> {
-    let temp_dir = TempDir::new().map_err(|e| GitNavigatorError::Io(e))?;
+    let temp_dir = TempDir::new().map_err(GitNavigatorError::Io)?;
     let repo_path = temp_dir.path().to_path_buf();
 
     // Initialize git repo
     std::process::Command::new("git")
-        .args(["init"])
+        .args(["init", "--initial-branch=main"])
         .current_dir(&repo_path)
         .output()
-        .map_err(|e| GitNavigatorError::Io(e))?;
+        .map_err(GitNavigatorError::Io)?;
 
     // Set git config to avoid prompts during tests
     std::process::Command::new("git")
         .args(["config", "user.name", "Test User"])
         .current_dir(&repo_path)
         .output()
-        .map_err(|e| GitNavigatorError::Io(e))?;
+        .map_err(GitNavigatorError::Io)?;
 
     std::process::Command::new("git")
         .args(["config", "user.email", "test@example.com"])
         .current_dir(&repo_path)
         .output()
-        .map_err(|e| GitNavigatorError::Io(e))?;
+        .map_err(GitNavigatorError::Io)?;
 
     Ok(TestRepo {
         temp_dir,
@@ -104,7 +103,7 @@ pub fn setup_test_repo_with_initial_commit() -> Result<TestRepo> {
 /// * `filename` - Name of the file to create
 /// * `content` - Content to write to the file
 pub fn create_file(repo_path: &Path, filename: &str, content: &str) -> Result<()> {
-    fs::write(repo_path.join(filename), content).map_err(|e| GitNavigatorError::Io(e))?;
+    fs::write(repo_path.join(filename), content).map_err(GitNavigatorError::Io)?;
     Ok(())
 }
 
@@ -119,7 +118,7 @@ pub fn git_add(repo_path: &Path, filename: &str) -> Result<()> {
         .args(["add", filename])
         .current_dir(repo_path)
         .output()
-        .map_err(|e| GitNavigatorError::Io(e))?;
+        .map_err(GitNavigatorError::Io)?;
     Ok(())
 }
 
@@ -134,7 +133,22 @@ pub fn git_commit(repo_path: &Path, message: &str) -> Result<()> {
         .args(["commit", "-m", message])
         .current_dir(repo_path)
         .output()
-        .map_err(|e| GitNavigatorError::Io(e))?;
+        .map_err(GitNavigatorError::Io)?;
+    Ok(())
+}
+
+/// Runs an arbitrary git command in the repository
+///
+/// # Arguments
+///
+/// * `repo_path` - Path to the repository
+/// * `args` - Arguments passed to git (e.g. `["checkout", "--detach"]`)
+pub fn run_git(repo_path: &Path, args: &[&str]) -> Result<()> {
+    std::process::Command::new("git")
+        .args(args)
+        .current_dir(repo_path)
+        .output()
+        .map_err(GitNavigatorError::Io)?;
     Ok(())
 }
 
@@ -145,7 +159,7 @@ pub fn git_commit(repo_path: &Path, message: &str) -> Result<()> {
 /// * `repo_path` - Path to the repository
 /// * `filename` - Name of the file to remove
 pub fn remove_file(repo_path: &Path, filename: &str) -> Result<()> {
-    fs::remove_file(repo_path.join(filename)).map_err(|e| GitNavigatorError::Io(e))?;
+    fs::remove_file(repo_path.join(filename)).map_err(GitNavigatorError::Io)?;
     Ok(())
 }
 
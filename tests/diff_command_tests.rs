@@ -18,7 +18,7 @@ mod diff_command_tests {
             .current_dir(&repo.path)
             .assert()
             .failure()
-            .stdout(
+            .stderr(
                 predicate::str::contains("No file indices provided").or(assertions::cache_error()),
             );
 
@@ -35,7 +35,7 @@ mod diff_command_tests {
             .current_dir(&repo.path)
             .assert()
             .failure()
-            .stdout(predicate::str::contains("Error"));
+            .stderr(predicate::str::contains("Error"));
 
         Ok(())
     }
@@ -54,7 +54,7 @@ mod diff_command_tests {
             .current_dir(non_repo_path)
             .assert()
             .failure()
-            .stdout(assertions::not_in_git_repo());
+            .stderr(assertions::not_in_git_repo());
 
         Ok(())
     }
@@ -206,8 +206,77 @@ mod diff_command_tests {
             .current_dir(&repo.path)
             .assert()
             .failure()
-            .stdout(predicate::str::contains("Error"));
+            .stderr(predicate::str::contains("Error"));
 
         Ok(())
     }
+
+    #[test]
+    fn test_gd_through_diff_tool() -> anyhow::Result<()> {
+        let repo = setup_test_repo()?;
+        create_file(&repo.path, "file1.txt", "initial content\n")?;
+        git_add(&repo.path, "file1.txt")?;
+        git_commit(&repo.path, "Initial commit")?;
+        create_file(&repo.path, "file1.txt", "modified content\n")?;
+        run_status_to_cache(&repo.path)?;
+
+        // A configured filter sees git's diff on stdin
+        std::fs::create_dir_all(repo.path.join(".git/git-navigator"))?;
+        std::fs::write(
+            repo.path.join(".git/git-navigator/config.toml"),
+            "[diff]\ntool = \"sed 's/^+/ADDED: /'\"\n",
+        )?;
+        let gd = |args: &[&str]| {
+            let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+            cmd.arg("diff").args(args).current_dir(&repo.path);
+            cmd
+        };
+        gd(&["1"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("ADDED: modified content"));
+
+        // --tool replaces it, and none shows git's own diff
+        gd(&["--tool", "tr a-z A-Z", "1"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("+MODIFIED CONTENT"));
+        gd(&["--tool", "none", "1"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("+modified content"));
+
+        gd(&["--tool", "exit 4", "1"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Diff tool 'exit 4' failed"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_gd_stat() -> anyhow::Result<()> {
+        let repo = setup_test_repo()?;
+        create_file(&repo.path, "file1.txt", "one\ntwo\nthree\n")?;
+        git_add(&repo.path, "file1.txt")?;
+        git_commit(&repo.path, "Initial commit")?;
+        create_file(&repo.path, "file1.txt", "one\n2\nthree\nfour\n")?;
+        create_file(&repo.path, "staged.txt", "new\n")?;
+        git_add(&repo.path, "staged.txt")?;
+        create_file(&repo.path, "untracked.txt", "a\nb\n")?;
+        run_status_to_cache(&repo.path)?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["diff", "--stat", "1-3"])
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::is_match(r"file1\.txt\s+\| 3 \+\+-")?)
+            .stdout(predicate::str::is_match(r"staged\.txt\s+\| 1 \+\n")?)
+            .stdout(predicate::str::is_match(r"untracked\.txt \| 2 \+\+\n")?)
+            .stdout(predicate::str::contains(
+                "3 files changed, 5 insertions(+), 1 deletion(-)",
+            ))
+            .stdout(predicate::str::contains("@@").not());
+        Ok(())
+    }
 }
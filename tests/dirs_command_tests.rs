@@ -0,0 +1,77 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::process::Command;
+
+mod common;
+use common::{assertions, repository::*};
+
+#[cfg(test)]
+mod dirs_command_tests {
+    use super::*;
+
+    #[test]
+    fn test_dirs_lists_and_resolves_changed_directories() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        std::fs::create_dir_all(repo.path.join("src/core"))?;
+        create_file(&repo.path, "src/core/a.rs", "a\n")?;
+        create_file(&repo.path, "src/core/b.rs", "b\n")?;
+        run_git(&repo.path, &["add", "."])?;
+        git_commit(&repo.path, "Add sources")?;
+        create_file(&repo.path, "src/core/a.rs", "changed a\n")?;
+        create_file(&repo.path, "src/core/b.rs", "changed b\n")?;
+        create_file(&repo.path, "initial.txt", "changed\n")?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.arg("dirs")
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("[1] . (1 file)"))
+            .stdout(predicate::str::contains("[2] src/core (2 files)"));
+
+        let output = Command::cargo_bin("git-navigator")?
+            .args(["dirs", "2"])
+            .current_dir(&repo.path)
+            .output()?;
+        assert!(output.status.success());
+        let printed = String::from_utf8(output.stdout)?;
+        assert_eq!(
+            std::fs::canonicalize(printed.trim())?,
+            std::fs::canonicalize(repo.path.join("src/core"))?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dirs_invalid_index_fails() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "initial.txt", "changed\n")?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.arg("dirs").current_dir(&repo.path).assert().success();
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["dirs", "5"])
+            .current_dir(&repo.path)
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Directory index 5 not found"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dirs_not_in_git_repo() -> anyhow::Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.arg("dirs")
+            .current_dir(temp_dir.path())
+            .assert()
+            .failure()
+            .stderr(assertions::not_in_git_repo());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,125 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::path::Path;
+use std::process::Command;
+
+mod common;
+use common::repository::*;
+
+fn doctor(repo_path: &Path, args: &[&str]) -> Command {
+    let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+    cmd.arg("doctor").args(args).current_dir(repo_path);
+    cmd
+}
+
+#[cfg(test)]
+mod doctor_command_tests {
+    use super::*;
+
+    #[test]
+    fn test_doctor_checks_each_remote() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let remote = tempfile::TempDir::new()?;
+        run_git(remote.path(), &["init", "--bare", "--quiet"])?;
+        run_git(
+            &repo.path,
+            &["remote", "add", "origin", &remote.path().to_string_lossy()],
+        )?;
+        run_git(&repo.path, &["push", "--quiet", "origin", "main"])?;
+
+        doctor(&repo.path, &[])
+            .env("GIT_NAVIGATOR_TOKEN", "secret")
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Credentials"))
+            .stdout(predicate::str::contains(
+                "tokens:            GIT_NAVIGATOR_TOKEN",
+            ))
+            .stdout(predicate::str::contains(
+                "origin: access OK (1 refs advertised)",
+            ))
+            .stdout(predicate::str::contains("secret").not());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_doctor_reports_missing_remote() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let missing = tempfile::TempDir::new()?;
+        run_git(
+            &repo.path,
+            &[
+                "remote",
+                "add",
+                "origin",
+                &missing.path().join("gone.git").to_string_lossy(),
+            ],
+        )?;
+
+        doctor(&repo.path, &["nope"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("nope: No remote named 'nope'"))
+            .stderr(predicate::str::contains(
+                "1 of 1 remote(s) failed the credentials check",
+            ));
+
+        doctor(&repo.path, &[])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Cannot access remote 'origin'"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_doctor_without_remotes() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+
+        doctor(&repo.path, &[])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("No remotes configured"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_doctor_shows_cache_ages() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache_home = tempfile::TempDir::new()?;
+        std::fs::create_dir_all(repo.path.join(".git/git-navigator"))?;
+        std::fs::write(
+            repo.path.join(".git/git-navigator/config.toml"),
+            "[cache]\nttl_minutes = 30\n",
+        )?;
+
+        doctor(&repo.path, &[])
+            .env("XDG_CACHE_HOME", cache_home.path())
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "ttl:               30m (then listed again)",
+            ))
+            .stdout(predicate::str::contains("files:             none yet"));
+
+        create_file(&repo.path, "new.txt", "new\n")?;
+        Command::cargo_bin("git-navigator")?
+            .arg("status")
+            .env("XDG_CACHE_HOME", cache_home.path())
+            .current_dir(&repo.path)
+            .assert()
+            .success();
+
+        doctor(&repo.path, &[])
+            .env("XDG_CACHE_HOME", cache_home.path())
+            .assert()
+            .success()
+            .stdout(predicate::str::is_match(
+                r"files\.json:\s+updated \d+s ago\n",
+            )?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::process::Command;
+
+mod common;
+use common::repository::*;
+
+#[cfg(test)]
+mod edit_command_tests {
+    use super::*;
+
+    fn setup_changed_file() -> anyhow::Result<TestRepo> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "notes.txt", "one\ntwo\nthree\nfour\n")?;
+        git_add(&repo.path, "notes.txt")?;
+        git_commit(&repo.path, "Add notes")?;
+        create_file(&repo.path, "notes.txt", "one\ntwo\nthree\nFOUR\n")?;
+        run_status_to_cache(&repo.path)?;
+        Ok(repo)
+    }
+
+    #[test]
+    fn test_edit_at_change_passes_first_changed_line() -> anyhow::Result<()> {
+        let repo = setup_changed_file()?;
+
+        // `echo` stands in for the editor and prints the arguments it got
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["edit", "1", "--at-change"])
+            .env("GIT_EDITOR", "echo")
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::is_match(r"^\+4 .*notes\.txt\n$")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_edit_uses_configured_line_syntax() -> anyhow::Result<()> {
+        let repo = setup_changed_file()?;
+        let config_home = tempfile::TempDir::new()?;
+        std::fs::create_dir_all(config_home.path().join("git-navigator"))?;
+        std::fs::write(
+            config_home.path().join("git-navigator/config.toml"),
+            "[editor.line_syntax]\necho = \"--goto {file}:{line}\"\n",
+        )?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["edit", "1", "--at-change"])
+            .env("GIT_EDITOR", "echo")
+            .env("XDG_CONFIG_HOME", config_home.path())
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::is_match(r"^--goto .*notes\.txt:4\n$")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_edit_without_at_change_opens_file_only() -> anyhow::Result<()> {
+        let repo = setup_changed_file()?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["edit", "1"])
+            .env("GIT_EDITOR", "echo")
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::is_match(r"^/.*notes\.txt\n$")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_edit_opens_several_files_in_one_editor() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "a.txt", "a\n")?;
+        create_file(&repo.path, "b.txt", "b\n")?;
+        run_status_to_cache(&repo.path)?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["edit", "-n", "7", "1-2"])
+            .env("GIT_EDITOR", "echo")
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::is_match(
+                r"^\+7 /.*a\.txt \+7 /.*b\.txt\n$",
+            )?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_edit_opens_unmerged_file_at_first_conflict() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "notes.txt", "one\ntwo\nthree\n")?;
+        git_add(&repo.path, "notes.txt")?;
+        git_commit(&repo.path, "Add notes")?;
+        run_git(&repo.path, &["checkout", "-q", "-b", "feature"])?;
+        create_file(&repo.path, "notes.txt", "one\ntwo\nTHREE\n")?;
+        git_add(&repo.path, "notes.txt")?;
+        git_commit(&repo.path, "Shout three")?;
+        run_git(&repo.path, &["checkout", "-q", "-"])?;
+        create_file(&repo.path, "notes.txt", "one\ntwo\n3\n")?;
+        git_add(&repo.path, "notes.txt")?;
+        git_commit(&repo.path, "Number three")?;
+        run_git(&repo.path, &["merge", "-q", "feature"])?;
+        run_status_to_cache(&repo.path)?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["edit", "1"])
+            .env("GIT_EDITOR", "echo")
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::is_match(r"^\+3 /.*notes\.txt\n$")?);
+
+        Ok(())
+    }
+}
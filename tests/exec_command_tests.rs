@@ -0,0 +1,67 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::process::Command;
+
+mod common;
+use common::repository::*;
+
+#[cfg(test)]
+mod exec_command_tests {
+    use super::*;
+
+    #[test]
+    fn test_exec_substitutes_placeholders_in_repo_root() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        std::fs::create_dir_all(repo.path.join("src"))?;
+        create_file(&repo.path, "a.txt", "a\n")?;
+        create_file(&repo.path, "src/b.txt", "b\n")?;
+        git_add(&repo.path, "src/b.txt")?;
+        run_status_to_cache(&repo.path)?;
+
+        // Run from a subdirectory: the paths still work, from the root
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["exec", "--", "cp", "{1}", "{2}.copy"])
+            .current_dir(repo.path.join("src"))
+            .assert()
+            .success();
+        assert_eq!(
+            std::fs::read_to_string(repo.path.join("a.txt.copy"))?,
+            "b\n"
+        );
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["exec", "--", "cat", "{3}"])
+            .current_dir(&repo.path)
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("out of range"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_exec_appends_selected_paths() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "a.txt", "a\n")?;
+        create_file(&repo.path, "b.txt", "b\n")?;
+        run_status_to_cache(&repo.path)?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["exec", "1-2", "--", "echo", "files:"])
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout("files: a.txt b.txt\n");
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["exec", "1", "--", "false"])
+            .current_dir(&repo.path)
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(
+                "Command 'false' failed: exited with",
+            ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::process::Command;
+
+mod common;
+use common::repository::*;
+
+#[cfg(test)]
+mod init_command_tests {
+    use super::*;
+
+    #[test]
+    fn test_init_bash_defines_functions() -> anyhow::Result<()> {
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        let output = cmd.args(["init", "bash"]).output()?;
+        assert!(output.status.success());
+        let script = String::from_utf8(output.stdout)?;
+
+        // The script must load cleanly even over existing aliases
+        let defined = Command::new("bash")
+            .args([
+                "-c",
+                "alias gs='git status'; eval \"$1\"; type -t gs; type -t gcd",
+                "bash",
+                &script,
+            ])
+            .output()?;
+        assert!(defined.status.success());
+        assert_eq!(String::from_utf8(defined.stdout)?, "function\nfunction\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_init_fish() -> anyhow::Result<()> {
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["init", "fish"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "function gs --wraps 'git-navigator status'",
+            ))
+            .stdout(predicate::str::contains("git-navigator status $argv"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_init_unknown_shell() -> anyhow::Result<()> {
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["init", "tcsh"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("invalid value 'tcsh'"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_init_export_env_sets_numbered_variables() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache_home = tempfile::TempDir::new()?;
+        create_file(&repo.path, "notes.txt", "draft\n")?;
+        let binary = assert_cmd::cargo::cargo_bin("git-navigator");
+        let path = format!(
+            "{}:{}",
+            binary.parent().unwrap().display(),
+            std::env::var("PATH").unwrap_or_default()
+        );
+
+        // $e2 and $e3 from an earlier, longer list go away
+        let output = Command::new("bash")
+            .args([
+                "-c",
+                "eval \"$(git-navigator init bash --export-env)\"; export e2=old e3=old; gs >/dev/null; echo \"e1=$e1 e3=${e3-unset}\"",
+            ])
+            .current_dir(&repo.path)
+            .env("PATH", path)
+            .env("XDG_CACHE_HOME", cache_home.path())
+            .output()?;
+        assert!(output.status.success());
+        let expected = format!("e1={} e3=unset\n", repo.path.join("notes.txt").display());
+        assert_eq!(String::from_utf8(output.stdout)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_usage_names_the_configured_alias() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache_home = tempfile::TempDir::new()?;
+        let config_home = tempfile::TempDir::new()?;
+        create_file(&repo.path, "notes.txt", "draft\n")?;
+        let settings_dir = config_home.path().join("git-navigator");
+        std::fs::create_dir_all(&settings_dir)?;
+        std::fs::write(
+            settings_dir.join("config.toml"),
+            "[aliases]\nadd = \"stage\"\n",
+        )?;
+        let binary = assert_cmd::cargo::cargo_bin("git-navigator");
+        let path = format!(
+            "{}:{}",
+            binary.parent().unwrap().display(),
+            std::env::var("PATH").unwrap_or_default()
+        );
+
+        let output = Command::new("bash")
+            .args([
+                "-c",
+                "eval \"$(git-navigator init bash)\"; gs >/dev/null; stage; git-navigator add",
+            ])
+            .current_dir(&repo.path)
+            .env("PATH", path)
+            .env("XDG_CACHE_HOME", cache_home.path())
+            .env("XDG_CONFIG_HOME", config_home.path())
+            .env_remove("GIT_NAVIGATOR_ALIAS")
+            .output()?;
+        let stderr = String::from_utf8(output.stderr)?;
+        assert!(stderr.contains("  stage <index>..."), "{stderr}");
+        assert!(
+            stderr.contains("  git-navigator add <index>..."),
+            "{stderr}"
+        );
+        assert!(!stderr.contains("ga <index>"), "{stderr}");
+        Ok(())
+    }
+}
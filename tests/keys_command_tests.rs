@@ -0,0 +1,74 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::path::Path;
+use std::process::Command;
+
+mod common;
+use common::repository::*;
+
+fn navigator(repo_path: &Path, cache_home: &Path, args: &[&str]) -> Command {
+    let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+    cmd.args(args)
+        .current_dir(repo_path)
+        .env("XDG_CACHE_HOME", cache_home);
+    cmd
+}
+
+#[cfg(test)]
+mod keys_command_tests {
+    use super::*;
+
+    #[test]
+    fn test_keys_reports_cached_numbers() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache_home = tempfile::TempDir::new()?;
+        create_file(&repo.path, "notes.txt", "draft\n")?;
+
+        // Nothing listed yet
+        navigator(&repo.path, cache_home.path(), &["keys"])
+            .assert()
+            .success()
+            .stdout("");
+
+        navigator(&repo.path, cache_home.path(), &["status"])
+            .assert()
+            .success();
+        create_file(&repo.path, "later.txt", "not listed yet\n")?;
+        navigator(&repo.path, cache_home.path(), &["keys"])
+            .assert()
+            .success()
+            .stdout("file\t1\tuntracked\tnotes.txt\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_keys_refresh_json() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache_home = tempfile::TempDir::new()?;
+        create_file(&repo.path, "notes.txt", "draft\n")?;
+        run_git(&repo.path, &["branch", "feature"])?;
+
+        let output = navigator(
+            &repo.path,
+            cache_home.path(),
+            &["keys", "--refresh", "--format", "json"],
+        )
+        .output()?;
+        assert!(output.status.success());
+        let keys: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+        assert_eq!(keys["files"][0]["index"], 1);
+        assert_eq!(keys["files"][0]["path"], "notes.txt");
+        assert!(keys["branches"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|branch| branch["name"] == "feature"));
+
+        // The refreshed numbers are the ones ga resolves
+        navigator(&repo.path, cache_home.path(), &["--plain", "add", "1"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Index 1: notes.txt (new)"));
+        Ok(())
+    }
+}
@@ -0,0 +1,355 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::path::Path;
+use std::process::Command;
+
+mod common;
+use common::repository::*;
+
+fn navigator(repo_path: &Path, cache_home: &Path, args: &[&str]) -> Command {
+    let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+    cmd.args(args)
+        .current_dir(repo_path)
+        .env("XDG_CACHE_HOME", cache_home);
+    cmd
+}
+
+/// Commit a new file as the given author
+fn commit_as(repo_path: &Path, name: &str, email: &str, file: &str) -> anyhow::Result<()> {
+    create_file(repo_path, file, "content\n")?;
+    git_add(repo_path, file)?;
+    run_git(
+        repo_path,
+        &[
+            "-c",
+            &format!("user.name={name}"),
+            "-c",
+            &format!("user.email={email}"),
+            "commit",
+            "--quiet",
+            "-m",
+            &format!("Add {file}"),
+        ],
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod log_command_tests {
+    use super::*;
+
+    /// Test User made the initial commit, Ada two more and Grace one
+    fn repo_with_authors() -> anyhow::Result<TestRepo> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        commit_as(&repo.path, "Ada", "ada@example.com", "engine.rs")?;
+        commit_as(&repo.path, "Grace", "grace@example.com", "compiler.rs")?;
+        commit_as(&repo.path, "Ada", "ada@example.com", "notes.md")?;
+        Ok(repo)
+    }
+
+    #[test]
+    fn test_log_lists_numbered_commits() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = repo_with_authors()?;
+
+        navigator(&repo.path, cache.path(), &["log"])
+            .assert()
+            .success()
+            .stdout(predicate::str::is_match(
+                r"\[1\] [0-9a-f]+ Add notes\.md \(\d+s ago\) <Ada>",
+            )?)
+            .stdout(predicate::str::contains("[4] "));
+
+        navigator(&repo.path, cache.path(), &["--plain", "log", "-n", "2"])
+            .assert()
+            .success()
+            .stdout(predicate::str::is_match(
+                r"Index 2: [0-9a-f]+ Add compiler\.rs \(\d+s ago, Grace\)",
+            )?)
+            .stdout(predicate::str::contains("Index 3:").not());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_log_links_issue_references() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let config_home = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "a.txt", "a\n")?;
+        git_add(&repo.path, "a.txt")?;
+        git_commit(&repo.path, "Fix crash (#12)")?;
+
+        std::fs::create_dir_all(config_home.path().join("git-navigator"))?;
+        std::fs::write(
+            config_home.path().join("git-navigator/config.toml"),
+            "[ui]\nhyperlinks = true\n[issues]\npattern = \"#([0-9]+)\"\nurl = \"https://github.com/o/r/issues/{issue}\"\n",
+        )?;
+        navigator(&repo.path, cache.path(), &["log"])
+            .env("XDG_CONFIG_HOME", config_home.path())
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "Fix crash (\x1b]8;;https://github.com/o/r/issues/12\x1b\\#12\x1b]8;;\x1b\\)",
+            ));
+
+        // Plain output never carries links
+        navigator(&repo.path, cache.path(), &["--plain", "log"])
+            .env("XDG_CONFIG_HOME", config_home.path())
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Fix crash (#12)"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_contributors_feed_log_author() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = repo_with_authors()?;
+
+        navigator(&repo.path, cache.path(), &["--plain", "contributors"])
+            .assert()
+            .success()
+            .stdout(predicate::str::is_match(
+                r"Index 1: Ada <ada@example\.com>, 2 commits, last commit \d+s ago",
+            )?)
+            .stdout(predicate::str::contains(
+                "Index 3: Test User <test@example.com>, 1 commit",
+            ));
+
+        // Index 2 is Grace: ties on commit count go by recency, then name
+        navigator(
+            &repo.path,
+            cache.path(),
+            &["--plain", "log", "--author", "2"],
+        )
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Add compiler.rs"))
+        .stdout(predicate::str::contains("Add engine.rs").not())
+        .stdout(predicate::str::contains("Index 2:").not());
+
+        navigator(
+            &repo.path,
+            cache.path(),
+            &["--plain", "log", "--author", "ADA@"],
+        )
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("Index 2: ").and(predicate::str::contains("Add engine.rs")),
+        );
+
+        navigator(&repo.path, cache.path(), &["log", "--author", "9"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Contributor index 9 not found"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_contributors_since() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "old.txt", "old\n")?;
+        git_add(&repo.path, "old.txt")?;
+        run_git(
+            &repo.path,
+            &[
+                "-c",
+                "user.name=Old Timer",
+                "-c",
+                "user.email=old@example.com",
+                "commit",
+                "--quiet",
+                "--date=2001-01-01T00:00:00",
+                "-m",
+                "Ancient history",
+            ],
+        )?;
+
+        navigator(&repo.path, cache.path(), &["contributors"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Old Timer"));
+
+        navigator(
+            &repo.path,
+            cache.path(),
+            &["contributors", "--since", "2 weeks"],
+        )
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Test User"))
+        .stdout(predicate::str::contains("Old Timer").not());
+
+        navigator(
+            &repo.path,
+            cache.path(),
+            &["contributors", "--since", "someday"],
+        )
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Cannot understand date 'someday'"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_log_file_follows_renames() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+        let body = "line one\nline two\nline three\nline four\n";
+        create_file(&repo.path, "a.txt", body)?;
+        git_add(&repo.path, "a.txt")?;
+        git_commit(&repo.path, "Add a")?;
+        create_file(&repo.path, "a.txt", &format!("{body}line five\n"))?;
+        git_add(&repo.path, "a.txt")?;
+        git_commit(&repo.path, "Edit a")?;
+        run_git(&repo.path, &["mv", "a.txt", "b.txt"])?;
+        git_commit(&repo.path, "Rename a to b")?;
+        commit_as(&repo.path, "Ada", "ada@example.com", "other.txt")?;
+
+        // gs lists the modified b.txt as [1]
+        create_file(&repo.path, "b.txt", "changed\n")?;
+        navigator(&repo.path, cache.path(), &["status"])
+            .assert()
+            .success();
+
+        navigator(&repo.path, cache.path(), &["--plain", "log", "--file", "1"])
+            .assert()
+            .success()
+            .stdout(
+                predicate::str::contains("Index 1: ")
+                    .and(predicate::str::contains("Rename a to b")),
+            )
+            .stdout(predicate::str::is_match(r"Index 2: [0-9a-f]+ Edit a")?)
+            .stdout(predicate::str::is_match(r"Index 3: [0-9a-f]+ Add a")?)
+            .stdout(predicate::str::contains("other.txt").not())
+            .stdout(predicate::str::contains("Initial commit").not());
+
+        navigator(&repo.path, cache.path(), &["log", "--file", "7"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Index 7 is out of range"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_log_grep_and_pickaxe() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "parser.rs", "fn parse() {}\n")?;
+        git_add(&repo.path, "parser.rs")?;
+        git_commit(&repo.path, "Add parser")?;
+        create_file(&repo.path, "README.md", "docs\n")?;
+        git_add(&repo.path, "README.md")?;
+        git_commit(&repo.path, "Fix typo in docs")?;
+        create_file(&repo.path, "parser.rs", "fn parse() {}\nfn lex() {}\n")?;
+        git_add(&repo.path, "parser.rs")?;
+        git_commit(&repo.path, "Add lexer")?;
+        create_file(&repo.path, "parser.rs", "fn lex() {}\n")?;
+        git_add(&repo.path, "parser.rs")?;
+        git_commit(&repo.path, "Remove parse helper")?;
+
+        navigator(
+            &repo.path,
+            cache.path(),
+            &["--plain", "log", "--grep", "^Fix"],
+        )
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(
+            r"Index 1: [0-9a-f]+ Fix typo in docs",
+        )?)
+        .stdout(predicate::str::contains("Index 2:").not());
+
+        navigator(
+            &repo.path,
+            cache.path(),
+            &["--plain", "log", "-S", "fn parse()"],
+        )
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(
+            r"Index 1: [0-9a-f]+ Remove parse helper",
+        )?)
+        .stdout(predicate::str::is_match(r"Index 2: [0-9a-f]+ Add parser")?)
+        .stdout(predicate::str::contains("Add lexer").not());
+
+        // The numbers now refer to the pickaxe results
+        navigator(&repo.path, cache.path(), &["show-commit", "1"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Remove parse helper"))
+            .stdout(predicate::str::contains("-fn parse() {}"));
+
+        navigator(&repo.path, cache.path(), &["log", "--grep", "("])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Invalid --grep pattern '('"));
+
+        navigator(&repo.path, cache.path(), &["show-commit", "9"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Commit index 9 not found"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cherry_pick_by_index() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+        commit_as(&repo.path, "Ada", "ada@example.com", "a.txt")?;
+        commit_as(&repo.path, "Ada", "ada@example.com", "b.txt")?;
+
+        navigator(&repo.path, cache.path(), &["log"])
+            .assert()
+            .success();
+        run_git(
+            &repo.path,
+            &["checkout", "--quiet", "-b", "other", "HEAD~2"],
+        )?;
+
+        navigator(&repo.path, cache.path(), &["cherry-pick", "1-2"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Cherry-picked 2 commit(s)"));
+        let output = std::process::Command::new("git")
+            .args(["log", "--format=%s", "-2"])
+            .current_dir(&repo.path)
+            .output()?;
+        assert_eq!(String::from_utf8(output.stdout)?, "Add b.txt\nAdd a.txt\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cherry_pick_conflict() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+        commit_as(&repo.path, "Ada", "ada@example.com", "a.txt")?;
+
+        navigator(&repo.path, cache.path(), &["log"])
+            .assert()
+            .success();
+        run_git(
+            &repo.path,
+            &["checkout", "--quiet", "-b", "other", "HEAD~1"],
+        )?;
+        create_file(&repo.path, "a.txt", "different\n")?;
+        git_add(&repo.path, "a.txt")?;
+        git_commit(&repo.path, "Conflicting a.txt")?;
+
+        navigator(&repo.path, cache.path(), &["cherry-pick", "1"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Cherry-pick stopped"))
+            .stderr(predicate::str::contains("git cherry-pick --continue"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+mod common;
+use common::repository::*;
+
+const PROXY_VARS: [&str; 6] = [
+    "HTTPS_PROXY",
+    "https_proxy",
+    "HTTP_PROXY",
+    "http_proxy",
+    "ALL_PROXY",
+    "all_proxy",
+];
+
+fn navigator(repo_path: &Path, config_home: &Path, args: &[&str]) -> Command {
+    let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+    cmd.args(args)
+        .current_dir(repo_path)
+        .env("XDG_CONFIG_HOME", config_home);
+    for var in PROXY_VARS {
+        cmd.env_remove(var);
+    }
+    cmd
+}
+
+fn write_config(config_home: &Path, contents: &str) -> anyhow::Result<()> {
+    let dir = config_home.join("git-navigator");
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(dir.join("config.toml"), contents)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod network_command_tests {
+    use super::*;
+
+    #[test]
+    fn test_fetch_and_pull_from_local_remote() -> anyhow::Result<()> {
+        let config = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+        let remote = tempfile::TempDir::new()?;
+        run_git(remote.path(), &["init", "--bare", "--quiet"])?;
+        let remote_url = remote.path().to_string_lossy().to_string();
+        run_git(&repo.path, &["remote", "add", "origin", &remote_url])?;
+        run_git(&repo.path, &["push", "--quiet", "-u", "origin", "main"])?;
+
+        // A second clone publishes a commit the first one has not seen
+        let other = tempfile::TempDir::new()?;
+        run_git(
+            other.path(),
+            &["clone", "--quiet", "--branch", "main", &remote_url, "."],
+        )?;
+        run_git(other.path(), &["config", "user.name", "Other"])?;
+        run_git(other.path(), &["config", "user.email", "other@example.com"])?;
+        create_file(other.path(), "other.txt", "other\n")?;
+        git_add(other.path(), "other.txt")?;
+        git_commit(other.path(), "Other work")?;
+        run_git(other.path(), &["push", "--quiet"])?;
+
+        navigator(&repo.path, config.path(), &["fetch"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Fetched origin"));
+        assert!(!repo.path.join("other.txt").exists());
+
+        navigator(&repo.path, config.path(), &["pull"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Pulled origin/main"));
+        assert!(repo.path.join("other.txt").exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pull_without_upstream_fails() -> anyhow::Result<()> {
+        let config = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+
+        navigator(&repo.path, config.path(), &["pull"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("has no upstream"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unreachable_remote_fails_fast() -> anyhow::Result<()> {
+        let config = tempfile::TempDir::new()?;
+        write_config(config.path(), "[network]\ntimeout_ms = 200\n")?;
+        let repo = setup_test_repo_with_initial_commit()?;
+        // TEST-NET-1 is reserved for documentation and never routed
+        run_git(
+            &repo.path,
+            &["remote", "add", "origin", "https://192.0.2.1/repo.git"],
+        )?;
+
+        for command in ["fetch", "push"] {
+            let started = Instant::now();
+            navigator(&repo.path, config.path(), &[command])
+                .assert()
+                .failure()
+                .stderr(predicate::str::contains(
+                    "Network unavailable: cannot reach 192.0.2.1",
+                ))
+                .stderr(predicate::str::contains("network.check = false"));
+            assert!(started.elapsed() < Duration::from_secs(10));
+        }
+
+        Ok(())
+    }
+}
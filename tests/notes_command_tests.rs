@@ -0,0 +1,171 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::path::Path;
+use std::process::Command;
+
+mod common;
+use common::repository::*;
+
+fn navigator(repo_path: &Path, cache_home: &Path, args: &[&str]) -> Command {
+    let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+    cmd.args(args)
+        .current_dir(repo_path)
+        .env("XDG_CACHE_HOME", cache_home);
+    cmd
+}
+
+#[cfg(test)]
+mod notes_command_tests {
+    use super::*;
+
+    #[test]
+    fn test_notes_since_tag_index_and_ref() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+        run_git(&repo.path, &["tag", "v1.0.0"])?;
+        for (file, message) in [
+            ("a.txt", "feat(log): add --grep"),
+            ("b.txt", "fix: handle empty repos"),
+            ("c.txt", "Tidy up"),
+        ] {
+            create_file(&repo.path, file, "content\n")?;
+            git_add(&repo.path, file)?;
+            git_commit(&repo.path, message)?;
+        }
+
+        navigator(&repo.path, cache.path(), &["tags"])
+            .assert()
+            .success();
+        navigator(&repo.path, cache.path(), &["notes", "--since", "1"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("## Changes since v1.0.0"))
+            .stdout(predicate::str::is_match(
+                r"### Features\n\n- \*\*log:\*\* add --grep \([0-9a-f]+\)",
+            )?)
+            .stdout(predicate::str::is_match(
+                r"### Bug Fixes\n\n- handle empty repos \([0-9a-f]+\)",
+            )?)
+            .stdout(predicate::str::is_match(
+                r"### Other\n\n- Tidy up \([0-9a-f]+\)",
+            )?)
+            .stdout(predicate::str::contains("Initial commit").not());
+
+        navigator(&repo.path, cache.path(), &["notes", "--since", "HEAD~1"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("## Changes since HEAD~1"))
+            .stdout(predicate::str::contains("Tidy up"))
+            .stdout(predicate::str::contains("Features").not());
+
+        // The drafted commits are numbered for show-commit, newest first
+        navigator(&repo.path, cache.path(), &["show-commit", "1"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Tidy up"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_notes_errors() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+        run_git(&repo.path, &["tag", "v1.0.0"])?;
+
+        navigator(&repo.path, cache.path(), &["notes", "--since", "v1.0.0"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("No commits since v1.0.0."));
+
+        navigator(&repo.path, cache.path(), &["notes", "--since", "nope"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(
+                "'nope' is not a tag index, tag or commit",
+            ));
+
+        navigator(&repo.path, cache.path(), &["notes", "--since", "4"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Tag index 4 not found"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_commit_notes_by_index() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "a.txt", "content\n")?;
+        git_add(&repo.path, "a.txt")?;
+        git_commit(&repo.path, "Add a")?;
+
+        navigator(&repo.path, cache.path(), &["notes", "list"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("No commits have notes yet"));
+
+        navigator(&repo.path, cache.path(), &["log"])
+            .assert()
+            .success();
+        navigator(
+            &repo.path,
+            cache.path(),
+            &["notes", "add", "2", "-m", "Deployed to staging"],
+        )
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Added a note to"));
+        navigator(
+            &repo.path,
+            cache.path(),
+            &["notes", "add", "2", "-m", "Again"],
+        )
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already has a note"));
+        navigator(
+            &repo.path,
+            cache.path(),
+            &["notes", "--ref", "deploy", "add", "1", "-m", "prod"],
+        )
+        .assert()
+        .success();
+
+        // Only the default ref's note is listed, renumbered for show
+        navigator(&repo.path, cache.path(), &["--plain", "notes", "list"])
+            .assert()
+            .success()
+            .stdout(predicate::str::is_match(
+                r"Index 1: [0-9a-f]+ Initial commit\nDeployed to staging",
+            )?)
+            .stdout(predicate::str::contains("prod").not());
+        navigator(&repo.path, cache.path(), &["notes", "show", "1"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Deployed to staging"));
+        assert_eq!(
+            std::process::Command::new("git")
+                .args(["notes", "--ref", "deploy", "show", "HEAD"])
+                .current_dir(&repo.path)
+                .output()?
+                .stdout,
+            b"prod\n"
+        );
+
+        navigator(&repo.path, cache.path(), &["log"])
+            .assert()
+            .success();
+        navigator(&repo.path, cache.path(), &["notes", "show", "1"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("has no note"));
+        navigator(&repo.path, cache.path(), &["notes", "edit", "1"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("non-interactive"));
+
+        Ok(())
+    }
+}
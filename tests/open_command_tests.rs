@@ -0,0 +1,76 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::process::Command;
+
+mod common;
+use common::repository::*;
+
+#[cfg(test)]
+mod open_command_tests {
+    use super::*;
+
+    fn setup_changed_file(config: &str) -> anyhow::Result<(TestRepo, tempfile::TempDir)> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "notes.txt", "one\ntwo\nthree\n")?;
+        git_add(&repo.path, "notes.txt")?;
+        git_commit(&repo.path, "Add notes")?;
+        create_file(&repo.path, "notes.txt", "one\nTWO\nthree\n")?;
+        run_status_to_cache(&repo.path)?;
+
+        let config_home = tempfile::TempDir::new()?;
+        std::fs::create_dir_all(config_home.path().join("git-navigator"))?;
+        std::fs::write(config_home.path().join("git-navigator/config.toml"), config)?;
+        Ok((repo, config_home))
+    }
+
+    #[test]
+    fn test_open_with_custom_command() -> anyhow::Result<()> {
+        let (repo, config_home) =
+            setup_changed_file("[editors.custom]\ncommand = \"echo {file}:{line}\"\n")?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["open", "1", "--app", "custom"])
+            .env("XDG_CONFIG_HOME", config_home.path())
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::is_match(r"(?m)^/.*notes\.txt:2$")?)
+            .stdout(predicate::str::contains("Opened notes.txt:2 in custom"));
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_open_url_app_uses_default_from_config() -> anyhow::Result<()> {
+        let (repo, config_home) = setup_changed_file(
+            "[open]\napp = \"web\"\n[editors.web]\nurl = \"myapp://open?file={file}&line={line}\"\n",
+        )?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["open", "1"])
+            .env("XDG_CONFIG_HOME", config_home.path())
+            .env("GIT_NAVIGATOR_OPENER", "true")
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Opened notes.txt:2 in web"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_unknown_app_fails() -> anyhow::Result<()> {
+        let (repo, config_home) = setup_changed_file("")?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["open", "1", "--app", "nano-gui"])
+            .env("XDG_CONFIG_HOME", config_home.path())
+            .current_dir(&repo.path)
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("no editor named 'nano-gui'"));
+
+        Ok(())
+    }
+}
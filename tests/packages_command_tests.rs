@@ -0,0 +1,140 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::path::Path;
+use std::process::Command;
+
+mod common;
+use common::repository::*;
+
+fn navigator(repo_path: &Path, cache_home: &Path, args: &[&str]) -> Command {
+    let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+    cmd.args(args)
+        .current_dir(repo_path)
+        .env("XDG_CACHE_HOME", cache_home);
+    cmd
+}
+
+fn write(repo_path: &Path, path: &str, content: &str) -> anyhow::Result<()> {
+    let path = repo_path.join(path);
+    std::fs::create_dir_all(path.parent().unwrap())?;
+    std::fs::write(path, content)?;
+    Ok(())
+}
+
+/// A Cargo workspace with two crates and an npm workspace with one package
+fn setup_monorepo(repo_path: &Path) -> anyhow::Result<()> {
+    write(
+        repo_path,
+        "Cargo.toml",
+        "[workspace]\nmembers = [\n    \"crates/*\",\n]\n",
+    )?;
+    write(
+        repo_path,
+        "crates/core/Cargo.toml",
+        "[package]\nname = \"nav-core\"\n",
+    )?;
+    write(repo_path, "crates/core/src/lib.rs", "// core\n")?;
+    write(
+        repo_path,
+        "crates/cli/Cargo.toml",
+        "[package]\nname = \"nav-cli\"\n",
+    )?;
+    write(repo_path, "crates/cli/src/main.rs", "// cli\n")?;
+    write(repo_path, "package.json", r#"{"workspaces": ["web/*"]}"#)?;
+    write(repo_path, "web/app/package.json", r#"{"name": "@nav/app"}"#)?;
+    write(repo_path, "web/app/index.js", "// app\n")?;
+    run_git(repo_path, &["add", "."])?;
+    git_commit(repo_path, "Add workspace")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod packages_command_tests {
+    use super::*;
+
+    #[test]
+    fn test_packages_lists_changed_packages() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+        setup_monorepo(&repo.path)?;
+
+        navigator(&repo.path, cache.path(), &["packages"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("No package has changes."));
+
+        write(&repo.path, "crates/core/src/lib.rs", "// core v2\n")?;
+        write(&repo.path, "crates/core/src/new.rs", "// new\n")?;
+        write(&repo.path, "web/app/index.js", "// app v2\n")?;
+        write(&repo.path, "README.md", "readme\n")?;
+
+        navigator(&repo.path, cache.path(), &["--plain", "packages"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "Index 1: nav-core (crates/core, 2 files)",
+            ))
+            .stdout(predicate::str::contains(
+                "Index 2: @nav/app (web/app, 1 file)",
+            ))
+            .stdout(predicate::str::contains("nav-cli").not())
+            .stdout(predicate::str::contains(
+                "1 changed file(s) are outside every package.",
+            ));
+
+        let expected = repo.path.canonicalize()?.join("web/app");
+        navigator(&repo.path, cache.path(), &["packages", "2"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(expected.to_string_lossy()));
+
+        navigator(&repo.path, cache.path(), &["packages", "9"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Package index 9 not found"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_packages_since_ref_and_globs() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+        setup_monorepo(&repo.path)?;
+        run_git(&repo.path, &["tag", "base"])?;
+
+        write(&repo.path, "crates/cli/src/main.rs", "// cli v2\n")?;
+        write(&repo.path, "services/api/main.go", "package main\n")?;
+        run_git(&repo.path, &["add", "."])?;
+        git_commit(&repo.path, "Touch cli and api")?;
+
+        // Committed changes only count against a base
+        navigator(&repo.path, cache.path(), &["packages"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("No package has changes."));
+
+        write(
+            &repo.path,
+            ".git/git-navigator/config.toml",
+            "[packages]\nglobs = [\"services/*\"]\n",
+        )?;
+        navigator(
+            &repo.path,
+            cache.path(),
+            &["--plain", "packages", "--since", "base"],
+        )
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Changed Packages since base"))
+        .stdout(predicate::str::contains(
+            "Index 1: nav-cli (crates/cli, 1 file)",
+        ))
+        .stdout(predicate::str::contains("Index 2: services/api (1 file)"));
+
+        navigator(&repo.path, cache.path(), &["packages", "--since", "nope"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("'nope' is not a tag index"));
+        Ok(())
+    }
+}
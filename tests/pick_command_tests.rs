@@ -0,0 +1,57 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::process::Command;
+
+mod common;
+use common::repository::*;
+
+#[cfg(test)]
+mod pick_command_tests {
+    use super::*;
+
+    #[test]
+    fn test_pick_without_terminal_prints_numbered_list() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "notes.txt", "draft\n")?;
+
+        // assert_cmd pipes stdout, so there is no terminal to draw on
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["--plain", "pick"])
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Index 1: notes.txt (untracked)"))
+            .stdout(predicate::str::contains(
+                "pick needs a terminal; use ga, grs or gd with these numbers.",
+            ));
+
+        // The numbers it printed are the ones ga resolves
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["add", "1"])
+            .current_dir(&repo.path)
+            .assert()
+            .success();
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["--plain", "status"])
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Index 1: notes.txt (new)"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pick_with_clean_tree() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.arg("pick")
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("No changes to pick from."));
+
+        Ok(())
+    }
+}
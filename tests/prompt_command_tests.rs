@@ -0,0 +1,75 @@
+use assert_cmd::prelude::*;
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+mod common;
+use common::repository::*;
+
+fn navigator(repo_path: &Path, cache_home: &Path, args: &[&str]) -> Command {
+    let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+    cmd.args(args)
+        .current_dir(repo_path)
+        .env("XDG_CACHE_HOME", cache_home);
+    cmd
+}
+
+fn prompt_output(repo_path: &Path, cache_home: &Path, args: &[&str]) -> anyhow::Result<String> {
+    let output = navigator(repo_path, cache_home, args).output()?;
+    assert!(output.status.success());
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+#[cfg(test)]
+mod prompt_command_tests {
+    use super::*;
+
+    #[test]
+    fn test_prompt_counts_changes() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache_home = tempfile::TempDir::new()?;
+        create_file(&repo.path, "initial.txt", "changed\n")?;
+        create_file(&repo.path, "notes.txt", "draft\n")?;
+        create_file(&repo.path, "staged.txt", "ready\n")?;
+        git_add(&repo.path, "staged.txt")?;
+
+        assert_eq!(
+            prompt_output(&repo.path, cache_home.path(), &["prompt"])?,
+            "main +1 ~1 ?1\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_prompt_async_serves_cache_and_refreshes() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache_home = tempfile::TempDir::new()?;
+        create_file(&repo.path, "notes.txt", "draft\n")?;
+
+        // Nothing saved yet: just the branch, while a refresh starts
+        assert_eq!(
+            prompt_output(&repo.path, cache_home.path(), &["prompt", "--async"])?,
+            "main\n"
+        );
+
+        let deadline = Instant::now() + Duration::from_secs(10);
+        loop {
+            let output = prompt_output(&repo.path, cache_home.path(), &["prompt", "--async"])?;
+            if output == "main ?1\n" {
+                break;
+            }
+            assert!(Instant::now() < deadline, "refresh never landed: {output}");
+            std::thread::sleep(Duration::from_millis(100));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_prompt_outside_repository_prints_nothing() -> anyhow::Result<()> {
+        let dir = tempfile::TempDir::new()?;
+        let output = navigator(dir.path(), dir.path(), &["prompt"]).output()?;
+        assert!(!output.status.success());
+        assert!(output.stdout.is_empty());
+        Ok(())
+    }
+}
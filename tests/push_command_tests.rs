@@ -0,0 +1,159 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::path::Path;
+use std::process::Command;
+
+mod common;
+use common::repository::*;
+
+fn git_output(repo_path: &Path, args: &[&str]) -> anyhow::Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(repo_path)
+        .output()?;
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+fn push(repo_path: &Path, args: &[&str]) -> Command {
+    let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+    cmd.arg("push").args(args).current_dir(repo_path);
+    cmd
+}
+
+#[cfg(test)]
+mod push_command_tests {
+    use super::*;
+
+    /// A repository with a bare `origin` it has not pushed to yet
+    fn repo_with_remote() -> anyhow::Result<(TestRepo, tempfile::TempDir)> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let remote = tempfile::TempDir::new()?;
+        run_git(remote.path(), &["init", "--bare", "--quiet"])?;
+        run_git(
+            &repo.path,
+            &["remote", "add", "origin", &remote.path().to_string_lossy()],
+        )?;
+        Ok((repo, remote))
+    }
+
+    #[test]
+    fn test_push_sets_upstream_then_pushes() -> anyhow::Result<()> {
+        let (repo, remote) = repo_with_remote()?;
+
+        push(&repo.path, &[])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "Pushed 'main' to origin/main and set it as upstream",
+            ));
+
+        create_file(&repo.path, "next.txt", "next\n")?;
+        git_add(&repo.path, "next.txt")?;
+        git_commit(&repo.path, "Add next")?;
+
+        push(&repo.path, &[])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Pushed 'main' to origin/main"));
+        assert_eq!(
+            git_output(remote.path(), &["log", "-1", "--format=%s", "main"])?,
+            "Add next\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_force_push_lists_discarded_commits_and_needs_confirmation() -> anyhow::Result<()> {
+        let (repo, remote) = repo_with_remote()?;
+        push(&repo.path, &[]).assert().success();
+
+        create_file(&repo.path, "shared.txt", "teammate\n")?;
+        git_add(&repo.path, "shared.txt")?;
+        git_commit(&repo.path, "Teammate work")?;
+        push(&repo.path, &[]).assert().success();
+
+        // Rewrite local history so the pushed commit would be lost
+        run_git(&repo.path, &["reset", "--hard", "--quiet", "HEAD~1"])?;
+        create_file(&repo.path, "mine.txt", "mine\n")?;
+        git_add(&repo.path, "mine.txt")?;
+        git_commit(&repo.path, "My rewrite")?;
+
+        push(&repo.path, &[])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Push failed"));
+
+        push(&repo.path, &["--force-with-lease"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(
+                "Force pushing will discard 1 commit(s) from origin/main",
+            ))
+            .stdout(predicate::str::is_match(r"\[1\] [0-9a-f]+ Teammate work")?)
+            .stderr(predicate::str::contains("pass --yes to confirm"));
+        assert_eq!(
+            git_output(remote.path(), &["log", "-1", "--format=%s", "main"])?,
+            "Teammate work\n"
+        );
+
+        push(&repo.path, &["--force-with-lease", "--yes"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "Force pushed 'main' to origin/main",
+            ));
+        assert_eq!(
+            git_output(remote.path(), &["log", "-1", "--format=%s", "main"])?,
+            "My rewrite\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_force_push_lease_rejects_unfetched_remote_work() -> anyhow::Result<()> {
+        let (repo, remote) = repo_with_remote()?;
+        push(&repo.path, &[]).assert().success();
+
+        // A teammate pushes from another clone; we have not fetched it
+        let other = tempfile::TempDir::new()?;
+        run_git(
+            other.path(),
+            &[
+                "clone",
+                "--quiet",
+                "--branch",
+                "main",
+                &remote.path().to_string_lossy(),
+                ".",
+            ],
+        )?;
+        run_git(other.path(), &["config", "user.name", "Teammate"])?;
+        run_git(
+            other.path(),
+            &["config", "user.email", "teammate@example.com"],
+        )?;
+        create_file(other.path(), "theirs.txt", "theirs\n")?;
+        git_add(other.path(), "theirs.txt")?;
+        git_commit(other.path(), "Unfetched work")?;
+        run_git(other.path(), &["push", "--quiet"])?;
+
+        run_git(
+            &repo.path,
+            &["commit", "--amend", "--quiet", "-m", "Amended"],
+        )?;
+
+        push(&repo.path, &["--force-with-lease", "--yes"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Push failed"))
+            .stderr(predicate::str::contains("stale info"));
+        assert_eq!(
+            git_output(remote.path(), &["log", "-1", "--format=%s", "main"])?,
+            "Unfetched work\n"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::path::Path;
+use std::process::Command;
+
+mod common;
+use common::repository::*;
+
+fn navigator(repo_path: &Path, cache_home: &Path, args: &[&str]) -> Command {
+    let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+    cmd.args(args)
+        .current_dir(repo_path)
+        .env("XDG_CACHE_HOME", cache_home);
+    cmd
+}
+
+#[cfg(test)]
+mod refresh_command_tests {
+    use super::*;
+
+    #[test]
+    fn test_refresh_warms_every_cache() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+        run_git(&repo.path, &["branch", "feature"])?;
+        run_git(&repo.path, &["tag", "v1.0"])?;
+        create_file(&repo.path, "initial.txt", "changed\n")?;
+        create_file(&repo.path, "new.txt", "new\n")?;
+
+        navigator(&repo.path, cache.path(), &["refresh"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "Refreshed 2 files, 1 branch, 1 directory and 1 tag",
+            ))
+            .stdout(predicate::str::contains("initial.txt").not());
+
+        // Index commands work without a gs or gb first
+        navigator(&repo.path, cache.path(), &["add", "2"])
+            .assert()
+            .success();
+        let staged = Command::new("git")
+            .args(["diff", "--cached", "--name-only"])
+            .current_dir(&repo.path)
+            .output()?;
+        assert_eq!(String::from_utf8(staged.stdout)?.trim(), "new.txt");
+
+        navigator(&repo.path, cache.path(), &["tags", "delete", "1", "--yes"])
+            .assert()
+            .success();
+        Ok(())
+    }
+
+    #[test]
+    fn test_refresh_quiet() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+
+        navigator(&repo.path, cache.path(), &["refresh", "--quiet"])
+            .assert()
+            .success()
+            .stdout(predicate::str::is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::path::Path;
+use std::process::Command;
+
+mod common;
+use common::repository::*;
+
+fn navigator(dir: &Path, cache_home: &Path, args: &[&str]) -> Command {
+    let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+    cmd.args(args)
+        .current_dir(dir)
+        .env("XDG_CACHE_HOME", cache_home);
+    cmd
+}
+
+#[cfg(test)]
+mod repos_command_tests {
+    use super::*;
+
+    #[test]
+    fn test_repos_lists_cached_repositories_and_prints_path() -> anyhow::Result<()> {
+        let cache_home = tempfile::TempDir::new()?;
+        let elsewhere = tempfile::TempDir::new()?;
+
+        navigator(elsewhere.path(), cache_home.path(), &["repos"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("No repositories yet"));
+
+        let first = setup_test_repo_with_initial_commit()?;
+        let second = setup_test_repo_with_initial_commit()?;
+        create_file(&second.path, "initial.txt", "changed\n")?;
+        // Any numbered list puts a repository on the list
+        navigator(&first.path, cache_home.path(), &["branches"])
+            .assert()
+            .success();
+        navigator(&second.path, cache_home.path(), &["status"])
+            .assert()
+            .success();
+        // Gone repositories drop out of the list
+        let gone = setup_test_repo_with_initial_commit()?;
+        navigator(&gone.path, cache_home.path(), &["branches"])
+            .assert()
+            .success();
+        drop(gone);
+
+        let second_path = second.path.canonicalize()?;
+        let mut paths = [first.path.canonicalize()?, second_path.clone()];
+        paths.sort();
+        let second_index = paths.iter().position(|p| *p == second_path).unwrap() + 1;
+
+        let output = navigator(&second.path, cache_home.path(), &["--plain", "repos"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(format!(
+                "Index {second_index}: {} (main, 1 changed) (current)",
+                second_path.display()
+            )))
+            .get_output()
+            .stdout
+            .clone();
+        assert_eq!(String::from_utf8(output)?.matches("Index ").count(), 2);
+
+        navigator(
+            elsewhere.path(),
+            cache_home.path(),
+            &["repos", &second_index.to_string()],
+        )
+        .assert()
+        .success()
+        .stdout(format!("{}\n", second_path.display()));
+
+        navigator(elsewhere.path(), cache_home.path(), &["repos", "9"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Repository index 9 not found"));
+
+        Ok(())
+    }
+}
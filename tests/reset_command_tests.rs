@@ -0,0 +1,135 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::path::Path;
+use std::process::Command;
+
+mod common;
+use common::repository::*;
+
+fn git_output(repo_path: &Path, args: &[&str]) -> anyhow::Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(repo_path)
+        .output()?;
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+fn reset(repo_path: &Path, args: &[&str]) -> Command {
+    let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+    cmd.arg("reset").args(args).current_dir(repo_path);
+    cmd
+}
+
+#[cfg(test)]
+mod reset_command_tests {
+    use super::*;
+
+    #[test]
+    fn test_hard_reset_of_files_lists_and_discards_changes() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "initial.txt", "changed\nand more\n")?;
+        create_file(&repo.path, "added.txt", "new\n")?;
+        git_add(&repo.path, "added.txt")?;
+        run_status_to_cache(&repo.path)?;
+
+        // Nobody to confirm with, so nothing is touched
+        reset(&repo.path, &["--hard", "1-2"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(
+                "Uncommitted changes to 2 file(s) will be lost",
+            ))
+            .stderr(predicate::str::contains("pass --yes to discard them"));
+        assert_eq!(
+            std::fs::read_to_string(repo.path.join("initial.txt"))?,
+            "changed\nand more\n"
+        );
+
+        reset(&repo.path, &["--hard", "--yes", "1-2"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "Discarded the changes to 2 file(s)",
+            ));
+        assert_eq!(
+            std::fs::read_to_string(repo.path.join("initial.txt"))?,
+            "initial content\n"
+        );
+        assert!(!repo.path.join("added.txt").exists());
+        assert_eq!(git_output(&repo.path, &["status", "--porcelain"])?, "");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reset_all_unstages_every_listed_file() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "initial.txt", "changed\n")?;
+        create_file(&repo.path, "added.txt", "new\n")?;
+        git_add(&repo.path, "initial.txt")?;
+        git_add(&repo.path, "added.txt")?;
+        run_status_to_cache(&repo.path)?;
+
+        reset(&repo.path, &["all"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "Successfully reset 2 file(s) from git index.",
+            ));
+        assert_eq!(
+            git_output(&repo.path, &["status", "--porcelain"])?,
+            " M initial.txt\n?? added.txt\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reset_to_commit_keeps_or_discards_changes() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "second.txt", "second\n")?;
+        git_add(&repo.path, "second.txt")?;
+        git_commit(&repo.path, "Add second")?;
+
+        // --mixed is the default: the commit goes, its changes stay unstaged
+        reset(&repo.path, &["HEAD~1"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Add second"))
+            .stdout(predicate::str::contains("HEAD is now at"))
+            .stdout(predicate::str::contains("Initial commit"));
+        assert_eq!(
+            git_output(&repo.path, &["status", "--porcelain"])?,
+            "?? second.txt\n"
+        );
+
+        git_add(&repo.path, "second.txt")?;
+        git_commit(&repo.path, "Add second again")?;
+        create_file(&repo.path, "scratch.txt", "notes\n")?;
+        reset(&repo.path, &["--hard", "HEAD~1"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(
+                "Resetting takes 1 commit(s) off the current branch",
+            ));
+        reset(&repo.path, &["--hard", "--yes", "HEAD~1"])
+            .assert()
+            .success();
+        assert_eq!(
+            git_output(&repo.path, &["log", "--format=%s"])?,
+            "Initial commit\n"
+        );
+        assert!(!repo.path.join("second.txt").exists());
+        // Untracked files survive a hard reset, as with git
+        assert!(repo.path.join("scratch.txt").exists());
+
+        reset(&repo.path, &["--soft", "no-such-commit"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(
+                "'no-such-commit' is neither a file index nor a commit",
+            ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::process::Command;
+
+mod common;
+use common::repository::*;
+
+#[cfg(test)]
+mod reveal_command_tests {
+    use super::*;
+
+    fn setup_nested_change() -> anyhow::Result<TestRepo> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        std::fs::create_dir_all(repo.path.join("docs/guide"))?;
+        create_file(&repo.path, "docs/guide/intro.md", "intro\n")?;
+        run_git(&repo.path, &["add", "."])?;
+        run_status_to_cache(&repo.path)?;
+        Ok(repo)
+    }
+
+    #[test]
+    fn test_reveal_print_shows_containing_directory() -> anyhow::Result<()> {
+        let repo = setup_nested_change()?;
+
+        let output = Command::cargo_bin("git-navigator")?
+            .args(["reveal", "1", "--print"])
+            .current_dir(&repo.path)
+            .output()?;
+        assert!(output.status.success());
+        let printed = String::from_utf8(output.stdout)?;
+        assert_eq!(
+            std::fs::canonicalize(printed.trim())?,
+            std::fs::canonicalize(repo.path.join("docs/guide"))?
+        );
+
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_reveal_uses_configured_opener() -> anyhow::Result<()> {
+        let repo = setup_nested_change()?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["reveal", "1"])
+            .env("GIT_NAVIGATOR_OPENER", "true")
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Opened '"));
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["reveal", "1"])
+            .env("GIT_NAVIGATOR_OPENER", "false")
+            .current_dir(&repo.path)
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Could not open"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reveal_without_index_shows_usage() -> anyhow::Result<()> {
+        let repo = setup_nested_change()?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.arg("reveal")
+            .current_dir(&repo.path)
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("reveal <index>"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::path::Path;
+use std::process::Command;
+
+mod common;
+use common::repository::*;
+
+fn navigator(repo_path: &Path, cache_home: &Path, args: &[&str]) -> Command {
+    let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+    cmd.args(args)
+        .current_dir(repo_path)
+        .env("XDG_CACHE_HOME", cache_home);
+    cmd
+}
+
+#[cfg(test)]
+mod review_command_tests {
+    use super::*;
+
+    /// A commit changing initial.txt and adding two files, listed by `gl`
+    fn repo_with_listed_commit(cache_home: &Path) -> anyhow::Result<TestRepo> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "initial.txt", "first line\nsecond line\n")?;
+        create_file(&repo.path, "api.rs", "fn api() {}\n")?;
+        create_file(&repo.path, "notes.md", "# Notes\n")?;
+        run_git(&repo.path, &["add", "."])?;
+        git_commit(&repo.path, "Add the api")?;
+        navigator(&repo.path, cache_home, &["log"])
+            .assert()
+            .success();
+        Ok(repo)
+    }
+
+    #[test]
+    fn test_review_lists_commit_files_without_terminal() -> anyhow::Result<()> {
+        let cache_home = tempfile::TempDir::new()?;
+        let repo = repo_with_listed_commit(cache_home.path())?;
+
+        navigator(&repo.path, cache_home.path(), &["review", "1", "--plain"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Add the api"))
+            .stdout(predicate::str::contains("Index 1: api.rs (new)"))
+            .stdout(predicate::str::contains("Index 2: initial.txt (modified)"))
+            .stdout(predicate::str::contains("Index 3: notes.md (new)"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_review_shows_one_file_diff() -> anyhow::Result<()> {
+        let cache_home = tempfile::TempDir::new()?;
+        let repo = repo_with_listed_commit(cache_home.path())?;
+
+        navigator(&repo.path, cache_home.path(), &["review", "1", "2"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("+second line"))
+            .stdout(predicate::str::contains("api.rs").not())
+            .stdout(predicate::str::contains("Add the api").not());
+        Ok(())
+    }
+
+    #[test]
+    fn test_review_rejects_unknown_indices() -> anyhow::Result<()> {
+        let cache_home = tempfile::TempDir::new()?;
+        let repo = repo_with_listed_commit(cache_home.path())?;
+
+        navigator(&repo.path, cache_home.path(), &["review", "1", "9"])
+            .assert()
+            .failure();
+        navigator(&repo.path, cache_home.path(), &["review", "99"])
+            .assert()
+            .failure();
+        Ok(())
+    }
+}
@@ -0,0 +1,219 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::path::Path;
+use std::process::Command;
+
+mod common;
+use common::repository::*;
+
+fn git_output(repo_path: &Path, args: &[&str]) -> anyhow::Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(repo_path)
+        .output()?;
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+fn reword(repo_path: &Path, args: &[&str], editor: &str) -> Command {
+    let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+    cmd.arg("reword")
+        .args(args)
+        .env("GIT_EDITOR", editor)
+        .current_dir(repo_path);
+    cmd
+}
+
+#[cfg(test)]
+mod reword_command_tests {
+    use super::*;
+
+    fn repo_with_history() -> anyhow::Result<TestRepo> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        for name in ["second", "third"] {
+            create_file(&repo.path, &format!("{name}.txt"), name)?;
+            git_add(&repo.path, &format!("{name}.txt"))?;
+            git_commit(&repo.path, &format!("Add {name}"))?;
+        }
+        Ok(repo)
+    }
+
+    #[test]
+    fn test_reword_lists_and_rewords_older_commit() -> anyhow::Result<()> {
+        let repo = repo_with_history()?;
+        // Local changes must survive the rewrite
+        create_file(&repo.path, "initial.txt", "dirty\n")?;
+
+        reword(&repo.path, &[], "true")
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Recent Commits"))
+            .stdout(predicate::str::is_match(r"\[1\] [0-9a-f]+ Add third")?)
+            .stdout(predicate::str::is_match(r"\[2\] [0-9a-f]+ Add second")?);
+
+        reword(&repo.path, &["2"], "printf 'Add the second file\\n' >")
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Reworded"))
+            .stdout(predicate::str::contains("Add the second file"));
+
+        assert_eq!(
+            git_output(&repo.path, &["log", "--format=%s"])?,
+            "Add third\nAdd the second file\nInitial commit\n"
+        );
+        assert_eq!(
+            git_output(&repo.path, &["status", "--porcelain"])?,
+            " M initial.txt\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reword_head_and_unchanged_message() -> anyhow::Result<()> {
+        let repo = repo_with_history()?;
+        reword(&repo.path, &[], "true").assert().success();
+
+        // Editor leaves the message as it was
+        reword(&repo.path, &["1"], "true")
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Message unchanged"));
+
+        reword(&repo.path, &["1"], "printf '# just a comment\\n' >")
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("empty commit message"));
+
+        reword(&repo.path, &["1"], "printf 'New subject\\n\\nBody\\n' >")
+            .assert()
+            .success();
+        assert_eq!(
+            git_output(&repo.path, &["log", "-1", "--format=%B"])?,
+            "New subject\n\nBody\n\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reword_refuses_published_commits() -> anyhow::Result<()> {
+        let repo = repo_with_history()?;
+        run_git(
+            &repo.path,
+            &["remote", "add", "origin", "https://example.com/repo.git"],
+        )?;
+        run_git(
+            &repo.path,
+            &["update-ref", "refs/remotes/origin/main", "HEAD~1"],
+        )?;
+        run_git(&repo.path, &["branch", "--set-upstream-to", "origin/main"])?;
+
+        reword(&repo.path, &[], "true").assert().success();
+
+        // HEAD is not pushed yet
+        reword(&repo.path, &["1"], "printf 'Local change\\n' >")
+            .assert()
+            .success();
+
+        reword(&repo.path, &["2"], "printf 'Rewritten\\n' >")
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("is already on origin/main"))
+            .stderr(predicate::str::contains("--force-accept"));
+
+        reword(
+            &repo.path,
+            &["2", "--force-accept"],
+            "printf 'Rewritten\\n' >",
+        )
+        .assert()
+        .success();
+        assert_eq!(
+            git_output(&repo.path, &["log", "--format=%s"])?,
+            "Local change\nRewritten\nInitial commit\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reword_keeps_later_message_bytes_and_warns_about_signatures() -> anyhow::Result<()> {
+        let repo = repo_with_history()?;
+        let message_file = repo.path.join(".git").join("latin1-message");
+        std::fs::write(&message_file, b"Caf\xe9\n")?;
+        run_git(
+            &repo.path,
+            &[
+                "-c",
+                "i18n.commitEncoding=ISO-8859-1",
+                "commit",
+                "--allow-empty",
+                "-F",
+                message_file.to_str().unwrap(),
+            ],
+        )?;
+
+        // Put a (fake) signature on the Latin-1 commit
+        let raw = Command::new("git")
+            .args(["cat-file", "commit", "HEAD"])
+            .current_dir(&repo.path)
+            .output()?
+            .stdout;
+        let split = raw.windows(2).position(|pair| pair == b"\n\n").unwrap() + 1;
+        let mut signed = raw[..split].to_vec();
+        signed.extend_from_slice(
+            b"gpgsig -----BEGIN PGP SIGNATURE-----\n \n -----END PGP SIGNATURE-----\n",
+        );
+        signed.extend_from_slice(&raw[split..]);
+        std::fs::write(&message_file, &signed)?;
+        let oid = git_output(
+            &repo.path,
+            &[
+                "hash-object",
+                "-t",
+                "commit",
+                "-w",
+                "--literally",
+                message_file.to_str().unwrap(),
+            ],
+        )?;
+        run_git(&repo.path, &["update-ref", "HEAD", oid.trim()])?;
+
+        reword(&repo.path, &[], "true").assert().success();
+        reword(&repo.path, &["2"], "printf 'Add the third file\\n' >")
+            .assert()
+            .success()
+            .stderr(predicate::str::contains(
+                "1 rewritten commit(s) were signed; their signatures were dropped.",
+            ));
+
+        let head = Command::new("git")
+            .args(["cat-file", "commit", "HEAD"])
+            .current_dir(&repo.path)
+            .output()?
+            .stdout;
+        let header = String::from_utf8_lossy(&head);
+        assert!(header.contains("\nencoding ISO-8859-1\n"));
+        assert!(!header.contains("gpgsig"));
+        assert!(head.ends_with(b"\n\nCaf\xe9\n"));
+        assert_eq!(
+            git_output(&repo.path, &["log", "--skip=1", "--format=%s"])?,
+            "Add the third file\nAdd second\nInitial commit\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reword_unknown_index() -> anyhow::Result<()> {
+        let repo = repo_with_history()?;
+        reword(&repo.path, &[], "true").assert().success();
+
+        reword(&repo.path, &["9"], "true")
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("commit index 9 not found"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::path::Path;
+use std::process::Command;
+
+mod common;
+use common::repository::*;
+
+fn git_output(repo_path: &Path, args: &[&str]) -> anyhow::Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(repo_path)
+        .output()?;
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+fn rm(repo_path: &Path, args: &[&str]) -> Command {
+    let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+    cmd.arg("rm").args(args).current_dir(repo_path);
+    cmd
+}
+
+#[cfg(test)]
+mod rm_command_tests {
+    use super::*;
+
+    #[test]
+    fn test_rm_asks_before_deleting_files() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "initial.txt", "changed\n")?;
+        run_status_to_cache(&repo.path)?;
+
+        // Nobody to confirm with, so nothing is touched
+        rm(&repo.path, &["1"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(
+                "1 file(s) will be deleted from disk",
+            ))
+            .stderr(predicate::str::contains("pass --yes to delete them"));
+        assert!(repo.path.join("initial.txt").exists());
+
+        // Uncommitted changes are no reason for git to refuse once confirmed
+        rm(&repo.path, &["--yes", "1"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Removed 1 file(s)."))
+            .stdout(predicate::str::contains("Updated status:"));
+        assert!(!repo.path.join("initial.txt").exists());
+        assert_eq!(
+            git_output(&repo.path, &["status", "--porcelain"])?,
+            "D  initial.txt\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rm_cached_keeps_files_on_disk() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "initial.txt", "changed\n")?;
+        create_file(&repo.path, "notes.txt", "not tracked\n")?;
+        run_status_to_cache(&repo.path)?;
+
+        rm(&repo.path, &["--cached", "1-2"])
+            .assert()
+            .success()
+            .stderr(predicate::str::contains(
+                "Leaving 1 untracked file(s) alone",
+            ))
+            .stdout(predicate::str::contains(
+                "Removed 1 file(s) from git index, keeping them on disk.",
+            ));
+        assert_eq!(
+            std::fs::read_to_string(repo.path.join("initial.txt"))?,
+            "changed\n"
+        );
+        assert_eq!(
+            git_output(&repo.path, &["status", "--porcelain"])?,
+            "D  initial.txt\n?? initial.txt\n?? notes.txt\n"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::path::Path;
+use std::process::Command;
+
+mod common;
+use common::repository::*;
+
+fn navigator(repo_path: &Path, cache_home: &Path, args: &[&str]) -> Command {
+    let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+    cmd.args(args)
+        .current_dir(repo_path)
+        .env("XDG_CACHE_HOME", cache_home);
+    cmd
+}
+
+#[cfg(test)]
+mod since_command_tests {
+    use super::*;
+
+    /// Commit everything with both dates set to `date`
+    fn commit_at(repo_path: &Path, message: &str, date: &str) -> anyhow::Result<()> {
+        run_git(repo_path, &["add", "."])?;
+        let output = Command::new("git")
+            .args(["commit", "-m", message])
+            .current_dir(repo_path)
+            .env("GIT_AUTHOR_DATE", date)
+            .env("GIT_COMMITTER_DATE", date)
+            .output()?;
+        assert!(output.status.success());
+        Ok(())
+    }
+
+    /// Two dated commits, then an edit and an untracked file in the worktree
+    fn repo_with_history() -> anyhow::Result<TestRepo> {
+        let repo = setup_test_repo()?;
+        create_file(&repo.path, "old.txt", "old\n")?;
+        commit_at(&repo.path, "Old work", "2020-01-01T12:00:00")?;
+
+        create_file(&repo.path, "old.txt", "old\nchanged\n")?;
+        create_file(&repo.path, "done.txt", "done\n")?;
+        commit_at(&repo.path, "Recent work", "2021-06-01T12:00:00")?;
+
+        create_file(&repo.path, "done.txt", "done\nalmost\n")?;
+        create_file(&repo.path, "notes.txt", "standup\n")?;
+        Ok(repo)
+    }
+
+    #[test]
+    fn test_since_lists_committed_and_uncommitted_changes() -> anyhow::Result<()> {
+        let cache_home = tempfile::TempDir::new()?;
+        let repo = repo_with_history()?;
+
+        navigator(
+            &repo.path,
+            cache_home.path(),
+            &["since", "2021-01-01", "--plain"],
+        )
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Old work"))
+        .stdout(predicate::str::contains("Index 1: done.txt (new)"))
+        .stdout(predicate::str::contains("Index 2: notes.txt (untracked)"))
+        .stdout(predicate::str::contains("Index 3: old.txt (modified)"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_since_shows_one_file_diff() -> anyhow::Result<()> {
+        let cache_home = tempfile::TempDir::new()?;
+        let repo = repo_with_history()?;
+
+        navigator(&repo.path, cache_home.path(), &["since", "2021-01-01", "3"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("+changed"))
+            .stdout(predicate::str::contains("done.txt").not());
+        navigator(&repo.path, cache_home.path(), &["since", "2021-01-01", "2"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("+standup"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_since_before_first_commit_lists_everything_as_new() -> anyhow::Result<()> {
+        let cache_home = tempfile::TempDir::new()?;
+        let repo = repo_with_history()?;
+
+        navigator(
+            &repo.path,
+            cache_home.path(),
+            &["since", "2019-01-01", "--plain"],
+        )
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("no earlier commit"))
+        .stdout(predicate::str::contains("Index 3: old.txt (new)"));
+        navigator(&repo.path, cache_home.path(), &["since", "2019-01-01", "3"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("+changed"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_today_lists_uncommitted_changes() -> anyhow::Result<()> {
+        let cache_home = tempfile::TempDir::new()?;
+        let repo = repo_with_history()?;
+
+        navigator(&repo.path, cache_home.path(), &["today", "--plain"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Recent work"))
+            .stdout(predicate::str::contains("Index 1: done.txt (modified)"))
+            .stdout(predicate::str::contains("Index 2: notes.txt (untracked)"))
+            .stdout(predicate::str::contains("old.txt").not());
+        Ok(())
+    }
+
+    #[test]
+    fn test_since_with_nothing_changed() -> anyhow::Result<()> {
+        let cache_home = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+
+        navigator(&repo.path, cache_home.path(), &["since", "0m"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Nothing changed since"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_since_rejects_unknown_time() -> anyhow::Result<()> {
+        let cache_home = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+
+        navigator(&repo.path, cache_home.path(), &["since", "someday"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Cannot understand date 'someday'"));
+        Ok(())
+    }
+}
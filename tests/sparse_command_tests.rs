@@ -0,0 +1,124 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::path::Path;
+use std::process::Command;
+
+mod common;
+use common::repository::*;
+
+fn navigator(repo_path: &Path, cache_home: &Path, args: &[&str]) -> Command {
+    let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+    cmd.args(args)
+        .current_dir(repo_path)
+        .env("XDG_CACHE_HOME", cache_home);
+    cmd
+}
+
+/// Files in `docs/` and `src/`, with only `src/` checked out
+fn setup_sparse_repo() -> anyhow::Result<TestRepo> {
+    let repo = setup_test_repo_with_initial_commit()?;
+    for dir in ["docs", "src"] {
+        std::fs::create_dir_all(repo.path.join(dir))?;
+        create_file(&repo.path, &format!("{dir}/readme.md"), dir)?;
+    }
+    run_git(&repo.path, &["add", "."])?;
+    git_commit(&repo.path, "Add docs and src")?;
+    run_git(&repo.path, &["sparse-checkout", "set", "src"])?;
+    Ok(repo)
+}
+
+#[cfg(test)]
+mod sparse_command_tests {
+    use super::*;
+
+    #[test]
+    fn test_status_marks_files_outside_the_cone() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = setup_sparse_repo()?;
+        assert!(!repo.path.join("docs/readme.md").exists());
+
+        // Files left out of the checkout are not reported as deleted
+        navigator(&repo.path, cache.path(), &["--plain", "status"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Branch: main (sparse, 1 dir)"))
+            .stdout(predicate::str::contains("deleted").not());
+
+        std::fs::create_dir_all(repo.path.join("docs"))?;
+        create_file(&repo.path, "docs/draft.md", "draft")?;
+        create_file(&repo.path, "src/lib.rs", "lib")?;
+        navigator(&repo.path, cache.path(), &["--plain", "status"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "Index 1: docs/draft.md (untracked), outside sparse checkout",
+            ))
+            .stdout(predicate::str::contains(
+                "Index 2: src/lib.rs (untracked)\n",
+            ))
+            .stdout(predicate::str::contains(
+                "1 file(s) are outside the sparse checkout",
+            ));
+
+        // ga warns before git refuses the file
+        navigator(&repo.path, cache.path(), &["add", "1"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(
+                "docs/draft.md is outside the sparse checkout, so git leaves it alone. Run 'sparse add 1' first",
+            ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sparse_shows_and_adds_cone_entries() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = setup_sparse_repo()?;
+        std::fs::create_dir_all(repo.path.join("docs"))?;
+        create_file(&repo.path, "docs/draft.md", "draft")?;
+        navigator(&repo.path, cache.path(), &["status"])
+            .assert()
+            .success();
+
+        navigator(&repo.path, cache.path(), &["--plain", "sparse"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Sparse Checkout Cone"))
+            .stdout(predicate::str::contains("\nsrc\n"));
+
+        navigator(&repo.path, cache.path(), &["sparse", "add", "1"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "Added docs to the sparse checkout",
+            ));
+        assert!(repo.path.join("docs/readme.md").exists());
+
+        navigator(&repo.path, cache.path(), &["--plain", "status"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Branch: main (sparse, 2 dirs)"))
+            .stdout(predicate::str::contains("outside sparse checkout").not());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sparse_in_full_checkout() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+
+        navigator(&repo.path, cache.path(), &["sparse"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "This worktree is not a sparse checkout.",
+            ));
+        navigator(&repo.path, cache.path(), &["sparse", "add", "docs"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(
+                "This worktree is not a sparse checkout. Start one with",
+            ));
+        Ok(())
+    }
+}
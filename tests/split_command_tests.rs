@@ -0,0 +1,146 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::path::Path;
+use std::process::Command;
+
+mod common;
+use common::repository::*;
+
+fn git_output(repo_path: &Path, args: &[&str]) -> anyhow::Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(repo_path)
+        .output()?;
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+#[cfg(test)]
+mod split_command_tests {
+    use super::*;
+
+    #[test]
+    fn test_split_commits_staged_files_in_order() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "a.txt", "a\n")?;
+        create_file(&repo.path, "b.txt", "b\n")?;
+        create_file(&repo.path, "initial.txt", "staged\n")?;
+        run_git(&repo.path, &["add", "."])?;
+        // Only the staged version of initial.txt may be committed
+        create_file(&repo.path, "initial.txt", "worktree\n")?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["split", "--commit", "1,3:First part", "-c", "2:Second part"])
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Committed 2 file(s): First part"))
+            .stdout(predicate::str::contains("Committed 1 file(s): Second part"));
+
+        assert_eq!(
+            git_output(&repo.path, &["log", "--format=%s", "-3"])?,
+            "Second part\nFirst part\nInitial commit\n"
+        );
+        assert_eq!(
+            git_output(&repo.path, &["show", "--name-only", "--format=", "HEAD~1"])?,
+            "a.txt\ninitial.txt\n"
+        );
+        assert_eq!(
+            git_output(&repo.path, &["show", "HEAD~1:initial.txt"])?,
+            "staged\n"
+        );
+
+        // Nothing is left staged and the unstaged edit survives
+        assert_eq!(
+            git_output(&repo.path, &["diff", "--cached", "--name-only"])?,
+            ""
+        );
+        assert_eq!(
+            std::fs::read_to_string(repo.path.join("initial.txt"))?,
+            "worktree\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_keeps_unassigned_files_staged() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "a.txt", "a\n")?;
+        create_file(&repo.path, "b.txt", "b\n")?;
+        run_git(&repo.path, &["add", "."])?;
+        run_git(&repo.path, &["rm", "--quiet", "initial.txt"])?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["split", "-c", "1 3:Add a, drop initial"])
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "1 staged file(s) were not assigned and are still staged.",
+            ));
+
+        assert_eq!(
+            git_output(&repo.path, &["show", "--name-status", "--format=", "HEAD"])?,
+            "A\ta.txt\nD\tinitial.txt\n"
+        );
+        assert_eq!(
+            git_output(&repo.path, &["diff", "--cached", "--name-only"])?,
+            "b.txt\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_commits_both_ends_of_a_rename() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        run_git(&repo.path, &["mv", "initial.txt", "renamed.txt"])?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["split", "--commit", "1:Rename"])
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Committed 1 file(s): Rename"));
+
+        assert_eq!(
+            git_output(&repo.path, &["ls-tree", "-r", "--name-only", "HEAD"])?,
+            "renamed.txt\n"
+        );
+        assert_eq!(git_output(&repo.path, &["status", "--porcelain"])?, "");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_errors() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["split", "-c", "1:Nothing"])
+            .current_dir(&repo.path)
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("nothing is staged"));
+
+        create_file(&repo.path, "a.txt", "a\n")?;
+        git_add(&repo.path, "a.txt")?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["split", "-c", "2:Missing"])
+            .current_dir(&repo.path)
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("[2] is not a staged file"));
+
+        // Without a terminal there is nobody to ask
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.arg("split")
+            .current_dir(&repo.path)
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("no terminal to ask"));
+
+        Ok(())
+    }
+}
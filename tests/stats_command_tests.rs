@@ -0,0 +1,132 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::path::Path;
+use std::process::Command;
+
+mod common;
+use common::repository::*;
+
+fn stats(repo_path: &Path, args: &[&str]) -> Command {
+    let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+    cmd.args(args).current_dir(repo_path);
+    cmd
+}
+
+#[cfg(test)]
+mod stats_command_tests {
+    use super::*;
+
+    /// Commit a 64 KiB asset, then delete it again
+    fn repo_with_deleted_asset() -> anyhow::Result<TestRepo> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        std::fs::create_dir_all(repo.path.join("assets"))?;
+        create_file(&repo.path, "assets/big.bin", &"x".repeat(64 * 1024))?;
+        git_add(&repo.path, "assets/big.bin")?;
+        git_commit(&repo.path, "Add big asset")?;
+        run_git(&repo.path, &["rm", "--quiet", "assets/big.bin"])?;
+        git_commit(&repo.path, "Remove big asset")?;
+        Ok(repo)
+    }
+
+    #[test]
+    fn test_big_objects_lists_blobs_with_introducing_commit() -> anyhow::Result<()> {
+        let repo = repo_with_deleted_asset()?;
+
+        stats(&repo.path, &["stats", "--big-objects", "1"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Largest Objects"))
+            .stdout(predicate::str::is_match(
+                r"\[1\] 64\.0 KiB  assets/big\.bin  [0-9a-f]+ Add big asset",
+            )?)
+            .stdout(predicate::str::contains("initial.txt").not())
+            .stdout(predicate::str::contains("git filter-repo"));
+
+        stats(&repo.path, &["--plain", "stats", "--big-objects"])
+            .assert()
+            .success()
+            .stdout(predicate::str::is_match(
+                r"Index 1: 64\.0 KiB assets/big\.bin \(added in [0-9a-f]+ Add big asset\)",
+            )?)
+            .stdout(
+                predicate::str::contains("Index 2: ").and(predicate::str::contains("initial.txt")),
+            );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_big_objects_includes_unreferenced_blobs() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "dangling.bin", &"y".repeat(4096))?;
+        run_git(&repo.path, &["hash-object", "-w", "dangling.bin"])?;
+
+        stats(&repo.path, &["--plain", "stats", "--big-objects", "1"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "Index 1: 4.0 KiB (unreferenced blob",
+            ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_stats_totals() -> anyhow::Result<()> {
+        let repo = repo_with_deleted_asset()?;
+
+        stats(&repo.path, &["stats"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Repository Objects"))
+            .stdout(predicate::str::is_match(r"Blobs:\s+2 \(64\.\d KiB\)")?)
+            .stdout(predicate::str::contains("stats --big-objects"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_self_usage_is_opt_in_and_counts_typed_names() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let config_home = tempfile::TempDir::new()?;
+        let with_config = |args: &[&str]| {
+            let mut cmd = stats(&repo.path, args);
+            cmd.env("XDG_CONFIG_HOME", config_home.path())
+                .env_remove("GIT_NAVIGATOR_ALIAS");
+            cmd
+        };
+
+        with_config(&["status"]).assert().success();
+        with_config(&["stats", "--self"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Usage statistics are off"));
+        assert!(!config_home.path().join("git-navigator/usage.json").exists());
+
+        std::fs::create_dir_all(config_home.path().join("git-navigator"))?;
+        std::fs::write(
+            config_home.path().join("git-navigator/config.toml"),
+            "[usage]\ncollect = true\n",
+        )?;
+        with_config(&["status"])
+            .env("GIT_NAVIGATOR_ALIAS", "gs")
+            .assert()
+            .success();
+        with_config(&["status"])
+            .env("GIT_NAVIGATOR_ALIAS", "gs")
+            .assert()
+            .success();
+        with_config(&["status"]).assert().success();
+
+        with_config(&["stats", "--self"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Your Usage"))
+            .stdout(predicate::str::is_match(
+                r"status\s+3 run\(s\)  avg \d+ ms  \(gs 2, git-navigator status 1\)",
+            )?)
+            .stdout(predicate::str::contains("Aliases you never typed: ga, gd"));
+
+        Ok(())
+    }
+}
@@ -110,8 +110,132 @@ mod status_command_tests {
             .assert()
             .success()
             .stdout(assertions::has_branch_info())
-            .stdout(assertions::has_parent_info());
+            .stdout(assertions::has_parent_info())
+            .stdout(predicate::str::contains("Branch: main"))
+            .stdout(predicate::str::contains("- no commits yet -"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gs_and_gl_in_shallow_clone() -> anyhow::Result<()> {
+        let origin = setup_test_repo_with_initial_commit()?;
+        create_file(&origin.path, "second.txt", "second")?;
+        git_add(&origin.path, "second.txt")?;
+        git_commit(&origin.path, "Second commit")?;
+
+        let clone = tempfile::TempDir::new()?;
+        let url = format!("file://{}", origin.path.display());
+        run_git(
+            clone.path(),
+            &["clone", "--quiet", "--depth", "1", &url, "shallow"],
+        )?;
+        let shallow = clone.path().join("shallow");
+        assert!(shallow.join(".git/shallow").exists());
+        create_file(&shallow, "second.txt", "changed")?;
+
+        Command::cargo_bin("git-navigator")?
+            .args(["--plain", "status"])
+            .current_dir(&shallow)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Branch: main (shallow, depth 1)"))
+            .stdout(predicate::str::is_match(
+                r"Parent: [0-9a-f]{7} Second commit",
+            )?)
+            .stdout(predicate::str::contains("second.txt"));
+
+        // Only the grafted commit is there to list
+        Command::cargo_bin("git-navigator")?
+            .args(["--plain", "log"])
+            .current_dir(&shallow)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Second commit"))
+            .stdout(predicate::str::contains("Initial commit").not())
+            .stdout(predicate::str::contains(
+                "History stops here because this is a shallow clone.",
+            ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_gs_shallow_fetch_hides_ahead_behind() -> anyhow::Result<()> {
+        let origin = setup_test_repo_with_initial_commit()?;
+        let clone = tempfile::TempDir::new()?;
+        let url = format!("file://{}", origin.path.display());
+        run_git(
+            clone.path(),
+            &["clone", "--quiet", "--depth", "1", &url, "shallow"],
+        )?;
+        let shallow = clone.path().join("shallow");
+
+        // A shallow fetch of new upstream work leaves no common ancestor to count from
+        for name in ["second.txt", "third.txt"] {
+            create_file(&origin.path, name, name)?;
+            git_add(&origin.path, name)?;
+            git_commit(&origin.path, name)?;
+        }
+        run_git(&shallow, &["fetch", "--quiet", "--depth", "1"])?;
+
+        Command::cargo_bin("git-navigator")?
+            .args(["--plain", "status"])
+            .current_dir(&shallow)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "Branch: main (ahead/behind unknown) (shallow, depth 1)",
+            ));
+
+        // Deepening restores the real counts
+        run_git(&shallow, &["fetch", "--quiet", "--unshallow"])?;
+        Command::cargo_bin("git-navigator")?
+            .args(["--plain", "status"])
+            .current_dir(&shallow)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Branch: main (behind 2)\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_gs_partial_clone_header() -> anyhow::Result<()> {
+        let origin = setup_test_repo_with_initial_commit()?;
+        create_file(&origin.path, "notes.txt", "first draft")?;
+        git_add(&origin.path, "notes.txt")?;
+        git_commit(&origin.path, "Add notes")?;
+        create_file(&origin.path, "notes.txt", "second draft")?;
+        git_add(&origin.path, "notes.txt")?;
+        git_commit(&origin.path, "Rewrite notes")?;
+        run_git(&origin.path, &["config", "uploadpack.allowFilter", "true"])?;
+
+        let clone = tempfile::TempDir::new()?;
+        let url = format!("file://{}", origin.path.display());
+        run_git(
+            clone.path(),
+            &["clone", "--quiet", "--filter=blob:none", &url, "partial"],
+        )?;
+
+        let partial = clone.path().join("partial");
 
+        Command::cargo_bin("git-navigator")?
+            .args(["--plain", "status"])
+            .current_dir(&partial)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "Branch: main (partial, blob:none)",
+            ));
+
+        // Searching old contents needs blobs the clone did not fetch
+        Command::cargo_bin("git-navigator")?
+            .args(["log", "-S", "draft"])
+            .current_dir(&partial)
+            .assert()
+            .failure()
+            .stderr(predicate::str::is_match(
+                r"Object [0-9a-f]{7} was left out of this partial clone",
+            )?);
         Ok(())
     }
 
@@ -128,7 +252,7 @@ mod status_command_tests {
             .current_dir(non_repo_path)
             .assert()
             .failure()
-            .stdout(assertions::not_in_git_repo());
+            .stderr(assertions::not_in_git_repo());
 
         Ok(())
     }
@@ -146,6 +270,7 @@ mod file_entry_tests {
             status: GitStatus::Modified,
             path: PathBuf::from("src/main.rs"),
             staged: false,
+            old_path: None,
         };
 
         assert_eq!(entry.index, 1);
@@ -161,6 +286,7 @@ mod file_entry_tests {
             status: GitStatus::Untracked,
             path: PathBuf::from("newfile.txt"),
             staged: false,
+            old_path: None,
         };
 
         let json = serde_json::to_string(&entry)?;
@@ -169,4 +295,1325 @@ mod file_entry_tests {
         assert_eq!(entry, deserialized);
         Ok(())
     }
+
+    #[test]
+    fn test_gs_shows_detached_head_context() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "second.txt", "second\n")?;
+        git_add(&repo.path, "second.txt")?;
+        git_commit(&repo.path, "Second commit")?;
+        run_git(&repo.path, &["checkout", "--detach", "HEAD~1"])?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.arg("status")
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("detached at"))
+            .stdout(predicate::str::contains("(main~1)"))
+            .stdout(predicate::str::contains("gco -b <name> to keep work"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gs_follows_git_color_ui() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "new.txt", "new\n")?;
+
+        run_git(&repo.path, &["config", "color.ui", "always"])?;
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.arg("status")
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("\x1b["));
+
+        run_git(&repo.path, &["config", "color.ui", "never"])?;
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.arg("status")
+            .env("CLICOLOR_FORCE", "1")
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("\x1b[").not());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gs_no_cache_write_leaves_cache_untouched() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache_home = tempfile::TempDir::new()?;
+        create_file(&repo.path, "new.txt", "new\n")?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["--no-cache-write", "status"])
+            .env("XDG_CACHE_HOME", cache_home.path())
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("new.txt"));
+
+        assert!(!cache_home.path().join("git-navigator").exists());
+
+        // The flag is global, so it also works after the subcommand
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["status", "--no-cache-write"])
+            .env("XDG_CACHE_HOME", cache_home.path())
+            .current_dir(&repo.path)
+            .assert()
+            .success();
+
+        assert!(!cache_home.path().join("git-navigator").exists());
+
+        // Without it, gs writes the cache as usual
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.arg("status")
+            .env("XDG_CACHE_HOME", cache_home.path())
+            .current_dir(&repo.path)
+            .assert()
+            .success();
+
+        assert!(cache_home.path().join("git-navigator").exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_concurrent_gs_and_torn_cache() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache_home = tempfile::TempDir::new()?;
+        create_file(&repo.path, "new.txt", "new\n")?;
+
+        let children = (0..8)
+            .map(|_| {
+                Command::cargo_bin("git-navigator")?
+                    .arg("status")
+                    .env("XDG_CACHE_HOME", cache_home.path())
+                    .current_dir(&repo.path)
+                    .stdout(std::process::Stdio::null())
+                    .spawn()
+                    .map_err(anyhow::Error::from)
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        for mut child in children {
+            assert!(child.wait()?.success());
+        }
+
+        // Every run replaced the file whole, leaving no temporary files behind
+        let cache_dir = std::fs::read_dir(cache_home.path().join("git-navigator"))?
+            .next()
+            .unwrap()?
+            .path();
+        let mut names: Vec<String> = std::fs::read_dir(&cache_dir)?
+            .map(|entry| Ok(entry?.file_name().to_string_lossy().into_owned()))
+            .collect::<std::io::Result<_>>()?;
+        names.sort();
+        assert_eq!(names, vec!["files.json", "view.json"]);
+        let cached = std::fs::read_to_string(cache_dir.join("files.json"))?;
+        serde_json::from_str::<serde_json::Value>(&cached)?;
+
+        // A file cut short is rebuilt from a fresh scan, and the index typed
+        // from the lost list is refused once
+        std::fs::write(cache_dir.join("files.json"), &cached[..cached.len() / 2])?;
+        let add = || -> anyhow::Result<Command> {
+            let mut cmd = Command::cargo_bin("git-navigator")?;
+            cmd.args(["add", "1"])
+                .env("XDG_CACHE_HOME", cache_home.path())
+                .current_dir(&repo.path);
+            Ok(cmd)
+        };
+        add()?
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("was rebuilt"));
+        add()?.assert().success();
+        let staged = std::process::Command::new("git")
+            .args(["diff", "--cached", "--name-only"])
+            .current_dir(&repo.path)
+            .output()?;
+        assert_eq!(String::from_utf8(staged.stdout)?.trim(), "new.txt");
+        Ok(())
+    }
+
+    #[test]
+    fn test_torn_cache_refuses_indices_from_the_lost_list() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache_home = tempfile::TempDir::new()?;
+        for name in ["a.txt", "b.txt"] {
+            create_file(&repo.path, name, "staged\n")?;
+            git_add(&repo.path, name)?;
+        }
+        let navigator = |args: &[&str]| -> anyhow::Result<Command> {
+            let mut cmd = Command::cargo_bin("git-navigator")?;
+            cmd.args(args)
+                .env("XDG_CACHE_HOME", cache_home.path())
+                .current_dir(&repo.path);
+            Ok(cmd)
+        };
+
+        navigator(&["--plain", "status", "b.txt"])?
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Index 1: b.txt"));
+        let cache_dir = std::fs::read_dir(cache_home.path().join("git-navigator"))?
+            .next()
+            .unwrap()?
+            .path();
+        std::fs::write(cache_dir.join("files.json"), "{\"files\": [")?;
+
+        navigator(&["reset", "1"])?
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("was rebuilt"));
+        let staged = std::process::Command::new("git")
+            .args(["diff", "--cached", "--name-only"])
+            .current_dir(&repo.path)
+            .output()?;
+        assert_eq!(String::from_utf8(staged.stdout)?, "a.txt\nb.txt\n");
+
+        // The list was rebuilt as `gs b.txt` showed it
+        navigator(&["reset", "1"])?.assert().success();
+        let staged = std::process::Command::new("git")
+            .args(["diff", "--cached", "--name-only"])
+            .current_dir(&repo.path)
+            .output()?;
+        assert_eq!(String::from_utf8(staged.stdout)?, "a.txt\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_gs_warns_once_when_cache_dir_unwritable() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache_parent = tempfile::TempDir::new()?;
+        // A regular file can't hold the cache directory, even for root
+        let cache_home = cache_parent.path().join("not-a-dir");
+        std::fs::write(&cache_home, "")?;
+        create_file(&repo.path, "new.txt", "new\n")?;
+
+        let output = Command::cargo_bin("git-navigator")?
+            .arg("status")
+            .env("XDG_CACHE_HOME", &cache_home)
+            .current_dir(&repo.path)
+            .output()?;
+
+        assert!(output.status.success());
+        assert!(String::from_utf8_lossy(&output.stdout).contains("new.txt"));
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert_eq!(stderr.matches("is not writable").count(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_gs_fast_mode_reuses_unchanged_status() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let config_home = tempfile::TempDir::new()?;
+        let cache_home = tempfile::TempDir::new()?;
+        std::fs::create_dir_all(config_home.path().join("git-navigator"))?;
+        std::fs::write(
+            config_home.path().join("git-navigator/config.toml"),
+            "[status]\nfast = true\n",
+        )?;
+        create_file(&repo.path, "new.txt", "new\n")?;
+
+        let gs = |extra: &[&str]| -> anyhow::Result<String> {
+            let output = Command::cargo_bin("git-navigator")?
+                .arg("status")
+                .args(extra)
+                .env("XDG_CONFIG_HOME", config_home.path())
+                .env("XDG_CACHE_HOME", cache_home.path())
+                .current_dir(&repo.path)
+                .output()?;
+            assert!(output.status.success());
+            Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+        };
+
+        assert!(!gs(&[])?.contains("(cached"));
+
+        let cached = gs(&[])?;
+        assert!(cached.contains("(cached"));
+        assert!(cached.contains("new.txt"));
+
+        assert!(!gs(&["--refresh"])?.contains("(cached"));
+
+        // Staging changes the index checksum
+        git_add(&repo.path, "new.txt")?;
+        let rescanned = gs(&[])?;
+        assert!(!rescanned.contains("(cached"));
+        assert!(rescanned.contains("➤ Staged (1):"));
+
+        // A new file changes the mtime of its directory
+        create_file(&repo.path, "another.txt", "another\n")?;
+        let rescanned = gs(&[])?;
+        assert!(!rescanned.contains("(cached"));
+        assert!(rescanned.contains("another.txt"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gs_applies_status_color_overrides() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let config_home = tempfile::TempDir::new()?;
+        std::fs::create_dir_all(config_home.path().join("git-navigator"))?;
+        std::fs::write(
+            config_home.path().join("git-navigator/config.toml"),
+            "[color.status]\nuntracked = \"bold 208\"\n",
+        )?;
+        create_file(&repo.path, "new.txt", "new\n")?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.arg("status")
+            .env("XDG_CONFIG_HOME", config_home.path())
+            .env("CLICOLOR_FORCE", "1")
+            .env("TERM", "xterm-256color")
+            .env_remove("COLORTERM")
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("\x1b[1;38;5;208mnew.txt"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gs_color_flag_and_no_color() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "new.txt", "new\n")?;
+        let gs = |args: &[&str], vars: &[(&str, &str)]| -> anyhow::Result<String> {
+            let mut cmd = Command::cargo_bin("git-navigator")?;
+            cmd.args(args)
+                .arg("status")
+                .env_remove("CLICOLOR_FORCE")
+                .env_remove("NO_COLOR")
+                .envs(vars.iter().copied())
+                .current_dir(&repo.path);
+            let output = cmd.output()?;
+            assert!(output.status.success());
+            Ok(String::from_utf8(output.stdout)?)
+        };
+
+        // Piped output is plain text unless asked otherwise
+        assert!(!gs(&[], &[])?.contains('\x1b'));
+        assert!(gs(&["--color=always"], &[])?.contains("\x1b["));
+        assert!(gs(&["--color", "always"], &[("NO_COLOR", "1")])?.contains("\x1b["));
+        assert!(!gs(&["--color=never"], &[("CLICOLOR_FORCE", "1")])?.contains('\x1b'));
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["--color=sometimes", "status"])
+            .current_dir(&repo.path)
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("invalid value 'sometimes'"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_gs_applies_template_overrides() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let config_home = tempfile::TempDir::new()?;
+        std::fs::create_dir_all(config_home.path().join("git-navigator"))?;
+        std::fs::write(
+            config_home.path().join("git-navigator/config.toml"),
+            "[templates]\nsection_untracked = \"New files ({count})\"\nfile_line = \"  {n}) {filename} <{file_status}>\"\n\n[color.status]\nuntracked = \"magenta\"\n",
+        )?;
+        create_file(&repo.path, "new.txt", "new\n")?;
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.arg("status")
+            .env("XDG_CONFIG_HOME", config_home.path())
+            .env("CLICOLOR_FORCE", "1")
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("\x1b[36mNew files (1):"))
+            .stdout(predicate::str::contains("\x1b[35mnew.txt\x1b[0m <"))
+            .stdout(predicate::str::contains("untracked"));
+
+        // Plain mode keeps its spelled-out form
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["--plain", "status"])
+            .env("XDG_CONFIG_HOME", config_home.path())
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Section: New files (1)"))
+            .stdout(predicate::str::contains("Index 1: new.txt (untracked)"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gs_hints_follow_ui_hints() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let config_home = tempfile::TempDir::new()?;
+        create_file(&repo.path, "initial.txt", "changed\n")?;
+        create_file(&repo.path, "new.txt", "new\n")?;
+        let gs = || {
+            let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+            cmd.args(["--plain", "status"])
+                .env("XDG_CONFIG_HOME", config_home.path())
+                .current_dir(&repo.path);
+            cmd
+        };
+
+        // Off unless asked for
+        gs().assert()
+            .success()
+            .stdout(predicate::str::contains("Tip:").not());
+
+        std::fs::create_dir_all(config_home.path().join("git-navigator"))?;
+        std::fs::write(
+            config_home.path().join("git-navigator/config.toml"),
+            "[ui]\nhints = true\n",
+        )?;
+        gs().assert().success().stdout(predicate::str::contains(
+            "Tip: `ga 1` to stage modified files, `ga 2` to track new files",
+        ));
+
+        git_add(&repo.path, "initial.txt")?;
+        gs().assert().success().stdout(predicate::str::contains(
+            "Tip: `gc` to commit the staged files, `ga 2` to track new files",
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_index_format_in_output_and_input() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let config_home = tempfile::TempDir::new()?;
+        std::fs::create_dir_all(config_home.path().join("git-navigator"))?;
+        std::fs::write(
+            config_home.path().join("git-navigator/config.toml"),
+            "[templates]\nindex = \"({n})\"\nindex_width = 2\n",
+        )?;
+        create_file(&repo.path, "a.txt", "a\n")?;
+        create_file(&repo.path, "b.txt", "b\n")?;
+        let navigator = |args: &[&str]| {
+            let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+            cmd.args(args)
+                .env("XDG_CONFIG_HOME", config_home.path())
+                .current_dir(&repo.path);
+            cmd
+        };
+
+        navigator(&["status"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("(01) a.txt"))
+            .stdout(predicate::str::contains("(02) b.txt"));
+
+        // The numbers work as they were printed
+        navigator(&["add", "(02)"]).assert().success();
+        navigator(&["--plain", "status"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Index 1: b.txt (new)"))
+            .stdout(predicate::str::contains("Index 2: a.txt (untracked)"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_gs_hex_colors_follow_terminal_depth() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let config_home = tempfile::TempDir::new()?;
+        std::fs::create_dir_all(config_home.path().join("git-navigator"))?;
+        std::fs::write(
+            config_home.path().join("git-navigator/config.toml"),
+            "[color.status]\nuntracked = \"#ff8700\"\n",
+        )?;
+        create_file(&repo.path, "new.txt", "new\n")?;
+
+        let gs = |colorterm: Option<&str>, term: &str| -> anyhow::Result<String> {
+            let mut cmd = Command::cargo_bin("git-navigator")?;
+            cmd.arg("status")
+                .env("XDG_CONFIG_HOME", config_home.path())
+                .env("CLICOLOR_FORCE", "1")
+                .env("TERM", term)
+                .env_remove("COLORTERM")
+                .current_dir(&repo.path);
+            if let Some(colorterm) = colorterm {
+                cmd.env("COLORTERM", colorterm);
+            }
+            Ok(String::from_utf8_lossy(&cmd.output()?.stdout).into_owned())
+        };
+
+        assert!(gs(Some("truecolor"), "xterm")?.contains("\x1b[38;2;255;135;0mnew.txt"));
+        assert!(gs(None, "xterm-256color")?.contains("\x1b[38;5;208mnew.txt"));
+        assert!(gs(None, "xterm")?.contains("\x1b[33mnew.txt"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_gs_plain_mode_uses_words() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "initial.txt", "modified content")?;
+        create_file(&repo.path, "new.txt", "new\n")?;
+
+        let output = Command::cargo_bin("git-navigator")?
+            .args(["--plain", "status"])
+            .env("CLICOLOR_FORCE", "1")
+            .current_dir(&repo.path)
+            .output()?;
+        let stdout = String::from_utf8(output.stdout)?;
+
+        assert!(stdout.contains("Section: Not staged (1)\nIndex 1: initial.txt (modified)\n"));
+        assert!(stdout.contains("Section: Untracked (1)\nIndex 2: new.txt (untracked)\n"));
+        assert!(
+            stdout.is_ascii(),
+            "plain output should have no glyphs: {stdout}"
+        );
+        assert!(!stdout.contains("\x1b["));
+        assert!(!stdout.contains("  "), "plain output should not be padded");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gs_ascii_glyphs_when_unicode_disabled() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let config_home = tempfile::TempDir::new()?;
+        std::fs::create_dir_all(config_home.path().join("git-navigator"))?;
+        std::fs::write(
+            config_home.path().join("git-navigator/config.toml"),
+            "[ui]\nunicode = false\n",
+        )?;
+        create_file(&repo.path, "initial.txt", "modified content")?;
+
+        let output = Command::cargo_bin("git-navigator")?
+            .arg("status")
+            .env("XDG_CONFIG_HOME", config_home.path())
+            .current_dir(&repo.path)
+            .output()?;
+        let stdout = String::from_utf8(output.stdout)?;
+        assert!(stdout.contains("> Not staged (1):"));
+        assert!(stdout.is_ascii(), "unexpected unicode glyphs: {stdout}");
+
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["add", "99"])
+            .env("XDG_CONFIG_HOME", config_home.path())
+            .current_dir(&repo.path)
+            .assert()
+            .stderr(predicate::str::contains("x Error:"))
+            .stdout(predicate::str::contains("✕").not());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gs_common_prefix_paths() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        std::fs::create_dir_all(repo.path.join("src/core"))?;
+        create_file(&repo.path, "src/core/a.rs", "a\n")?;
+        create_file(&repo.path, "src/core/b.rs", "b\n")?;
+        run_git(&repo.path, &["add", "."])?;
+        git_commit(&repo.path, "Add sources")?;
+        create_file(&repo.path, "src/core/a.rs", "changed a\n")?;
+        create_file(&repo.path, "src/core/b.rs", "changed b\n")?;
+
+        let output = Command::cargo_bin("git-navigator")?
+            .args(["status", "--paths", "common-prefix"])
+            .current_dir(&repo.path)
+            .output()?;
+        let stdout = String::from_utf8(output.stdout)?;
+        assert!(
+            stdout.contains("Not staged (2) (in src/core/):"),
+            "{stdout}"
+        );
+        assert!(stdout.contains("[1] a.rs"));
+        assert!(!stdout.contains("src/core/a.rs"));
+
+        // Indices still resolve to the full paths
+        let mut cmd = Command::cargo_bin("git-navigator")?;
+        cmd.args(["diff", "1"])
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("src/core/a.rs"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gs_hyperlinks_follow_setting() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let config_home = tempfile::TempDir::new()?;
+        create_file(&repo.path, "new.txt", "new\n")?;
+
+        let gs = |config: &str| -> anyhow::Result<String> {
+            std::fs::create_dir_all(config_home.path().join("git-navigator"))?;
+            std::fs::write(config_home.path().join("git-navigator/config.toml"), config)?;
+            let output = Command::cargo_bin("git-navigator")?
+                .arg("status")
+                .env("XDG_CONFIG_HOME", config_home.path())
+                .env("TERM_PROGRAM", "WezTerm")
+                .current_dir(&repo.path)
+                .output()?;
+            assert!(output.status.success());
+            Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+        };
+
+        // Not a terminal, so auto detection leaves links off
+        assert!(!gs("")?.contains("\x1b]8;;"));
+
+        let linked = gs("[ui]\nhyperlinks = true\n")?;
+        assert!(linked.contains("\x1b]8;;file:///"));
+        assert!(linked.contains("new.txt\x1b\\new.txt\x1b]8;;\x1b\\"));
+
+        let custom =
+            gs("[ui]\nhyperlinks = true\nhyperlink_url = \"vscode://file{file}:{line}\"\n")?;
+        assert!(custom.contains("\x1b]8;;vscode://file/"));
+        assert!(custom.contains("new.txt:1\x1b\\"));
+
+        assert!(!gs("[ui]\nhyperlinks = false\n")?.contains("\x1b]8;;"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_gs_links_issue_keys() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let config_home = tempfile::TempDir::new()?;
+        run_git(
+            &repo.path,
+            &["checkout", "-q", "-b", "feature/NAV-42-links"],
+        )?;
+        create_file(&repo.path, "a.txt", "a\n")?;
+        git_add(&repo.path, "a.txt")?;
+        git_commit(&repo.path, "Fix NAV-7 crash")?;
+
+        std::fs::create_dir_all(config_home.path().join("git-navigator"))?;
+        std::fs::write(
+            config_home.path().join("git-navigator/config.toml"),
+            "[ui]\nhyperlinks = true\n[issues]\nurl = \"https://jira.example.com/browse/{issue}\"\n",
+        )?;
+        let output = Command::cargo_bin("git-navigator")?
+            .arg("status")
+            .env("XDG_CONFIG_HOME", config_home.path())
+            .current_dir(&repo.path)
+            .output()?;
+        assert!(output.status.success());
+        let stdout = String::from_utf8_lossy(&output.stdout);
+
+        assert!(stdout
+            .contains("\x1b]8;;https://jira.example.com/browse/NAV-42\x1b\\NAV-42\x1b]8;;\x1b\\"));
+        assert!(stdout
+            .contains("\x1b]8;;https://jira.example.com/browse/NAV-7\x1b\\NAV-7\x1b]8;;\x1b\\"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_gs_code_owners() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache_home = tempfile::TempDir::new()?;
+        let config_home = tempfile::TempDir::new()?;
+        std::fs::create_dir_all(repo.path.join("web"))?;
+        std::fs::create_dir_all(repo.path.join("src"))?;
+        std::fs::create_dir_all(repo.path.join(".github"))?;
+        create_file(&repo.path, "README.md", "readme\n")?;
+        create_file(&repo.path, "web/app.js", "app\n")?;
+        create_file(&repo.path, "src/core.rs", "core\n")?;
+        // Staged, so the new directories are listed file by file
+        run_git(&repo.path, &["add", "README.md", "web", "src"])?;
+
+        let gs = |args: &[&str]| {
+            let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+            cmd.arg("--plain")
+                .arg("status")
+                .args(args)
+                .env("XDG_CACHE_HOME", cache_home.path())
+                .env("XDG_CONFIG_HOME", config_home.path())
+                .current_dir(&repo.path);
+            cmd
+        };
+
+        gs(&["--owner", "@org/web"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("No CODEOWNERS file found"));
+
+        create_file(
+            &repo.path,
+            ".github/CODEOWNERS",
+            "*.rs @org/rust\nweb/ @org/web @alice\n",
+        )?;
+
+        // Owners are only shown when ui.show_owners is on
+        gs(&[])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("owned by").not());
+
+        std::fs::create_dir_all(config_home.path().join("git-navigator"))?;
+        std::fs::write(
+            config_home.path().join("git-navigator/config.toml"),
+            "[ui]\nshow_owners = true\n",
+        )?;
+        gs(&[])
+            .assert()
+            .success()
+            .stdout(predicate::str::is_match(
+                r"src/core\.rs \([a-z ]+\), owned by @org/rust\n",
+            )?)
+            .stdout(predicate::str::is_match(
+                r"web/app\.js \([a-z ]+\), owned by @org/web @alice\n",
+            )?)
+            .stdout(predicate::str::is_match(r"README\.md \([a-z ]+\)\n")?);
+
+        // The filter keeps the indices of the full listing, so ga still agrees
+        let output = gs(&["--owner", "org/web"]).output()?;
+        assert!(output.status.success());
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.contains("web/app.js"));
+        assert!(!stdout.contains("src/core.rs"));
+        assert!(!stdout.contains("Index 1:"));
+
+        gs(&["--owner", "@org/docs"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "No changed files are owned by @org/docs.",
+            ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_gs_review_budget() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache_home = tempfile::TempDir::new()?;
+        let lines: String = (1..=10).map(|n| format!("line {n}\n")).collect();
+        create_file(&repo.path, "big.txt", &lines)?;
+        create_file(&repo.path, "small.txt", "one\n")?;
+
+        // Within budget is a note on stdout, over it a warning on stderr
+        let gs = |budget: &[&str]| -> anyhow::Result<String> {
+            let output = Command::cargo_bin("git-navigator")?
+                .arg("status")
+                .args(budget)
+                .env("XDG_CACHE_HOME", cache_home.path())
+                .current_dir(&repo.path)
+                .output()?;
+            assert!(output.status.success());
+            Ok(format!(
+                "{}{}",
+                String::from_utf8_lossy(&output.stdout),
+                String::from_utf8_lossy(&output.stderr)
+            ))
+        };
+
+        let within = gs(&["--review-budget"])?;
+        assert!(within.contains("Pending changes touch 11 of 400 budgeted lines."));
+
+        let over = gs(&["--review-budget", "5"])?;
+        assert!(over.contains("Pending changes touch 11 lines, over the review budget of 5."));
+        assert!(over.contains("Consider splitting"));
+        assert!(over.contains("big.txt (+10 -0)"));
+
+        // Stats are recomputed once the worktree changes
+        create_file(&repo.path, "small.txt", "one\ntwo\n")?;
+        assert!(gs(&["--review-budget", "5"])?.contains("touch 12 lines"));
+
+        assert!(!gs(&[])?.contains("Pending changes"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_gs_shows_stale_last_fetch() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let remote = tempfile::TempDir::new()?;
+        let config_home = tempfile::TempDir::new()?;
+        run_git(remote.path(), &["init", "--bare", "--quiet"])?;
+        run_git(
+            &repo.path,
+            &["remote", "add", "origin", &remote.path().to_string_lossy()],
+        )?;
+        run_git(&repo.path, &["push", "--quiet", "-u", "origin", "main"])?;
+        run_git(&repo.path, &["fetch", "--quiet"])?;
+
+        let gs = |config: &str| -> anyhow::Result<String> {
+            std::fs::create_dir_all(config_home.path().join("git-navigator"))?;
+            std::fs::write(config_home.path().join("git-navigator/config.toml"), config)?;
+            let output = Command::cargo_bin("git-navigator")?
+                .arg("status")
+                .env("XDG_CONFIG_HOME", config_home.path())
+                .current_dir(&repo.path)
+                .output()?;
+            assert!(output.status.success());
+            Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+        };
+
+        // Just fetched
+        assert!(!gs("")?.contains("Last fetch"));
+
+        let three_hours_ago =
+            std::time::SystemTime::now() - std::time::Duration::from_secs(3 * 3600 + 60);
+        std::fs::File::options()
+            .write(true)
+            .open(repo.path.join(".git/FETCH_HEAD"))?
+            .set_modified(three_hours_ago)?;
+
+        assert!(gs("")?.contains("Last fetch: 3h ago"));
+        assert!(!gs("[status]\nlast_fetch_hours = 4\n")?.contains("Last fetch"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_gs_parent_full_shows_wrapped_body() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "notes.txt", "notes")?;
+        git_add(&repo.path, "notes.txt")?;
+        run_git(
+            &repo.path,
+            &[
+                "commit",
+                "--quiet",
+                "-m",
+                "Add notes",
+                "-m",
+                "Keeps track of the open questions from the design review so work can resume",
+            ],
+        )?;
+
+        let gs = |args: &[&str]| -> anyhow::Result<String> {
+            let output = Command::cargo_bin("git-navigator")?
+                .args(args)
+                .env("COLUMNS", "48")
+                .current_dir(&repo.path)
+                .output()?;
+            assert!(output.status.success());
+            Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+        };
+
+        assert!(!gs(&["--plain", "status"])?.contains("open questions"));
+
+        // Wrapped to 40 columns after the 8-column indent
+        let output = gs(&["--plain", "status", "--parent-full"])?;
+        assert!(output.contains("Add notes\nKeeps track of the open questions from\nthe design review so work can resume\n"));
+
+        std::fs::create_dir_all(repo.path.join(".git/git-navigator"))?;
+        create_file(
+            &repo.path,
+            ".git/git-navigator/config.toml",
+            "[status]\nparent_full = true\n",
+        )?;
+        assert!(gs(&["status"])?.contains("        the design review so work can resume"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_gs_cuts_long_parent_subject() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "notes.txt", "notes")?;
+        git_add(&repo.path, "notes.txt")?;
+        git_commit(&repo.path, "Rework the cache layer so lookups stay fast")?;
+
+        // Piped output keeps the whole subject
+        Command::cargo_bin("git-navigator")?
+            .args(["--plain", "status"])
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("so lookups stay fast"));
+
+        std::fs::create_dir_all(repo.path.join(".git/git-navigator"))?;
+        create_file(
+            &repo.path,
+            ".git/git-navigator/config.toml",
+            "[status]\nparent_width = 36\n",
+        )?;
+        Command::cargo_bin("git-navigator")?
+            .args(["--plain", "status"])
+            .current_dir(&repo.path)
+            .assert()
+            .success()
+            .stdout(predicate::str::is_match(
+                r"\nParent: [0-9a-f]{7} Rework the cache la…\n",
+            )?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_gs_export_env_prints_numbered_paths() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache_home = tempfile::TempDir::new()?;
+        create_file(&repo.path, "notes.txt", "draft\n")?;
+        create_file(&repo.path, "todo.txt", "later\n")?;
+
+        Command::cargo_bin("git-navigator")?
+            .args(["--plain", "status"])
+            .current_dir(&repo.path)
+            .env("XDG_CACHE_HOME", cache_home.path())
+            .assert()
+            .success();
+
+        let expected = format!(
+            "export e1='{}'\nexport e2='{}'\nunset e3 e4\n",
+            repo.path.join("notes.txt").display(),
+            repo.path.join("todo.txt").display()
+        );
+        Command::cargo_bin("git-navigator")?
+            .args(["status", "--export-env"])
+            .current_dir(&repo.path)
+            .env("XDG_CACHE_HOME", cache_home.path())
+            .env("e3", "old")
+            .env("e4", "old")
+            .assert()
+            .success()
+            .stdout(expected);
+
+        Command::cargo_bin("git-navigator")?
+            .args(["status", "--export-env=fish"])
+            .current_dir(&repo.path)
+            .env("XDG_CACHE_HOME", cache_home.path())
+            .assert()
+            .success()
+            .stdout(predicate::str::starts_with("set -gx e1 '"));
+
+        // Once everything is committed a clean `gs` keeps its cached list,
+        // but none of the variables may still point into it
+        git_add(&repo.path, ".")?;
+        git_commit(&repo.path, "Add notes")?;
+        Command::cargo_bin("git-navigator")?
+            .args(["--plain", "status"])
+            .current_dir(&repo.path)
+            .env("XDG_CACHE_HOME", cache_home.path())
+            .assert()
+            .success();
+        Command::cargo_bin("git-navigator")?
+            .args(["status", "--export-env"])
+            .current_dir(&repo.path)
+            .env("XDG_CACHE_HOME", cache_home.path())
+            .env("e1", "old")
+            .env("e2", "old")
+            .assert()
+            .success()
+            .stdout("unset e1 e2\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_ga_refuses_indices_that_moved_since_gs() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache_home = tempfile::TempDir::new()?;
+        create_file(&repo.path, "b.txt", "b\n")?;
+        let navigator = |args: &[&str]| {
+            let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+            cmd.args(args)
+                .current_dir(&repo.path)
+                .env("XDG_CACHE_HOME", cache_home.path());
+            cmd
+        };
+        navigator(&["--plain", "status"]).assert().success();
+
+        // A new file takes over [1], so `ga 1` must not stage it unseen
+        create_file(&repo.path, "a.txt", "a\n")?;
+        navigator(&["add", "1"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(
+                "The file list is out of date (the worktree changed): [1] was b.txt, now a.txt",
+            ));
+
+        // The list was refreshed, so the new numbers work right away
+        navigator(&["--plain", "add", "2"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Index 1: b.txt (new)"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_ga_leaves_out_excluded_indices() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache_home = tempfile::TempDir::new()?;
+        for name in ["a.txt", "b.txt", "c.txt", "d.txt"] {
+            create_file(&repo.path, name, "new\n")?;
+        }
+        let navigator = |args: &[&str]| {
+            let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+            cmd.args(args)
+                .current_dir(&repo.path)
+                .env("XDG_CACHE_HOME", cache_home.path());
+            cmd
+        };
+        let staged = || -> anyhow::Result<String> {
+            let output = Command::new("git")
+                .args(["diff", "--cached", "--name-only"])
+                .current_dir(&repo.path)
+                .output()?;
+            Ok(String::from_utf8(output.stdout)?)
+        };
+        navigator(&["--plain", "status"]).assert().success();
+
+        navigator(&["add", "all", "^9"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Index 9 is out of range"));
+        assert_eq!(staged()?, "");
+
+        navigator(&["add", "all", "^2", "^3-4"]).assert().success();
+        assert_eq!(staged()?, "a.txt\n");
+
+        // "-3" works as well, in front of a single index
+        run_git(&repo.path, &["reset", "-q"])?;
+        navigator(&["--plain", "status"]).assert().success();
+        navigator(&["add", "1-3", "-2"]).assert().success();
+        assert_eq!(staged()?, "a.txt\nc.txt\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_index_commands_take_section_names() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache_home = tempfile::TempDir::new()?;
+        create_file(&repo.path, "initial.txt", "changed\n")?;
+        create_file(&repo.path, "b.txt", "b\n")?;
+        create_file(&repo.path, "c.txt", "c\n")?;
+        let navigator = |args: &[&str]| {
+            let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+            cmd.args(args)
+                .current_dir(&repo.path)
+                .env("XDG_CACHE_HOME", cache_home.path());
+            cmd
+        };
+        navigator(&["--plain", "status"]).assert().success();
+
+        navigator(&["add", "unmerged"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(
+                "No unmerged files in the last gs listing",
+            ));
+
+        navigator(&["--plain", "add", "untracked"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Index 1: b.txt (new)"))
+            .stdout(predicate::str::contains("Index 2: c.txt (new)"))
+            .stdout(predicate::str::contains("Index 3: initial.txt (modified)"));
+
+        // A lone section name is a file selection, not a commit to reset to
+        navigator(&["--plain", "reset", "staged"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "Successfully reset 2 file(s) from git index.",
+            ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_index_commands_take_paths_next_to_indices() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache_home = tempfile::TempDir::new()?;
+        std::fs::create_dir_all(repo.path.join("src"))?;
+        create_file(&repo.path, "a.txt", "a\n")?;
+        create_file(&repo.path, "src/lib.rs", "lib\n")?;
+        git_add(&repo.path, "src/lib.rs")?;
+        git_commit(&repo.path, "Add lib")?;
+        create_file(&repo.path, "src/lib.rs", "changed\n")?;
+        let navigator = |dir: &std::path::Path, args: &[&str]| {
+            let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+            cmd.args(args)
+                .current_dir(dir)
+                .env("XDG_CACHE_HOME", cache_home.path());
+            cmd
+        };
+        let staged = || -> anyhow::Result<String> {
+            let output = Command::new("git")
+                .args(["diff", "--cached", "--name-only"])
+                .current_dir(&repo.path)
+                .output()?;
+            Ok(String::from_utf8(output.stdout)?)
+        };
+        navigator(&repo.path, &["--plain", "status"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Index 1: src/lib.rs (modified)"))
+            .stdout(predicate::str::contains("Index 2: a.txt (untracked)"));
+
+        navigator(&repo.path, &["add", "1", "missing.txt"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(
+                "'missing.txt' is neither a file index nor a path",
+            ));
+        navigator(&repo.path, &["add", "initial.txt"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(
+                "No changed files at 'initial.txt'",
+            ));
+
+        // A file created after gs is found in a fresh status
+        create_file(&repo.path, "new.txt", "new\n")?;
+        navigator(&repo.path, &["add", "2", "new.txt"])
+            .assert()
+            .success();
+        assert_eq!(staged()?, "a.txt\nnew.txt\n");
+
+        // Paths are relative to the current directory, as with git
+        navigator(&repo.path, &["--plain", "status"])
+            .assert()
+            .success();
+        navigator(&repo.path.join("src"), &["add", "lib.rs"])
+            .assert()
+            .success();
+        assert_eq!(staged()?, "a.txt\nnew.txt\nsrc/lib.rs\n");
+
+        navigator(&repo.path, &["--plain", "status"])
+            .assert()
+            .success();
+        navigator(&repo.path, &["reset", "src/"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "Successfully reset 1 file(s) from git index.",
+            ));
+        assert_eq!(staged()?, "a.txt\nnew.txt\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_ga_patch_needs_a_terminal() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache_home = tempfile::TempDir::new()?;
+        create_file(&repo.path, "initial.txt", "initial content\nmore\n")?;
+        let navigator = |args: &[&str]| {
+            let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+            cmd.args(args)
+                .current_dir(&repo.path)
+                .env("XDG_CACHE_HOME", cache_home.path());
+            cmd
+        };
+        navigator(&["status"]).assert().success();
+
+        navigator(&["add", "-p", "1"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(
+                "pass the indices without --patch to stage whole files",
+            ));
+        let staged = Command::new("git")
+            .args(["diff", "--cached", "--name-only"])
+            .current_dir(&repo.path)
+            .output()?;
+        assert!(staged.stdout.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_gs_lists_changed_submodules_in_their_own_section() -> anyhow::Result<()> {
+        let lib = setup_test_repo_with_initial_commit()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache_home = tempfile::TempDir::new()?;
+        let git = |dir: &std::path::Path, args: &[&str]| -> anyhow::Result<()> {
+            let status = Command::new("git")
+                .args(["-c", "protocol.file.allow=always"])
+                .args([
+                    "-c",
+                    "user.name=Test User",
+                    "-c",
+                    "user.email=test@example.com",
+                ])
+                .args(args)
+                .current_dir(dir)
+                .output()?
+                .status;
+            anyhow::ensure!(status.success(), "git {args:?} failed");
+            Ok(())
+        };
+        let lib_url = lib.path.to_string_lossy().into_owned();
+        for name in ["moved", "dirty", "scratch"] {
+            git(&repo.path, &["submodule", "add", "-q", &lib_url, name])?;
+        }
+        git(&repo.path, &["commit", "-qm", "Add submodules"])?;
+        git(
+            &repo.path.join("moved"),
+            &["commit", "-q", "--allow-empty", "-m", "More"],
+        )?;
+        create_file(&repo.path.join("dirty"), "initial.txt", "changed\n")?;
+        create_file(&repo.path.join("scratch"), "notes.txt", "notes\n")?;
+        create_file(&repo.path, "new.txt", "new\n")?;
+        let navigator = |args: &[&str]| {
+            let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+            cmd.args(args)
+                .current_dir(&repo.path)
+                .env("XDG_CACHE_HOME", cache_home.path());
+            cmd
+        };
+
+        navigator(&["--plain", "status"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Submodules (3)"))
+            .stdout(predicate::str::contains(
+                "Index 1: dirty (modified content)",
+            ))
+            .stdout(predicate::str::contains("Index 2: moved (new commits)"))
+            .stdout(predicate::str::contains(
+                "Index 3: scratch (untracked content)",
+            ))
+            .stdout(predicate::str::contains("Index 4: new.txt (untracked)"));
+
+        // gd shows what changed inside, ga records the new commit
+        navigator(&["diff", "1"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("+changed"));
+        navigator(&["add", "2"]).assert().success();
+        navigator(&["--plain", "status"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Index 1: moved (modified)"))
+            .stdout(predicate::str::contains("Submodules (2)"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_gs_pairs_staged_renames_and_copies() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache_home = tempfile::TempDir::new()?;
+        let lines: String = (1..=40).map(|n| format!("line {n}\n")).collect();
+        create_file(&repo.path, "source.txt", &lines)?;
+        git_add(&repo.path, "source.txt")?;
+        git_commit(&repo.path, "Add source")?;
+        run_git(&repo.path, &["mv", "initial.txt", "renamed.txt"])?;
+        create_file(&repo.path, "source.txt", &format!("{lines}line 41\n"))?;
+        create_file(&repo.path, "copy.txt", &lines)?;
+        run_git(&repo.path, &["add", "source.txt", "copy.txt"])?;
+        let navigator = |args: &[&str]| {
+            let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+            cmd.args(args)
+                .current_dir(&repo.path)
+                .env("XDG_CACHE_HOME", cache_home.path());
+            cmd
+        };
+
+        let output = navigator(&["--plain", "status"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Index 1: source.txt (modified)"))
+            .stdout(predicate::str::contains(
+                "Index 2: initial.txt -> renamed.txt (renamed)",
+            ))
+            .stdout(predicate::str::contains(
+                "Index 3: source.txt -> copy.txt (copied)",
+            ))
+            .get_output()
+            .stdout
+            .clone();
+        assert_eq!(String::from_utf8(output)?.matches("Index ").count(), 3);
+
+        // Unstaging a rename unstages both ends
+        navigator(&["reset", "2"]).assert().success();
+        let status = Command::new("git")
+            .args(["status", "--porcelain"])
+            .current_dir(&repo.path)
+            .output()?;
+        let status = String::from_utf8(status.stdout)?;
+        assert!(status.contains(" D initial.txt"), "{status}");
+        assert!(status.contains("?? renamed.txt"), "{status}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_gs_limits_list_and_indices_to_picked_sections() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache_home = tempfile::TempDir::new()?;
+        create_file(&repo.path, "staged.txt", "staged\n")?;
+        git_add(&repo.path, "staged.txt")?;
+        create_file(&repo.path, "initial.txt", "changed\n")?;
+        create_file(&repo.path, "new.txt", "new\n")?;
+        let navigator = |args: &[&str]| {
+            let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+            cmd.args(args)
+                .current_dir(&repo.path)
+                .env("XDG_CACHE_HOME", cache_home.path());
+            cmd
+        };
+
+        let output = navigator(&["--plain", "status", "--untracked"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Index 1: new.txt (untracked)"))
+            .get_output()
+            .stdout
+            .clone();
+        assert_eq!(String::from_utf8(output)?.matches("Index ").count(), 1);
+
+        // The numbers are those of the short list, and stay so
+        navigator(&["add", "1"]).assert().success();
+        let staged = Command::new("git")
+            .args(["diff", "--cached", "--name-only"])
+            .current_dir(&repo.path)
+            .output()?;
+        assert_eq!(String::from_utf8(staged.stdout)?, "new.txt\nstaged.txt\n");
+
+        navigator(&["--plain", "status", "--unmerged"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("No unmerged files."));
+        navigator(&["add", "1"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("No cached files found"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_gs_limits_list_and_indices_to_paths() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache_home = tempfile::TempDir::new()?;
+        std::fs::create_dir_all(repo.path.join("src/core"))?;
+        create_file(&repo.path, "src/core/a.rs", "a\n")?;
+        create_file(&repo.path, "src/b.rs", "b\n")?;
+        create_file(&repo.path, "src/notes.md", "notes\n")?;
+        create_file(&repo.path, "initial.txt", "changed\n")?;
+        let navigator = |dir: &std::path::Path, args: &[&str]| {
+            let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+            cmd.args(args)
+                .current_dir(dir)
+                .env("XDG_CACHE_HOME", cache_home.path());
+            cmd
+        };
+
+        // Paths are taken from the current directory; globs cross directories
+        let src = repo.path.join("src");
+        let output = navigator(&src, &["--plain", "status", "*.rs"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Index 1: src/b.rs (untracked)"))
+            .stdout(predicate::str::contains(
+                "Index 2: src/core/a.rs (untracked)",
+            ))
+            .get_output()
+            .stdout
+            .clone();
+        assert_eq!(String::from_utf8(output)?.matches("Index ").count(), 2);
+
+        // ga works on the short list
+        navigator(&repo.path, &["add", "2"]).assert().success();
+        let staged = Command::new("git")
+            .args(["diff", "--cached", "--name-only"])
+            .current_dir(&repo.path)
+            .output()?;
+        assert_eq!(String::from_utf8(staged.stdout)?, "src/core/a.rs\n");
+
+        navigator(
+            &repo.path,
+            &["--plain", "status", "--staged", "src/core", "docs"],
+        )
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Index 1: src/core/a.rs (new)"));
+        navigator(&repo.path, &["--plain", "status", "docs"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("No changed files match docs."));
+        navigator(&repo.path, &["status", ".."])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("'..' is outside the repository"));
+        Ok(())
+    }
 }
@@ -0,0 +1,210 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::path::Path;
+use std::process::Command;
+
+mod common;
+use common::repository::*;
+
+fn navigator(repo_path: &Path, cache_home: &Path, args: &[&str]) -> Command {
+    let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+    cmd.args(args)
+        .current_dir(repo_path)
+        .env("XDG_CACHE_HOME", cache_home);
+    cmd
+}
+
+fn git_output(repo_path: &Path, args: &[&str]) -> anyhow::Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(repo_path)
+        .output()?;
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+#[cfg(test)]
+mod tags_command_tests {
+    use super::*;
+
+    #[test]
+    fn test_create_lightweight_and_annotated_tags() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+        create_file(&repo.path, "next.txt", "next\n")?;
+        git_add(&repo.path, "next.txt")?;
+        git_commit(&repo.path, "Add next")?;
+
+        navigator(&repo.path, cache.path(), &["tags", "create", "v0.2.0"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "Created lightweight tag 'v0.2.0' at",
+            ));
+
+        // Tag the initial commit, [2] in gl, with an annotation
+        navigator(&repo.path, cache.path(), &["log"])
+            .assert()
+            .success();
+        navigator(
+            &repo.path,
+            cache.path(),
+            &[
+                "tags",
+                "create",
+                "v0.1.0",
+                "-m",
+                "First release",
+                "--at",
+                "2",
+            ],
+        )
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Created annotated tag 'v0.1.0' at",
+        ));
+
+        assert_eq!(
+            git_output(&repo.path, &["cat-file", "-t", "v0.1.0"])?,
+            "tag\n"
+        );
+        assert_eq!(
+            git_output(&repo.path, &["log", "-1", "--format=%s", "v0.1.0"])?,
+            "Initial commit\n"
+        );
+        assert_eq!(
+            git_output(&repo.path, &["cat-file", "-t", "v0.2.0"])?,
+            "commit\n"
+        );
+
+        navigator(&repo.path, cache.path(), &["--plain", "tags"])
+            .assert()
+            .success()
+            .stdout(predicate::str::is_match(
+                r"Index 1: v0\.2\.0 [0-9a-f]+ \(lightweight, \d+s ago\)",
+            )?)
+            .stdout(predicate::str::is_match(
+                r"Index 2: v0\.1\.0 [0-9a-f]+ \(annotated, \d+s ago\)",
+            )?);
+
+        navigator(&repo.path, cache.path(), &["tags", "create", "v0.2.0"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Tag 'v0.2.0' already exists"));
+        navigator(&repo.path, cache.path(), &["tags", "create", "bad..name"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains(
+                "'bad..name' is not a valid tag name",
+            ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_delete_local_tag() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+        run_git(&repo.path, &["tag", "v1"])?;
+
+        navigator(&repo.path, cache.path(), &["tags"])
+            .assert()
+            .success();
+        navigator(&repo.path, cache.path(), &["tags", "delete", "1"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Deleted tag 'v1'"));
+        assert_eq!(git_output(&repo.path, &["tag"])?, "");
+
+        navigator(&repo.path, cache.path(), &["tags", "delete", "1"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Tag index 1 not found"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_delete_pushed_tag_needs_confirmation() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+        let remote = tempfile::TempDir::new()?;
+        run_git(remote.path(), &["init", "--bare", "--quiet"])?;
+        run_git(
+            &repo.path,
+            &["remote", "add", "origin", &remote.path().to_string_lossy()],
+        )?;
+        run_git(&repo.path, &["tag", "-a", "v1", "-m", "Release"])?;
+        run_git(&repo.path, &["push", "--quiet", "origin", "v1"])?;
+
+        navigator(&repo.path, cache.path(), &["tags"])
+            .assert()
+            .success();
+        navigator(&repo.path, cache.path(), &["tags", "delete", "1"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Tag 'v1' is on origin"))
+            .stderr(predicate::str::contains("pass --yes to confirm"));
+        assert_eq!(git_output(&repo.path, &["tag"])?, "v1\n");
+
+        navigator(&repo.path, cache.path(), &["tags", "delete", "1", "--yes"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Deleted tag 'v1'"));
+        assert_eq!(git_output(&repo.path, &["tag"])?, "");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_show_and_checkout_by_index() -> anyhow::Result<()> {
+        let repo = setup_test_repo_with_initial_commit()?;
+        let cache = tempfile::TempDir::new()?;
+        run_git(
+            &repo.path,
+            &["tag", "-a", "v1.10.0", "-m", "Ten\n\nWith notes"],
+        )?;
+        run_git(&repo.path, &["tag", "v1.9.0"])?;
+        run_git(&repo.path, &["tag", "v1.2"])?;
+        create_file(&repo.path, "later.txt", "later\n")?;
+        git_add(&repo.path, "later.txt")?;
+        git_commit(&repo.path, "Later work")?;
+
+        navigator(
+            &repo.path,
+            cache.path(),
+            &["--plain", "tags", "--sort", "version"],
+        )
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Index 1: v1.10.0"))
+        .stdout(predicate::str::contains("Index 2: v1.9.0"))
+        .stdout(predicate::str::contains("Index 3: v1.2"));
+
+        navigator(&repo.path, cache.path(), &["--plain", "tags", "show", "1"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Commit: "))
+            .stdout(predicate::str::contains("Initial commit"))
+            .stdout(predicate::str::contains("Tagger: Test User"))
+            .stdout(predicate::str::contains("    Ten\n\n    With notes"));
+        navigator(&repo.path, cache.path(), &["tags", "show", "2"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Lightweight tag"));
+
+        navigator(&repo.path, cache.path(), &["tags", "checkout", "2"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "(tag 'v1.9.0'), not on any branch",
+            ));
+        assert_eq!(
+            git_output(&repo.path, &["rev-parse", "HEAD"])?,
+            git_output(&repo.path, &["rev-parse", "v1.9.0^{commit}"])?
+        );
+        assert_eq!(git_output(&repo.path, &["symbolic-ref", "-q", "HEAD"])?, "");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::path::Path;
+use std::process::Command;
+
+/// Run with a home, config and cache directory of its own
+fn navigator(home: &Path, args: &[&str]) -> Command {
+    let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+    cmd.args(args)
+        .current_dir(home)
+        .env("HOME", home)
+        .env("XDG_CONFIG_HOME", home.join(".config"))
+        .env("XDG_CACHE_HOME", home.join(".cache"));
+    cmd
+}
+
+#[cfg(test)]
+mod uninstall_command_tests {
+    use super::*;
+
+    /// Settings, a backup, a cache and an rc file line, as a real install has
+    fn installed_home() -> anyhow::Result<tempfile::TempDir> {
+        let home = tempfile::TempDir::new()?;
+        let config = home.path().join(".config/git-navigator");
+        std::fs::create_dir_all(config.join("backups"))?;
+        std::fs::write(config.join("config.toml"), "[ui]\ncompact = true\n")?;
+        std::fs::write(config.join("backups/git-navigator-v0.1.0"), "old binary")?;
+        std::fs::create_dir_all(home.path().join(".cache/git-navigator"))?;
+        std::fs::write(home.path().join(".cache/git-navigator/files.json"), "[]")?;
+        std::fs::write(
+            home.path().join(".bashrc"),
+            "export EDITOR=vim\neval \"$(git-navigator init bash)\"\n",
+        )?;
+        Ok(home)
+    }
+
+    #[test]
+    fn test_uninstall_dry_run_keeps_everything() -> anyhow::Result<()> {
+        let home = installed_home()?;
+
+        navigator(home.path(), &["uninstall", "--dry-run"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Settings"))
+            .stdout(predicate::str::contains("Backups"))
+            .stdout(predicate::str::contains("Caches"))
+            .stdout(predicate::str::contains(".bashrc:2: eval"));
+        assert!(home
+            .path()
+            .join(".config/git-navigator/config.toml")
+            .exists());
+        assert!(home.path().join(".cache/git-navigator").exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_uninstall_removes_components() -> anyhow::Result<()> {
+        let home = installed_home()?;
+
+        navigator(home.path(), &["uninstall"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("pass --yes to remove everything"));
+
+        navigator(home.path(), &["uninstall", "--yes"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Removed 3 of 3 component(s)."))
+            .stdout(predicate::str::contains(
+                "Delete these shell integration lines",
+            ))
+            .stdout(predicate::str::contains("was not installed by install.sh"));
+        assert!(!home.path().join(".config/git-navigator").exists());
+        assert!(!home.path().join(".cache/git-navigator").exists());
+        // The rc file is only pointed at, and the test binary stays
+        assert!(home.path().join(".bashrc").exists());
+        assert!(assert_cmd::cargo::cargo_bin("git-navigator").exists());
+
+        navigator(home.path(), &["uninstall", "--yes"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Nothing of git-navigator is left"));
+        Ok(())
+    }
+}
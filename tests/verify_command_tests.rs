@@ -0,0 +1,113 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::path::Path;
+use std::process::Command;
+
+mod common;
+use common::repository::*;
+
+fn navigator(repo_path: &Path, cache_home: &Path, args: &[&str]) -> Command {
+    let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+    cmd.args(args)
+        .current_dir(repo_path)
+        .env("XDG_CACHE_HOME", cache_home);
+    cmd
+}
+
+fn configure(repo_path: &Path, content: &str) -> anyhow::Result<()> {
+    let dir = repo_path.join(".git/git-navigator");
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(dir.join("config.toml"), content)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod verify_command_tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_requires_configured_steps() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+
+        navigator(&repo.path, cache.path(), &["verify"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("No verify steps configured"));
+
+        configure(&repo.path, "[verify]\nsteps = [\"fmt\"]\nfmt = \"true\"\n")?;
+        navigator(&repo.path, cache.path(), &["verify", "bench"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("No verify step named 'bench'"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_summarizes_steps() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+        configure(
+            &repo.path,
+            "[verify]\nsteps = [\"fmt\", \"lint\", \"test\"]\nfmt = \"true\"\nlint = \"echo linting {files}\"\ntest = \"exit 3\"\n",
+        )?;
+
+        navigator(&repo.path, cache.path(), &["--plain", "verify"])
+            .assert()
+            .failure()
+            .stdout(predicate::str::contains("linting ."))
+            .stdout(predicate::str::contains("fmt: passed"))
+            .stdout(predicate::str::contains("lint: passed"))
+            .stdout(predicate::str::contains("test: failed (exit status: 3"))
+            .stderr(predicate::str::contains("1 of 3 verify step(s) failed"));
+
+        navigator(&repo.path, cache.path(), &["verify", "fmt", "lint"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("All 2 verify step(s) passed"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_changed_files_only() -> anyhow::Result<()> {
+        let cache = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+        configure(
+            &repo.path,
+            "[verify]\nsteps = [\"test\", \"lint\"]\nlint = \"echo linting {files}\"\ntest = \"exit 1\"\n",
+        )?;
+
+        navigator(
+            &repo.path,
+            cache.path(),
+            &["--plain", "verify", "--changed", "lint"],
+        )
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("lint: skipped (no changed files)"));
+
+        create_file(&repo.path, "changed file.txt", "content")?;
+        navigator(
+            &repo.path,
+            cache.path(),
+            &["--plain", "verify", "--changed", "--fail-fast"],
+        )
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("test: failed"))
+        .stdout(predicate::str::contains(
+            "lint: skipped (an earlier step failed)",
+        ));
+
+        navigator(
+            &repo.path,
+            cache.path(),
+            &["--plain", "verify", "--changed"],
+        )
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("linting changed file.txt"))
+        .stdout(predicate::str::contains("lint: passed"));
+        Ok(())
+    }
+}
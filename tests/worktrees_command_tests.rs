@@ -0,0 +1,83 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::path::Path;
+use std::process::Command;
+
+mod common;
+use common::repository::*;
+
+fn navigator(dir: &Path, cache_home: &Path, args: &[&str]) -> Command {
+    let mut cmd = Command::cargo_bin("git-navigator").unwrap();
+    cmd.args(args)
+        .current_dir(dir)
+        .env("XDG_CACHE_HOME", cache_home);
+    cmd
+}
+
+#[cfg(test)]
+mod worktrees_command_tests {
+    use super::*;
+
+    #[test]
+    fn test_add_enter_and_remove_worktrees_by_index() -> anyhow::Result<()> {
+        let cache_home = tempfile::TempDir::new()?;
+        let elsewhere = tempfile::TempDir::new()?;
+        let repo = setup_test_repo_with_initial_commit()?;
+        run_git(&repo.path, &["branch", "feature"])?;
+
+        // gb numbers feature [1], main [2]
+        navigator(&repo.path, cache_home.path(), &["branches"])
+            .assert()
+            .success();
+        let worktree = elsewhere.path().join("feature");
+        navigator(
+            &repo.path,
+            cache_home.path(),
+            &["worktrees", "add", "1", worktree.to_str().unwrap()],
+        )
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Checked out 'feature' in a new worktree",
+        ))
+        .stdout(predicate::str::contains("'gwt 2' takes you there."));
+        assert!(worktree.join("initial.txt").exists());
+
+        let worktree = worktree.canonicalize()?;
+        navigator(&repo.path, cache_home.path(), &["--plain", "worktrees"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("(main worktree) (current)"))
+            .stdout(predicate::str::contains(format!(
+                "Index 2: {} [feature]",
+                worktree.display()
+            )));
+
+        navigator(&repo.path, cache_home.path(), &["worktrees", "2"])
+            .assert()
+            .success()
+            .stdout(format!("{}\n", worktree.display()));
+
+        navigator(&repo.path, cache_home.path(), &["worktrees", "remove", "1"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("is the main worktree"));
+
+        // Removing works from inside the other worktree too
+        navigator(&worktree, cache_home.path(), &["worktrees", "remove", "2"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Removed worktree"))
+            .stdout(predicate::str::contains(
+                "gets you back to the main worktree",
+            ));
+        assert!(!worktree.exists());
+
+        navigator(&repo.path, cache_home.path(), &["worktrees", "2"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("Worktree index 2 not found"));
+
+        Ok(())
+    }
+}